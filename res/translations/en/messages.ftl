@@ -40,6 +40,9 @@ loading_networking_stack_step_created_successfully = Networking stack created su
 loading_consensus_node_title = Initializing consensus node
 loading_consensus_node_step_creating = Creating consensus node...
 loading_consensus_node_step_created_successfully = Consensus node created successfully
+loading_consensus_node_step_connecting_to_external_node = Connecting to external node...
+loading_consensus_node_step_connected_to_external_node = Connected to external node successfully
+loading_consensus_node_step_external_node_unreachable = External node is unreachable, using embedded node instead
 loading_farmer_title = Instantiating farmer
 loading_farmer_step_initializing = Initializing farms {$index}/{$farms_total}...
 loading_farmer_step_created_successfully = Farmer created successfully
@@ -57,46 +60,234 @@ configuration_node_path_placeholder = Example: {$path}
 configuration_node_path_tooltip = Absolute path where node files will be stored, prepare to dedicate at least 100 GiB of space for it, good quality SSD recommended
 configuration_node_path_button_select = Select
 configuration_node_path_error_doesnt_exist_or_write_permissions = Folder doesn't exist or user is lacking write permissions
+configuration_node_path_error_farm_path_conflict = Node can't use the same folder as one of the farms, or a folder inside of it or containing it
+configuration_node_path_free_space = Free space: {$free_space}
+configuration_node_path_free_space_unknown = Free space: unknown
+configuration_node_path_warning_low_free_space = Less than {$threshold} of free space left, node database grows over time and may run out of space
 configuration_reward_address = Rewards address
 configuration_reward_address_placeholder = Example: {$address}
-configuration_reward_address_tooltip = Use Subwallet or polkadot{"{"}.js{"}"} extension or any other Substrate wallet to create it first (address for any Substrate chain in SS58 format works)
+configuration_reward_address_tooltip = Use Subwallet or polkadot{"{"}.js{"}"} extension or any other Substrate wallet to create it first (address in SS58 format for Subspace or generic Substrate format works)
 configuration_reward_address_button_create_wallet = Create wallet
-configuration_reward_address_error_evm_address = This should be a Substrate (SS58) address (any chain will do), not EVM address
+configuration_reward_address_error_evm_address = This should be a Substrate (SS58) address, not EVM address
+configuration_reward_address_error_wrong_network = This address belongs to a different network (SS58 prefix {$a_prefix}), use address for Subspace (prefix {$b_expected_prefix}) or generic Substrate format (prefix 42)
 configuration_farm = Path to farm {$index} and its size
+configuration_farm_named = Path to farm "{$name}" and its size
+configuration_farm_drag_handle_tooltip = Drag to change order of farms
+configuration_farm_collapse_tooltip = Collapse farm
+configuration_farm_expand_tooltip = Expand farm for editing
+configuration_farm_collapsed_summary = {$path}, size {$size}
+configuration_farm_name_placeholder = Name (optional)
+configuration_farm_name_tooltip = Optional name of the farm to make it easier to distinguish from other farms, for example "NVMe-A"
+configuration_farm_name_accessible_label = Farm name
+configuration_farms_search_placeholder = Search farms by path or name
 configuration_farm_path_placeholder = Example: {$path}
 configuration_farm_path_tooltip = Absolute path where farm files will be stored, any SSD works, high endurance not necessary
+configuration_farm_path_accessible_label = Farm folder
 configuration_farm_path_button_select = Select
+configuration_farm_path_button_select_accessible_label = Select farm folder
+configuration_farm_path_button_recent = Recently selected folders
+configuration_farm_path_checking = Checking folder...
 configuration_farm_path_error_doesnt_exist_or_write_permissions = Folder doesn't exist or user is lacking write permissions
+configuration_farm_path_error_empty = Folder for the farm is not selected
+configuration_farm_path_state_empty = Folder is empty, new farm will be created
+configuration_farm_path_state_existing_farm = Existing farm found in this folder, it will be resumed
+configuration_farm_path_state_unrelated_files = Folder contains files that don't belong to a farm, make sure it is not used for anything else
+configuration_farm_path_warning_removable_or_network_drive = Folder is located on a removable or network drive, farm will stop working and might get corrupted if drive is disconnected
+configuration_farm_path_warning_system_disk = Folder is located on the same disk as operating system or home folder, filling it up with farm data might make the system unstable, consider using a separate disk
+configuration_farm_path_error_duplicate = This folder is already used by another farm
+configuration_farm_path_error_node_path_conflict = Farm can't use the same folder as node, or a folder inside of it or containing it
+configuration_farm_storage_kind_ssd = Detected as SSD
+configuration_farm_storage_kind_hdd = Detected as HDD, plotting and farming on HDDs is much slower than on SSDs
+configuration_farm_storage_kind_unknown = Storage device kind is unknown
 configuration_farm_size_kind_fixed = Fixed size
 configuration_farm_size_kind_free_percentage = % of free disk space
 configuration_farm_fixed_size_placeholder = Example: 4T, 2.5TB, 500GiB, etc.
-configuration_farm_fixed_size_tooltip = Size of the farm in whichever units you prefer, any amount of space above 2 GB works
+configuration_farm_fixed_size_tooltip = Size of the farm in whichever units you prefer, any amount of space above 2 GB works. Both decimal (KB, MB, GB, TB, 1 TB = 10^12 bytes) and binary (KiB, MiB, GiB, TiB, 1 TiB = 2^40 bytes) units are supported
+configuration_farm_fixed_size_accessible_label = Farm size
+configuration_farm_fixed_size_preview = = {$a_bytes} bytes ({$b_decimal}, {$c_binary})
+configuration_farm_sectors = ≈ {$sectors} sectors
+configuration_farm_usable_size = Usable for plotting: {$size}
+configuration_farm_usable_size_warning = Only {$a_size} is usable for plotting, remaining {$b_wasted} is not enough for another sector and will not be used
+configuration_farm_fixed_size_button_max = Max
+configuration_farm_fixed_size_button_max_tooltip = Use all free disk space, except 2 GiB that remain free to avoid errors
+configuration_farm_fixed_size_warning_doesnt_fit = Farm size exceeds available free space on disk, it will be reduced to fit unless farm already occupies that space
+configuration_farm_fixed_size_error_too_small = Farm size is below minimum of {$min_size}
+configuration_farm_size_error_invalid_format = Size is not recognized, use format like 2TB or 500GiB
+configuration_farm_free_percentage_size_error_invalid = Must be a percentage above 0% and up to 100%, for example 90%
 configuration_farm_free_percentage_size_placeholder = Example: 100%, 1.1%, etc.
 configuration_farm_free_percentage_size_tooltip = Percentage of free disk space to occupy by this farm, anything above 0% works, but at least 2 GB of free space should remain on disk to avoid errors
+configuration_farm_free_percentage_size_accessible_label = Farm size as percentage of free disk space
+configuration_farm_free_percentage_size_resolved = Approximately {$size} with currently available free space, farm's own already occupied space is taken into account when starting
+configuration_farm_free_space = Free space: {$free_space}
+configuration_farm_free_space_unknown = Free space: unknown
+configuration_farm_reserve = Keep free on disk:
+configuration_farm_reserve_placeholder = Example: 10GiB (optional)
+configuration_farm_reserve_tooltip = Amount of space that must remain free on disk in addition to the farm, useful for file systems that misbehave when completely full, empty means no reserve
+configuration_farm_reward_address = Reward address:
+configuration_farm_reward_address_placeholder = Global reward address is used if empty
+configuration_farm_reward_address_tooltip = Optional reward address for this farm only, useful when farms on the same machine belong to different people, global reward address is used if empty
+configuration_farm_reward_address_error_invalid = Reward address is not a valid address of this network
+configuration_farm_benchmark_button = Test disk speed
+configuration_farm_benchmark_button_tooltip = Write and read a temporary 256 MiB file in this folder to check whether disk is fast enough for plotting and farming, file is removed afterwards
+configuration_farm_benchmark_running = Testing disk speed...
+configuration_farm_benchmark_result = Sequential write: {NUMBER($a_sequential_write, maximumFractionDigits: 0)} MB/s, sequential read: {NUMBER($b_sequential_read, maximumFractionDigits: 0)} MB/s, random read: {NUMBER($c_random_read, maximumFractionDigits: 0)} MB/s
+configuration_farm_benchmark_result_pass = Disk is fast enough for plotting and farming
+configuration_farm_benchmark_result_warn = Disk might be too slow for plotting and farming, consider using a different disk
+configuration_farm_benchmark_error = Failed to test disk speed: {$error}
 configuration_farm_delete = Delete this farm
+configuration_farm_delete_confirmation = Delete this farm?
+configuration_farm_delete_confirmation_button_cancel = Cancel
+configuration_farm_delete_confirmation_button_delete = Delete
+configuration_farm_delete_confirmation_delete_data = Also delete farm data from disk, this will free {$size}
+configuration_farm_delete_confirmation_delete_data_in_use = Farm is in use, disable it on the running screen first to delete its data
+configuration_farm_delete_data_progress = Deleting farm data…
+configuration_farm_delete_data_error = Failed to delete farm data: {$error}
+configuration_farms_summary = Total size of all farms: {$size}
+configuration_farms_summary_with_free_space = Total size of all farms: {$a_size} (free space on all disks: {$b_free_space})
 configuration_advanced = Advanced configuration
 configuration_advanced_farmer = Farmer configuration
 configuration_advanced_farmer_reduce_plotting_cpu_load = Reduce plotting CPU load
 configuration_advanced_farmer_reduce_plotting_cpu_load_tooltip = Initial plotting uses all CPU cores by default, while with this option it will start using half of the cores like replotting, improving system responsiveness for other tasks
+configuration_advanced_farmer_plotting_threads = Plotting CPU threads
+configuration_advanced_farmer_plotting_threads_tooltip = Number of CPU threads to use for plotting, lower values leave more CPU for other applications, all {$cpu_threads} threads are used by default
+configuration_advanced_farmer_sector_encoding_concurrency = Sectors encoded concurrently
+configuration_advanced_farmer_sector_encoding_concurrency_tooltip = Number of sectors plotted at the same time, higher values may speed up plotting on powerful machines at the cost of more RAM, lower values reduce RAM usage
+configuration_advanced_farmer_sector_encoding_concurrency_memory = ~{$ram} of RAM
+configuration_advanced_farmer_auditing_threads = Auditing CPU threads
+configuration_advanced_farmer_auditing_threads_tooltip = Total number of CPU threads used for auditing and proving, split evenly between farms with at least one thread per farm, lower values reduce disk and CPU saturation with many farms at the cost of auditing latency, each farm uses its own thread pool sized for the CPU when all {$cpu_threads} threads are selected
+configuration_advanced_farmer_auditing_threads_error_too_few = Fewer threads than farms, each of {$farms} farms needs at least one thread
+configuration_advanced_farmer_piece_cache_size = Piece cache size
+configuration_advanced_farmer_piece_cache_size_tooltip = Total size of piece cache shared by all farms, for example 10 GiB, larger cache speeds up syncing and plotting at the cost of space available for plotting, minimal cache is used if empty
+configuration_advanced_farmer_piece_cache_size_low = Piece cache this small may slow down syncing and plotting
+configuration_advanced_farmer_piece_cache_path = Piece cache folder
+configuration_advanced_farmer_piece_cache_path_placeholder = Stored within farms
+configuration_advanced_farmer_piece_cache_path_tooltip = Optional folder for piece cache, for example on a fast SSD next to the node while farms are on HDDs, piece cache is stored within farms if not selected
+configuration_advanced_farmer_piece_cache_path_button_select = Select
+configuration_advanced_farmer_piece_cache_path_button_clear = Store piece cache within farms
+configuration_advanced_farmer_piece_cache_path_error_farm_path_conflict = Piece cache can't use the same folder as one of the farms, or a folder inside of it or containing it
+configuration_advanced_farmer_reward_notifications = Reward notifications
+configuration_advanced_farmer_reward_notifications_tooltip = Show desktop notifications when rewards are received or reward signing fails, notifications are shown at most once a minute
+configuration_advanced_farmer_plotting_notifications = Plotting notifications
+configuration_advanced_farmer_plotting_notifications_tooltip = Show desktop notification once initial plotting of all farms is finished
+configuration_advanced_farmer_farm_health_notifications = Farm health notifications
+configuration_advanced_farmer_farm_health_notifications_tooltip = Show desktop notification when one of the farms is consistently auditing slower than slot duration and missing rewards
+configuration_advanced_application = Application configuration
+configuration_advanced_application_tray_icon = Tray icon
+configuration_advanced_application_tray_icon_tooltip = Show icon in system tray, not all desktop environments support it well, requires restart
+configuration_advanced_application_minimize_to_tray = Minimize to tray on close
+configuration_advanced_application_minimize_to_tray_tooltip = Hide window to tray instead of exiting when it is closed, farming keeps running in the background, requires restart
+configuration_advanced_application_theme = Theme
+configuration_advanced_application_theme_tooltip = Color scheme of the application, applied immediately
+configuration_advanced_application_theme_system = System
+configuration_advanced_application_theme_light = Light
+configuration_advanced_application_theme_dark = Dark
+configuration_advanced_application_theme_high_contrast = High contrast
+configuration_advanced_application_language = Language
+configuration_advanced_application_language_tooltip = Language of user interface, system language is used if it is not available, requires restart
+configuration_advanced_application_language_system = System
+configuration_advanced_application_log_level = Log level
+configuration_advanced_application_log_level_tooltip = How detailed logs are, applied immediately after saving without restart
+configuration_advanced_application_log_level_error = Error
+configuration_advanced_application_log_level_warn = Warning
+configuration_advanced_application_log_level_info = Info
+configuration_advanced_application_log_level_debug = Debug
+configuration_advanced_application_log_level_trace = Trace
+configuration_advanced_application_log_level_verbose_warning = Debug and trace levels produce a lot of logs and may affect performance, revert to info once the issue is diagnosed
+configuration_advanced_application_offline_mode = Offline mode
+configuration_advanced_application_offline_mode_tooltip = Suppress outbound connections that are not necessary for farming, like new version checks, system clock checks and node telemetry, node and farmer networking keeps working
+configuration_advanced_application_update_checks = Check for new versions
+configuration_advanced_application_update_checks_tooltip = Periodically check GitHub for new releases, disable on metered connections or air-gapped setups, applied immediately after saving
+configuration_advanced_application_update_check_interval = Check frequency
+configuration_advanced_application_update_check_interval_hourly = Hourly
+configuration_advanced_application_update_check_interval_daily = Daily
+configuration_advanced_application_update_check_interval_weekly = Weekly
+configuration_advanced_application_update_auto_download = Download new versions automatically
+configuration_advanced_application_update_auto_download_tooltip = Download installer of new version into downloads folder instead of only showing a notification, it still needs to be installed manually
+configuration_advanced_application_versions = Installed version: {$a_installed}, latest version: {$b_latest}
+configuration_advanced_application_versions_latest_unknown = Installed version: {$installed}, latest version is not known yet
 configuration_advanced_network = Network configuration
 configuration_advanced_network_default_port_number_tooltip = Default port number is {$port}
 configuration_advanced_network_substrate_port = Substrate (blockchain) P2P port (TCP):
 configuration_advanced_network_subspace_port = Subspace (DSN) P2P port (TCP):
 configuration_advanced_network_faster_networking = Faster networking:
 configuration_advanced_network_faster_networking_tooltip = By default networking is optimized for consumer routers, but if you have more powerful setup, faster networking may improve sync speed and other processes
+configuration_advanced_network_external_address = External address
+configuration_advanced_network_external_address_tooltip = Optional DSN address advertised to other peers in multiaddr format, useful when port is forwarded on the router
+configuration_advanced_network_node_rpc_url = External node RPC URL (optional):
+configuration_advanced_network_node_rpc_url_tooltip = WebSocket RPC URL of your own node to farm against instead of embedded node, leave empty to use embedded node
+configuration_advanced_network_node_rpc_url_button_check = Check
+configuration_advanced_network_node_rpc_url_connected = Connected to external node successfully
+configuration_advanced_network_node_rpc_url_failed = Failed to connect to external node: {$error}
+configuration_advanced_network_metrics = Prometheus metrics:
+configuration_advanced_network_metrics_tooltip = Expose node and farmer metrics over HTTP in Prometheus format
+configuration_advanced_network_metrics_address_tooltip = Address to serve metrics on, for example 127.0.0.1:9616
+configuration_advanced_network_node_rpc = Node RPC:
+configuration_advanced_network_node_rpc_tooltip = Expose embedded node's RPC on custom address for external tools, node RPC is only available on an internal local port otherwise
+configuration_advanced_network_node_rpc_address_tooltip = Address to serve node RPC on, for example 127.0.0.1:9944
+configuration_advanced_network_node_rpc_warning_public = Node RPC will be reachable from other machines, anyone who can reach this address will be able to query your node. Only use non-local address on trusted networks or behind a firewall.
+configuration_advanced_network_control_api = Local control API:
+configuration_advanced_network_control_api_tooltip = Allow scripts on this computer to check status and pause or resume farming through a local socket (named pipe on Windows), only accessible to current user
 configuration_button_add_farm = Add farm
+configuration_button_add_farm_tooltip = Add one more farm (Ctrl+N)
+configuration_button_suggest_farms = Auto-configure farms
+configuration_button_suggest_farms_tooltip = Find disks with enough free space (except system disk and removable drives) and add a farm using 90% of free space on each of them, farms can be adjusted or removed before saving
+configuration_suggest_farms_none_found = No suitable disks found, farms can be added manually
+configuration_button_import_config = Import config
+configuration_button_import_config_tooltip = Import node path, reward address, network configuration and farms from a configuration file, for example from another machine
+configuration_button_export_config = Export config
+configuration_button_export_config_tooltip = Export current configuration to a file, for example to back it up or to use on another machine, file with .toml extension is written in TOML format and in JSON format otherwise
 configuration_button_help = Help
 configuration_button_cancel = Cancel
 configuration_button_back = Back
 configuration_button_save = Save
 configuration_button_start = Start
+configuration_plotting_memory_warning = Plotting is estimated to need ~{$a_estimated} of RAM, which is too much for {$b_available} currently available on this machine and may cause the application to be killed by the operating system
+configuration_plotting_memory_warning_reduce = Reducing sectors encoded concurrently to {$a_concurrency} will lower usage to ~{$b_memory}
+configuration_plotting_memory_warning_button_cancel = Cancel
+configuration_plotting_memory_warning_button_continue = Continue anyway
+configuration_plotting_memory_warning_button_reduce = Reduce and continue
 configuration_dialog_button_select = Select
 configuration_dialog_button_cancel = Cancel
+configuration_dialog_button_import = Import
+configuration_dialog_button_export = Export
+configuration_dialog_filter_json = JSON files
+configuration_dialog_filter_toml = TOML files
+configuration_import_config_error_not_found = Configuration file not found
+configuration_import_config_error = Failed to import configuration: {$error}
+configuration_export_config_error_incomplete = Configuration is not fully loaded yet, try again
+configuration_export_config_error = Failed to export configuration: {$error}
 
 running_title = Running
 running_node_title = {$chain_name} consensus node
 running_node_title_tooltip = Click to open in file manager
+running_node_button_copy_peer_id = Copy node peer ID
+running_node_button_copy_genesis_hash = Copy genesis hash
+running_node_genesis_hash = Genesis hash: {$genesis_hash}
+running_node_clock_offset_warning_seconds =
+    {$a_direction ->
+        [ahead] System clock is {NUMBER($b_offset, maximumFractionDigits: 1)} seconds ahead of accurate time
+        *[behind] System clock is {NUMBER($b_offset, maximumFractionDigits: 1)} seconds behind accurate time
+    }, this may prevent node from syncing and farmer from receiving rewards. Enable automatic time synchronization (NTP) in system settings
+running_node_clock_offset_warning_minutes =
+    {$a_direction ->
+        [ahead] System clock is {NUMBER($b_offset, maximumFractionDigits: 1)} minutes ahead of accurate time
+        *[behind] System clock is {NUMBER($b_offset, maximumFractionDigits: 1)} minutes behind accurate time
+    }, this may prevent node from syncing and farmer from receiving rewards. Enable automatic time synchronization (NTP) in system settings
 running_node_free_disk_space_tooltip = Free disk space: {$size} remaining
+running_node_peers =
+    {$peers_count ->
+        [one] 1 peer
+        *[other] {$peers_count} peers
+    }
+running_node_peers_tooltip = Peers the node is connected to, click to see the list
+running_node_peers_empty = Not connected to any peers
+running_node_peer = {$a_peer_id}
+    {$b_role ->
+        [authority] Authority
+        [light] Light client
+        *[full] Full node
+    }, best block #{$c_best_block_number}, {$d_address}
+running_node_peer_address_unknown = address unknown
 running_node_status_connecting = Connecting to the network, best block #{$block_number}
 running_node_status_syncing_speed_no_eta = , {NUMBER($blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} blocks/s
 running_node_status_syncing_speed_hours_eta = , {NUMBER($a_blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} blocks/s (~{NUMBER($b_hours_remaining, minimumFractionDigits: 2, maximumFractionDigits: 2)} hours remaining)
@@ -112,8 +303,16 @@ running_node_status_synced = Synced, best block #{$best_block_number}
 running_farmer_title = Farmer
 running_farmer_button_expand_details = Expand details about each farm
 running_farmer_button_pause_plotting = Pause plotting/replotting, note that currently encoding sectors will not be interrupted
-running_farmer_account_balance_tooltip = Total account balance and coins farmed since application started, click to see details in Astral
+running_farmer_button_pause_farming = Pause farming, neither rewards will be signed nor sectors plotted until farming is resumed
+running_farmer_farming_paused = Farming paused
+running_farmer_slow_auditing_warning = Auditing is too slow and rewards are being missed on farms: {$farms}, check disk health
+running_farmer_account_balance_tooltip = Total balance of all reward addresses and coins farmed since application started, click to see details of the global reward address in Astral
+running_farmer_button_copy_reward_address = Copy reward address
+running_farmer_network_space = Network space (estimate): {$a_network_space}, your share: {NUMBER($b_share, maximumFractionDigits: 4)}%
+running_farmer_network_space_tooltip = Total space pledged to the network is estimated from the solution range of the latest block, your share of it is based on space plotted so far and is approximately the chance to produce each block
 running_farmer_piece_cache_sync = Piece cache sync {NUMBER($percentage, minimumFractionDigits: 2, maximumFractionDigits: 2)}%
+running_farmer_piece_cache_sync_pieces = {$a_cached_pieces}/{$b_total_pieces} pieces cached
+running_farmer_piece_cache_sync_description = Piece cache is being filled with pieces downloaded from the network, plotting is not stalled, but may be slower until this is done
 running_farmer_next_reward_estimate =
     Next reward estimate: {$eta_string ->
         [any_time_now] any time now
@@ -123,19 +322,85 @@ running_farmer_next_reward_estimate =
         [more_than_a_week] more than a week
         *[unknown] unknown
     }
+running_farmer_rewards_title = Rewards
+running_farmer_rewards_mode_cumulative = Cumulative
+running_farmer_rewards_mode_per_interval = Per interval
+running_farmer_rewards_cumulative_tooltip = Total rewards earned over time
+running_farmer_rewards_per_interval_tooltip = Rewards earned per {$unit ->
+        [hour] hour
+        [day] day
+        *[week] week
+    }
+running_farmer_stats_title = Lifetime statistics
+running_farmer_stats_uptime = Uptime: {$a_days}d {$b_hours}h {$c_minutes}m
+running_farmer_stats_rewards_count = Rewards won (blocks and votes): {$rewards_count}
+running_farmer_stats_rewards_total = Total rewards: {NUMBER($a_amount, minimumFractionDigits: 2, maximumFractionDigits: 2)} {$b_token_symbol}
+running_farmer_stats_button_reset = Reset
+running_farmer_stats_button_reset_tooltip = Reset lifetime statistics to zero
+running_farmer_stats_reset_confirmation = Reset lifetime statistics? This can't be undone.
+running_farmer_stats_reset_confirmation_button_cancel = Cancel
+running_farmer_stats_reset_confirmation_button_reset = Reset
+running_farmer_farms_sort_by = Sort farms by:
+running_farmer_farms_sort_by_default = Default
+running_farmer_farms_sort_by_default_tooltip = Order in which farms are specified in configuration
+running_farmer_farms_sort_by_size = Size
+running_farmer_farms_sort_by_size_tooltip = Largest farms first
+running_farmer_farms_sort_by_path = Path
+running_farmer_farms_sort_by_status = Status
+running_farmer_farms_sort_by_status_tooltip = Farms with errors first, then plotting, farming, paused and stopped farms
+running_farmer_farms_compact_view = Compact view
+running_farmer_farms_compact_view_tooltip = Show farms as a grid of small tiles colored by state, click on a tile to see farm details
 running_farmer_farm_tooltip = Click to open in file manager
+running_farmer_farm_enabled_tooltip = Enable or disable this farm, disabled farm is stopped and will not be opened on next start
 running_farmer_farm_reward_signatures_tooltip = {$successful_signatures}/{$total_signatures} successful reward signatures, expand farm details to see more information
 running_farmer_farm_auditing_performance_tooltip = Auditing performance: average time {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}s, time limit {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
 running_farmer_farm_proving_performance_tooltip = Proving performance: average time {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}s, time limit {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
+running_farmer_farm_auditing_latency = Auditing p50/p95/max: {NUMBER($a_p50, minimumFractionDigits: 2, maximumFractionDigits: 2)}s/{NUMBER($b_p95, minimumFractionDigits: 2, maximumFractionDigits: 2)}s/{NUMBER($c_max, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
+running_farmer_farm_auditing_latency_tooltip = Auditing time percentiles over the last 10 minutes, time limit {NUMBER($time_limit, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
+running_farmer_farm_auditing_latency_slow_tooltip = Auditing takes longer than slot duration, farm is missing rewards, check disk health
+running_farmer_farm_proving_latency = Proving p50/p95/max: {NUMBER($a_p50, minimumFractionDigits: 2, maximumFractionDigits: 2)}s/{NUMBER($b_p95, minimumFractionDigits: 2, maximumFractionDigits: 2)}s/{NUMBER($c_max, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
+running_farmer_farm_proving_latency_tooltip = Proving time percentiles over the last 100 proofs, time limit {NUMBER($time_limit, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
 running_farmer_farm_non_fatal_error_tooltip = Non-fatal farming error happened and was recovered, see logs for more details: {$error}
 running_farmer_farm_crashed = Farm crashed: {$error}
+running_farmer_farm_disk_full = Farm stopped because disk is full. Free up some space on the disk or reduce allocated space in configuration, then resume the farm
+running_farmer_farm_directory_inaccessible = Farm stopped because its directory is not accessible anymore. Make sure the disk is connected
+running_farmer_farm_directory_accessible = Farm directory is accessible again, reconnect to continue farming
+running_farmer_farm_button_resume = Resume
+running_farmer_farm_button_reconnect = Reconnect
+running_farmer_farm_button_resume_tooltip = Open farm again and continue farming and plotting
+running_farmer_farm_resize_button_tooltip = Increase farm size
+running_farmer_farm_resize_title = Increase farm size
+running_farmer_farm_resize_placeholder = New size, for example 4T, 2.5TB, 500GiB, etc.
+running_farmer_farm_resize_apply = Apply
+running_farmer_farm_resize_error_invalid = Invalid size
+running_farmer_farm_resize_error_shrink = Farm size can only be increased, shrinking farm is not supported, create a new farm with a smaller size instead
+running_farmer_farm_resize_error_too_small = Farm size must be at least {$min_size}
+running_farmer_farm_resize_error_doesnt_fit = Not enough free space on disk, farm can be increased by at most {$free_space}
+running_farmer_farm_disabled = Disabled
+running_farmer_farm_enabled_restart_needed = Enabled, restart is needed for farm to start
+running_farmer_farm_plotted_sectors = {$a_plotted_sectors}/{$b_total_sectors} sectors plotted
+running_farmer_farm_tile_progress = {NUMBER($progress, maximumFractionDigits: 0)}%
+running_farmer_farm_tile_status =
+    {$status ->
+        [error] Stopped with an error
+        [plotting] Plotting
+        [paused] Paused
+        [stopped] Stopped
+        *[farming] Farming
+    }
 running_farmer_farm_plotting_speed =  ({NUMBER($a_sector_time, minimumFractionDigits: 2, maximumFractionDigits: 2)} m/sector, {NUMBER($b_sectors_per_hour, minimumFractionDigits: 2, maximumFractionDigits: 2)} sectors/h)
+running_farmer_farm_plotting_eta_calculating = ETA: calculating…
+running_farmer_farm_plotting_eta_hours = ETA: {NUMBER($hours, minimumFractionDigits: 1, maximumFractionDigits: 1)} h
+running_farmer_farm_plotting_eta_minutes = ETA: {NUMBER($minutes, minimumFractionDigits: 0, maximumFractionDigits: 0)} min
+running_farmer_farm_disk_io = Disk: {$a_read}/s read, {$b_write}/s write, {$c_iops} IOPS
+running_farmer_farm_disk_io_tooltip = Average I/O of the disk partition farm is located on over the last 10 seconds, includes I/O of other farms and applications on the same partition
 running_farmer_farm_plotting_initial =
     {$pausing_state ->
         [pausing] Pausing initial plotting
         [paused] Paused initial plotting
         *[no] Initial plotting
     } {NUMBER($b_progress, minimumFractionDigits: 2, maximumFractionDigits: 2)}%{$plotting_speed}, {$farming ->
+        [paused] farming paused
         [yes] farming
         *[no] not farming
     }
@@ -145,10 +410,12 @@ running_farmer_farm_replotting =
         [paused] Paused initial plotting
         *[default] Replotting
     } {NUMBER($b_progress, minimumFractionDigits: 2, maximumFractionDigits: 2)}%{$plotting_speed}, {$farming ->
+        [paused] farming paused
         [yes] farming
         *[no] not farming
     }
 running_farmer_farm_farming = Farming
+running_farmer_farm_farming_paused = Farming paused
 running_farmer_farm_waiting_for_node_to_sync = Waiting for node to sync
 running_farmer_farm_sector = Sector {$sector_index}
 running_farmer_farm_sector_up_to_date = Sector {$sector_index}: up to date
@@ -161,6 +428,14 @@ running_farmer_farm_sector_writing = Sector {$sector_index}: writing
 
 shutting_down_title = Shutting down
 shutting_down_description = This may take a couple of seconds to a few minutes depending on what application is doing
+shutting_down_step_stopping = Stopping…
+shutting_down_step_stopping_farmer = Stopping farmer, flushing plotted sectors to disk…
+shutting_down_step_stopping_node = Stopping node, flushing database to disk…
+shutting_down_step_stopping_networking = Stopping networking…
+shutting_down_button_force_quit = Force quit
+shutting_down_button_force_quit_tooltip = Quit without waiting for shutdown to complete, data may not be fully written to disk
+restarting_services_title = Restarting node and farmer
+restarting_services_description = Node and farmer are stopped and started again with configuration from disk, application keeps running
 
 stopped_title = Stopped
 stopped_message = Stopped 🛑
@@ -168,43 +443,189 @@ stopped_message_with_error = Stopped with error: {$error}
 stopped_button_show_logs = Show logs
 stopped_button_help_from_community = Help from community
 
+startup_retry_title = Retrying startup
+startup_retry_message = Failed to start: {$error}
+startup_retry_countdown = Attempt {$attempt} failed, retrying in {$seconds} s
+startup_retry_button_retry_now = Retry now
+startup_retry_button_cancel = Cancel
+farm_mismatches_dialog_title = Some farms don't match configuration
+farm_mismatches_dialog_description = Farms below would fail to start as configured, this usually happens after disks were moved around. Choose what to do with each of them before continuing.
+farm_mismatches_dialog_missing_plot = Farm was not found in this folder, disk with it might not be connected
+farm_mismatches_dialog_unexpected_plot = Folder contains a different farm than before
+farm_mismatches_dialog_unexpected_plot_different_chain = Folder contains a farm of a different network
+farm_mismatches_dialog_size_mismatch = Configured size {$configured_size} is smaller than size of the farm on disk {$farm_size}, shrinking farm is not supported
+farm_mismatches_dialog_resolution_skip = Skip (disable farm for now)
+farm_mismatches_dialog_resolution_replot = Delete existing data and plot again
+farm_mismatches_dialog_resolution_replot_missing = Plot new farm in this folder
+farm_mismatches_dialog_resolution_fix_missing = Remove farm from configuration
+farm_mismatches_dialog_resolution_fix_unexpected = Use farm found in this folder
+farm_mismatches_dialog_resolution_fix_size = Use size of the farm on disk
+farm_mismatches_dialog_button_continue = Continue
+
 error_title = Error
 error_message = Error: {$error}
 error_message_failed_to_send_config_to_backend = Failed to send config to backend: {$error}
 error_message_failed_to_send_pause_plotting_to_backend = Failed to send pause plotting to backend: {$error}
+error_message_failed_to_send_pause_farming_to_backend = Failed to send pause farming to backend: {$error}
+error_message_failed_to_send_stop_farm_to_backend = Failed to send stop farm to backend: {$error}
+error_message_failed_to_send_start_farm_to_backend = Failed to send start farm to backend: {$error}
+error_message_failed_to_send_restart_farm_to_backend = Failed to send restart farm to backend: {$error}
+error_message_failed_to_send_resize_farm_to_backend = Failed to send resize farm to backend: {$error}
+error_message_failed_to_send_startup_retry_to_backend = Failed to send startup retry to backend: {$error}
+error_message_failed_to_send_farm_mismatch_resolutions_to_backend = Failed to send farm mismatch resolutions to backend: {$error}
+error_message_failed_to_send_restart_services_to_backend = Failed to send restart of node and farmer to backend: {$error}
 error_button_show_logs = Show logs
 error_button_help_from_community = Help from community
 
 new_version_available = Version {$version} available 🎉
-new_version_available_button_open = Open releases page
+new_version_available_button_open = Show release notes of the new version
+new_version_downloaded = Version {$version} downloaded 🎉
+new_version_downloaded_button_open = Show release notes and downloaded installer of the new version
+new_version_dialog_title = What's new in version {$version}
+new_version_dialog_no_release_notes = Release notes are not available, see release page for details
+new_version_dialog_button_open_installer_folder = Open installer folder
+new_version_dialog_button_open_release_page = Open release page
+new_version_dialog_button_close = Close
+offline_mode_indicator = Offline mode
+offline_mode_indicator_tooltip = New version checks, system clock checks and node telemetry are disabled, offline mode can be turned off in configuration
+profile_indicator = Profile: {$name}
+profile_indicator_tooltip = Node and farmer use configuration of this profile, profiles can be managed from the main menu
 
+main_menu_log_viewer = View logs
 main_menu_show_logs = Show logs in file manager
+main_menu_event_log = View event log
+main_menu_profiles = Configuration profiles
+main_menu_export_diagnostics = Export diagnostics
 main_menu_change_configuration = Change configuration
+main_menu_restart_services = Restart node and farmer
 main_menu_share_feedback = Share feedback
 main_menu_about = About
 main_menu_exit = Exit
 
+log_viewer_title = Logs
+log_viewer_level = Level
+log_viewer_level_error = Errors only
+log_viewer_level_warn = Warnings and errors
+log_viewer_level_info = Info and above
+log_viewer_level_debug = Debug and above
+log_viewer_level_trace = Everything
+log_viewer_pause_tooltip = Pause to stop new lines from appearing and scrolling
+log_viewer_resume_tooltip = Resume showing new lines
+log_viewer_button_copy_all = Copy all
+log_viewer_button_open_log_file = Open log file
+
+event_log_title = Event log
+event_log_filter = Show
+event_log_filter_all = All events
+event_log_filter_node = Node
+event_log_filter_farms = Farms
+event_log_filter_rewards = Rewards
+event_log_filter_errors = Errors only
+event_log_button_open_event_log_file = Open event log file
+event_log_empty = No events recorded yet
+event_log_event_services_started = Node and farmer started
+event_log_event_services_stopped = Node and farmer stopped
+event_log_event_services_stopped_with_error = Node and farmer stopped with an error: {$error}
+event_log_event_services_restarting = Restarting node and farmer
+event_log_event_profile_switched = Switched to configuration profile {$profile}
+event_log_event_startup_failed = Startup attempt {$a_attempt} failed: {$b_error}
+event_log_event_irrecoverable_error = Irrecoverable error: {$error}
+event_log_event_node_synced = Node is synced
+event_log_event_node_syncing = Node started syncing
+event_log_event_node_disconnected = Node lost connection to all peers
+event_log_event_node_reconnected = Node reconnected to peers
+event_log_event_plotting_started = Plotting started on farm {$farm}
+event_log_event_plotting_finished = Plotting finished on farm {$farm}
+event_log_event_farm_error = Farm {$a_farm} failed: {$b_error}
+event_log_event_reward = Reward of {NUMBER($a_amount, minimumFractionDigits: 2, maximumFractionDigits: 2)} {$b_token_symbol} received
+
+diagnostics_title = Export diagnostics
+diagnostics_description = Diagnostics bundle contains configuration, recent logs and basic system information in a single file that can be attached to a bug report or shared with support
+diagnostics_include_sensitive = Include reward address and full paths
+diagnostics_include_sensitive_tooltip = By default reward address is removed and home directory is replaced with ~ in every file of the bundle
+diagnostics_button_export = Export…
+diagnostics_export_success = Diagnostics saved to {$path}
+diagnostics_export_error = Failed to export diagnostics: {$error}
+diagnostics_dialog_button_save = Save
+diagnostics_dialog_button_cancel = Cancel
+diagnostics_dialog_filter = Diagnostics bundle
+
+profiles_title = Configuration profiles
+profiles_description = Each profile has its own configuration with node location, network, reward address and farms. Switching profile stops node and farmer and starts them again with configuration of the selected profile, new profiles are configured from scratch.
+profiles_active_profile = {$name} (active)
+profiles_button_switch = Switch
+profiles_button_delete = Delete
+profiles_button_create = Create
+profiles_new_profile_name_placeholder = New profile name, for example testnet
+profiles_new_profile_name_tooltip = Up to 32 latin letters, digits, "-" and "_", must be different from names of existing profiles
+profiles_error = Error: {$error}
+profiles_switch_confirmation = Switch to profile {$name}?
+profiles_switch_confirmation_details = Node and farmer will be stopped and started again with configuration of this profile
+profiles_switch_confirmation_button_switch = Switch
+profiles_delete_confirmation = Delete profile {$name}?
+profiles_delete_confirmation_details = Configuration of this profile will be deleted, node and farms data stay on disk
+profiles_delete_confirmation_button_delete = Delete
+profiles_confirmation_button_cancel = Cancel
+
 status_bar_message_configuration_is_invalid = Configuration is invalid: {$error}
 status_bar_message_restart_is_needed_for_configuration = Application restart is needed for configuration changes to take effect
+status_bar_message_services_restart_is_needed_for_configuration = Node and farmer restart is needed for configuration changes to take effect
 status_bar_message_failed_to_save_configuration = Failed to save configuration changes: {$error}
 status_bar_message_restarted_after_crash = Space Acres automatically restarted after crash, check application and system logs for details
 status_bar_button_restart = Restart
+status_bar_button_restart_services = Restart node and farmer
+status_bar_button_restart_services_tooltip = Apply configuration by restarting node and farmer only, application keeps running
 status_bar_button_ok = Ok
+copy_button_copied = Copied to clipboard
+
+toast_configuration_saved = Configuration saved
+toast_configuration_imported = Configuration imported, review it and save to apply
+toast_configuration_exported = Configuration exported to {$path}
+toast_suggested_farms_added =
+    {$count ->
+        [one] Added 1 suggested farm
+        *[other] Added {$count} suggested farms
+    }
+toast_failed_to_open = Failed to open: {$error}
+toast_profile_created = Profile {$name} created
+toast_profile_deleted = Profile {$name} deleted
 
 about_system_information =
     Config directory: {$config_directory}
     Data directory (including logs): {$data_directory}
 
 tray_icon_open = Open
-tray_icon_close = Close
+tray_icon_pause = Pause farming
+tray_icon_resume = Resume farming
+tray_icon_tooltip_loading = Space Acres
+tray_icon_tooltip_syncing = Space Acres: syncing
+tray_icon_tooltip_plotting = Space Acres: plotting
+tray_icon_tooltip_farming = Space Acres: farming
+tray_icon_tooltip_paused = Space Acres: farming is paused
+tray_icon_tooltip_error = Space Acres: error
+tray_icon_quit = Quit
 
 notification_app_minimized_to_tray = Space Acres was minimized to tray
     .body = You can open it again or exit completely using tray icon menu
+notification_already_running = Space Acres is already running
+    .body = Only one instance can run at a time, use the window of the running instance or its tray icon
 notification_stopped_with_error = Space Acres stopped with error
     .body = An error happened and requires user intervention to resolve
 notification_farm_error = One of the farms failed in Space Acres
     .body = An error happened and requires user intervention to resolve
-notification_signed_reward_successfully = Signed new reward successfully 🥳
-    .body = Thank you for securing the network 🙌
+notification_farm_disk_full = Disk of one of the farms is full
+    .body = Farm was stopped, free up some space on the disk to resume it
+notification_farm_directory_inaccessible = Directory of one of the farms is not accessible
+    .body = Farm was stopped, make sure the disk is connected
 notification_missed_reward = Reward signing failed 😞
     .body = This is unfortunate, but there will be another chance soon
+notification_reward_received = Received {NUMBER($a_amount, minimumFractionDigits: 2, maximumFractionDigits: 2)} {$b_token_symbol} 🥳
+notification_reward_received_body = {$a_rewards_count ->
+        [1] Reward signed by farm {$b_farm}, thank you for securing the network 🙌
+        *[other] {$a_rewards_count} rewards received, the last one signed by farm {$b_farm}
+    }
+notification_reward_received_unknown_farm = unknown
+notification_initial_plotting_finished = Initial plotting finished 🎉
+notification_initial_plotting_finished_body = {$a_space_plotted} plotted in {NUMBER($b_hours, minimumFractionDigits: 1, maximumFractionDigits: 1)} h, all farms are farming now
+notification_slow_auditing = Farm is auditing too slowly ⚠️
+notification_slow_auditing_body = Farm {$farm} takes longer than slot duration to audit and is missing rewards, check disk health
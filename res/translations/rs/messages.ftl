@@ -41,6 +41,9 @@ loading_networking_stack_step_created_successfully = Mrežni stek je uspešno kr
 loading_consensus_node_title = Inicijalizacija konsenzus čvora
 loading_consensus_node_step_creating = Kreiranje konsenzus čvora...
 loading_consensus_node_step_created_successfully = Konsenzus čvor uspešno kreiran
+loading_consensus_node_step_connecting_to_external_node = Povezivanje sa spoljnim čvorom...
+loading_consensus_node_step_connected_to_external_node = Uspešno povezan sa spoljnim čvorom
+loading_consensus_node_step_external_node_unreachable = Spoljni čvor nije dostupan, koristi se ugrađeni čvor
 loading_farmer_title = Inicijalizacija farmera
 loading_farmer_step_initializing = Inicijalizacija farmi {$index}/{$farms_total}...
 loading_farmer_step_created_successfully = Farmer uspešno kreiran
@@ -58,46 +61,235 @@ configuration_node_path_placeholder = Primer: {$path}
 configuration_node_path_tooltip = Apsolutna putanja gde će se čuvati datoteke čvora, pripremite najmanje 100 GiB prostora za to, preporučuje se kvalitetan SSD
 configuration_node_path_button_select = Izaberi
 configuration_node_path_error_doesnt_exist_or_write_permissions = Folder ne postoji ili korisnik nema dozvolu za pisanje
+configuration_node_path_error_farm_path_conflict = Čvor ne može koristiti isti folder kao neka od farmi, niti folder unutar nje ili koji je sadrži
+configuration_node_path_free_space = Slobodan prostor: {$free_space}
+configuration_node_path_free_space_unknown = Slobodan prostor: nepoznat
+configuration_node_path_warning_low_free_space = Ostalo je manje od {$threshold} slobodnog prostora, baza podataka čvora raste vremenom i može ostati bez prostora
 configuration_reward_address = Adresa za nagrade
 configuration_reward_address_placeholder = Primer: {$address}
-configuration_reward_address_tooltip = Koristite Subwallet ili polkadot.js ekstenziju ili bilo koji drugi Substrate novčanik za njegovo kreiranje (adresa za bilo koji Substrate lanac u SS58 formatu funkcioniše)
+configuration_reward_address_tooltip = Koristite Subwallet ili polkadot.js ekstenziju ili bilo koji drugi Substrate novčanik za njegovo kreiranje (adresa u SS58 formatu za Subspace ili generički Substrate format funkcioniše)
 configuration_reward_address_button_create_wallet = Kreiraj novčanik
-configuration_reward_address_error_evm_address = Ovo bi trebala biti Substrate (SS58) adresa (bilo koji lanac će raditi), a ne EVM adresa
+configuration_reward_address_error_evm_address = Ovo bi trebala biti Substrate (SS58) adresa, a ne EVM adresa
+configuration_reward_address_error_wrong_network = Ova adresa pripada drugoj mreži (SS58 prefiks {$a_prefix}), koristite adresu za Subspace (prefiks {$b_expected_prefix}) ili generički Substrate format (prefiks 42)
 configuration_farm = Putanja do farme {$index} i njena veličina
+configuration_farm_named = Putanja do farme "{$name}" i njena veličina
+configuration_farm_drag_handle_tooltip = Prevucite da promenite redosled farmi
+configuration_farm_collapse_tooltip = Skupi farmu
+configuration_farm_expand_tooltip = Proširi farmu za izmenu
+configuration_farm_collapsed_summary = {$path}, veličina {$size}
+configuration_farm_name_placeholder = Naziv (opciono)
+configuration_farm_name_tooltip = Opcioni naziv farme da bi se lakše razlikovala od ostalih farmi, na primer "NVMe-A"
+configuration_farm_name_accessible_label = Naziv farme
+configuration_farms_search_placeholder = Pretraži farme po putanji ili nazivu
 configuration_farm_path_placeholder = Primer: {$path}
 configuration_farm_path_tooltip = Apsolutna putanja gde će se čuvati datoteke farme, bilo koji SSD funkcioniše, visoka izdržljivost nije neophodna
+configuration_farm_path_accessible_label = Folder farme
 configuration_farm_path_button_select = Izaberi
+configuration_farm_path_button_select_accessible_label = Izaberi folder farme
+configuration_farm_path_button_recent = Nedavno izabrani folderi
+configuration_farm_path_checking = Provera foldera...
 configuration_farm_path_error_doesnt_exist_or_write_permissions = Folder ne postoji ili korisnik nema dozvolu za pisanje
+configuration_farm_path_error_empty = Folder za farmu nije izabran
+configuration_farm_path_state_empty = Folder je prazan, biće kreirana nova farma
+configuration_farm_path_state_existing_farm = Postojeća farma je pronađena u ovom folderu, biće nastavljena
+configuration_farm_path_state_unrelated_files = Folder sadrži datoteke koje ne pripadaju farmi, proverite da se ne koristi ni za šta drugo
+configuration_farm_path_warning_removable_or_network_drive = Folder se nalazi na prenosivom ili mrežnom disku, farma će prestati da radi i može biti oštećena ako se disk isključi
+configuration_farm_path_warning_system_disk = Folder se nalazi na istom disku kao operativni sistem ili lični folder, popunjavanje diska podacima farme može učiniti sistem nestabilnim, razmislite o korišćenju zasebnog diska
+configuration_farm_path_error_duplicate = Ovaj folder već koristi druga farma
+configuration_farm_path_error_node_path_conflict = Farma ne može koristiti isti folder kao čvor, niti folder unutar njega ili koji ga sadrži
+configuration_farm_storage_kind_ssd = Prepoznat kao SSD
+configuration_farm_storage_kind_hdd = Prepoznat kao HDD, plotovanje i farmovanje na HDD-u je mnogo sporije nego na SSD-u
+configuration_farm_storage_kind_unknown = Vrsta uređaja za skladištenje je nepoznata
 configuration_farm_size_kind_fixed = Fiksna veličina
 configuration_farm_size_kind_free_percentage = % slobodnog prostora
 configuration_farm_fixed_size_placeholder = Primer: 4T, 2.5TB, 500GiB itd.
-configuration_farm_fixed_size_tooltip = Veličina farme u jedinicama koje preferirate, bilo koja količina prostora iznad 2 GB funkcioniše
+configuration_farm_fixed_size_tooltip = Veličina farme u jedinicama koje preferirate, bilo koja količina prostora iznad 2 GB funkcioniše. Podržane su i decimalne (KB, MB, GB, TB, 1 TB = 10^12 bajtova) i binarne (KiB, MiB, GiB, TiB, 1 TiB = 2^40 bajtova) jedinice
+configuration_farm_fixed_size_accessible_label = Veličina farme
+configuration_farm_fixed_size_preview = = {$a_bytes} bajtova ({$b_decimal}, {$c_binary})
+configuration_farm_sectors = ≈ {$sectors} sektora
+configuration_farm_usable_size = Upotrebljivo za plotovanje: {$size}
+configuration_farm_usable_size_warning = Samo {$a_size} je upotrebljivo za plotovanje, preostalih {$b_wasted} nije dovoljno za još jedan sektor i neće biti iskorišćeno
+configuration_farm_fixed_size_button_max = Maks
+configuration_farm_fixed_size_button_max_tooltip = Iskoristi sav slobodan prostor, osim 2 GiB koji ostaju slobodni da se izbegnu greške
+configuration_farm_fixed_size_warning_doesnt_fit = Veličina farme premašuje slobodan prostor na disku, biće smanjena da stane osim ako farma već zauzima taj prostor
+configuration_farm_fixed_size_error_too_small = Veličina farme je ispod minimuma od {$min_size}
+configuration_farm_size_error_invalid_format = Veličina nije prepoznata, koristite format kao 2TB ili 500GiB
+configuration_farm_free_percentage_size_error_invalid = Mora biti procenat veći od 0% i najviše 100%, na primer 90%
 configuration_farm_free_percentage_size_placeholder = Primer: 100%, 1.1%, itd.
 configuration_farm_free_percentage_size_tooltip = Procenat slobodnog prostora koji ova farma zauzima, sve preko 0% funkcioniše, ostavite minimum 2GB prostora da izbegnete greške
+configuration_farm_free_percentage_size_accessible_label = Veličina farme kao procenat slobodnog prostora na disku
+configuration_farm_free_percentage_size_resolved = Približno {$size} sa trenutno dostupnim slobodnim prostorom, prostor koji farma već zauzima se uzima u obzir pri pokretanju
+configuration_farm_free_space = Slobodan prostor: {$free_space}
+configuration_farm_free_space_unknown = Slobodan prostor: nepoznato
+configuration_farm_reserve = Ostavi slobodno na disku:
+configuration_farm_reserve_placeholder = Primer: 10GiB (opciono)
+configuration_farm_reserve_tooltip = Količina prostora koja mora ostati slobodna na disku pored farme, korisno za sisteme datoteka koji se loše ponašaju kada su potpuno puni, prazno znači bez rezerve
+configuration_farm_reward_address = Adresa za nagrade:
+configuration_farm_reward_address_placeholder = Ako je prazno, koristi se globalna adresa za nagrade
+configuration_farm_reward_address_tooltip = Opciona adresa za nagrade samo za ovu farmu, korisno kada farme na istom računaru pripadaju različitim ljudima, ako je prazno koristi se globalna adresa za nagrade
+configuration_farm_reward_address_error_invalid = Adresa za nagrade nije važeća adresa ove mreže
+configuration_farm_benchmark_button = Testiraj brzinu diska
+configuration_farm_benchmark_button_tooltip = Upiši i pročitaj privremenu datoteku od 256 MiB u ovom folderu da bi se proverilo da li je disk dovoljno brz za plotovanje i farmovanje, datoteka se nakon toga uklanja
+configuration_farm_benchmark_running = Testiranje brzine diska...
+configuration_farm_benchmark_result = Sekvencijalni upis: {NUMBER($a_sequential_write, maximumFractionDigits: 0)} MB/s, sekvencijalno čitanje: {NUMBER($b_sequential_read, maximumFractionDigits: 0)} MB/s, nasumično čitanje: {NUMBER($c_random_read, maximumFractionDigits: 0)} MB/s
+configuration_farm_benchmark_result_pass = Disk je dovoljno brz za plotovanje i farmovanje
+configuration_farm_benchmark_result_warn = Disk bi mogao biti prespor za plotovanje i farmovanje, razmislite o korišćenju drugog diska
+configuration_farm_benchmark_error = Nije uspelo testiranje brzine diska: {$error}
 configuration_farm_delete = Obriši ovu farmu
+configuration_farm_delete_confirmation = Obrisati ovu farmu?
+configuration_farm_delete_confirmation_button_cancel = Otkaži
+configuration_farm_delete_confirmation_button_delete = Obriši
+configuration_farm_delete_confirmation_delete_data = Takođe obriši podatke farme sa diska, ovo će osloboditi {$size}
+configuration_farm_delete_confirmation_delete_data_in_use = Farma je u upotrebi, prvo je onemogući na ekranu rada da bi obrisao njene podatke
+configuration_farm_delete_data_progress = Brisanje podataka farme…
+configuration_farm_delete_data_error = Nije uspelo brisanje podataka farme: {$error}
+configuration_farms_summary = Ukupna veličina svih farmi: {$size}
+configuration_farms_summary_with_free_space = Ukupna veličina svih farmi: {$a_size} (slobodan prostor na svim diskovima: {$b_free_space})
 configuration_advanced = Napredna konfiguracija
 configuration_advanced_farmer = Konfiguracija farmera
 configuration_advanced_farmer_reduce_plotting_cpu_load = Smanjeno opterećenje procesora
 configuration_advanced_farmer_reduce_plotting_cpu_load_tooltip = Inicijalno plotovanje koristi sva jegra na procesoru, dok će sa ovom opcijom koristiti jednu polovinu dostupnih jezgra, ovo poboljšava odaziv i performanse ostalih zadataka
+configuration_advanced_farmer_plotting_threads = CPU niti za plotovanje
+configuration_advanced_farmer_plotting_threads_tooltip = Broj CPU niti koje se koriste za plotovanje, manje vrednosti ostavljaju više procesora drugim aplikacijama, podrazumevano se koristi svih {$cpu_threads} niti
+configuration_advanced_farmer_sector_encoding_concurrency = Sektori koji se istovremeno kodiraju
+configuration_advanced_farmer_sector_encoding_concurrency_tooltip = Broj sektora koji se plotuju u isto vreme, veće vrednosti mogu ubrzati plotovanje na jakim računarima po cenu više RAM memorije, manje vrednosti smanjuju upotrebu RAM memorije
+configuration_advanced_farmer_sector_encoding_concurrency_memory = ~{$ram} RAM memorije
+configuration_advanced_farmer_auditing_threads = CPU niti za reviziju
+configuration_advanced_farmer_auditing_threads_tooltip = Ukupan broj CPU niti koje se koriste za reviziju i dokazivanje, ravnomerno raspoređen između farmi sa najmanje jednom niti po farmi, manje vrednosti smanjuju opterećenje diskova i procesora kod mnogo farmi po cenu sporije revizije, svaka farma koristi sopstveni skup niti prilagođen procesoru kada je izabrano svih {$cpu_threads} niti
+configuration_advanced_farmer_auditing_threads_error_too_few = Manje niti nego farmi, svakoj od {$farms} farmi je potrebna bar jedna nit
+configuration_advanced_farmer_piece_cache_size = Veličina keša delova
+configuration_advanced_farmer_piece_cache_size_tooltip = Ukupna veličina keša delova koji dele sve farme, na primer 10 GiB, veći keš ubrzava sinhronizaciju i plotovanje na račun prostora dostupnog za plotovanje, minimalni keš se koristi ako je prazno
+configuration_advanced_farmer_piece_cache_size_low = Ovako mali keš delova može usporiti sinhronizaciju i plotovanje
+configuration_advanced_farmer_piece_cache_path = Folder keša delova
+configuration_advanced_farmer_piece_cache_path_placeholder = Čuva se unutar farmi
+configuration_advanced_farmer_piece_cache_path_tooltip = Opcioni folder za keš delova, na primer na brzom SSD-u pored čvora dok su farme na HDD-ovima, keš delova se čuva unutar farmi ako nije izabran
+configuration_advanced_farmer_piece_cache_path_button_select = Izaberi
+configuration_advanced_farmer_piece_cache_path_button_clear = Čuvaj keš delova unutar farmi
+configuration_advanced_farmer_piece_cache_path_error_farm_path_conflict = Keš delova ne može koristiti isti folder kao neka od farmi, niti folder unutar nje ili koji je sadrži
+configuration_advanced_farmer_reward_notifications = Obaveštenja o nagradama
+configuration_advanced_farmer_reward_notifications_tooltip = Prikaži obaveštenja na radnoj površini kada su nagrade primljene ili potpisivanje nagrade ne uspe, obaveštenja se prikazuju najviše jednom u minuti
+configuration_advanced_farmer_plotting_notifications = Obaveštenja o plotovanju
+configuration_advanced_farmer_plotting_notifications_tooltip = Prikaži obaveštenje na radnoj površini kada se završi početno plotovanje svih farmi
+configuration_advanced_farmer_farm_health_notifications = Obaveštenja o stanju farmi
+configuration_advanced_farmer_farm_health_notifications_tooltip = Prikaži obaveštenje na radnoj površini kada neka od farmi konstantno vrši reviziju sporije od trajanja slota i propušta nagrade
+configuration_advanced_application = Konfiguracija aplikacije
+configuration_advanced_application_tray_icon = Ikonica u sistemskoj traci
+configuration_advanced_application_tray_icon_tooltip = Prikaži ikonicu u sistemskoj traci, ne podržavaju je sva radna okruženja dobro, zahteva ponovno pokretanje
+configuration_advanced_application_minimize_to_tray = Minimiziraj u sistemsku traku pri zatvaranju
+configuration_advanced_application_minimize_to_tray_tooltip = Sakrij prozor u sistemsku traku umesto izlaska kada se zatvori, farmovanje nastavlja da radi u pozadini, zahteva ponovno pokretanje
+configuration_advanced_application_theme = Tema
+configuration_advanced_application_theme_tooltip = Šema boja aplikacije, primenjuje se odmah
+configuration_advanced_application_theme_system = Sistemska
+configuration_advanced_application_theme_light = Svetla
+configuration_advanced_application_theme_dark = Tamna
+configuration_advanced_application_theme_high_contrast = Visoki kontrast
+configuration_advanced_application_language = Jezik
+configuration_advanced_application_language_tooltip = Jezik korisničkog interfejsa, ako nije dostupan koristi se jezik sistema, zahteva ponovno pokretanje
+configuration_advanced_application_language_system = Sistemski
+configuration_advanced_application_log_level = Nivo dnevnika
+configuration_advanced_application_log_level_tooltip = Koliko je dnevnik detaljan, primenjuje se odmah nakon čuvanja bez ponovnog pokretanja
+configuration_advanced_application_log_level_error = Greška
+configuration_advanced_application_log_level_warn = Upozorenje
+configuration_advanced_application_log_level_info = Informacije
+configuration_advanced_application_log_level_debug = Otklanjanje grešaka
+configuration_advanced_application_log_level_trace = Praćenje
+configuration_advanced_application_log_level_verbose_warning = Nivoi za otklanjanje grešaka i praćenje proizvode mnogo zapisa i mogu uticati na performanse, vratite na informacije kada se problem dijagnostikuje
+configuration_advanced_application_offline_mode = Oflajn režim
+configuration_advanced_application_offline_mode_tooltip = Spreči odlazne konekcije koje nisu neophodne za farmovanje, kao što su provere novih verzija, provere sistemskog sata i telemetrija čvora, mreža čvora i farmera nastavlja da radi
+configuration_advanced_application_update_checks = Proveravaj nove verzije
+configuration_advanced_application_update_checks_tooltip = Povremeno proveravaj nova izdanja na GitHub-u, isključite na ograničenim konekcijama ili izolovanim sistemima, primenjuje se odmah nakon čuvanja
+configuration_advanced_application_update_check_interval = Učestalost provere
+configuration_advanced_application_update_check_interval_hourly = Svakog sata
+configuration_advanced_application_update_check_interval_daily = Dnevno
+configuration_advanced_application_update_check_interval_weekly = Nedeljno
+configuration_advanced_application_update_auto_download = Automatski preuzimaj nove verzije
+configuration_advanced_application_update_auto_download_tooltip = Preuzmi instalaciju nove verzije u folder za preuzimanja umesto samo prikazivanja obaveštenja, i dalje je treba ručno instalirati
+configuration_advanced_application_versions = Instalirana verzija: {$a_installed}, najnovija verzija: {$b_latest}
+configuration_advanced_application_versions_latest_unknown = Instalirana verzija: {$installed}, najnovija verzija još nije poznata
 configuration_advanced_network = Konfiguracija mreže
 configuration_advanced_network_default_port_number_tooltip = Podrazumevani broj porta je {$port}
 configuration_advanced_network_substrate_port = Substrate (blockchain) P2P port (TCP):
 configuration_advanced_network_subspace_port = Subspace (DSN) P2P port (TCP):
 configuration_advanced_network_faster_networking = Brže umrežavanje:
 configuration_advanced_network_faster_networking_tooltip = Podrazumevano, umrežavanje je optimizovano za kućne rutere, ali ako imate jaču opremu, brže umrežavanje može poboljšati brzinu sinhronizacije i druge procese
+configuration_advanced_network_external_address = Spoljna adresa
+configuration_advanced_network_external_address_tooltip = Opciona DSN adresa u multiaddr formatu koja se oglašava drugim peerovima, korisno kada je port prosleđen na ruteru
+configuration_advanced_network_node_rpc_url = RPC URL spoljnog čvora (opciono):
+configuration_advanced_network_node_rpc_url_tooltip = WebSocket RPC URL vašeg čvora za farmovanje umesto ugrađenog čvora, ostavite prazno da biste koristili ugrađeni čvor
+configuration_advanced_network_node_rpc_url_button_check = Proveri
+configuration_advanced_network_node_rpc_url_connected = Uspešno povezan sa spoljnim čvorom
+configuration_advanced_network_node_rpc_url_failed = Povezivanje sa spoljnim čvorom nije uspelo: {$error}
+configuration_advanced_network_metrics = Prometheus metrike:
+configuration_advanced_network_metrics_tooltip = Izloži metrike čvora i farmera preko HTTP-a u Prometheus formatu
+configuration_advanced_network_metrics_address_tooltip = Adresa na kojoj se služe metrike, na primer 127.0.0.1:9616
+configuration_advanced_network_node_rpc = RPC čvora:
+configuration_advanced_network_node_rpc_tooltip = Izloži RPC ugrađenog čvora na prilagođenoj adresi za eksterne alate, inače je RPC čvora dostupan samo na internom lokalnom portu
+configuration_advanced_network_node_rpc_address_tooltip = Adresa na kojoj se služi RPC čvora, na primer 127.0.0.1:9944
+configuration_advanced_network_node_rpc_warning_public = RPC čvora će biti dostupan sa drugih računara, svako ko može da pristupi ovoj adresi moći će da šalje upite vašem čvoru. Koristite nelokalnu adresu samo na pouzdanim mrežama ili iza zaštitnog zida.
+configuration_advanced_network_control_api = Lokalni API za upravljanje:
+configuration_advanced_network_control_api_tooltip = Dozvoli skriptama na ovom računaru da proveravaju status i pauziraju ili nastave farmovanje preko lokalnog soketa (imenovane cevi na Windows-u), dostupno samo trenutnom korisniku
 configuration_button_add_farm = Dodaj farmu
+configuration_button_add_farm_tooltip = Dodaj još jednu farmu (Ctrl+N)
+configuration_button_suggest_farms = Automatski podesi farme
+configuration_button_suggest_farms_tooltip = Pronađi diskove sa dovoljno slobodnog prostora (osim sistemskog diska i prenosivih uređaja) i dodaj farmu koja koristi 90% slobodnog prostora na svakom od njih, farme se mogu izmeniti ili ukloniti pre čuvanja
+configuration_suggest_farms_none_found = Nisu pronađeni odgovarajući diskovi, farme se mogu dodati ručno
+configuration_button_import_config = Uvezi konfiguraciju
+configuration_button_import_config_tooltip = Uvezi putanju čvora, adresu za nagrade, mrežnu konfiguraciju i farme iz konfiguracionog fajla, na primer sa druge mašine
+configuration_button_export_config = Izvezi konfiguraciju
+configuration_button_export_config_tooltip = Izvezi trenutnu konfiguraciju u fajl, na primer radi rezervne kopije ili korišćenja na drugoj mašini, fajl sa ekstenzijom .toml se upisuje u TOML formatu, a u suprotnom u JSON formatu
 configuration_button_help = Pomoć
 configuration_button_cancel = Otkaži
 configuration_button_back = Nazad
 configuration_button_save = Sačuvaj
 configuration_button_start = Pokreni
+configuration_plotting_memory_warning = Procenjuje se da je plotovanju potrebno ~{$a_estimated} RAM memorije, što je previše za {$b_available} trenutno dostupnih na ovom računaru i može dovesti do toga da operativni sistem ugasi aplikaciju
+configuration_plotting_memory_warning_reduce = Smanjenje broja sektora koji se istovremeno kodiraju na {$a_concurrency} smanjiće upotrebu na ~{$b_memory}
+configuration_plotting_memory_warning_button_cancel = Otkaži
+configuration_plotting_memory_warning_button_continue = Ipak nastavi
+configuration_plotting_memory_warning_button_reduce = Smanji i nastavi
 configuration_dialog_button_select = Izaberi
 configuration_dialog_button_cancel = Otkaži
+configuration_dialog_button_import = Uvezi
+configuration_dialog_button_export = Izvezi
+configuration_dialog_filter_json = JSON fajlovi
+configuration_dialog_filter_toml = TOML fajlovi
+configuration_import_config_error_not_found = Konfiguracioni fajl nije pronađen
+configuration_import_config_error = Uvoz konfiguracije nije uspeo: {$error}
+configuration_export_config_error_incomplete = Konfiguracija još nije potpuno učitana, pokušajte ponovo
+configuration_export_config_error = Izvoz konfiguracije nije uspeo: {$error}
 
 running_title = U radu
 running_node_title = {$chain_name} konsenzus čvor
 running_node_title_tooltip = Kliknite da otvorite u upravitelju datotekama
+running_node_button_copy_peer_id = Kopiraj peer ID čvora
+running_node_button_copy_genesis_hash = Kopiraj genesis heš
+running_node_genesis_hash = Genesis heš: {$genesis_hash}
+running_node_clock_offset_warning_seconds =
+    {$a_direction ->
+        [ahead] Sistemski sat žuri {NUMBER($b_offset, maximumFractionDigits: 1)} sekundi u odnosu na tačno vreme
+        *[behind] Sistemski sat kasni {NUMBER($b_offset, maximumFractionDigits: 1)} sekundi u odnosu na tačno vreme
+    }, što može sprečiti sinhronizaciju čvora i primanje nagrada. Uključite automatsku sinhronizaciju vremena (NTP) u sistemskim podešavanjima
+running_node_clock_offset_warning_minutes =
+    {$a_direction ->
+        [ahead] Sistemski sat žuri {NUMBER($b_offset, maximumFractionDigits: 1)} minuta u odnosu na tačno vreme
+        *[behind] Sistemski sat kasni {NUMBER($b_offset, maximumFractionDigits: 1)} minuta u odnosu na tačno vreme
+    }, što može sprečiti sinhronizaciju čvora i primanje nagrada. Uključite automatsku sinhronizaciju vremena (NTP) u sistemskim podešavanjima
 running_node_free_disk_space_tooltip = Slobodan prostor na disku: preostalo {$size}
+running_node_peers =
+    {$peers_count ->
+        [one] {$peers_count} peer
+        [few] {$peers_count} peera
+        *[other] {$peers_count} peerova
+    }
+running_node_peers_tooltip = Peerovi sa kojima je čvor povezan, kliknite da vidite listu
+running_node_peers_empty = Nije povezan ni sa jednim peerom
+running_node_peer = {$a_peer_id}
+    {$b_role ->
+        [authority] Autoritet
+        [light] Laki klijent
+        *[full] Puni čvor
+    }, najbolji blok #{$c_best_block_number}, {$d_address}
+running_node_peer_address_unknown = adresa nepoznata
 running_node_status_connecting = Povezivanje sa mrežom, najbolji blok #{$block_number}
 running_node_status_syncing_speed_no_eta = , {NUMBER($blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} blokova/s
 running_node_status_syncing_speed_hours_eta = , {NUMBER($a_blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} blokova/s (~{NUMBER($b_hours_remaining, minimumFractionDigits: 2, maximumFractionDigits: 2)} sati preostalo)
@@ -113,8 +305,16 @@ running_node_status_synced = Sinhronizovano, najbolji blok #{$best_block_number}
 running_farmer_title = Farmer
 running_farmer_button_expand_details = Proširi detalje o svakoj farmi
 running_farmer_button_pause_plotting = Pauziraj plotovanje/preplotovanje, imajte na umu da trenutno kodiranje sektora neće biti prekinuto
-running_farmer_account_balance_tooltip = Ukupni saldo i kovanice zarđene od početka rada aplikacije, kliknite da vidite detalje u Astral
+running_farmer_button_pause_farming = Pauziraj farmovanje, nagrade neće biti potpisivane niti sektori plotovani dok se farmovanje ne nastavi
+running_farmer_farming_paused = Farmovanje pauzirano
+running_farmer_slow_auditing_warning = Revizija je prespora i nagrade se propuštaju na farmama: {$farms}, proverite ispravnost diska
+running_farmer_account_balance_tooltip = Ukupni saldo svih adresa za nagrade i kovanice zarađene od početka rada aplikacije, kliknite da vidite detalje globalne adrese za nagrade u Astral
+running_farmer_button_copy_reward_address = Kopiraj adresu za nagrade
+running_farmer_network_space = Prostor mreže (procena): {$a_network_space}, vaš udeo: {NUMBER($b_share, maximumFractionDigits: 4)}%
+running_farmer_network_space_tooltip = Ukupan prostor koji je posvećen mreži se procenjuje na osnovu opsega rešenja poslednjeg bloka, vaš udeo se zasniva na do sada isplotovanom prostoru i približno odgovara šansi da proizvedete svaki blok
 running_farmer_piece_cache_sync = Sinhronizacija delova keša {NUMBER($percentage, minimumFractionDigits: 2, maximumFractionDigits: 2)}%
+running_farmer_piece_cache_sync_pieces = {$a_cached_pieces}/{$b_total_pieces} delova keširano
+running_farmer_piece_cache_sync_description = Keš delova se puni delovima preuzetim sa mreže, plotovanje nije zaglavljeno, ali može biti sporije dok se ovo ne završi
 running_farmer_next_reward_estimate =
     Sledeća procena nagrade: {$eta_string ->
         [any_time_now] bilo kada
@@ -124,19 +324,85 @@ running_farmer_next_reward_estimate =
         [more_than_a_week] više od nedelje
         *[unknown] nepoznato
     }
+running_farmer_rewards_title = Nagrade
+running_farmer_rewards_mode_cumulative = Ukupno
+running_farmer_rewards_mode_per_interval = Po intervalu
+running_farmer_rewards_cumulative_tooltip = Ukupne nagrade zarađene tokom vremena
+running_farmer_rewards_per_interval_tooltip = Nagrade zarađene po {$unit ->
+        [hour] satu
+        [day] danu
+        *[week] nedelji
+    }
+running_farmer_stats_title = Ukupna statistika
+running_farmer_stats_uptime = Vreme rada: {$a_days}d {$b_hours}h {$c_minutes}m
+running_farmer_stats_rewards_count = Osvojene nagrade (blokovi i glasovi): {$rewards_count}
+running_farmer_stats_rewards_total = Ukupne nagrade: {NUMBER($a_amount, minimumFractionDigits: 2, maximumFractionDigits: 2)} {$b_token_symbol}
+running_farmer_stats_button_reset = Resetuj
+running_farmer_stats_button_reset_tooltip = Vrati ukupnu statistiku na nulu
+running_farmer_stats_reset_confirmation = Resetovati ukupnu statistiku? Ovo se ne može poništiti.
+running_farmer_stats_reset_confirmation_button_cancel = Otkaži
+running_farmer_stats_reset_confirmation_button_reset = Resetuj
+running_farmer_farms_sort_by = Sortiraj farme po:
+running_farmer_farms_sort_by_default = Podrazumevano
+running_farmer_farms_sort_by_default_tooltip = Redosled kojim su farme navedene u konfiguraciji
+running_farmer_farms_sort_by_size = Veličini
+running_farmer_farms_sort_by_size_tooltip = Najveće farme prvo
+running_farmer_farms_sort_by_path = Putanji
+running_farmer_farms_sort_by_status = Statusu
+running_farmer_farms_sort_by_status_tooltip = Prvo farme sa greškama, zatim farme koje plotuju, farmuju, pauzirane i zaustavljene farme
+running_farmer_farms_compact_view = Kompaktan prikaz
+running_farmer_farms_compact_view_tooltip = Prikaži farme kao mrežu malih pločica obojenih prema stanju, kliknite na pločicu da vidite detalje farme
 running_farmer_farm_tooltip = Kliknite da otvorite u upravitelju datotekama
+running_farmer_farm_enabled_tooltip = Omogući ili onemogući ovu farmu, onemogućena farma se zaustavlja i neće biti otvorena pri sledećem pokretanju
 running_farmer_farm_reward_signatures_tooltip = {$successful_signatures}/{$total_signatures} uspešnih potpisa nagrada, proširi detalje farme da vidiš više informacija
 running_farmer_farm_auditing_performance_tooltip = Provera performansi: prosečno vreme {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}s, vremensko ograničenje {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
 running_farmer_farm_proving_performance_tooltip = Dokazivanje performansi: prosečno vreme {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}s, vremensko ograničenje {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
+running_farmer_farm_auditing_latency = Revizija p50/p95/max: {NUMBER($a_p50, minimumFractionDigits: 2, maximumFractionDigits: 2)}s/{NUMBER($b_p95, minimumFractionDigits: 2, maximumFractionDigits: 2)}s/{NUMBER($c_max, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
+running_farmer_farm_auditing_latency_tooltip = Percentili vremena revizije u poslednjih 10 minuta, vremensko ograničenje {NUMBER($time_limit, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
+running_farmer_farm_auditing_latency_slow_tooltip = Revizija traje duže od trajanja slota, farma propušta nagrade, proverite ispravnost diska
+running_farmer_farm_proving_latency = Dokazivanje p50/p95/max: {NUMBER($a_p50, minimumFractionDigits: 2, maximumFractionDigits: 2)}s/{NUMBER($b_p95, minimumFractionDigits: 2, maximumFractionDigits: 2)}s/{NUMBER($c_max, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
+running_farmer_farm_proving_latency_tooltip = Percentili vremena dokazivanja za poslednjih 100 dokaza, vremensko ograničenje {NUMBER($time_limit, minimumFractionDigits: 2, maximumFractionDigits: 2)}s
 running_farmer_farm_non_fatal_error_tooltip = Dogodila se ne-fatalna greška u farmovanju i uspešno je ispravljena, pogledajte dnevnik za više detalja: {$error}
 running_farmer_farm_crashed = Farma se srušila: {$error}
+running_farmer_farm_disk_full = Farma je zaustavljena jer je disk pun. Oslobodite prostor na disku ili smanjite dodeljeni prostor u konfiguraciji, a zatim nastavite rad farme
+running_farmer_farm_directory_inaccessible = Farma je zaustavljena jer njen direktorijum više nije dostupan. Proverite da li je disk povezan
+running_farmer_farm_directory_accessible = Direktorijum farme je ponovo dostupan, ponovo se povežite da biste nastavili farmovanje
+running_farmer_farm_button_resume = Nastavi
+running_farmer_farm_button_reconnect = Ponovo poveži
+running_farmer_farm_button_resume_tooltip = Ponovo otvori farmu i nastavi farmovanje i plotovanje
+running_farmer_farm_resize_button_tooltip = Povećaj veličinu farme
+running_farmer_farm_resize_title = Povećaj veličinu farme
+running_farmer_farm_resize_placeholder = Nova veličina, na primer 4T, 2.5TB, 500GiB, itd.
+running_farmer_farm_resize_apply = Primeni
+running_farmer_farm_resize_error_invalid = Neispravna veličina
+running_farmer_farm_resize_error_shrink = Veličina farme se može samo povećati, smanjivanje farme nije podržano, umesto toga napravite novu farmu manje veličine
+running_farmer_farm_resize_error_too_small = Veličina farme mora biti najmanje {$min_size}
+running_farmer_farm_resize_error_doesnt_fit = Nema dovoljno slobodnog prostora na disku, farma se može povećati najviše za {$free_space}
+running_farmer_farm_disabled = Onemogućeno
+running_farmer_farm_enabled_restart_needed = Omogućeno, potrebno je ponovno pokretanje da bi farma počela sa radom
+running_farmer_farm_plotted_sectors = Plotovano {$a_plotted_sectors}/{$b_total_sectors} sektora
+running_farmer_farm_tile_progress = {NUMBER($progress, maximumFractionDigits: 0)}%
+running_farmer_farm_tile_status =
+    {$status ->
+        [error] Zaustavljena zbog greške
+        [plotting] Plotovanje
+        [paused] Pauzirano
+        [stopped] Zaustavljena
+        *[farming] Farmovanje
+    }
 running_farmer_farm_plotting_speed =  ({NUMBER($a_sector_time, minimumFractionDigits: 2, maximumFractionDigits: 2)} m/sektoru, {NUMBER($b_sectors_per_hour, minimumFractionDigits: 2, maximumFractionDigits: 2)} sektora/h)
+running_farmer_farm_plotting_eta_calculating = Preostalo vreme: računanje…
+running_farmer_farm_plotting_eta_hours = Preostalo vreme: {NUMBER($hours, minimumFractionDigits: 1, maximumFractionDigits: 1)} h
+running_farmer_farm_plotting_eta_minutes = Preostalo vreme: {NUMBER($minutes, minimumFractionDigits: 0, maximumFractionDigits: 0)} min
+running_farmer_farm_disk_io = Disk: {$a_read}/s čitanje, {$b_write}/s pisanje, {$c_iops} IOPS
+running_farmer_farm_disk_io_tooltip = Prosečan I/O particije diska na kojoj se nalazi farma tokom poslednjih 10 sekundi, uključuje I/O drugih farmi i aplikacija na istoj particiji
 running_farmer_farm_plotting_initial =
     {$pausing_state ->
         [pausing] Pauziranje početnog plotovanja
         [paused] Početno plotovanje pauzirano
         *[no] Početno plotovanje
     } {NUMBER($b_progress, minimumFractionDigits: 2, maximumFractionDigits: 2)}%{$plotting_speed}, {$farming ->
+        [paused] farmovanje pauzirano
         [yes] farmovanje
         *[no] ne farmuje
     }
@@ -146,10 +412,12 @@ running_farmer_farm_replotting =
         [paused] Početno plotovanje pauzirano
         *[default] Preplotovanje
     } {NUMBER($b_progress, minimumFractionDigits: 2, maximumFractionDigits: 2)}%{$plotting_speed}, {$farming ->
+        [paused] farmovanje pauzirano
         [yes] farmovanje
         *[no] ne farmuje
     }
 running_farmer_farm_farming = Farmovanje
+running_farmer_farm_farming_paused = Farmovanje pauzirano
 running_farmer_farm_waiting_for_node_to_sync = Čeka se sinhronizacija čvora
 running_farmer_farm_sector = Sektor {$sector_index}
 running_farmer_farm_sector_up_to_date = Sektor {$sector_index}: ažuriran
@@ -162,6 +430,14 @@ running_farmer_farm_sector_writing = Sektor {$sector_index}: pisanje
 
 shutting_down_title = Gašenje
 shutting_down_description = Ovo može da potraje od nekoliko sekundi do nekoliko minuta u zavisnosti od toga šta je aplikacija radila u tom trenutku
+shutting_down_step_stopping = Zaustavljanje…
+shutting_down_step_stopping_farmer = Zaustavljanje farmera, upisivanje isplotovanih sektora na disk…
+shutting_down_step_stopping_node = Zaustavljanje čvora, upisivanje baze podataka na disk…
+shutting_down_step_stopping_networking = Zaustavljanje mreže…
+shutting_down_button_force_quit = Prinudno zatvori
+shutting_down_button_force_quit_tooltip = Zatvori bez čekanja da se gašenje završi, podaci možda neće biti u potpunosti upisani na disk
+restarting_services_title = Ponovno pokretanje čvora i farmera
+restarting_services_description = Čvor i farmer se zaustavljaju i ponovo pokreću sa konfiguracijom sa diska, aplikacija nastavlja da radi
 
 stopped_title = Zaustavljeno
 stopped_message = Zaustavljeno 🛑
@@ -169,43 +445,190 @@ stopped_message_with_error = Zaustavljeno sa greškom: {$error}
 stopped_button_show_logs = Prikaz dnevnika
 stopped_button_help_from_community = Pomoć zajednice
 
+startup_retry_title = Ponovno pokretanje
+startup_retry_message = Pokretanje nije uspelo: {$error}
+startup_retry_countdown = Pokušaj {$attempt} nije uspeo, novi pokušaj za {$seconds} s
+startup_retry_button_retry_now = Pokušaj odmah
+startup_retry_button_cancel = Otkaži
+farm_mismatches_dialog_title = Neke farme se ne poklapaju sa konfiguracijom
+farm_mismatches_dialog_description = Farme ispod ne bi uspele da se pokrenu ovako kako su konfigurisane, ovo se obično dešava nakon premeštanja diskova. Izaberite šta da se uradi sa svakom od njih pre nastavka.
+farm_mismatches_dialog_missing_plot = Farma nije pronađena u ovom folderu, disk sa njom možda nije povezan
+farm_mismatches_dialog_unexpected_plot = Folder sadrži drugu farmu nego ranije
+farm_mismatches_dialog_unexpected_plot_different_chain = Folder sadrži farmu druge mreže
+farm_mismatches_dialog_size_mismatch = Konfigurisana veličina {$configured_size} je manja od veličine farme na disku {$farm_size}, smanjivanje farme nije podržano
+farm_mismatches_dialog_resolution_skip = Preskoči (za sada onemogući farmu)
+farm_mismatches_dialog_resolution_replot = Obriši postojeće podatke i plotuj ponovo
+farm_mismatches_dialog_resolution_replot_missing = Plotuj novu farmu u ovom folderu
+farm_mismatches_dialog_resolution_fix_missing = Ukloni farmu iz konfiguracije
+farm_mismatches_dialog_resolution_fix_unexpected = Koristi farmu pronađenu u ovom folderu
+farm_mismatches_dialog_resolution_fix_size = Koristi veličinu farme na disku
+farm_mismatches_dialog_button_continue = Nastavi
+
 error_title = Greška
 error_message = Greška: {$error}
 error_message_failed_to_send_config_to_backend = Nije uspelo slanje konfiguracije na backend: {$error}
 error_message_failed_to_send_pause_plotting_to_backend = Nije uspelo slanje pauze plotovanja na backend: {$error}
+error_message_failed_to_send_pause_farming_to_backend = Nije uspelo slanje pauze farmovanja na backend: {$error}
+error_message_failed_to_send_stop_farm_to_backend = Nije uspelo slanje zaustavljanja farme na backend: {$error}
+error_message_failed_to_send_start_farm_to_backend = Nije uspelo slanje pokretanja farme na backend: {$error}
+error_message_failed_to_send_restart_farm_to_backend = Nije uspelo slanje ponovnog pokretanja farme na backend: {$error}
+error_message_failed_to_send_resize_farm_to_backend = Nije uspelo slanje promene veličine farme na backend: {$error}
+error_message_failed_to_send_startup_retry_to_backend = Nije uspelo slanje ponovnog pokretanja na backend: {$error}
+error_message_failed_to_send_farm_mismatch_resolutions_to_backend = Nije uspelo slanje rešenja neusklađenosti farmi na backend: {$error}
+error_message_failed_to_send_restart_services_to_backend = Nije uspelo slanje ponovnog pokretanja čvora i farmera na backend: {$error}
 error_button_show_logs = Prikaz dnevnika
 error_button_help_from_community = Pomoć zajednice
 
 new_version_available = Dostupna je nova verzija {$version} 🎉
-new_version_available_button_open = Otvori stranicu sa izdanjima
+new_version_available_button_open = Prikaži beleške o izdanju nove verzije
+new_version_downloaded = Verzija {$version} preuzeta 🎉
+new_version_downloaded_button_open = Prikaži beleške o izdanju i preuzetu instalaciju nove verzije
+new_version_dialog_title = Šta je novo u verziji {$version}
+new_version_dialog_no_release_notes = Beleške o izdanju nisu dostupne, pogledajte stranicu izdanja za detalje
+new_version_dialog_button_open_installer_folder = Otvori folder sa instalacijom
+new_version_dialog_button_open_release_page = Otvori stranicu izdanja
+new_version_dialog_button_close = Zatvori
+offline_mode_indicator = Oflajn režim
+offline_mode_indicator_tooltip = Provere novih verzija, provere sistemskog sata i telemetrija čvora su isključene, oflajn režim se može isključiti u konfiguraciji
+profile_indicator = Profil: {$name}
+profile_indicator_tooltip = Čvor i farmer koriste konfiguraciju ovog profila, profilima se upravlja iz glavnog menija
 
+main_menu_log_viewer = Pregled dnevnika
 main_menu_show_logs = Prikaz dnevnika u upravitelju datotekama
+main_menu_event_log = Prikaz dnevnika događaja
+main_menu_profiles = Konfiguracioni profili
+main_menu_export_diagnostics = Izvezi dijagnostiku
 main_menu_change_configuration = Promeni konfiguraciju
+main_menu_restart_services = Ponovo pokreni čvor i farmera
 main_menu_share_feedback = Deli povratne informacije
 main_menu_about = O aplikaciji
 main_menu_exit = Izlaz
 
+log_viewer_title = Dnevnik
+log_viewer_level = Nivo
+log_viewer_level_error = Samo greške
+log_viewer_level_warn = Upozorenja i greške
+log_viewer_level_info = Informacije i više
+log_viewer_level_debug = Otklanjanje grešaka i više
+log_viewer_level_trace = Sve
+log_viewer_pause_tooltip = Pauziraj da nove linije prestanu da se pojavljuju i skroluju
+log_viewer_resume_tooltip = Nastavi sa prikazom novih linija
+log_viewer_button_copy_all = Kopiraj sve
+log_viewer_button_open_log_file = Otvori fajl dnevnika
+
+event_log_title = Dnevnik događaja
+event_log_filter = Prikaži
+event_log_filter_all = Svi događaji
+event_log_filter_node = Čvor
+event_log_filter_farms = Farme
+event_log_filter_rewards = Nagrade
+event_log_filter_errors = Samo greške
+event_log_button_open_event_log_file = Otvori fajl dnevnika događaja
+event_log_empty = Još nema zabeleženih događaja
+event_log_event_services_started = Čvor i farmer su pokrenuti
+event_log_event_services_stopped = Čvor i farmer su zaustavljeni
+event_log_event_services_stopped_with_error = Čvor i farmer su zaustavljeni sa greškom: {$error}
+event_log_event_services_restarting = Ponovno pokretanje čvora i farmera
+event_log_event_profile_switched = Prebačeno na konfiguracioni profil {$profile}
+event_log_event_startup_failed = Pokušaj pokretanja {$a_attempt} nije uspeo: {$b_error}
+event_log_event_irrecoverable_error = Nepopravljiva greška: {$error}
+event_log_event_node_synced = Čvor je sinhronizovan
+event_log_event_node_syncing = Čvor je počeo sinhronizaciju
+event_log_event_node_disconnected = Čvor je izgubio vezu sa svim peerovima
+event_log_event_node_reconnected = Čvor se ponovo povezao sa peerovima
+event_log_event_plotting_started = Plotovanje je počelo na farmi {$farm}
+event_log_event_plotting_finished = Plotovanje je završeno na farmi {$farm}
+event_log_event_farm_error = Farma {$a_farm} nije uspela: {$b_error}
+event_log_event_reward = Primljena nagrada od {NUMBER($a_amount, minimumFractionDigits: 2, maximumFractionDigits: 2)} {$b_token_symbol}
+
+diagnostics_title = Izvoz dijagnostike
+diagnostics_description = Dijagnostički paket sadrži konfiguraciju, nedavne zapise dnevnika i osnovne informacije o sistemu u jednom fajlu koji se može priložiti uz prijavu greške ili poslati podršci
+diagnostics_include_sensitive = Uključi adresu za nagrade i pune putanje
+diagnostics_include_sensitive_tooltip = Podrazumevano se adresa za nagrade uklanja, a početni direktorijum zamenjuje sa ~ u svakom fajlu paketa
+diagnostics_button_export = Izvezi…
+diagnostics_export_success = Dijagnostika je sačuvana u {$path}
+diagnostics_export_error = Izvoz dijagnostike nije uspeo: {$error}
+diagnostics_dialog_button_save = Sačuvaj
+diagnostics_dialog_button_cancel = Otkaži
+diagnostics_dialog_filter = Dijagnostički paket
+
+profiles_title = Konfiguracioni profili
+profiles_description = Svaki profil ima sopstvenu konfiguraciju sa lokacijom čvora, mrežom, adresom za nagrade i farmama. Promena profila zaustavlja čvor i farmer i ponovo ih pokreće sa konfiguracijom izabranog profila, novi profili se konfigurišu od početka.
+profiles_active_profile = {$name} (aktivan)
+profiles_button_switch = Prebaci
+profiles_button_delete = Obriši
+profiles_button_create = Napravi
+profiles_new_profile_name_placeholder = Naziv novog profila, na primer testnet
+profiles_new_profile_name_tooltip = Do 32 latinična slova, cifre, "-" i "_", mora se razlikovati od naziva postojećih profila
+profiles_error = Greška: {$error}
+profiles_switch_confirmation = Prebaciti na profil {$name}?
+profiles_switch_confirmation_details = Čvor i farmer će biti zaustavljeni i ponovo pokrenuti sa konfiguracijom ovog profila
+profiles_switch_confirmation_button_switch = Prebaci
+profiles_delete_confirmation = Obrisati profil {$name}?
+profiles_delete_confirmation_details = Konfiguracija ovog profila će biti obrisana, podaci čvora i farmi ostaju na disku
+profiles_delete_confirmation_button_delete = Obriši
+profiles_confirmation_button_cancel = Otkaži
+
 status_bar_message_configuration_is_invalid = Konfiguracija je nevažeća: {$error}
 status_bar_message_restart_is_needed_for_configuration = Potreban je restart aplikacije za primenu promena u konfiguraciji
+status_bar_message_services_restart_is_needed_for_configuration = Potreban je restart čvora i farmera za primenu promena u konfiguraciji
 status_bar_message_failed_to_save_configuration = Nije uspelo čuvanje promena konfiguracije: {$error}
 status_bar_message_restarted_after_crash = Space Acres se automatski restartovao nakon kraha, proveri dnevnik aplikacije za više informacija
 status_bar_button_restart = Ponovo pokreni
+status_bar_button_restart_services = Ponovo pokreni čvor i farmera
+status_bar_button_restart_services_tooltip = Primeni konfiguraciju ponovnim pokretanjem samo čvora i farmera, aplikacija nastavlja da radi
 status_bar_button_ok = U redu
+copy_button_copied = Kopirano u privremenu memoriju
+
+toast_configuration_saved = Konfiguracija je sačuvana
+toast_configuration_imported = Konfiguracija je uvezena, pregledajte je i sačuvajte da bi se primenila
+toast_configuration_exported = Konfiguracija je izvezena u {$path}
+toast_suggested_farms_added =
+    {$count ->
+        [one] Dodata je {$count} predložena farma
+        [few] Dodate su {$count} predložene farme
+        *[other] Dodato je {$count} predloženih farmi
+    }
+toast_failed_to_open = Otvaranje nije uspelo: {$error}
+toast_profile_created = Profil {$name} je napravljen
+toast_profile_deleted = Profil {$name} je obrisan
 
 about_system_information =
     Konfiguracioni direktorijum: {$config_directory}
     Direktorijum podataka (uključujući dnevnike): {$data_directory}
 
 tray_icon_open = Otvori
-tray_icon_close = Zatvori
+tray_icon_pause = Pauziraj farmovanje
+tray_icon_resume = Nastavi farmovanje
+tray_icon_tooltip_loading = Space Acres
+tray_icon_tooltip_syncing = Space Acres: sinhronizacija
+tray_icon_tooltip_plotting = Space Acres: plotovanje
+tray_icon_tooltip_farming = Space Acres: farmovanje
+tray_icon_tooltip_paused = Space Acres: farmovanje je pauzirano
+tray_icon_tooltip_error = Space Acres: greška
+tray_icon_quit = Izađi
 
 notification_app_minimized_to_tray = Space Acres je minimiziran u sistemsku traku
     .body = Možete ga ponovo otvoriti ili potpuno izaći koristeći meni ikone u sistemskoj traci
+notification_already_running = Space Acres je već pokrenut
+    .body = Samo jedna instanca može raditi u isto vreme, koristite prozor pokrenute instance ili njenu ikonicu u sistemskoj traci
 notification_stopped_with_error = Space Acres je zaustavljen zbog greške
     .body = Došlo je do greške koja zahteva intervenciju korisnika za rešavanje
 notification_farm_error = Jedna od farmi u Space Acresu nije uspela
     .body = Došlo je do greške koja zahteva intervenciju korisnika za rešavanje
-notification_signed_reward_successfully = Uspešno potpisana nova nagrada 🥳
-    .body = Hvala vam što osiguravate mrežu 🙌
+notification_farm_disk_full = Disk jedne od farmi je pun
+    .body = Farma je zaustavljena, oslobodite prostor na disku da biste nastavili njen rad
+notification_farm_directory_inaccessible = Direktorijum jedne od farmi nije dostupan
+    .body = Farma je zaustavljena, proverite da li je disk povezan
 notification_missed_reward = Potpisivanje nagrade nije uspelo 😞
     .body = To je nesreća, ali biće još prilika uskoro
+notification_reward_received = Primljeno {NUMBER($a_amount, minimumFractionDigits: 2, maximumFractionDigits: 2)} {$b_token_symbol} 🥳
+notification_reward_received_body = {$a_rewards_count ->
+        [1] Nagradu je potpisala farma {$b_farm}, hvala vam što obezbeđujete mrežu 🙌
+        *[other] Primljeno {$a_rewards_count} nagrada, poslednju je potpisala farma {$b_farm}
+    }
+notification_reward_received_unknown_farm = nepoznato
+notification_initial_plotting_finished = Početno plotovanje završeno 🎉
+notification_initial_plotting_finished_body = {$a_space_plotted} plotovano za {NUMBER($b_hours, minimumFractionDigits: 1, maximumFractionDigits: 1)} h, sve farme sada farmuju
+notification_slow_auditing = Farma prespora vrši reviziju ⚠️
+notification_slow_auditing_body = Farmi {$farm} revizija traje duže od trajanja slota i propušta nagrade, proverite ispravnost diska
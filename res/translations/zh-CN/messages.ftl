@@ -40,6 +40,9 @@ loading_networking_stack_step_created_successfully = 网络工作栈创建成功
 loading_consensus_node_title = 初始化共识节点
 loading_consensus_node_step_creating = 创建共识节点...
 loading_consensus_node_step_created_successfully = 共识节点创建成功
+loading_consensus_node_step_connecting_to_external_node = 正在连接外部节点...
+loading_consensus_node_step_connected_to_external_node = 已成功连接到外部节点
+loading_consensus_node_step_external_node_unreachable = 无法连接外部节点，改用内置节点
 loading_farmer_title = 实例化农民
 loading_farmer_step_initializing = 初始化农场 {$index}/{$farms_total}...
 loading_farmer_step_created_successfully = 农民创建成功
@@ -57,46 +60,230 @@ configuration_node_path_placeholder = 示例: {$path}
 configuration_node_path_tooltip = 节点文件的绝对路径，建议预留不少于 100G 的高性能 SSD 空间
 configuration_node_path_button_select = 选择
 configuration_node_path_error_doesnt_exist_or_write_permissions = 文件目录不存在或当前用户无写入权限
+configuration_node_path_error_farm_path_conflict = 节点不能与任何农场使用相同的文件夹，也不能使用其子文件夹或上级文件夹
+configuration_node_path_free_space = 可用空间: {$free_space}
+configuration_node_path_free_space_unknown = 可用空间: 未知
+configuration_node_path_warning_low_free_space = 可用空间不足 {$threshold}，节点数据库会随时间增长，可能会耗尽空间
 configuration_reward_address = 奖励地址
 configuration_reward_address_placeholder = 示例: {$address}
-configuration_reward_address_tooltip = 使用 Subwallet 或 polkadot{"{"}.js{"}"} 扩展来创建地址，任何 Substrate 链的 SS58 格式地址都可用作奖励地址
+configuration_reward_address_tooltip = 使用 Subwallet 或 polkadot{"{"}.js{"}"} 扩展来创建地址，Subspace 或通用 Substrate 格式的 SS58 地址都可用作奖励地址
 configuration_reward_address_button_create_wallet = 创建钱包
 configuration_reward_address_error_evm_address = 应使用 SS58 格式的 Substrate 地址，而不是 EVM 地址
+configuration_reward_address_error_wrong_network = 该地址属于其他网络（SS58 前缀 {$a_prefix}），请使用 Subspace 地址（前缀 {$b_expected_prefix}）或通用 Substrate 格式地址（前缀 42）
 configuration_farm = 农场 {$index} 的目录和大小
+configuration_farm_named = 农场 "{$name}" 的目录和大小
+configuration_farm_drag_handle_tooltip = 拖动以调整农场顺序
+configuration_farm_collapse_tooltip = 折叠农场
+configuration_farm_expand_tooltip = 展开农场以进行编辑
+configuration_farm_collapsed_summary = {$path}，大小 {$size}
+configuration_farm_name_placeholder = 名称（可选）
+configuration_farm_name_tooltip = 可选的农场名称，便于与其他农场区分，例如 "NVMe-A"
+configuration_farm_name_accessible_label = 农场名称
+configuration_farms_search_placeholder = 按路径或名称搜索农场
 configuration_farm_path_placeholder = 示例: {$path}
 configuration_farm_path_tooltip = 存储农场数据文件的绝对路径，可使用任何类型的 SSD
+configuration_farm_path_accessible_label = 农场文件目录
 configuration_farm_path_button_select = 选择
+configuration_farm_path_button_select_accessible_label = 选择农场文件目录
+configuration_farm_path_button_recent = 最近选择的文件目录
+configuration_farm_path_checking = 正在检查文件目录...
 configuration_farm_path_error_doesnt_exist_or_write_permissions = 文件目录不存在或当前用户无写入权限
+configuration_farm_path_error_empty = 尚未选择农场文件夹
+configuration_farm_path_state_empty = 文件目录为空，将创建新农场
+configuration_farm_path_state_existing_farm = 在该文件目录中找到现有农场，将继续使用
+configuration_farm_path_state_unrelated_files = 文件目录包含不属于农场的文件，请确保该目录未被用于其他用途
+configuration_farm_path_warning_removable_or_network_drive = 文件目录位于可移动或网络驱动器上，如果驱动器断开连接，农场将停止工作并可能损坏
+configuration_farm_path_warning_system_disk = 文件目录与操作系统或主文件夹位于同一磁盘上，农场数据占满该磁盘可能导致系统不稳定，建议使用单独的磁盘
+configuration_farm_path_error_duplicate = 该文件目录已被其他农场使用
+configuration_farm_path_error_node_path_conflict = 农场不能与节点使用相同的文件夹，也不能使用其子文件夹或上级文件夹
+configuration_farm_storage_kind_ssd = 检测为 SSD
+configuration_farm_storage_kind_hdd = 检测为 HDD，在 HDD 上绘图和耕种比 SSD 慢得多
+configuration_farm_storage_kind_unknown = 存储设备类型未知
 configuration_farm_size_kind_fixed = 固定大小
 configuration_farm_size_kind_free_percentage = % 空闲磁盘大小
 configuration_farm_fixed_size_placeholder = 示例: 4T, 2.5TB, 500GiB, 等.
-configuration_farm_fixed_size_tooltip = 农场单元的大小，可以使用任何大于 2GB 的值
+configuration_farm_fixed_size_tooltip = 农场单元的大小，可以使用任何大于 2GB 的值。支持十进制单位（KB、MB、GB、TB，1 TB = 10^12 字节）和二进制单位（KiB、MiB、GiB、TiB，1 TiB = 2^40 字节）
+configuration_farm_fixed_size_accessible_label = 农场大小
+configuration_farm_fixed_size_preview = = {$a_bytes} 字节 ({$b_decimal}, {$c_binary})
+configuration_farm_sectors = ≈ {$sectors} 个扇区
+configuration_farm_usable_size = 可用于绘图：{$size}
+configuration_farm_usable_size_warning = 仅 {$a_size} 可用于绘图，剩余的 {$b_wasted} 不足以容纳另一个扇区，将不会被使用
+configuration_farm_fixed_size_button_max = 最大
+configuration_farm_fixed_size_button_max_tooltip = 使用所有空闲磁盘空间，保留 2 GiB 剩余空间以避免出现问题
+configuration_farm_fixed_size_warning_doesnt_fit = 农场大小超过磁盘可用空间，除非农场已占用该空间，否则将被缩减至可容纳的大小
+configuration_farm_fixed_size_error_too_small = 农场大小低于最小值 {$min_size}
+configuration_farm_size_error_invalid_format = 无法识别大小，请使用 2TB 或 500GiB 之类的格式
+configuration_farm_free_percentage_size_error_invalid = 必须是大于 0% 且不超过 100% 的百分比，例如 90%
 configuration_farm_free_percentage_size_placeholder = 示例: 100%, 1.1%, 等.
 configuration_farm_free_percentage_size_tooltip = 用于该农场的磁盘大小百分比，可以指定任意大小，但需保留最少2GB的剩余空间避免出现问题
+configuration_farm_free_percentage_size_accessible_label = 农场大小占磁盘可用空间的百分比
+configuration_farm_free_percentage_size_resolved = 按当前可用空间约为 {$size}，启动时会计入农场已占用的空间
+configuration_farm_free_space = 可用空间: {$free_space}
+configuration_farm_free_space_unknown = 可用空间: 未知
+configuration_farm_reserve = 磁盘保留空间:
+configuration_farm_reserve_placeholder = 示例: 10GiB（可选）
+configuration_farm_reserve_tooltip = 除农场外磁盘上必须保留的空闲空间，适用于磁盘完全写满时可能出现问题的文件系统，留空表示不保留
+configuration_farm_reward_address = 奖励地址：
+configuration_farm_reward_address_placeholder = 留空则使用全局奖励地址
+configuration_farm_reward_address_tooltip = 仅用于此农场的可选奖励地址，适用于同一台机器上的农场属于不同人的情况，留空则使用全局奖励地址
+configuration_farm_reward_address_error_invalid = 奖励地址不是该网络的有效地址
+configuration_farm_benchmark_button = 测试磁盘速度
+configuration_farm_benchmark_button_tooltip = 在此文件夹中写入并读取一个256 MiB的临时文件，以检查磁盘是否足够快以进行绘图和耕种，随后该文件会被删除
+configuration_farm_benchmark_running = 正在测试磁盘速度...
+configuration_farm_benchmark_result = 顺序写入: {NUMBER($a_sequential_write, maximumFractionDigits: 0)} MB/s，顺序读取: {NUMBER($b_sequential_read, maximumFractionDigits: 0)} MB/s，随机读取: {NUMBER($c_random_read, maximumFractionDigits: 0)} MB/s
+configuration_farm_benchmark_result_pass = 磁盘速度足以进行绘图和耕种
+configuration_farm_benchmark_result_warn = 磁盘速度可能不足以进行绘图和耕种，请考虑使用其他磁盘
+configuration_farm_benchmark_error = 测试磁盘速度失败: {$error}
 configuration_farm_delete = 删除这个农场
+configuration_farm_delete_confirmation = 删除这个农场?
+configuration_farm_delete_confirmation_button_cancel = 取消
+configuration_farm_delete_confirmation_button_delete = 删除
+configuration_farm_delete_confirmation_delete_data = 同时从磁盘删除农场数据，这将释放 {$size}
+configuration_farm_delete_confirmation_delete_data_in_use = 农场正在使用中，请先在运行界面禁用它，然后才能删除其数据
+configuration_farm_delete_data_progress = 正在删除农场数据…
+configuration_farm_delete_data_error = 删除农场数据出错: {$error}
+configuration_farms_summary = 所有农场总大小: {$size}
+configuration_farms_summary_with_free_space = 所有农场总大小: {$a_size}（所有磁盘可用空间: {$b_free_space}）
 configuration_advanced = 高级配置
 configuration_advanced_farmer = 农民配置
 configuration_advanced_farmer_reduce_plotting_cpu_load = 降低绘图时CPU负载
 configuration_advanced_farmer_reduce_plotting_cpu_load_tooltip = 初次绘图默认会使用所有CPU核心，这个选项可以在重新绘图时只占用50%的CPU来使系统响应其他任务更加流畅
+configuration_advanced_farmer_plotting_threads = 绘图 CPU 线程数
+configuration_advanced_farmer_plotting_threads_tooltip = 用于绘图的 CPU 线程数，较小的值可为其他应用程序留出更多 CPU，默认使用全部 {$cpu_threads} 个线程
+configuration_advanced_farmer_sector_encoding_concurrency = 并发编码扇区数
+configuration_advanced_farmer_sector_encoding_concurrency_tooltip = 同时绘制的扇区数量，较大的值可在高性能机器上加快绘图速度但会占用更多内存，较小的值可减少内存占用
+configuration_advanced_farmer_sector_encoding_concurrency_memory = 约 {$ram} 内存
+configuration_advanced_farmer_auditing_threads = 审计 CPU 线程数
+configuration_advanced_farmer_auditing_threads_tooltip = 用于审计和证明的 CPU 线程总数，在各农场之间平均分配，每个农场至少一个线程，较小的值可在农场较多时减轻磁盘和 CPU 的负载，但会增加审计延迟，选择全部 {$cpu_threads} 个线程时每个农场使用根据 CPU 确定大小的独立线程池
+configuration_advanced_farmer_auditing_threads_error_too_few = 线程数少于农场数，{$farms} 个农场中的每一个都至少需要一个线程
+configuration_advanced_farmer_piece_cache_size = 碎片缓存大小
+configuration_advanced_farmer_piece_cache_size_tooltip = 所有农场共享的碎片缓存总大小, 例如 10 GiB, 较大的缓存可加快同步和绘制, 但会减少可用于绘制的空间, 留空则使用最小缓存
+configuration_advanced_farmer_piece_cache_size_low = 碎片缓存过小可能会减慢同步和绘制
+configuration_advanced_farmer_piece_cache_path = 碎片缓存文件夹
+configuration_advanced_farmer_piece_cache_path_placeholder = 存储在农场中
+configuration_advanced_farmer_piece_cache_path_tooltip = 可选的碎片缓存文件夹，例如在农场位于机械硬盘时放在节点所在的高速固态硬盘上，未选择时碎片缓存存储在农场中
+configuration_advanced_farmer_piece_cache_path_button_select = 选择
+configuration_advanced_farmer_piece_cache_path_button_clear = 将碎片缓存存储在农场中
+configuration_advanced_farmer_piece_cache_path_error_farm_path_conflict = 碎片缓存不能与任何农场使用相同的文件夹，也不能使用其子文件夹或上级文件夹
+configuration_advanced_farmer_reward_notifications = 奖励通知
+configuration_advanced_farmer_reward_notifications_tooltip = 获得奖励或签署奖励失败时显示桌面通知，通知最多每分钟显示一次
+configuration_advanced_farmer_plotting_notifications = 绘制通知
+configuration_advanced_farmer_plotting_notifications_tooltip = 所有农场的初始绘制完成后显示桌面通知
+configuration_advanced_farmer_farm_health_notifications = 农场健康通知
+configuration_advanced_farmer_farm_health_notifications_tooltip = 当某个农场持续审计慢于时隙时长并错失奖励时显示桌面通知
+configuration_advanced_application = 应用程序配置
+configuration_advanced_application_tray_icon = 托盘图标
+configuration_advanced_application_tray_icon_tooltip = 在系统托盘中显示图标，并非所有桌面环境都能很好地支持，需要重启
+configuration_advanced_application_minimize_to_tray = 关闭时最小化到托盘
+configuration_advanced_application_minimize_to_tray_tooltip = 关闭窗口时隐藏到托盘而不是退出，耕种在后台继续运行，需要重启
+configuration_advanced_application_theme = 主题
+configuration_advanced_application_theme_tooltip = 应用程序的配色方案，立即生效
+configuration_advanced_application_theme_system = 跟随系统
+configuration_advanced_application_theme_light = 浅色
+configuration_advanced_application_theme_dark = 深色
+configuration_advanced_application_theme_high_contrast = 高对比度
+configuration_advanced_application_language = 语言
+configuration_advanced_application_language_tooltip = 用户界面语言，如果不可用则使用系统语言，需要重启
+configuration_advanced_application_language_system = 跟随系统
+configuration_advanced_application_log_level = 日志级别
+configuration_advanced_application_log_level_tooltip = 日志的详细程度，保存后立即生效，无需重启
+configuration_advanced_application_log_level_error = 错误
+configuration_advanced_application_log_level_warn = 警告
+configuration_advanced_application_log_level_info = 信息
+configuration_advanced_application_log_level_debug = 调试
+configuration_advanced_application_log_level_trace = 跟踪
+configuration_advanced_application_log_level_verbose_warning = 调试和跟踪级别会产生大量日志并可能影响性能，问题诊断完成后请改回信息级别
+configuration_advanced_application_offline_mode = 离线模式
+configuration_advanced_application_offline_mode_tooltip = 禁止与耕种无关的出站连接，例如新版本检查、系统时钟检查和节点遥测，节点和农民的网络连接不受影响
+configuration_advanced_application_update_checks = 检查新版本
+configuration_advanced_application_update_checks_tooltip = 定期在 GitHub 上检查新版本，在按流量计费的网络或离线环境中请禁用，保存后立即生效
+configuration_advanced_application_update_check_interval = 检查频率
+configuration_advanced_application_update_check_interval_hourly = 每小时
+configuration_advanced_application_update_check_interval_daily = 每天
+configuration_advanced_application_update_check_interval_weekly = 每周
+configuration_advanced_application_update_auto_download = 自动下载新版本
+configuration_advanced_application_update_auto_download_tooltip = 将新版本安装程序下载到下载文件夹，而不仅仅显示通知，仍需手动安装
+configuration_advanced_application_versions = 已安装版本：{$a_installed}，最新版本：{$b_latest}
+configuration_advanced_application_versions_latest_unknown = 已安装版本：{$installed}，尚不知道最新版本
 configuration_advanced_network = 网络配置
 configuration_advanced_network_default_port_number_tooltip = 默认端口是 {$port}
 configuration_advanced_network_substrate_port = Substrate (blockchain) P2P 端口 (TCP):
 configuration_advanced_network_subspace_port = Subspace (DSN) P2P 端口 (TCP):
 configuration_advanced_network_faster_networking = 快速网络:
 configuration_advanced_network_faster_networking_tooltip = 默认的网络配置已为消费级路由优化，但如果你有高性能的配置，快速网络设置可能提升节点同步速度和其他流程
+configuration_advanced_network_external_address = 外部地址
+configuration_advanced_network_external_address_tooltip = 可选的 DSN 地址（multiaddr 格式），会公布给其他节点，在路由器上做了端口转发时很有用
+configuration_advanced_network_node_rpc_url = 外部节点 RPC URL（可选）:
+configuration_advanced_network_node_rpc_url_tooltip = 使用你自己节点的 WebSocket RPC URL 代替内置节点进行耕种，留空则使用内置节点
+configuration_advanced_network_node_rpc_url_button_check = 检查
+configuration_advanced_network_node_rpc_url_connected = 已成功连接到外部节点
+configuration_advanced_network_node_rpc_url_failed = 连接外部节点失败: {$error}
+configuration_advanced_network_metrics = Prometheus 指标:
+configuration_advanced_network_metrics_tooltip = 通过 HTTP 以 Prometheus 格式公开节点和农民指标
+configuration_advanced_network_metrics_address_tooltip = 提供指标的地址，例如 127.0.0.1:9616
+configuration_advanced_network_node_rpc = 节点 RPC:
+configuration_advanced_network_node_rpc_tooltip = 在自定义地址上为外部工具公开内置节点的 RPC，否则节点 RPC 仅在内部本地端口上可用
+configuration_advanced_network_node_rpc_address_tooltip = 提供节点 RPC 的地址，例如 127.0.0.1:9944
+configuration_advanced_network_node_rpc_warning_public = 节点 RPC 将可以从其他计算机访问，任何能访问此地址的人都可以查询您的节点。仅在受信任的网络或防火墙后使用非本地地址。
+configuration_advanced_network_control_api = 本地控制 API:
+configuration_advanced_network_control_api_tooltip = 允许本机上的脚本通过本地套接字（Windows 上为命名管道）查看状态并暂停或恢复耕种，仅当前用户可访问
 configuration_button_add_farm = 新增农场
+configuration_button_add_farm_tooltip = 添加一个农场 (Ctrl+N)
+configuration_button_suggest_farms = 自动配置农场
+configuration_button_suggest_farms_tooltip = 查找有足够可用空间的磁盘（系统盘和可移动驱动器除外），并在每个磁盘上添加使用 90% 可用空间的农场，保存前可以调整或删除这些农场
+configuration_suggest_farms_none_found = 未找到合适的磁盘，可以手动添加农场
+configuration_button_import_config = 导入配置
+configuration_button_import_config_tooltip = 从配置文件（例如来自另一台机器）导入节点路径、奖励地址、网络配置和农场
+configuration_button_export_config = 导出配置
+configuration_button_export_config_tooltip = 将当前配置导出到文件，例如用于备份或在另一台机器上使用，扩展名为 .toml 的文件以 TOML 格式写入，否则以 JSON 格式写入
 configuration_button_help = 帮助
 configuration_button_cancel = 取消
 configuration_button_back = 返回
 configuration_button_save = 保存
 configuration_button_start = 开始
+configuration_plotting_memory_warning = 预计绘图需要约 {$a_estimated} 内存，对于本机当前可用的 {$b_available} 内存来说过多，可能导致应用程序被操作系统终止
+configuration_plotting_memory_warning_reduce = 将并发编码扇区数减少到 {$a_concurrency} 可将内存占用降低到约 {$b_memory}
+configuration_plotting_memory_warning_button_cancel = 取消
+configuration_plotting_memory_warning_button_continue = 仍然继续
+configuration_plotting_memory_warning_button_reduce = 减少并继续
 configuration_dialog_button_select = 选择
 configuration_dialog_button_cancel = 取消
+configuration_dialog_button_import = 导入
+configuration_dialog_button_export = 导出
+configuration_dialog_filter_json = JSON 文件
+configuration_dialog_filter_toml = TOML 文件
+configuration_import_config_error_not_found = 未找到配置文件
+configuration_import_config_error = 导入配置失败: {$error}
+configuration_export_config_error_incomplete = 配置尚未完全加载，请重试
+configuration_export_config_error = 导出配置失败: {$error}
 
 running_title = 运行中
 running_node_title = {$chain_name} 共识节点
 running_node_title_tooltip = 在文件管理器中打开
+running_node_button_copy_peer_id = 复制节点 Peer ID
+running_node_button_copy_genesis_hash = 复制创世哈希
+running_node_genesis_hash = 创世哈希：{$genesis_hash}
+running_node_clock_offset_warning_seconds =
+    {$a_direction ->
+        [ahead] 系统时钟比准确时间快 {NUMBER($b_offset, maximumFractionDigits: 1)} 秒
+        *[behind] 系统时钟比准确时间慢 {NUMBER($b_offset, maximumFractionDigits: 1)} 秒
+    }，这可能导致节点无法同步或农民无法获得奖励。请在系统设置中启用自动时间同步 (NTP)
+running_node_clock_offset_warning_minutes =
+    {$a_direction ->
+        [ahead] 系统时钟比准确时间快 {NUMBER($b_offset, maximumFractionDigits: 1)} 分钟
+        *[behind] 系统时钟比准确时间慢 {NUMBER($b_offset, maximumFractionDigits: 1)} 分钟
+    }，这可能导致节点无法同步或农民无法获得奖励。请在系统设置中启用自动时间同步 (NTP)
 running_node_free_disk_space_tooltip = 空闲磁盘大小: {$size}
+running_node_peers = {$peers_count} 个对等节点
+running_node_peers_tooltip = 节点已连接的对等节点，点击查看列表
+running_node_peers_empty = 未连接到任何对等节点
+running_node_peer = {$a_peer_id}
+    {$b_role ->
+        [authority] 权威节点
+        [light] 轻客户端
+        *[full] 全节点
+    }，最佳区块 #{$c_best_block_number}，{$d_address}
+running_node_peer_address_unknown = 地址未知
 running_node_status_connecting = 连接网络中，最新区块 #{$block_number}
 running_node_status_syncing_speed_no_eta = , {NUMBER($blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} blocks/s
 running_node_status_syncing_speed_hours_eta = , {NUMBER($a_blocks_per_second, minimumFractionDigits: 2, maximumFractionDigits: 2)} blocks/s (预计 ~{NUMBER($b_hours_remaining, minimumFractionDigits: 2, maximumFractionDigits: 2)} 小时)
@@ -112,8 +299,16 @@ running_node_status_synced = 同步完成, 最新区块 #{$best_block_number}
 running_farmer_title = 农民
 running_farmer_button_expand_details = 各农场的详细信息
 running_farmer_button_pause_plotting = 暂停绘图/重新绘图，当前的编码扇区不会被中断
-running_farmer_account_balance_tooltip = 自启动以来耕种到的总奖励币，点击在 Astral 中查看更多详细信息
+running_farmer_button_pause_farming = 暂停耕种，在恢复耕种之前不会签署奖励，也不会绘制扇区
+running_farmer_farming_paused = 耕种已暂停
+running_farmer_slow_auditing_warning = 以下农场审计过慢, 正在错失奖励: {$farms}, 请检查磁盘健康状况
+running_farmer_account_balance_tooltip = 所有奖励地址的总余额以及自启动以来耕种到的奖励币，点击在 Astral 中查看全局奖励地址的详细信息
+running_farmer_button_copy_reward_address = 复制奖励地址
+running_farmer_network_space = 全网空间（估算）：{$a_network_space}，您的占比：{NUMBER($b_share, maximumFractionDigits: 4)}%
+running_farmer_network_space_tooltip = 全网质押空间根据最新区块的解范围估算，您的占比基于目前已绘制的空间，约等于您出每个区块的概率
 running_farmer_piece_cache_sync = Piece缓存同步 {NUMBER($percentage, minimumFractionDigits: 2, maximumFractionDigits: 2)}%
+running_farmer_piece_cache_sync_pieces = 已缓存 {$a_cached_pieces}/{$b_total_pieces} 个碎片
+running_farmer_piece_cache_sync_description = 正在使用从网络下载的碎片填充碎片缓存, 绘制并未停滞, 但在完成之前可能会较慢
 running_farmer_next_reward_estimate =
     下次奖励预计: {$eta_string ->
         [any_time_now] 即将发生
@@ -123,19 +318,85 @@ running_farmer_next_reward_estimate =
         [more_than_a_week] 一周以上
         *[unknown] 未知
     }
+running_farmer_rewards_title = 奖励
+running_farmer_rewards_mode_cumulative = 累计
+running_farmer_rewards_mode_per_interval = 按时段
+running_farmer_rewards_cumulative_tooltip = 随时间累计获得的奖励总额
+running_farmer_rewards_per_interval_tooltip = 每{$unit ->
+        [hour] 小时
+        [day] 天
+        *[week] 周
+    }获得的奖励
+running_farmer_stats_title = 累计统计
+running_farmer_stats_uptime = 运行时间: {$a_days}天 {$b_hours}小时 {$c_minutes}分钟
+running_farmer_stats_rewards_count = 获得奖励次数 (区块和投票): {$rewards_count}
+running_farmer_stats_rewards_total = 累计奖励: {NUMBER($a_amount, minimumFractionDigits: 2, maximumFractionDigits: 2)} {$b_token_symbol}
+running_farmer_stats_button_reset = 重置
+running_farmer_stats_button_reset_tooltip = 将累计统计清零
+running_farmer_stats_reset_confirmation = 重置累计统计？此操作无法撤销。
+running_farmer_stats_reset_confirmation_button_cancel = 取消
+running_farmer_stats_reset_confirmation_button_reset = 重置
+running_farmer_farms_sort_by = 农场排序：
+running_farmer_farms_sort_by_default = 默认
+running_farmer_farms_sort_by_default_tooltip = 按配置中农场的顺序
+running_farmer_farms_sort_by_size = 大小
+running_farmer_farms_sort_by_size_tooltip = 最大的农场在前
+running_farmer_farms_sort_by_path = 路径
+running_farmer_farms_sort_by_status = 状态
+running_farmer_farms_sort_by_status_tooltip = 出错的农场在前，然后是绘图中、耕种中、已暂停和已停止的农场
+running_farmer_farms_compact_view = 紧凑视图
+running_farmer_farms_compact_view_tooltip = 以按状态着色的小方块网格显示农场，点击方块查看农场详情
 running_farmer_farm_tooltip = 在文件管理器中打开
+running_farmer_farm_enabled_tooltip = 启用或禁用此农场，禁用的农场会被停止，下次启动时也不会被打开
 running_farmer_farm_reward_signatures_tooltip = {$successful_signatures}/{$total_signatures} 奖励签名成功，打开农场查看更多信息
 running_farmer_farm_auditing_performance_tooltip = 审计性能: 平均时长 {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}秒, 时间限制 {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}秒
 running_farmer_farm_proving_performance_tooltip = 证明性能: 平均时长 {NUMBER($a_average_time, minimumFractionDigits: 2, maximumFractionDigits: 2)}秒, 时间限制 {NUMBER($b_time_timit, minimumFractionDigits: 2, maximumFractionDigits: 2)}秒
+running_farmer_farm_auditing_latency = 审计 p50/p95/max: {NUMBER($a_p50, minimumFractionDigits: 2, maximumFractionDigits: 2)}秒/{NUMBER($b_p95, minimumFractionDigits: 2, maximumFractionDigits: 2)}秒/{NUMBER($c_max, minimumFractionDigits: 2, maximumFractionDigits: 2)}秒
+running_farmer_farm_auditing_latency_tooltip = 过去 10 分钟的审计时长百分位数, 时间限制 {NUMBER($time_limit, minimumFractionDigits: 2, maximumFractionDigits: 2)}秒
+running_farmer_farm_auditing_latency_slow_tooltip = 审计时长超过时隙时长, 农场正在错失奖励, 请检查磁盘健康状况
+running_farmer_farm_proving_latency = 证明 p50/p95/max: {NUMBER($a_p50, minimumFractionDigits: 2, maximumFractionDigits: 2)}秒/{NUMBER($b_p95, minimumFractionDigits: 2, maximumFractionDigits: 2)}秒/{NUMBER($c_max, minimumFractionDigits: 2, maximumFractionDigits: 2)}秒
+running_farmer_farm_proving_latency_tooltip = 最近 100 次证明的时长百分位数, 时间限制 {NUMBER($time_limit, minimumFractionDigits: 2, maximumFractionDigits: 2)}秒
 running_farmer_farm_non_fatal_error_tooltip = 非致命错误发生并已经恢复，在日志中查看更多信息: {$error}
 running_farmer_farm_crashed = 农场崩溃: {$error}
+running_farmer_farm_disk_full = 磁盘已满，农场已停止。请释放磁盘空间或在配置中减少分配的空间，然后恢复农场
+running_farmer_farm_directory_inaccessible = 农场目录无法访问，农场已停止。请确认磁盘已连接
+running_farmer_farm_directory_accessible = 农场目录已可再次访问，重新连接以继续耕种
+running_farmer_farm_button_resume = 恢复
+running_farmer_farm_button_reconnect = 重新连接
+running_farmer_farm_button_resume_tooltip = 重新打开农场并继续耕种和绘图
+running_farmer_farm_resize_button_tooltip = 增加农场大小
+running_farmer_farm_resize_title = 增加农场大小
+running_farmer_farm_resize_placeholder = 新的大小，例如 4T, 2.5TB, 500GiB 等
+running_farmer_farm_resize_apply = 应用
+running_farmer_farm_resize_error_invalid = 无效的大小
+running_farmer_farm_resize_error_shrink = 农场大小只能增加，不支持缩小农场，请改为创建一个更小的新农场
+running_farmer_farm_resize_error_too_small = 农场大小必须至少为 {$min_size}
+running_farmer_farm_resize_error_doesnt_fit = 磁盘可用空间不足，农场最多可以增加 {$free_space}
+running_farmer_farm_disabled = 已禁用
+running_farmer_farm_enabled_restart_needed = 已启用，需要重新启动农场才能开始运行
+running_farmer_farm_plotted_sectors = 已绘制 {$a_plotted_sectors}/{$b_total_sectors} 个扇区
+running_farmer_farm_tile_progress = {NUMBER($progress, maximumFractionDigits: 0)}%
+running_farmer_farm_tile_status =
+    {$status ->
+        [error] 因错误已停止
+        [plotting] 绘图中
+        [paused] 已暂停
+        [stopped] 已停止
+        *[farming] 耕种中
+    }
 running_farmer_farm_plotting_speed =  ({NUMBER($a_sector_time, minimumFractionDigits: 2, maximumFractionDigits: 2)} 分钟/扇区, {NUMBER($b_sectors_per_hour, minimumFractionDigits: 2, maximumFractionDigits: 2)} 扇区/小时)
+running_farmer_farm_plotting_eta_calculating = 预计剩余时间: 计算中…
+running_farmer_farm_plotting_eta_hours = 预计剩余时间: {NUMBER($hours, minimumFractionDigits: 1, maximumFractionDigits: 1)} 小时
+running_farmer_farm_plotting_eta_minutes = 预计剩余时间: {NUMBER($minutes, minimumFractionDigits: 0, maximumFractionDigits: 0)} 分钟
+running_farmer_farm_disk_io = 磁盘：读取 {$a_read}/s，写入 {$b_write}/s，{$c_iops} IOPS
+running_farmer_farm_disk_io_tooltip = 农场所在磁盘分区在过去 10 秒内的平均 I/O，包括同一分区上其他农场和应用程序的 I/O
 running_farmer_farm_plotting_initial =
     {$pausing_state ->
         [pausing] 初始绘制暂停中
         [paused] 初始绘制暂停
         *[no] 初始绘制中
     } {NUMBER($b_progress, minimumFractionDigits: 2, maximumFractionDigits: 2)}%{$plotting_speed}, {$farming ->
+        [paused] 耕种已暂停
         [yes] 耕种中
         *[no] 未耕种
     }
@@ -145,10 +406,12 @@ running_farmer_farm_replotting =
         [paused] 初始绘制暂停
         *[default] 重新绘制
     } {NUMBER($b_progress, minimumFractionDigits: 2, maximumFractionDigits: 2)}%{$plotting_speed}, {$farming ->
+        [paused] 耕种已暂停
         [yes] 耕种中
         *[no] 未耕种
     }
 running_farmer_farm_farming = 耕种
+running_farmer_farm_farming_paused = 耕种已暂停
 running_farmer_farm_waiting_for_node_to_sync = 等待节点同步
 running_farmer_farm_sector = 扇区 {$sector_index}
 running_farmer_farm_sector_up_to_date = 扇区 {$sector_index}: 有效
@@ -161,6 +424,14 @@ running_farmer_farm_sector_writing = 扇区 {$sector_index}: 写入中
 
 shutting_down_title = 关闭
 shutting_down_description = 这可能需要几秒到几分钟的时间，取决于你运行的程序
+shutting_down_step_stopping = 正在停止…
+shutting_down_step_stopping_farmer = 正在停止农场，将已绘制的扇区写入磁盘…
+shutting_down_step_stopping_node = 正在停止节点，将数据库写入磁盘…
+shutting_down_step_stopping_networking = 正在停止网络…
+shutting_down_button_force_quit = 强制退出
+shutting_down_button_force_quit_tooltip = 不等待关闭完成直接退出，数据可能无法完整写入磁盘
+restarting_services_title = 正在重启节点和农民
+restarting_services_description = 节点和农民将停止并使用磁盘上的配置重新启动，应用程序保持运行
 
 stopped_title = 暂停
 stopped_message = 暂停 🛑
@@ -168,43 +439,185 @@ stopped_message_with_error = 由于错误暂停: {$error}
 stopped_button_show_logs = 打开日志
 stopped_button_help_from_community = 获得社区支持
 
+startup_retry_title = 正在重试启动
+startup_retry_message = 启动失败: {$error}
+startup_retry_countdown = 第 {$attempt} 次尝试失败，将在 {$seconds} 秒后重试
+startup_retry_button_retry_now = 立即重试
+startup_retry_button_cancel = 取消
+farm_mismatches_dialog_title = 部分农场与配置不匹配
+farm_mismatches_dialog_description = 以下农场按当前配置将无法启动，这通常发生在移动磁盘之后。继续之前请为每个农场选择处理方式。
+farm_mismatches_dialog_missing_plot = 在此文件夹中未找到农场，存放它的磁盘可能未连接
+farm_mismatches_dialog_unexpected_plot = 文件夹中的农场与之前不同
+farm_mismatches_dialog_unexpected_plot_different_chain = 文件夹中包含其他网络的农场
+farm_mismatches_dialog_size_mismatch = 配置的大小 {$configured_size} 小于磁盘上农场的大小 {$farm_size}，不支持缩小农场
+farm_mismatches_dialog_resolution_skip = 跳过（暂时禁用农场）
+farm_mismatches_dialog_resolution_replot = 删除现有数据并重新绘图
+farm_mismatches_dialog_resolution_replot_missing = 在此文件夹中绘制新农场
+farm_mismatches_dialog_resolution_fix_missing = 从配置中移除农场
+farm_mismatches_dialog_resolution_fix_unexpected = 使用在此文件夹中找到的农场
+farm_mismatches_dialog_resolution_fix_size = 使用磁盘上农场的大小
+farm_mismatches_dialog_button_continue = 继续
+
 error_title = 错误
 error_message = 错误: {$error}
 error_message_failed_to_send_config_to_backend = 发送到后端过程出错: {$error}
 error_message_failed_to_send_pause_plotting_to_backend = 发送暂停任务到后端出错: {$error}
+error_message_failed_to_send_pause_farming_to_backend = 发送暂停耕种到后端出错: {$error}
+error_message_failed_to_send_stop_farm_to_backend = 发送停止农场到后端出错: {$error}
+error_message_failed_to_send_start_farm_to_backend = 发送启动农场到后端出错: {$error}
+error_message_failed_to_send_restart_farm_to_backend = 发送重启农场到后端出错: {$error}
+error_message_failed_to_send_resize_farm_to_backend = 发送调整农场大小到后端出错: {$error}
+error_message_failed_to_send_startup_retry_to_backend = 发送重试启动到后端出错: {$error}
+error_message_failed_to_send_farm_mismatch_resolutions_to_backend = 发送农场不匹配处理方式到后端出错: {$error}
+error_message_failed_to_send_restart_services_to_backend = 发送重启节点和农民请求到后端出错: {$error}
 error_button_show_logs = 打开日志
 error_button_help_from_community = 获得社区支持
 
 new_version_available = 版本 {$version} 可用 🎉
-new_version_available_button_open = 打开版本发布页面
+new_version_available_button_open = 显示新版本的发布说明
+new_version_downloaded = 版本 {$version} 已下载 🎉
+new_version_downloaded_button_open = 显示新版本的发布说明和已下载的安装程序
+new_version_dialog_title = 版本 {$version} 的新内容
+new_version_dialog_no_release_notes = 暂无发布说明，详情请查看发布页面
+new_version_dialog_button_open_installer_folder = 打开安装程序文件夹
+new_version_dialog_button_open_release_page = 打开发布页面
+new_version_dialog_button_close = 关闭
+offline_mode_indicator = 离线模式
+offline_mode_indicator_tooltip = 新版本检查、系统时钟检查和节点遥测已禁用，可在配置中关闭离线模式
+profile_indicator = 档案：{$name}
+profile_indicator_tooltip = 节点和农民使用此档案的配置，可在主菜单中管理档案
 
+main_menu_log_viewer = 查看日志
 main_menu_show_logs = 在文件管理器中打开日志
+main_menu_event_log = 查看事件日志
+main_menu_profiles = 配置档案
+main_menu_export_diagnostics = 导出诊断信息
 main_menu_change_configuration = 修改配置
+main_menu_restart_services = 重启节点和农民
 main_menu_share_feedback = 分享反馈
 main_menu_about = 关于
 main_menu_exit = 退出
 
+log_viewer_title = 日志
+log_viewer_level = 级别
+log_viewer_level_error = 仅错误
+log_viewer_level_warn = 警告和错误
+log_viewer_level_info = 信息及以上
+log_viewer_level_debug = 调试及以上
+log_viewer_level_trace = 全部
+log_viewer_pause_tooltip = 暂停以停止显示新行和自动滚动
+log_viewer_resume_tooltip = 继续显示新行
+log_viewer_button_copy_all = 全部复制
+log_viewer_button_open_log_file = 打开日志文件
+
+event_log_title = 事件日志
+event_log_filter = 显示
+event_log_filter_all = 全部事件
+event_log_filter_node = 节点
+event_log_filter_farms = 农场
+event_log_filter_rewards = 奖励
+event_log_filter_errors = 仅错误
+event_log_button_open_event_log_file = 打开事件日志文件
+event_log_empty = 尚未记录任何事件
+event_log_event_services_started = 节点和农民已启动
+event_log_event_services_stopped = 节点和农民已停止
+event_log_event_services_stopped_with_error = 节点和农民因错误停止：{$error}
+event_log_event_services_restarting = 正在重启节点和农民
+event_log_event_profile_switched = 已切换到配置档案 {$profile}
+event_log_event_startup_failed = 第 {$a_attempt} 次启动失败：{$b_error}
+event_log_event_irrecoverable_error = 不可恢复的错误：{$error}
+event_log_event_node_synced = 节点已同步
+event_log_event_node_syncing = 节点开始同步
+event_log_event_node_disconnected = 节点与所有对等节点断开连接
+event_log_event_node_reconnected = 节点已重新连接到对等节点
+event_log_event_plotting_started = 农场 {$farm} 开始绘图
+event_log_event_plotting_finished = 农场 {$farm} 绘图完成
+event_log_event_farm_error = 农场 {$a_farm} 出错：{$b_error}
+event_log_event_reward = 收到 {NUMBER($a_amount, minimumFractionDigits: 2, maximumFractionDigits: 2)} {$b_token_symbol} 奖励
+
+diagnostics_title = 导出诊断信息
+diagnostics_description = 诊断包将配置、最近的日志和基本系统信息打包到一个文件中，可附加到问题报告或发送给支持人员
+diagnostics_include_sensitive = 包含奖励地址和完整路径
+diagnostics_include_sensitive_tooltip = 默认情况下，诊断包中的所有文件都会移除奖励地址，并将主目录替换为 ~
+diagnostics_button_export = 导出…
+diagnostics_export_success = 诊断信息已保存到 {$path}
+diagnostics_export_error = 导出诊断信息失败：{$error}
+diagnostics_dialog_button_save = 保存
+diagnostics_dialog_button_cancel = 取消
+diagnostics_dialog_filter = 诊断包
+
+profiles_title = 配置档案
+profiles_description = 每个档案都有自己的配置，包括节点位置、网络、奖励地址和农场。切换档案会停止节点和农民，并使用所选档案的配置重新启动它们，新档案需要从头开始配置。
+profiles_active_profile = {$name}（当前）
+profiles_button_switch = 切换
+profiles_button_delete = 删除
+profiles_button_create = 创建
+profiles_new_profile_name_placeholder = 新档案名称，例如 testnet
+profiles_new_profile_name_tooltip = 最多 32 个拉丁字母、数字、"-" 和 "_"，不能与现有档案名称相同
+profiles_error = 错误：{$error}
+profiles_switch_confirmation = 切换到档案 {$name}？
+profiles_switch_confirmation_details = 节点和农民将被停止，并使用此档案的配置重新启动
+profiles_switch_confirmation_button_switch = 切换
+profiles_delete_confirmation = 删除档案 {$name}？
+profiles_delete_confirmation_details = 此档案的配置将被删除，节点和农场数据保留在磁盘上
+profiles_delete_confirmation_button_delete = 删除
+profiles_confirmation_button_cancel = 取消
+
 status_bar_message_configuration_is_invalid = 配置不可用: {$error}
 status_bar_message_restart_is_needed_for_configuration = 重启以使配置修改生效
+status_bar_message_services_restart_is_needed_for_configuration = 重启节点和农民以使配置修改生效
 status_bar_message_failed_to_save_configuration = 保存配置修改失败: {$error}
 status_bar_message_restarted_after_crash = Space Acres在崩溃后自动重启，请在日志中查看详细信息
 status_bar_button_restart = 重启
+status_bar_button_restart_services = 重启节点和农民
+status_bar_button_restart_services_tooltip = 仅重启节点和农民以应用配置，应用程序保持运行
 status_bar_button_ok = 正常
+copy_button_copied = 已复制到剪贴板
+
+toast_configuration_saved = 配置已保存
+toast_configuration_imported = 配置已导入，请检查后保存以应用
+toast_configuration_exported = 配置已导出到 {$path}
+toast_suggested_farms_added = 已添加 {$count} 个建议的农场
+toast_failed_to_open = 无法打开：{$error}
+toast_profile_created = 档案 {$name} 已创建
+toast_profile_deleted = 档案 {$name} 已删除
 
 about_system_information =
     配置目录: {$config_directory}
     数据目录 (包括日志): {$data_directory}
 
 tray_icon_open = 打开
-tray_icon_close = 关闭
+tray_icon_pause = 暂停耕种
+tray_icon_resume = 恢复耕种
+tray_icon_tooltip_loading = Space Acres
+tray_icon_tooltip_syncing = Space Acres：同步中
+tray_icon_tooltip_plotting = Space Acres：绘制中
+tray_icon_tooltip_farming = Space Acres：耕种中
+tray_icon_tooltip_paused = Space Acres：耕种已暂停
+tray_icon_tooltip_error = Space Acres：错误
+tray_icon_quit = 退出
 
 notification_app_minimized_to_tray = Space Acres已最小化到托盘
     .body = 你可以关闭或从托盘中重新打开
+notification_already_running = Space Acres已在运行
+    .body = 同一时间只能运行一个实例, 请使用正在运行的实例窗口或其托盘图标
 notification_stopped_with_error = Space Acres由于错误暂停
     .body = 出现一个错误，需要手动解决
 notification_farm_error = 一个Space Acres农场出错
     .body = 出现一个错误，需要手动解决
-notification_signed_reward_successfully = 成功签署一份奖励 🥳
-    .body = 感谢参与 🙌
+notification_farm_disk_full = 一个农场的磁盘已满
+    .body = 农场已停止，请释放磁盘空间以恢复农场
+notification_farm_directory_inaccessible = 一个农场的目录无法访问
+    .body = 农场已停止，请确认磁盘已连接
 notification_missed_reward = 签署奖励失败 😞
     .body = 很不幸，但马上会有下次啦
+notification_reward_received = 获得 {NUMBER($a_amount, minimumFractionDigits: 2, maximumFractionDigits: 2)} {$b_token_symbol} 🥳
+notification_reward_received_body = {$a_rewards_count ->
+        [1] 奖励由农场 {$b_farm} 签署，感谢您保护网络 🙌
+        *[other] 共获得 {$a_rewards_count} 份奖励，最后一份由农场 {$b_farm} 签署
+    }
+notification_reward_received_unknown_farm = 未知
+notification_initial_plotting_finished = 初始绘制完成 🎉
+notification_initial_plotting_finished_body = 已在 {NUMBER($b_hours, minimumFractionDigits: 1, maximumFractionDigits: 1)} 小时内绘制 {$a_space_plotted}，所有农场现在都在耕种
+notification_slow_auditing = 农场审计过慢 ⚠️
+notification_slow_auditing_body = 农场 {$farm} 的审计时长超过时隙时长, 正在错失奖励, 请检查磁盘健康状况
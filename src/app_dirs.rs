@@ -0,0 +1,184 @@
+#[cfg(target_os = "linux")]
+use crate::instance_lock::InstanceLock;
+#[cfg(target_os = "linux")]
+use std::path::Path;
+use std::path::PathBuf;
+#[cfg(target_os = "linux")]
+use std::{fs, io};
+
+/// Directory with application configuration.
+///
+/// On Linux `$XDG_CONFIG_HOME/space-acres` is used with fallback to `~/.config/space-acres`,
+/// relative `$XDG_CONFIG_HOME` is ignored as required by XDG Base Directory specification. On
+/// other platforms conventional location is used.
+pub fn config_dir() -> Option<PathBuf> {
+    base_config_dir().map(|config_dir| config_dir.join(env!("CARGO_PKG_NAME")))
+}
+
+/// Directory with application data, like logs and statistics.
+///
+/// On Linux `$XDG_DATA_HOME/space-acres` is used with fallback to `~/.local/share/space-acres`,
+/// relative `$XDG_DATA_HOME` is ignored as required by XDG Base Directory specification. On
+/// other platforms conventional location is used.
+pub fn data_dir() -> Option<PathBuf> {
+    base_data_dir().map(|data_dir| data_dir.join(env!("CARGO_PKG_NAME")))
+}
+
+#[cfg(target_os = "linux")]
+fn base_config_dir() -> Option<PathBuf> {
+    xdg_dir("XDG_CONFIG_HOME").or_else(|| Some(dirs::home_dir()?.join(".config")))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn base_config_dir() -> Option<PathBuf> {
+    dirs::config_local_dir()
+}
+
+#[cfg(target_os = "linux")]
+fn base_data_dir() -> Option<PathBuf> {
+    xdg_dir("XDG_DATA_HOME").or_else(|| Some(dirs::home_dir()?.join(".local").join("share")))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn base_data_dir() -> Option<PathBuf> {
+    dirs::data_local_dir()
+}
+
+/// Directory from XDG environment variable, `None` if not set, empty or relative
+#[cfg(target_os = "linux")]
+fn xdg_dir(variable: &str) -> Option<PathBuf> {
+    std::env::var_os(variable)
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+}
+
+/// Move configuration and data from default locations to locations specified by XDG environment
+/// variables in case they were set after application was used already.
+///
+/// `dirs` already follows XDG environment variables, but location is resolved anew on every start,
+/// so setting or changing variables later (like when moving home layout around) makes application
+/// look unconfigured, while farms it created are still on disk and are not known to it anymore.
+///
+/// Nothing is done if application already has anything in new location or if another instance is
+/// running from default location, since it still uses files that would be moved.
+#[cfg(target_os = "linux")]
+pub fn migrate_legacy_dirs() {
+    let Some(home_dir) = dirs::home_dir() else {
+        return;
+    };
+
+    let legacy_base_data_dir = home_dir.join(".local").join("share");
+    let legacy_dirs = [
+        (home_dir.join(".config"), base_config_dir()),
+        (legacy_base_data_dir.clone(), base_data_dir()),
+    ];
+    let migrations = legacy_dirs
+        .into_iter()
+        .filter_map(|(legacy_base_dir, base_dir)| {
+            let base_dir = base_dir?;
+            if base_dir == legacy_base_dir {
+                return None;
+            }
+
+            let legacy_dir = legacy_base_dir.join(env!("CARGO_PKG_NAME"));
+            let dir = base_dir.join(env!("CARGO_PKG_NAME"));
+            (legacy_dir.is_dir() && !dir.exists()).then_some((legacy_dir, dir))
+        })
+        .collect::<Vec<_>>();
+    if migrations.is_empty() {
+        return;
+    }
+
+    // Instance lock is stored in data directory, lock of the instance that might still be using
+    // default location needs to be held until migration is done
+    let legacy_data_dir = legacy_base_data_dir.join(env!("CARGO_PKG_NAME"));
+    let _legacy_instance_lock = if legacy_data_dir.is_dir() {
+        match InstanceLock::try_acquire(&legacy_data_dir) {
+            Ok(Some(instance_lock)) => Some(instance_lock),
+            Ok(None) => {
+                eprintln!(
+                    "Another instance is running from \"{}\", not migrating",
+                    legacy_data_dir.display()
+                );
+                return;
+            }
+            Err(error) => {
+                eprintln!(
+                    "Failed to acquire instance lock in \"{}\", not migrating: {error}",
+                    legacy_data_dir.display()
+                );
+                return;
+            }
+        }
+    } else {
+        None
+    };
+
+    for (legacy_dir, dir) in migrations {
+        match move_dir(&legacy_dir, &dir) {
+            Ok(()) => {
+                eprintln!(
+                    "Moved \"{}\" to \"{}\" according to XDG environment variables",
+                    legacy_dir.display(),
+                    dir.display()
+                );
+            }
+            Err(error) => {
+                eprintln!(
+                    "Failed to move \"{}\" to \"{}\": {error}",
+                    legacy_dir.display(),
+                    dir.display()
+                );
+            }
+        }
+    }
+}
+
+/// Nothing to migrate, locations on other platforms didn't change
+#[cfg(not(target_os = "linux"))]
+pub fn migrate_legacy_dirs() {}
+
+/// Move directory, falls back to copying when it is located on a different file system
+#[cfg(target_os = "linux")]
+fn move_dir(from: &Path, to: &Path) -> io::Result<()> {
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    if fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+
+    if let Err(error) = copy_dir(from, to) {
+        // Don't leave partial copy behind, otherwise migration would not be retried
+        let _ = fs::remove_dir_all(to);
+        return Err(error);
+    }
+
+    fs::remove_dir_all(from)
+}
+
+/// Copy directory recursively, symlinks are recreated as is rather than followed
+#[cfg(target_os = "linux")]
+fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+    fs::create_dir(to)?;
+
+    for entry in fs::read_dir(from)? {
+        let path = entry?.path();
+        let target = to.join(
+            path.file_name()
+                .expect("Directory entry always has a name; qed"),
+        );
+        let file_type = fs::symlink_metadata(&path)?.file_type();
+
+        if file_type.is_symlink() {
+            std::os::unix::fs::symlink(fs::read_link(&path)?, target)?;
+        } else if file_type.is_dir() {
+            copy_dir(&path, &target)?;
+        } else {
+            fs::copy(&path, target)?;
+        }
+    }
+
+    Ok(())
+}
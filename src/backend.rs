@@ -1,22 +1,33 @@
 // TODO: Make these modules private
+pub mod clock;
 pub mod config;
+mod control;
 pub mod farmer;
+mod metrics;
 mod networking;
 pub mod node;
 mod utils;
 
+use crate::backend::clock::{measure_clock_offset, ClockOffset};
 use crate::backend::config::{Config, ConfigError, RawConfig};
+use crate::backend::control::{serve_control, ControlState};
 use crate::backend::farmer::maybe_node_client::MaybeNodeClient;
+use crate::backend::farmer::reconciliation::{
+    find_farm_mismatches, forget_farm, remember_farms, wipe_farm, FarmMismatch, FarmMismatchKind,
+    FarmMismatchResolution,
+};
 use crate::backend::farmer::{
-    DiskFarm, Farmer, FarmerAction, FarmerNotification, FarmerOptions, InitialFarmState,
-    CACHE_PERCENTAGE,
+    DiskCache, DiskFarm, Farmer, FarmerAction, FarmerNotification, FarmerOptions, InitialFarmState,
 };
+use crate::backend::metrics::{serve_metrics, Metrics};
 use crate::backend::networking::{create_network, NetworkOptions};
+use crate::backend::node::external::ExternalConsensusNode;
 use crate::backend::node::{
-    dsn_bootstrap_nodes, BlockImportedNotification, ChainInfo, ChainSpec, ConsensusNode,
-    ConsensusNodeCreationError, SyncState, GENESIS_HASH,
+    dsn_bootstrap_nodes, AnyConsensusNode, BlockImportedNotification, ChainInfo, ChainSpec,
+    ConsensusNode, ConsensusNodeCreationError, PeerInfo, SyncState, GENESIS_HASH,
 };
 use async_lock::RwLock as AsyncRwLock;
+use backoff::backoff::Backoff;
 use backoff::ExponentialBackoff;
 use future::FutureExt;
 use futures::channel::mpsc;
@@ -24,12 +35,13 @@ use futures::{future, select, SinkExt, StreamExt};
 use sc_subspace_chain_specs::GEMINI_3H_CHAIN_SPEC;
 use sp_consensus_subspace::ChainConstants;
 use std::error::Error;
-use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, TcpListener};
 use std::num::{NonZeroU8, NonZeroUsize};
 use std::path::{Path, PathBuf};
 use std::pin::pin;
 use std::sync::{Arc, Weak};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use subspace_core_primitives::crypto::kzg::{embedded_kzg_settings, Kzg};
 use subspace_core_primitives::{BlockNumber, Piece, PieceIndex, PublicKey};
 use subspace_farmer::farm::plotted_pieces::PlottedPieces;
@@ -43,7 +55,7 @@ use subspace_farmer::utils::run_future_in_dedicated_thread;
 use subspace_farmer_components::PieceGetter;
 use subspace_networking::libp2p::identity::ed25519::{Keypair, SecretKey};
 use subspace_networking::libp2p::multiaddr::Protocol;
-use subspace_networking::libp2p::Multiaddr;
+use subspace_networking::libp2p::{Multiaddr, PeerId};
 use subspace_networking::utils::piece_provider::PieceProvider;
 use subspace_networking::{Node, NodeRunner};
 use subspace_runtime_primitives::Balance;
@@ -52,7 +64,7 @@ use tokio::fs;
 use tokio::fs::OpenOptions;
 use tokio::io::AsyncWriteExt;
 use tokio::runtime::Handle;
-use tracing::{error, info_span, warn, Instrument};
+use tracing::{debug, error, info, info_span, warn, Instrument};
 
 pub type FarmIndex = u8;
 pub type CacheIndex = u8;
@@ -66,6 +78,14 @@ const PIECE_GETTER_MAX_CONCURRENCY: NonZeroUsize = NonZeroUsize::new(128).expect
 const GET_PIECE_INITIAL_INTERVAL: Duration = Duration::from_secs(5);
 /// Defines max duration between get_piece calls.
 const GET_PIECE_MAX_INTERVAL: Duration = Duration::from_secs(40);
+/// Max number of automatic startup retries after transient errors
+const STARTUP_MAX_RETRIES: u32 = 10;
+/// Delay before the first automatic startup retry
+const STARTUP_RETRY_INITIAL_INTERVAL: Duration = Duration::from_secs(5);
+/// Max delay between automatic startup retries
+const STARTUP_RETRY_MAX_INTERVAL: Duration = Duration::from_secs(5 * 60);
+/// How often to check system clock against NTP servers
+const CLOCK_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
 
 #[derive(Debug, Clone)]
 struct PieceGetterWrapper(
@@ -158,6 +178,10 @@ pub enum LoadingStep {
     NetworkingStackCreatedSuccessfully,
     CreatingConsensusNode,
     ConsensusNodeCreatedSuccessfully,
+    ConnectingToExternalNode,
+    ConnectedToExternalNode,
+    /// External node is unreachable, embedded node will be used instead
+    ExternalNodeUnreachable,
     InitializingFarms {
         farms_total: u16,
     },
@@ -200,6 +224,9 @@ impl LoadingStep {
             LoadingStep::NetworkingStackCreatedSuccessfully => 20.0,
             LoadingStep::CreatingConsensusNode => 20.0,
             LoadingStep::ConsensusNodeCreatedSuccessfully => 40.0,
+            LoadingStep::ConnectingToExternalNode => 40.0,
+            LoadingStep::ConnectedToExternalNode => 40.0,
+            LoadingStep::ExternalNodeUnreachable => 40.0,
             LoadingStep::InitializingFarms { .. } => 40.0,
             LoadingStep::FarmInitialized {
                 farm_index,
@@ -218,6 +245,16 @@ impl LoadingStep {
     }
 }
 
+/// Steps of graceful shutdown, components are stopped in this order
+#[derive(Debug, Copy, Clone)]
+pub enum ShutdownStep {
+    /// Farmer is stopping and flushing plotted sectors and caches to disk
+    StoppingFarmer,
+    /// Node is stopping and flushing its database to disk
+    StoppingNode,
+    StoppingNetworking,
+}
+
 #[derive(Debug)]
 enum LoadedConsensusChainNode {
     Compatible(ConsensusNode),
@@ -228,6 +265,9 @@ enum LoadedConsensusChainNode {
 pub enum NodeNotification {
     SyncStateUpdate(SyncState),
     BlockImported(BlockImportedNotification),
+    PeersUpdate(Vec<PeerInfo>),
+    /// Offset of the system clock relatively to NTP servers, checked periodically
+    ClockOffset(ClockOffset),
 }
 
 /// Notification messages send from backend about its operation
@@ -247,15 +287,33 @@ pub enum BackendNotification {
         error: ConfigError,
     },
     ConfigSaveResult(anyhow::Result<()>),
+    /// Configured farms don't match farms on disk, startup continues once
+    /// [`BackendAction::ResolveFarmMismatches`] is received
+    FarmMismatches {
+        mismatches: Vec<FarmMismatch>,
+    },
+    /// Startup failed with transient error and will be retried automatically
+    StartupRetry {
+        /// Error that caused startup failure
+        error: String,
+        /// Number of failed startup attempts so far
+        attempt: u32,
+        /// Time left until the next attempt
+        retry_in: Duration,
+    },
     Running {
         config: Config,
         raw_config: RawConfig,
         best_block_number: BlockNumber,
+        /// Total balance of all reward addresses
         reward_address_balance: Balance,
         initial_farm_states: Vec<InitialFarmState>,
+        /// Number of pieces piece cache can hold in total
+        piece_cache_capacity: u64,
         cache_percentage: NonZeroU8,
         chain_info: ChainInfo,
         chain_constants: ChainConstants,
+        peer_id: PeerId,
     },
     Node(NodeNotification),
     Farmer(FarmerNotification<FarmIndex>),
@@ -263,6 +321,8 @@ pub enum BackendNotification {
         /// Error in case stopped due to error
         error: Option<anyhow::Error>,
     },
+    /// Graceful shutdown progress
+    ShuttingDown(ShutdownStep),
     IrrecoverableError {
         /// Error that happened
         error: anyhow::Error,
@@ -276,20 +336,41 @@ pub enum BackendAction {
     NewConfig { raw_config: RawConfig },
     /// Farmer action
     Farmer(FarmerAction),
+    /// Retry startup right away instead of waiting for scheduled retry
+    RetryStartupNow,
+    /// Cancel scheduled startup retry
+    CancelStartupRetry,
+    /// Resolve farms that don't match configuration, farms that are not mentioned are skipped
+    ResolveFarmMismatches {
+        resolutions: Vec<(PathBuf, FarmMismatchResolution)>,
+    },
+    /// Stop node and farmer and start them again with configuration from disk, without restarting
+    /// the whole application
+    RestartServices,
+    /// Stop gracefully, backend exits once all components have stopped
+    Shutdown,
 }
 
 struct LoadedBackend {
     config: Config,
     raw_config: RawConfig,
     config_file_path: PathBuf,
-    consensus_node: ConsensusNode,
+    consensus_node: AnyConsensusNode,
     farmer: Farmer<FarmIndex>,
     node_runner: NodeRunner<FarmerCache<CacheIndex>>,
+    peer_id: PeerId,
 }
 
 enum BackendLoadingResult {
     Success(LoadedBackend),
-    IncompatibleChain { compatible_chain: String },
+    IncompatibleChain {
+        compatible_chain: String,
+    },
+    FarmMismatches {
+        raw_config: RawConfig,
+        config_file_path: PathBuf,
+        mismatches: Vec<FarmMismatch>,
+    },
 }
 
 // NOTE: this is an async function, but it might do blocking operations and should be running on a
@@ -298,95 +379,210 @@ pub async fn create(
     mut backend_action_receiver: mpsc::Receiver<BackendAction>,
     mut notifications_sender: mpsc::Sender<BackendNotification>,
 ) {
-    let loading_result = try {
-        'load: loop {
-            if let Some(backend_loaded) = load(&mut notifications_sender).await? {
-                break backend_loaded;
-            }
+    let mut startup_attempt = 0;
+    let mut startup_retry_backoff = ExponentialBackoff {
+        initial_interval: STARTUP_RETRY_INITIAL_INTERVAL,
+        max_interval: STARTUP_RETRY_MAX_INTERVAL,
+        // Number of attempts is limited explicitly instead
+        max_elapsed_time: None,
+        multiplier: 2.0,
+        // Exact countdown is shown to the user, no need for randomization with a single client
+        randomization_factor: 0.0,
+        ..ExponentialBackoff::default()
+    };
 
-            if let Err(error) = notifications_sender
-                .send(BackendNotification::NotConfigured)
-                .await
-            {
-                error!(%error, "Failed to send not configured notification");
-                return;
-            }
+    loop {
+        let loaded_backend = loop {
+            let loading_result = try {
+                'load: loop {
+                    if let Some(backend_loaded) = load(&mut notifications_sender).await? {
+                        break backend_loaded;
+                    }
+
+                    if let Err(error) = notifications_sender
+                        .send(BackendNotification::NotConfigured)
+                        .await
+                    {
+                        error!(%error, "Failed to send not configured notification");
+                        return;
+                    }
 
-            // Remove suppression once we have more actions for backend
-            #[allow(clippy::never_loop)]
-            while let Some(backend_action) = backend_action_receiver.next().await {
-                match backend_action {
-                    BackendAction::NewConfig { raw_config } => {
-                        if let Err(error) = Config::try_from_raw_config(&raw_config).await {
-                            notifications_sender
-                                .send(BackendNotification::ConfigurationIsInvalid { error })
-                                .await?;
+                    // Remove suppression once we have more actions for backend
+                    #[allow(clippy::never_loop)]
+                    while let Some(backend_action) = backend_action_receiver.next().await {
+                        match backend_action {
+                            BackendAction::NewConfig { raw_config } => {
+                                if let Err(error) = Config::try_from_raw_config(&raw_config).await {
+                                    notifications_sender
+                                        .send(BackendNotification::ConfigurationIsInvalid { error })
+                                        .await?;
+                                }
+
+                                let config_file_path = RawConfig::default_path().await?;
+                                raw_config
+                                    .write_to_path_with_backup(&config_file_path)
+                                    .await
+                                    .map_err(|error| {
+                                        anyhow::anyhow!(
+                                            "Failed to write config to \"{}\": {}",
+                                            config_file_path.display(),
+                                            error
+                                        )
+                                    })?;
+
+                                // Try to load config and start again
+                                continue 'load;
+                            }
+                            BackendAction::Farmer(farmer_action) => {
+                                warn!(
+                                    ?farmer_action,
+                                    "Farmer action is not expected before initialization, ignored"
+                                );
+                            }
+                            BackendAction::RetryStartupNow
+                            | BackendAction::CancelStartupRetry
+                            | BackendAction::ResolveFarmMismatches { .. } => {
+                                // Nothing to retry, cancel or resolve
+                            }
+                            BackendAction::RestartServices => {
+                                // Active configuration profile might have changed, try to load
+                                // config again
+                                continue 'load;
+                            }
+                            BackendAction::Shutdown => {
+                                return;
+                            }
                         }
+                    }
+
+                    return;
+                }
+            };
 
-                        let config_file_path = RawConfig::default_path().await?;
-                        raw_config
-                            .write_to_path(&config_file_path)
-                            .await
-                            .map_err(|error| {
-                                anyhow::anyhow!(
-                                    "Failed to write config to \"{}\": {}",
-                                    config_file_path.display(),
-                                    error
-                                )
-                            })?;
-
-                        // Try to load config and start again
-                        continue 'load;
+            match loading_result {
+                Ok(BackendLoadingResult::Success(loaded_backend)) => {
+                    // Loaded successfully
+                    break loaded_backend;
+                }
+                Ok(BackendLoadingResult::IncompatibleChain { compatible_chain }) => {
+                    if let Err(error) = notifications_sender
+                        .send(BackendNotification::IncompatibleChain { compatible_chain })
+                        .await
+                    {
+                        error!(%error, "Failed to send incompatible chain notification");
                     }
-                    BackendAction::Farmer(farmer_action) => {
+                    return;
+                }
+                Ok(BackendLoadingResult::FarmMismatches {
+                    raw_config,
+                    config_file_path,
+                    mismatches,
+                }) => {
+                    let resolve_result = resolve_farm_mismatches(
+                        raw_config,
+                        &config_file_path,
+                        mismatches,
+                        &mut backend_action_receiver,
+                        &mut notifications_sender,
+                    )
+                    .await;
+                    match resolve_result {
+                        Ok(true) => {
+                            // Configuration or farms changed, load again
+                            continue;
+                        }
+                        Ok(false) => {
+                            return;
+                        }
+                        Err(error) => {
+                            if let Err(error) = notifications_sender
+                                .send(BackendNotification::IrrecoverableError { error })
+                                .await
+                            {
+                                error!(%error, "Failed to send error notification");
+                            }
+                            return;
+                        }
+                    }
+                }
+                Err(error) => {
+                    startup_attempt += 1;
+                    let maybe_retry_in = if is_transient_startup_error(&error)
+                        && startup_attempt <= STARTUP_MAX_RETRIES
+                    {
+                        startup_retry_backoff.next_backoff()
+                    } else {
+                        None
+                    };
+
+                    if let Some(retry_in) = maybe_retry_in {
                         warn!(
-                            ?farmer_action,
-                            "Farmer action is not expected before initialization, ignored"
+                            %error,
+                            %startup_attempt,
+                            ?retry_in,
+                            "Transient error during startup, will retry"
                         );
+
+                        let retry_result = wait_for_startup_retry(
+                            &error,
+                            startup_attempt,
+                            retry_in,
+                            &mut backend_action_receiver,
+                            &mut notifications_sender,
+                        )
+                        .await;
+                        match retry_result {
+                            Ok(StartupRetryDecision::Retry) => {
+                                continue;
+                            }
+                            Ok(StartupRetryDecision::Cancel) => {
+                                // Error is shown below
+                            }
+                            Ok(StartupRetryDecision::Shutdown) => {
+                                return;
+                            }
+                            Err(error) => {
+                                error!(%error, "Failed to send startup retry notification");
+                                return;
+                            }
+                        }
+                    }
+
+                    if let Err(error) = notifications_sender
+                        .send(BackendNotification::IrrecoverableError { error })
+                        .await
+                    {
+                        error!(%error, "Failed to send error notification");
                     }
+                    return;
                 }
             }
-
-            return;
-        }
-    };
-
-    let loaded_backend = match loading_result {
-        Ok(BackendLoadingResult::Success(loaded_backend)) => {
-            // Loaded successfully
-            loaded_backend
-        }
-        Ok(BackendLoadingResult::IncompatibleChain { compatible_chain }) => {
-            if let Err(error) = notifications_sender
-                .send(BackendNotification::IncompatibleChain { compatible_chain })
-                .await
-            {
-                error!(%error, "Failed to send incompatible chain notification");
+        };
+
+        let run_fut = run(
+            loaded_backend,
+            &mut backend_action_receiver,
+            &mut notifications_sender,
+        );
+        match run_fut.await {
+            Ok(true) => {
+                info!("Restarting node and farmer");
+                // Restart is not a failure, it gets a fresh set of startup retries
+                startup_attempt = 0;
+                startup_retry_backoff.reset();
             }
-            return;
-        }
-        Err(error) => {
-            if let Err(error) = notifications_sender
-                .send(BackendNotification::IrrecoverableError { error })
-                .await
-            {
-                error!(%error, "Failed to send error notification");
+            Ok(false) => {
+                return;
+            }
+            Err(error) => {
+                if let Err(error) = notifications_sender
+                    .send(BackendNotification::IrrecoverableError { error })
+                    .await
+                {
+                    error!(%error, "Failed to send run error notification");
+                }
+                return;
             }
-            return;
-        }
-    };
-
-    let run_fut = run(
-        loaded_backend,
-        &mut backend_action_receiver,
-        &mut notifications_sender,
-    );
-    if let Err(error) = run_fut.await {
-        if let Err(error) = notifications_sender
-            .send(BackendNotification::IrrecoverableError { error })
-            .await
-        {
-            error!(%error, "Failed to send run error notification");
         }
     }
 }
@@ -403,6 +599,17 @@ async fn load(
         return Ok(None);
     };
 
+    // Catch farms that don't match configuration before starting anything, otherwise they'd fail
+    // in confusing ways later
+    let mismatches = find_farm_mismatches(&config.farms).await;
+    if !mismatches.is_empty() {
+        return Ok(Some(BackendLoadingResult::FarmMismatches {
+            raw_config,
+            config_file_path,
+            mismatches,
+        }));
+    }
+
     let chain_spec = load_chain_specification(notifications_sender).await?;
 
     preparing_node_path(&config.node_path, notifications_sender).await?;
@@ -418,6 +625,7 @@ async fn load(
             notifications_sender,
         )
         .await?;
+    let peer_id = node.id();
 
     let kzg = Kzg::new(embedded_kzg_settings());
     let piece_provider = PieceProvider::new(
@@ -448,22 +656,44 @@ async fn load(
         PIECE_GETTER_MAX_CONCURRENCY,
     ));
 
-    let create_consensus_node_fut = create_consensus_node(
-        &network_keypair,
-        config.node_path.clone(),
-        config.network.substrate_port,
-        chain_spec,
-        Arc::new(piece_getter.clone()),
-        node.clone(),
-        &maybe_node_client,
-        notifications_sender,
-    );
-    let consensus_node = match create_consensus_node_fut.await? {
-        LoadedConsensusChainNode::Compatible(consensus_node) => consensus_node,
-        LoadedConsensusChainNode::Incompatible { compatible_chain } => {
-            return Ok(Some(BackendLoadingResult::IncompatibleChain {
-                compatible_chain,
-            }));
+    let maybe_external_node = match &config.node_rpc_url {
+        Some(node_rpc_url) => {
+            connect_to_external_node(
+                node_rpc_url,
+                &chain_spec,
+                &maybe_node_client,
+                notifications_sender,
+            )
+            .await?
+        }
+        None => None,
+    };
+
+    let consensus_node = match maybe_external_node {
+        Some(external_node) => AnyConsensusNode::External(external_node),
+        None => {
+            let create_consensus_node_fut = create_consensus_node(
+                &network_keypair,
+                config.node_path.clone(),
+                config.network.substrate_port,
+                config.node_rpc_address,
+                chain_spec,
+                Arc::new(piece_getter.clone()),
+                node.clone(),
+                &maybe_node_client,
+                !config.offline_mode,
+                notifications_sender,
+            );
+            match create_consensus_node_fut.await? {
+                LoadedConsensusChainNode::Compatible(consensus_node) => {
+                    AnyConsensusNode::Embedded(consensus_node)
+                }
+                LoadedConsensusChainNode::Incompatible { compatible_chain } => {
+                    return Ok(Some(BackendLoadingResult::IncompatibleChain {
+                        compatible_chain,
+                    }));
+                }
+            }
         }
     };
 
@@ -476,11 +706,26 @@ async fn load(
         maybe_node_client,
         kzg,
         config.reduce_plotting_cpu_load,
+        config.plotting_threads,
+        config.sector_encoding_concurrency,
+        config.auditing_threads,
+        config.cache_percentage,
+        config.piece_cache.clone(),
         piece_getter,
         notifications_sender,
     )
     .await?;
 
+    remember_farms(
+        &config.farms,
+        &raw_config
+            .farms()
+            .iter()
+            .map(|farm| farm.path.clone())
+            .collect::<Vec<_>>(),
+    )
+    .await;
+
     Ok(Some(BackendLoadingResult::Success(LoadedBackend {
         config,
         raw_config,
@@ -488,14 +733,16 @@ async fn load(
         consensus_node,
         farmer,
         node_runner,
+        peer_id,
     })))
 }
 
+/// Run loaded backend, returns `true` if node and farmer should be started again after stopping
 async fn run(
     loaded_backend: LoadedBackend,
     backend_action_receiver: &mut mpsc::Receiver<BackendAction>,
     notifications_sender: &mut mpsc::Sender<BackendNotification>,
-) -> anyhow::Result<()> {
+) -> anyhow::Result<bool> {
     let LoadedBackend {
         config,
         raw_config,
@@ -503,6 +750,7 @@ async fn run(
         consensus_node,
         farmer,
         mut node_runner,
+        peer_id,
     } = loaded_backend;
     let networking_fut = run_future_in_dedicated_thread(
         {
@@ -514,25 +762,44 @@ async fn run(
         "networking".to_string(),
     )?;
 
-    let reward_address = config.reward_address;
+    let reward_addresses = config.reward_addresses();
+    let cache_percentage = config.cache_percentage;
+    let metrics_endpoint = config.metrics_endpoint;
+    let control_api = config.control_api;
+    let offline_mode = config.offline_mode;
+    let metrics = Arc::new(Metrics::new(farmer.initial_farm_states()));
+    let best_block_number = consensus_node.best_block_number().await;
+    let reward_address_balance = consensus_node.accounts_balance(&reward_addresses).await;
+    let control_state = Arc::new(ControlState::new(
+        &config.farms,
+        farmer.initial_farm_states(),
+        best_block_number,
+        reward_address_balance,
+    ));
     notifications_sender
         .send(BackendNotification::Running {
             config,
             raw_config,
-            best_block_number: consensus_node.best_block_number(),
-            reward_address_balance: consensus_node.account_balance(&reward_address),
+            best_block_number,
+            reward_address_balance,
             initial_farm_states: farmer.initial_farm_states().to_vec(),
-            cache_percentage: CACHE_PERCENTAGE,
+            piece_cache_capacity: farmer.piece_cache_capacity(),
+            cache_percentage,
             chain_info: consensus_node.chain_info().clone(),
             chain_constants: *consensus_node.chain_constants(),
+            peer_id,
         })
         .await?;
 
     let _on_sync_state_change_handler_id = consensus_node.on_sync_state_change({
         let notifications_sender = notifications_sender.clone();
+        let metrics = Arc::clone(&metrics);
+        let control_state = Arc::clone(&control_state);
 
         Arc::new(move |&sync_state| {
             let notification = NodeNotification::SyncStateUpdate(sync_state);
+            metrics.on_node_notification(&notification);
+            control_state.on_node_notification(&notification);
 
             let mut notifications_sender = notifications_sender.clone();
 
@@ -550,9 +817,13 @@ async fn run(
     });
     let _on_imported_block_handler_id = consensus_node.on_block_imported({
         let notifications_sender = notifications_sender.clone();
+        let metrics = Arc::clone(&metrics);
+        let control_state = Arc::clone(&control_state);
 
         Arc::new(move |&block_imported| {
             let notification = NodeNotification::BlockImported(block_imported);
+            metrics.on_node_notification(&notification);
+            control_state.on_node_notification(&notification);
 
             let mut notifications_sender = notifications_sender.clone();
 
@@ -568,10 +839,39 @@ async fn run(
             }
         })
     });
+    let _on_peers_update_handler_id = consensus_node.on_peers_update({
+        let notifications_sender = notifications_sender.clone();
+        let metrics = Arc::clone(&metrics);
+        let control_state = Arc::clone(&control_state);
+
+        Arc::new(move |peers| {
+            let notification = NodeNotification::PeersUpdate(peers.clone());
+            metrics.on_node_notification(&notification);
+            control_state.on_node_notification(&notification);
+
+            let mut notifications_sender = notifications_sender.clone();
+
+            if let Err(error) = notifications_sender
+                .try_send(BackendNotification::Node(notification))
+                .or_else(|error| {
+                    tokio::task::block_in_place(|| {
+                        Handle::current().block_on(notifications_sender.send(error.into_inner()))
+                    })
+                })
+            {
+                warn!(%error, "Failed to send peers update backend notification");
+            }
+        })
+    });
     let _on_farmer_notification_handler_id = farmer.on_notification({
         let notifications_sender = notifications_sender.clone();
+        let metrics = Arc::clone(&metrics);
+        let control_state = Arc::clone(&control_state);
 
         Arc::new(move |notification| {
+            metrics.on_farmer_notification(notification);
+            control_state.on_farmer_notification(notification);
+
             let mut notifications_sender = notifications_sender.clone();
 
             if let Err(error) = notifications_sender
@@ -588,11 +888,12 @@ async fn run(
     });
 
     let mut farmer_action_sender = farmer.action_sender();
+    let control_farmer_action_sender = farmer_action_sender.clone();
 
     // Order is important here, we want to destroy dependents first and only then corresponding
     // dependencies to avoid unnecessary errors and warnings in logs
     let networking_fut = networking_fut;
-    let consensus_node_fut = consensus_node.run(&reward_address);
+    let consensus_node_fut = consensus_node.run(&reward_addresses);
     let farmer_fut = farmer.run();
     let process_backend_actions_fut = {
         let mut notifications_sender = notifications_sender.clone();
@@ -608,33 +909,113 @@ async fn run(
         }
     };
 
-    let networking_fut = pin!(networking_fut);
-    let consensus_node_fut = pin!(consensus_node_fut);
-    let farmer_fut = pin!(farmer_fut);
+    let metrics_fut = async move {
+        if let Some(address) = metrics_endpoint
+            && let Err(error) = serve_metrics(address, metrics).await
+        {
+            // Metrics are optional, farming continues without them
+            error!(%error, %address, "Failed to serve metrics");
+        }
+
+        future::pending::<()>().await
+    };
+
+    let clock_check_fut = {
+        let notifications_sender = notifications_sender.clone();
+
+        async move {
+            // Checking clock requires querying NTP servers, which is not strictly necessary
+            if !offline_mode {
+                check_clock_offset(notifications_sender).await;
+            }
+
+            future::pending::<()>().await
+        }
+    };
+
+    let control_fut = async move {
+        if control_api
+            && let Err(error) = serve_control(control_state, control_farmer_action_sender).await
+        {
+            // Control API is optional, farming continues without it
+            error!(%error, "Failed to serve control API");
+        }
+
+        future::pending::<()>().await
+    };
+
+    // Boxed rather than pinned on the stack, so that they can be dropped one by one on shutdown
+    let mut networking_fut = Box::pin(networking_fut);
+    let mut consensus_node_fut = Box::pin(consensus_node_fut);
+    let mut farmer_fut = Box::pin(farmer_fut);
     let process_backend_actions_fut = pin!(process_backend_actions_fut);
+    let metrics_fut = pin!(metrics_fut);
+    let control_fut = pin!(control_fut);
+    let clock_check_fut = pin!(clock_check_fut);
 
+    let mut shutdown_requested = false;
+    let mut restart_requested = false;
     let result: anyhow::Result<()> = select! {
-        result = networking_fut.fuse() => {
+        result = (&mut networking_fut).fuse() => {
             result.map_err(|error| anyhow::anyhow!("Networking exited: {error}"))
         }
-        result = consensus_node_fut.fuse() => {
+        result = (&mut consensus_node_fut).fuse() => {
             result.map_err(|error| anyhow::anyhow!("Consensus node exited: {error}"))
         }
-        result = farmer_fut.fuse() => {
+        result = (&mut farmer_fut).fuse() => {
             result.map_err(|error| anyhow::anyhow!("Farm exited: {error}"))
         }
-        _ = process_backend_actions_fut.fuse() => {
+        restart = process_backend_actions_fut.fuse() => {
+            // Shutdown or restart was requested or frontend is gone
+            shutdown_requested = true;
+            restart_requested = restart;
             Ok(())
         }
+        _ = metrics_fut.fuse() => {
+            unreachable!("Metrics future never exits; qed")
+        }
+        _ = control_fut.fuse() => {
+            unreachable!("Control future never exits; qed")
+        }
+        _ = clock_check_fut.fuse() => {
+            unreachable!("Clock check future never exits; qed")
+        }
     };
 
+    if shutdown_requested {
+        // Stop components in the same order they'd be dropped otherwise, but one at a time, so
+        // that user can see what is happening while data is flushed to disk
+        notifications_sender
+            .send(BackendNotification::ShuttingDown(
+                ShutdownStep::StoppingFarmer,
+            ))
+            .await?;
+        drop(farmer_fut);
+
+        notifications_sender
+            .send(BackendNotification::ShuttingDown(
+                ShutdownStep::StoppingNode,
+            ))
+            .await?;
+        drop(consensus_node_fut);
+
+        notifications_sender
+            .send(BackendNotification::ShuttingDown(
+                ShutdownStep::StoppingNetworking,
+            ))
+            .await?;
+        drop(networking_fut);
+
+        return Ok(restart_requested);
+    }
+
     notifications_sender
         .send(BackendNotification::Stopped {
             error: result.err(),
         })
         .await?;
 
-    Ok(())
+    Ok(false)
 }
 
 async fn load_configuration(
@@ -654,7 +1035,7 @@ async fn load_configuration(
         ))
         .await?;
 
-    let maybe_raw_config = RawConfig::read_from_path(&config_file_path).await?;
+    let maybe_raw_config = RawConfig::read_from_path_and_upgrade(&config_file_path).await?;
 
     notifications_sender
         .send(BackendNotification::Loading(
@@ -860,6 +1241,18 @@ async fn create_networking_stack(
         ))
         .await?;
 
+    // Fail early with clear error instead of networking stack silently listening elsewhere
+    // Error kind is preserved, so that busy port is recognized as transient error
+    check_port_available(config.network.subspace_port).map_err(|error| {
+        io::Error::new(
+            error.kind(),
+            format!(
+                "DSN port {} is not available: {error}",
+                config.network.subspace_port
+            ),
+        )
+    })?;
+
     let mut network_options = NetworkOptions {
         keypair: network_keypair.clone(),
         bootstrap_nodes,
@@ -871,6 +1264,13 @@ async fn create_networking_stack(
         ],
         ..NetworkOptions::default()
     };
+    if let Some(external_address) = &config.network.external_address {
+        network_options.external_addresses.push(
+            external_address
+                .parse()
+                .map_err(|error| anyhow::anyhow!("Invalid external address: {error}"))?,
+        );
+    }
     if config.network.faster_networking {
         network_options.in_connections = 500;
         network_options.out_connections = 500;
@@ -916,10 +1316,12 @@ async fn create_consensus_node(
     network_keypair: &Keypair,
     node_path: PathBuf,
     substrate_port: u16,
+    rpc_address: Option<SocketAddr>,
     chain_spec: ChainSpec,
     piece_getter: Arc<dyn DsnSyncPieceGetter + Send + Sync + 'static>,
     node: Node,
     maybe_node_client: &MaybeNodeClient,
+    telemetry: bool,
     notifications_sender: &mut mpsc::Sender<BackendNotification>,
 ) -> anyhow::Result<LoadedConsensusChainNode> {
     notifications_sender
@@ -928,14 +1330,32 @@ async fn create_consensus_node(
         ))
         .await?;
 
+    check_port_available(substrate_port).map_err(|error| {
+        io::Error::new(
+            error.kind(),
+            format!("Node port {substrate_port} is not available: {error}"),
+        )
+    })?;
+
+    if let Some(rpc_address) = rpc_address
+        && !rpc_address.ip().is_loopback()
+    {
+        warn!(
+            %rpc_address,
+            "Node RPC is exposed on non-local address, anyone who can reach it can query the node"
+        );
+    }
+
     let create_consensus_node_fut = node::create_consensus_node(
         network_keypair,
         node_path,
         substrate_port,
+        rpc_address,
         chain_spec,
         piece_getter,
         node,
         maybe_node_client,
+        telemetry,
     );
     let consensus_node = match create_consensus_node_fut.await {
         Ok(consensus_node) => consensus_node,
@@ -956,6 +1376,289 @@ async fn create_consensus_node(
     Ok(LoadedConsensusChainNode::Compatible(consensus_node))
 }
 
+/// Periodically compare system clock against NTP servers, only returns if frontend is gone
+async fn check_clock_offset(mut notifications_sender: mpsc::Sender<BackendNotification>) {
+    loop {
+        match measure_clock_offset().await {
+            Ok(clock_offset) => {
+                if clock_offset.is_significant() {
+                    warn!(
+                        offset = ?clock_offset.magnitude(),
+                        direction = clock_offset.direction(),
+                        "System clock is out of sync, enable automatic time synchronization (NTP)"
+                    );
+                } else {
+                    debug!(?clock_offset, "System clock is in sync");
+                }
+
+                if notifications_sender
+                    .send(BackendNotification::Node(NodeNotification::ClockOffset(
+                        clock_offset,
+                    )))
+                    .await
+                    .is_err()
+                {
+                    return;
+                }
+            }
+            Err(error) => {
+                // NTP might be blocked on some networks, this is not critical
+                info!(%error, "Failed to check system clock offset");
+            }
+        }
+
+        tokio::time::sleep(CLOCK_CHECK_INTERVAL).await;
+    }
+}
+
+/// Check that nothing else is listening on the port already
+fn check_port_available(port: u16) -> io::Result<()> {
+    TcpListener::bind((Ipv4Addr::UNSPECIFIED, port))?;
+    TcpListener::bind((Ipv6Addr::UNSPECIFIED, port))
+        .map(|_| ())
+        .or_else(|error| {
+            // IPv6 might not be available on this machine at all
+            if error.kind() == io::ErrorKind::AddrInUse {
+                Err(error)
+            } else {
+                Ok(())
+            }
+        })
+}
+
+/// Whether startup error is likely to go away on its own (port is temporarily busy, network hiccup,
+/// etc.) as opposed to errors that require user intervention like invalid configuration
+fn is_transient_startup_error(error: &anyhow::Error) -> bool {
+    error.chain().any(|error| {
+        error.downcast_ref::<io::Error>().is_some_and(|error| {
+            matches!(
+                error.kind(),
+                io::ErrorKind::AddrInUse
+                    | io::ErrorKind::AddrNotAvailable
+                    | io::ErrorKind::ConnectionRefused
+                    | io::ErrorKind::ConnectionReset
+                    | io::ErrorKind::ConnectionAborted
+                    | io::ErrorKind::TimedOut
+                    | io::ErrorKind::Interrupted
+                    | io::ErrorKind::WouldBlock
+            )
+        })
+    })
+}
+
+enum StartupRetryDecision {
+    Retry,
+    Cancel,
+    Shutdown,
+}
+
+/// Wait until startup retry is due while sending countdown notifications
+async fn wait_for_startup_retry(
+    error: &anyhow::Error,
+    attempt: u32,
+    retry_in: Duration,
+    backend_action_receiver: &mut mpsc::Receiver<BackendAction>,
+    notifications_sender: &mut mpsc::Sender<BackendNotification>,
+) -> Result<StartupRetryDecision, mpsc::SendError> {
+    let retry_at = Instant::now() + retry_in;
+    let error = error.to_string();
+
+    loop {
+        let retry_in = retry_at.saturating_duration_since(Instant::now());
+        if retry_in.is_zero() {
+            return Ok(StartupRetryDecision::Retry);
+        }
+
+        notifications_sender
+            .send(BackendNotification::StartupRetry {
+                error: error.clone(),
+                attempt,
+                retry_in,
+            })
+            .await?;
+
+        // Wake up every second to update countdown
+        let countdown_tick_fut = pin!(tokio::time::sleep(retry_in.min(Duration::from_secs(1))));
+
+        select! {
+            _ = countdown_tick_fut.fuse() => {}
+            maybe_backend_action = backend_action_receiver.next() => {
+                match maybe_backend_action {
+                    Some(BackendAction::RetryStartupNow) => {
+                        return Ok(StartupRetryDecision::Retry);
+                    }
+                    Some(BackendAction::CancelStartupRetry) => {
+                        return Ok(StartupRetryDecision::Cancel);
+                    }
+                    Some(BackendAction::Shutdown) | None => {
+                        return Ok(StartupRetryDecision::Shutdown);
+                    }
+                    Some(backend_action) => {
+                        warn!(
+                            ?backend_action,
+                            "Backend action is not expected while waiting for startup retry, \
+                            ignored"
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Report farms that don't match configuration and apply resolutions chosen by user, returns
+/// `false` if backend should shut down instead of loading again
+async fn resolve_farm_mismatches(
+    mut raw_config: RawConfig,
+    config_file_path: &Path,
+    mismatches: Vec<FarmMismatch>,
+    backend_action_receiver: &mut mpsc::Receiver<BackendAction>,
+    notifications_sender: &mut mpsc::Sender<BackendNotification>,
+) -> anyhow::Result<bool> {
+    notifications_sender
+        .send(BackendNotification::FarmMismatches {
+            mismatches: mismatches.clone(),
+        })
+        .await?;
+
+    let resolutions = loop {
+        match backend_action_receiver.next().await {
+            Some(BackendAction::ResolveFarmMismatches { resolutions }) => {
+                break resolutions;
+            }
+            Some(BackendAction::Shutdown) | None => {
+                return Ok(false);
+            }
+            Some(backend_action) => {
+                warn!(
+                    ?backend_action,
+                    "Backend action is not expected while waiting for farm mismatches to be \
+                    resolved, ignored"
+                );
+            }
+        }
+    };
+
+    for mismatch in mismatches {
+        let directory = &mismatch.directory;
+        let resolution = resolutions
+            .iter()
+            .find_map(|(resolution_directory, resolution)| {
+                (resolution_directory == directory).then_some(*resolution)
+            })
+            .unwrap_or(FarmMismatchResolution::Skip);
+        let resolution = if resolution == FarmMismatchResolution::Fix && !mismatch.fixable() {
+            warn!(
+                directory = %directory.display(),
+                "Farm mismatch can't be fixed, farm is skipped instead"
+            );
+            FarmMismatchResolution::Skip
+        } else {
+            resolution
+        };
+
+        match (resolution, mismatch.kind) {
+            (FarmMismatchResolution::Replot, FarmMismatchKind::MissingPlot) => {
+                forget_farm(directory).await?;
+            }
+            (FarmMismatchResolution::Replot, _) => {
+                wipe_farm(directory.clone()).await.map_err(|error| {
+                    anyhow::anyhow!("Failed to wipe farm at {}: {error}", directory.display())
+                })?;
+            }
+            (FarmMismatchResolution::Skip, _) => {
+                for farm in raw_config.farms_mut() {
+                    if &farm.path == directory {
+                        farm.disabled = true;
+                    }
+                }
+            }
+            (FarmMismatchResolution::Fix, FarmMismatchKind::MissingPlot) => {
+                raw_config
+                    .farms_mut()
+                    .retain(|farm| &farm.path != directory);
+                forget_farm(directory).await?;
+            }
+            (FarmMismatchResolution::Fix, FarmMismatchKind::UnexpectedPlot { .. }) => {
+                // Farm that is on disk now will be remembered once started
+                forget_farm(directory).await?;
+            }
+            (FarmMismatchResolution::Fix, FarmMismatchKind::SizeMismatch { farm_size, .. }) => {
+                for farm in raw_config.farms_mut() {
+                    if &farm.path == directory {
+                        // Exact number of bytes, formatting with units is lossy
+                        farm.size = format!("{farm_size}B");
+                    }
+                }
+            }
+        }
+    }
+
+    raw_config
+        .write_to_path_with_backup(config_file_path)
+        .await
+        .map_err(|error| {
+            anyhow::anyhow!(
+                "Failed to write config to \"{}\": {}",
+                config_file_path.display(),
+                error
+            )
+        })?;
+
+    Ok(true)
+}
+
+/// Makes farmer and UI use external node instead of embedded one, returns `None` if external node
+/// is unreachable, in which case embedded node needs to be created instead.
+async fn connect_to_external_node(
+    node_rpc_url: &str,
+    chain_spec: &ChainSpec,
+    maybe_node_client: &MaybeNodeClient,
+    notifications_sender: &mut mpsc::Sender<BackendNotification>,
+) -> anyhow::Result<Option<ExternalConsensusNode>> {
+    notifications_sender
+        .send(BackendNotification::Loading(
+            LoadingStep::ConnectingToExternalNode,
+        ))
+        .await?;
+
+    let connect_fut = async {
+        let node_rpc_client = farmer::connect_to_node_rpc(node_rpc_url).await?;
+        let external_node = ExternalConsensusNode::connect(node_rpc_url, chain_spec).await?;
+
+        anyhow::Ok((node_rpc_client, external_node))
+    };
+
+    match connect_fut.await {
+        Ok((node_rpc_client, external_node)) => {
+            maybe_node_client.inject(Box::new(node_rpc_client));
+
+            notifications_sender
+                .send(BackendNotification::Loading(
+                    LoadingStep::ConnectedToExternalNode,
+                ))
+                .await?;
+
+            Ok(Some(external_node))
+        }
+        Err(error) => {
+            warn!(
+                %error,
+                %node_rpc_url,
+                "Failed to connect to external node, using embedded node instead"
+            );
+
+            notifications_sender
+                .send(BackendNotification::Loading(
+                    LoadingStep::ExternalNodeUnreachable,
+                ))
+                .await?;
+
+            Ok(None)
+        }
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 async fn create_farmer(
     reward_address: PublicKey,
@@ -966,6 +1669,11 @@ async fn create_farmer(
     node_client: MaybeNodeClient,
     kzg: Kzg,
     reduce_plotting_cpu_load: bool,
+    plotting_threads: Option<NonZeroUsize>,
+    sector_encoding_concurrency: Option<NonZeroUsize>,
+    auditing_threads: Option<NonZeroUsize>,
+    cache_percentage: NonZeroU8,
+    piece_cache: Option<DiskCache>,
     piece_getter: PieceGetterWrapper,
     notifications_sender: &mut mpsc::Sender<BackendNotification>,
 ) -> anyhow::Result<Farmer<FarmIndex>> {
@@ -1009,6 +1717,11 @@ async fn create_farmer(
             }
         },
         reduce_plotting_cpu_load,
+        plotting_threads,
+        sector_encoding_concurrency,
+        auditing_threads,
+        cache_percentage,
+        piece_cache,
     };
 
     let farmer = farmer::create_farmer(farmer_options).await?;
@@ -1022,17 +1735,18 @@ async fn create_farmer(
     Ok(farmer)
 }
 
+/// Process backend actions until stop is requested, returns `true` if restart was requested
 async fn process_backend_actions(
     config_file_path: &Path,
     backend_action_receiver: &mut mpsc::Receiver<BackendAction>,
     farmer_action_sender: &mut mpsc::Sender<FarmerAction>,
     notifications_sender: &mut mpsc::Sender<BackendNotification>,
-) {
+) -> bool {
     while let Some(action) = backend_action_receiver.next().await {
         match action {
             BackendAction::NewConfig { raw_config } => {
                 let result = raw_config
-                    .write_to_path(config_file_path)
+                    .write_to_path_with_backup(config_file_path)
                     .await
                     .map_err(|error| {
                         anyhow::anyhow!(
@@ -1053,8 +1767,21 @@ async fn process_backend_actions(
                     error!(%error, "Failed to forward farmer action");
                 }
             }
+            BackendAction::RetryStartupNow
+            | BackendAction::CancelStartupRetry
+            | BackendAction::ResolveFarmMismatches { .. } => {
+                // Already started
+            }
+            BackendAction::RestartServices => {
+                return true;
+            }
+            BackendAction::Shutdown => {
+                break;
+            }
         }
     }
+
+    false
 }
 
 pub async fn wipe(
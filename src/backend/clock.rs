@@ -0,0 +1,189 @@
+use std::io;
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::net::{lookup_host, UdpSocket};
+use tracing::debug;
+
+/// Servers are tried in order until one of them responds
+const NTP_SERVERS: &[&str] = &["pool.ntp.org:123", "time.cloudflare.com:123"];
+const NTP_RESPONSE_TIMEOUT: Duration = Duration::from_secs(5);
+const NTP_PACKET_SIZE: usize = 48;
+/// Seconds between NTP epoch (1900) and Unix epoch (1970)
+const NTP_UNIX_EPOCH_OFFSET: f64 = 2_208_988_800.0;
+/// Leap indicator 0, version 4, client mode
+const NTP_CLIENT_REQUEST_HEADER: u8 = 0b00_100_011;
+const NTP_SERVER_MODE: u8 = 4;
+/// Slots are one second long, offset larger than this noticeably affects block production and
+/// import
+pub const CLOCK_OFFSET_WARNING_THRESHOLD: Duration = Duration::from_secs(2);
+
+/// Offset of the system clock relatively to reference time
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ClockOffset {
+    Ahead(Duration),
+    Behind(Duration),
+}
+
+impl ClockOffset {
+    /// Offset regardless of direction
+    pub fn magnitude(&self) -> Duration {
+        match self {
+            Self::Ahead(offset) | Self::Behind(offset) => *offset,
+        }
+    }
+
+    /// Whether offset is large enough for user to be warned about it
+    pub fn is_significant(&self) -> bool {
+        self.magnitude() >= CLOCK_OFFSET_WARNING_THRESHOLD
+    }
+
+    pub fn direction(&self) -> &'static str {
+        match self {
+            Self::Ahead(_) => "ahead",
+            Self::Behind(_) => "behind",
+        }
+    }
+
+    /// Offset is positive when reference time is ahead of the system clock
+    fn from_secs_f64(offset: f64) -> Self {
+        if offset >= 0.0 {
+            Self::Behind(Duration::from_secs_f64(offset))
+        } else {
+            Self::Ahead(Duration::from_secs_f64(-offset))
+        }
+    }
+}
+
+/// Measure offset of the system clock against public NTP servers using SNTP (RFC 4330)
+pub(super) async fn measure_clock_offset() -> io::Result<ClockOffset> {
+    let mut last_error = io::Error::new(io::ErrorKind::NotFound, "No NTP servers configured");
+
+    for &server in NTP_SERVERS {
+        match tokio::time::timeout(NTP_RESPONSE_TIMEOUT, query_ntp_server(server)).await {
+            Ok(Ok(offset)) => {
+                return Ok(offset);
+            }
+            Ok(Err(error)) => {
+                debug!(%error, %server, "Failed to query NTP server");
+                last_error = error;
+            }
+            Err(_elapsed) => {
+                debug!(%server, "NTP server didn't respond in time");
+                last_error = io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    format!("NTP server {server} didn't respond in time"),
+                );
+            }
+        }
+    }
+
+    Err(last_error)
+}
+
+/// Resolve server and connect to the first address that is reachable, socket is bound to the
+/// unspecified address of the same family, so that both IPv4 and IPv6 servers work
+async fn connect_to_ntp_server(server: &str) -> io::Result<UdpSocket> {
+    let mut last_error = io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("NTP server {server} didn't resolve to any address"),
+    );
+
+    for address in lookup_host(server).await? {
+        let unspecified_address = match address {
+            SocketAddr::V4(_) => SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)),
+            SocketAddr::V6(_) => SocketAddr::from((Ipv6Addr::UNSPECIFIED, 0)),
+        };
+        let result: io::Result<UdpSocket> = try {
+            let socket = UdpSocket::bind(unspecified_address).await?;
+            socket.connect(address).await?;
+            socket
+        };
+
+        match result {
+            Ok(socket) => {
+                return Ok(socket);
+            }
+            Err(error) => {
+                debug!(%error, %address, "Failed to connect to NTP server address");
+                last_error = error;
+            }
+        }
+    }
+
+    Err(last_error)
+}
+
+async fn query_ntp_server(server: &str) -> io::Result<ClockOffset> {
+    let socket = connect_to_ntp_server(server).await?;
+
+    let mut request = [0u8; NTP_PACKET_SIZE];
+    request[0] = NTP_CLIENT_REQUEST_HEADER;
+    let sent_at = unix_time_now();
+    // Transmit timestamp is echoed back by the server as originate timestamp, it is used to match
+    // response to this request
+    let transmit_timestamp = to_ntp_timestamp(sent_at);
+    request[40..].copy_from_slice(&transmit_timestamp.to_be_bytes());
+    socket.send(&request).await?;
+
+    let mut response = [0u8; NTP_PACKET_SIZE];
+    let response_size = socket.recv(&mut response).await?;
+    let received_at = unix_time_now();
+
+    let invalid_response = |reason: &str| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Invalid response from NTP server {server}: {reason}"),
+        )
+    };
+
+    if response_size < NTP_PACKET_SIZE {
+        return Err(invalid_response("response is too short"));
+    }
+    if response[0] & 0b111 != NTP_SERVER_MODE {
+        return Err(invalid_response("unexpected mode"));
+    }
+    // Stratum 0 is a "kiss-o'-death" message, server asks to not be queried
+    if response[1] == 0 {
+        return Err(invalid_response("server refused to serve time"));
+    }
+    if response[24..32] != transmit_timestamp.to_be_bytes() {
+        return Err(invalid_response("response doesn't match request"));
+    }
+
+    let read_timestamp = |offset: usize| {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&response[offset..][..8]);
+        from_ntp_timestamp(u64::from_be_bytes(bytes))
+    };
+    let server_received_at = read_timestamp(32);
+    let server_sent_at = read_timestamp(40);
+
+    // Standard NTP offset calculation, network delay is assumed to be symmetric
+    let offset = ((server_received_at - sent_at) + (server_sent_at - received_at)) / 2.0;
+
+    Ok(ClockOffset::from_secs_f64(offset))
+}
+
+/// Seconds since Unix epoch with fractional part
+fn unix_time_now() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs_f64()
+}
+
+/// NTP timestamp is 32-bit seconds since NTP epoch followed by 32-bit fraction of a second
+fn to_ntp_timestamp(unix_time: f64) -> u64 {
+    let ntp_time = unix_time + NTP_UNIX_EPOCH_OFFSET;
+    let seconds = ntp_time.trunc() as u64;
+    let fraction = (ntp_time.fract() * f64::from(u32::MAX)) as u64;
+
+    (seconds << 32) | fraction
+}
+
+fn from_ntp_timestamp(ntp_timestamp: u64) -> f64 {
+    let seconds = (ntp_timestamp >> 32) as f64;
+    let fraction = (ntp_timestamp & u64::from(u32::MAX)) as f64 / f64::from(u32::MAX);
+
+    seconds + fraction - NTP_UNIX_EPOCH_OFFSET
+}
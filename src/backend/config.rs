@@ -1,23 +1,81 @@
-use crate::backend::farmer::{DiskFarm, CACHE_PERCENTAGE};
+pub mod profiles;
+
+use crate::app_dirs;
+use crate::backend::farmer::{DiskCache, DiskFarm, CACHE_PERCENTAGE};
 use bytesize::ByteSize;
 use serde::{Deserialize, Serialize};
-use std::io;
+use std::io::Write;
+use std::net::SocketAddr;
+use std::num::{NonZeroU8, NonZeroUsize};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{io, iter, thread};
 use subspace_core_primitives::PublicKey;
 use subspace_farmer::single_disk_farm::SingleDiskFarm;
 use subspace_farmer::utils::ss58::{parse_ss58_reward_address, Ss58ParsingError};
+use subspace_farmer::utils::thread_pool_core_indices;
+use subspace_farmer_components::sector::{sector_size, SectorMetadataChecksummed};
+use subspace_networking::libp2p::Multiaddr;
+use tempfile::NamedTempFile;
 use tokio::io::AsyncWriteExt;
 use tokio::task;
-use tracing::warn;
+use tracing::{info, warn};
 
 const DEFAULT_SUBSTRATE_PORT: u16 = 30333;
 const DEFAULT_SUBSPACE_PORT: u16 = 30433;
+const DEFAULT_METRICS_ADDRESS: &str = "127.0.0.1:9616";
+const DEFAULT_NODE_RPC_ADDRESS: &str = "127.0.0.1:9944";
 pub const MIN_FARM_SIZE: u64 = ByteSize::gb(2).as_u64();
 /// Marginal difference in farm size that will not trigger resizing
 const FARM_SIZE_DIFF_MARGIN: u64 = ByteSize::gib(5).as_u64();
 /// Margin for farm size allocation relatively to available space
-const FARM_SIZE_ALLOCATION_MARGIN: u64 = ByteSize::gib(2).as_u64();
+pub const FARM_SIZE_ALLOCATION_MARGIN: u64 = ByteSize::gib(2).as_u64();
+/// Piece cache smaller than this is likely to slow down syncing and plotting
+pub const PIECE_CACHE_SIZE_WARNING_THRESHOLD: u64 = ByteSize::gib(1).as_u64();
+/// Piece cache is not allowed to occupy more than this percentage of farms allocated space
+const MAX_CACHE_PERCENTAGE: u8 = 50;
+/// Space farmer reserves in every farm regardless of its size (plot metadata, farm info, identity
+/// and known peers), rounded up
+const FARM_FIXED_SPACE_USAGE: u64 = ByteSize::mib(3).as_u64();
+/// Farmer leaves space for one disk sector when rounding farm size down to whole sectors
+const FARM_DISK_SECTOR_SIZE: u64 = 4096;
+/// Number of pieces in a sector, as defined by protocol parameters of the chain
+const PIECES_IN_SECTOR: u16 = 1000;
+/// Upper bound for number of sectors encoded concurrently, higher values only waste RAM
+pub const MAX_SECTOR_ENCODING_CONCURRENCY: usize = 32;
+/// Rough amount of memory occupied by one sector during plotting, sector itself is ~1 GiB
+const SECTOR_PLOTTING_MEMORY: u64 = ByteSize::gib(1).as_u64();
+/// Number of config file backups to keep
+const MAX_CONFIG_BACKUPS: usize = 3;
+const CONFIG_FILE_NAME: &str = "config.json";
+/// Extension of config files in TOML format, all other config files are in JSON format
+const TOML_CONFIG_EXTENSION: &str = "toml";
+/// Version of configuration file layout, configuration files of older versions are migrated to it
+/// with [`CONFIG_MIGRATIONS`]
+const CONFIG_VERSION: &str = "0";
+/// Migrations of configuration file layout, each transforms layout of one version into the next
+/// one until [`CONFIG_VERSION`] is reached.
+///
+/// When layout changes in a way that old files can't be deserialized anymore (field is renamed,
+/// its type changes, etc.), new version needs to be added together with migration to it.
+const CONFIG_MIGRATIONS: &[ConfigMigration] = &[ConfigMigration {
+    from_version: None,
+    to_version: "0",
+    description: "added missing version field",
+    // Layout is the same, only version field is added
+    migrate: |_config| {},
+}];
+
+/// Migration of configuration file layout from one version to another
+struct ConfigMigration {
+    /// Version migration applies to, `None` for files without version
+    from_version: Option<&'static str>,
+    to_version: &'static str,
+    /// What was changed, for logging purposes
+    description: &'static str,
+    migrate: fn(&mut serde_json::Map<String, serde_json::Value>),
+}
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -25,6 +83,18 @@ pub struct Farm {
     pub path: PathBuf,
     /// Could be absolute value or percentage of free disk space (when ends with `%`)
     pub size: String,
+    /// Optional user-defined name of the farm
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Optional amount of space that must remain free on disk, absolute value
+    #[serde(default)]
+    pub reserve: Option<String>,
+    /// Optional reward address of the farm, global reward address is used if not specified
+    #[serde(default)]
+    pub reward_address: Option<String>,
+    /// Disabled farms are not opened by the farmer
+    #[serde(default)]
+    pub disabled: bool,
 }
 
 /// Configuration error
@@ -43,14 +113,29 @@ pub enum RawConfigError {
     /// Failed to deserialize configuration file
     #[error("Failed to deserialize configuration file: {0}")]
     FailedToDeserialize(serde_json::Error),
+    /// Failed to deserialize configuration file in TOML format
+    #[error("Failed to deserialize configuration file: {0}")]
+    FailedToDeserializeToml(toml::de::Error),
+    /// Configuration file has version this version of application doesn't know how to migrate
+    #[error(
+        "Configuration file version \"{version}\" is not supported, it was likely created by a newer \
+        version of Space Acres"
+    )]
+    UnsupportedVersion { version: String },
+    /// Failed to write migrated configuration file
+    #[error("Failed to write migrated configuration file: {0}")]
+    FailedToWriteMigrated(io::Error),
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct NetworkConfiguration {
     pub substrate_port: u16,
     pub subspace_port: u16,
     #[serde(default)]
     pub faster_networking: bool,
+    /// External DSN address (multiaddr) to advertise to other peers, useful with port forwarding
+    #[serde(default)]
+    pub external_address: Option<String>,
 }
 
 impl Default for NetworkConfiguration {
@@ -59,10 +144,173 @@ impl Default for NetworkConfiguration {
             substrate_port: DEFAULT_SUBSTRATE_PORT,
             subspace_port: DEFAULT_SUBSPACE_PORT,
             faster_networking: false,
+            external_address: None,
+        }
+    }
+}
+
+/// Prometheus metrics endpoint configuration
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricsConfiguration {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Address to listen on, like `127.0.0.1:9616`
+    #[serde(default = "MetricsConfiguration::default_address")]
+    pub address: String,
+}
+
+impl Default for MetricsConfiguration {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            address: Self::default_address(),
         }
     }
 }
 
+impl MetricsConfiguration {
+    fn default_address() -> String {
+        DEFAULT_METRICS_ADDRESS.to_string()
+    }
+}
+
+/// RPC endpoint of embedded node for external tools
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeRpcConfiguration {
+    /// Expose RPC on specified address instead of internal localhost-only port
+    #[serde(default)]
+    pub enabled: bool,
+    /// Address to listen on, like `127.0.0.1:9944`
+    pub address: String,
+}
+
+impl Default for NodeRpcConfiguration {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            address: DEFAULT_NODE_RPC_ADDRESS.to_string(),
+        }
+    }
+}
+
+/// How often to check for new versions of the application
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum UpdateCheckInterval {
+    #[default]
+    Hourly,
+    Daily,
+    Weekly,
+}
+
+impl UpdateCheckInterval {
+    /// All intervals supported by the application
+    pub const ALL: &'static [Self] = &[Self::Hourly, Self::Daily, Self::Weekly];
+
+    pub fn duration(&self) -> Duration {
+        match self {
+            Self::Hourly => Duration::from_secs(3600),
+            Self::Daily => Duration::from_secs(24 * 3600),
+            Self::Weekly => Duration::from_secs(7 * 24 * 3600),
+        }
+    }
+}
+
+/// New version checks configuration
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatesConfiguration {
+    /// Do not check for new versions at all, useful on metered or air-gapped setups
+    #[serde(default)]
+    pub disable_checks: bool,
+    #[serde(default)]
+    pub check_interval: UpdateCheckInterval,
+    /// Download installer of new version automatically instead of only notifying about it
+    #[serde(default)]
+    pub auto_download: bool,
+}
+
+/// Size of a single sector, farm space is used for plotting in whole sectors
+pub fn farm_sector_size() -> u64 {
+    sector_size(PIECES_IN_SECTOR) as u64
+}
+
+/// Number of whole sectors farmer will plot in the farm of allocated size and space that remains
+/// unused because it is not enough for another sector
+pub fn farm_sectors(allocated_space: u64, cache_percentage: NonZeroU8) -> (u64, u64) {
+    // Farmer allocates fixed space for its metadata first, then piece cache as a percentage of the
+    // rest, remaining space is used for sectors together with their metadata
+    let plottable_space = allocated_space.saturating_sub(FARM_FIXED_SPACE_USAGE) / 100
+        * (100 - u64::from(cache_percentage.get()));
+    let plottable_space = plottable_space.saturating_sub(FARM_DISK_SECTOR_SIZE);
+    let single_sector_overhead =
+        farm_sector_size() + SectorMetadataChecksummed::encoded_size() as u64;
+    let sectors = plottable_space / single_sector_overhead;
+
+    (sectors, plottable_space - sectors * single_sector_overhead)
+}
+
+/// Log level selected in the application
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LogLevel {
+    Error,
+    Warn,
+    #[default]
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    /// All log levels, from least to most verbose
+    pub const ALL: &'static [Self] = &[
+        Self::Error,
+        Self::Warn,
+        Self::Info,
+        Self::Debug,
+        Self::Trace,
+    ];
+
+    /// Whether log level is verbose enough to affect performance
+    pub fn is_verbose(&self) -> bool {
+        matches!(self, Self::Debug | Self::Trace)
+    }
+}
+
+/// Color scheme of the application
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Theme {
+    /// Follow system preference
+    #[default]
+    System,
+    Light,
+    Dark,
+    /// High contrast variant of the system color scheme for low-vision users
+    HighContrast,
+}
+
+impl Theme {
+    /// All themes supported by the application
+    pub const ALL: &'static [Self] = &[Self::System, Self::Light, Self::Dark, Self::HighContrast];
+}
+
+/// What needs to be restarted for configuration changes to take effect, ordered from least to
+/// most disruptive
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum RestartRequirement {
+    /// Changes are applied while running
+    #[default]
+    None,
+    /// Node and farmer need to be restarted
+    Services,
+    /// Whole application needs to be restarted
+    Application,
+}
+
 // TODO: This config is not necessarily valid, probably combine with valid config
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(tag = "version")]
@@ -75,8 +323,68 @@ pub enum RawConfig {
         farms: Vec<Farm>,
         #[serde(default)]
         reduce_plotting_cpu_load: bool,
+        /// Number of CPU threads to use for plotting, all CPU threads are used if not specified
+        #[serde(default)]
+        plotting_threads: Option<usize>,
+        /// Number of sectors encoded concurrently, derived from CPU topology if not specified
+        #[serde(default)]
+        sector_encoding_concurrency: Option<usize>,
+        /// Total number of CPU threads used for auditing and proving across all farms, each farm
+        /// uses thread pool sized for the CPU if not specified
+        #[serde(default)]
+        auditing_threads: Option<usize>,
+        /// Total size of piece cache across all farms, minimal cache is used if not specified
+        #[serde(default)]
+        piece_cache_size: Option<String>,
+        /// Directory for piece cache (for example on a faster disk than farms), piece cache is
+        /// stored within farms if not specified
+        #[serde(default)]
+        piece_cache_path: Option<PathBuf>,
         #[serde(default)]
         network: NetworkConfiguration,
+        /// Farm directories that were recently selected, most recent first
+        #[serde(default)]
+        recent_farm_directories: Vec<PathBuf>,
+        /// WebSocket RPC URL of external node to farm against instead of embedded node
+        #[serde(default)]
+        node_rpc_url: Option<String>,
+        /// Do not show desktop notifications about rewards
+        #[serde(default)]
+        disable_reward_notifications: bool,
+        /// Do not show desktop notification when initial plotting is finished
+        #[serde(default)]
+        disable_plotting_notifications: bool,
+        /// Do not show desktop notifications when farm is auditing too slowly
+        #[serde(default)]
+        disable_farm_health_notifications: bool,
+        /// Do not show icon in system tray
+        #[serde(default)]
+        disable_tray_icon: bool,
+        /// Hide window to tray instead of exiting when window is closed
+        #[serde(default)]
+        minimize_to_tray: bool,
+        /// Log level, `RUST_LOG` environment variable with fallback to `info` is used if not
+        /// specified
+        #[serde(default)]
+        log_level: Option<LogLevel>,
+        #[serde(default)]
+        theme: Theme,
+        /// Language of user interface, language of the system is used if not specified
+        #[serde(default)]
+        language: Option<String>,
+        #[serde(default)]
+        metrics: MetricsConfiguration,
+        #[serde(default)]
+        node_rpc: NodeRpcConfiguration,
+        /// Expose local control socket (named pipe on Windows) for automation scripts
+        #[serde(default)]
+        enable_control_api: bool,
+        #[serde(default)]
+        updates: UpdatesConfiguration,
+        /// Suppress outbound connections that are not necessary for farming, like new version
+        /// checks, system clock checks and node telemetry
+        #[serde(default)]
+        offline_mode: bool,
     },
 }
 
@@ -87,37 +395,112 @@ impl Default for RawConfig {
             node_path: PathBuf::new(),
             farms: Vec::new(),
             reduce_plotting_cpu_load: false,
+            plotting_threads: None,
+            sector_encoding_concurrency: None,
+            auditing_threads: None,
+            piece_cache_size: None,
+            piece_cache_path: None,
             network: NetworkConfiguration::default(),
+            recent_farm_directories: Vec::new(),
+            node_rpc_url: None,
+            disable_reward_notifications: false,
+            disable_plotting_notifications: false,
+            disable_farm_health_notifications: false,
+            disable_tray_icon: false,
+            minimize_to_tray: false,
+            log_level: None,
+            theme: Theme::default(),
+            language: None,
+            metrics: MetricsConfiguration::default(),
+            node_rpc: NodeRpcConfiguration::default(),
+            enable_control_api: false,
+            updates: UpdatesConfiguration::default(),
+            offline_mode: false,
         }
     }
 }
 
 impl RawConfig {
+    /// Path to configuration file of the active profile
     pub async fn default_path() -> Result<PathBuf, RawConfigError> {
-        let Some(config_local_dir) = dirs::config_local_dir() else {
+        let Some(app_config_dir) = app_dirs::config_dir() else {
             return Err(RawConfigError::FailedToDetermineConfigDirectory);
         };
 
-        let app_config_dir = config_local_dir.join(env!("CARGO_PKG_NAME"));
-        let config_file_path = match tokio::fs::create_dir(&app_config_dir).await {
-            Ok(()) => app_config_dir.join("config.json"),
-            Err(error) => {
-                if error.kind() == io::ErrorKind::AlreadyExists {
-                    app_config_dir.join("config.json")
-                } else {
-                    return Err(RawConfigError::FailedToCreateConfigDirectory(error));
-                }
-            }
-        };
+        let config_dir =
+            profiles::profile_directory(&app_config_dir, &profiles::active_profile().await);
+        tokio::fs::create_dir_all(&config_dir)
+            .await
+            .map_err(RawConfigError::FailedToCreateConfigDirectory)?;
+
+        Ok(config_dir.join(CONFIG_FILE_NAME))
+    }
+
+    /// Blocking version of reading config from default location, for use before async runtime
+    /// is available. Returns `None` if config doesn't exist or can't be read.
+    pub fn read_from_default_path_blocking() -> Option<Self> {
+        let config_file_path = profiles::profile_directory(
+            &app_dirs::config_dir()?,
+            &profiles::active_profile_blocking(),
+        )
+        .join(CONFIG_FILE_NAME);
+        let config = std::fs::read_to_string(config_file_path).ok()?;
 
-        Ok(config_file_path)
+        Self::parse(&config)
+            .ok()
+            .map(|(raw_config, _migrations)| raw_config)
     }
 
+    /// Read config from path, config in older layout is migrated to the current one in memory
     pub async fn read_from_path(config_file_path: &Path) -> Result<Option<Self>, RawConfigError> {
+        Ok(Self::read_and_migrate(config_file_path)
+            .await?
+            .map(|(raw_config, _migrations)| raw_config))
+    }
+
+    /// Same as [`Self::read_from_path()`], but config in older layout is also written back in the
+    /// current layout (with backup of the original file)
+    pub async fn read_from_path_and_upgrade(
+        config_file_path: &Path,
+    ) -> Result<Option<Self>, RawConfigError> {
+        let Some((raw_config, migrations)) = Self::read_and_migrate(config_file_path).await? else {
+            return Ok(None);
+        };
+
+        if !migrations.is_empty() {
+            for migration in &migrations {
+                info!(
+                    from_version = migration.from_version.unwrap_or("none"),
+                    to_version = migration.to_version,
+                    "Migrated configuration file: {}",
+                    migration.description
+                );
+            }
+
+            raw_config
+                .write_to_path_with_backup(config_file_path)
+                .await
+                .map_err(RawConfigError::FailedToWriteMigrated)?;
+            info!(
+                path = %config_file_path.display(),
+                "Configuration file upgraded to version {CONFIG_VERSION}"
+            );
+        }
+
+        Ok(Some(raw_config))
+    }
+
+    async fn read_and_migrate(
+        config_file_path: &Path,
+    ) -> Result<Option<(Self, Vec<&'static ConfigMigration>)>, RawConfigError> {
         match tokio::fs::read_to_string(config_file_path).await {
-            Ok(config) => serde_json::from_str::<Self>(&config)
-                .map(Some)
-                .map_err(RawConfigError::FailedToDeserialize),
+            Ok(config) => {
+                if is_toml_path(config_file_path) {
+                    Self::parse_toml(&config).map(Some)
+                } else {
+                    Self::parse(&config).map(Some)
+                }
+            }
             Err(error) => {
                 if error.kind() == io::ErrorKind::NotFound {
                     Ok(None)
@@ -128,7 +511,71 @@ impl RawConfig {
         }
     }
 
+    /// Parse config, migrating it from older layout if necessary, returns applied migrations
+    /// alongside config
+    fn parse(config: &str) -> Result<(Self, Vec<&'static ConfigMigration>), RawConfigError> {
+        let config = serde_json::from_str::<serde_json::Value>(config)
+            .map_err(RawConfigError::FailedToDeserialize)?;
+
+        Self::migrate(config)
+    }
+
+    /// Same as [`Self::parse()`], but for config in TOML format
+    fn parse_toml(config: &str) -> Result<(Self, Vec<&'static ConfigMigration>), RawConfigError> {
+        let config = toml::from_str::<serde_json::Value>(config)
+            .map_err(RawConfigError::FailedToDeserializeToml)?;
+
+        Self::migrate(config)
+    }
+
+    fn migrate(
+        mut config: serde_json::Value,
+    ) -> Result<(Self, Vec<&'static ConfigMigration>), RawConfigError> {
+        let mut migrations = Vec::new();
+        if let Some(config) = config.as_object_mut() {
+            loop {
+                let version = config
+                    .get("version")
+                    .and_then(serde_json::Value::as_str)
+                    .map(str::to_string);
+                if version.as_deref() == Some(CONFIG_VERSION) {
+                    break;
+                }
+
+                let Some(migration) = CONFIG_MIGRATIONS
+                    .iter()
+                    .find(|migration| migration.from_version == version.as_deref())
+                else {
+                    return Err(RawConfigError::UnsupportedVersion {
+                        version: version.unwrap_or_else(|| "none".to_string()),
+                    });
+                };
+
+                (migration.migrate)(config);
+                config.insert(
+                    "version".to_string(),
+                    serde_json::Value::from(migration.to_version),
+                );
+                migrations.push(migration);
+            }
+        }
+
+        let raw_config =
+            serde_json::from_value::<Self>(config).map_err(RawConfigError::FailedToDeserialize)?;
+
+        Ok((raw_config, migrations))
+    }
+
+    /// Write config to path, config is written in TOML format if path has `.toml` extension and
+    /// in JSON format otherwise
     pub async fn write_to_path(&self, config_file_path: &Path) -> io::Result<()> {
+        let contents = if is_toml_path(config_file_path) {
+            toml::to_string_pretty(self)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?
+        } else {
+            serde_json::to_string_pretty(self).expect("Config serialization is infallible; qed")
+        };
+
         let mut options = tokio::fs::OpenOptions::new();
         options.write(true).truncate(true).create(true);
         #[cfg(unix)]
@@ -136,14 +583,88 @@ impl RawConfig {
         options
             .open(config_file_path)
             .await?
-            .write_all(
-                serde_json::to_string_pretty(self)
-                    .expect("Config serialization is infallible; qed")
-                    .as_bytes(),
-            )
+            .write_all(contents.as_bytes())
             .await
     }
 
+    /// Same as [`Self::write_to_path()`], but copies existing config file into a timestamped backup
+    /// first (only last [`MAX_CONFIG_BACKUPS`] backups are kept) and replaces config file
+    /// atomically, such that existing config file is never left partially written
+    pub async fn write_to_path_with_backup(&self, config_file_path: &Path) -> io::Result<()> {
+        if tokio::fs::try_exists(config_file_path).await? {
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis();
+            let mut backup_path = config_file_path.as_os_str().to_os_string();
+            backup_path.push(format!(".{timestamp}.bak"));
+            let backup_path = PathBuf::from(backup_path);
+
+            tokio::fs::copy(config_file_path, &backup_path).await?;
+        }
+
+        let contents =
+            serde_json::to_string_pretty(self).expect("Config serialization is infallible; qed");
+        let path = config_file_path.to_path_buf();
+        task::spawn_blocking(move || {
+            let parent = path
+                .parent()
+                .expect("Config file is always inside of config directory; qed");
+
+            // Written to temporary file that replaces config file once complete, such that config
+            // is not lost if application exits in the middle of writing
+            let mut file = NamedTempFile::new_in(parent)?;
+            file.write_all(contents.as_bytes())?;
+            file.as_file().sync_all()?;
+            file.persist(&path)?;
+
+            Ok::<_, io::Error>(())
+        })
+        .await??;
+
+        if let Err(error) = Self::remove_old_backups(config_file_path).await {
+            warn!(%error, "Failed to remove old config backups");
+        }
+
+        Ok(())
+    }
+
+    async fn remove_old_backups(config_file_path: &Path) -> io::Result<()> {
+        let (Some(directory), Some(file_name)) = (
+            config_file_path.parent(),
+            config_file_path
+                .file_name()
+                .and_then(|file_name| file_name.to_str()),
+        ) else {
+            return Ok(());
+        };
+        let backup_prefix = format!("{file_name}.");
+
+        let mut backups = Vec::new();
+        let mut entries = tokio::fs::read_dir(directory).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let entry_file_name = entry.file_name();
+            let Some(timestamp) = entry_file_name
+                .to_str()
+                .and_then(|entry_file_name| entry_file_name.strip_prefix(&backup_prefix))
+                .and_then(|suffix| suffix.strip_suffix(".bak"))
+                .and_then(|timestamp| timestamp.parse::<u128>().ok())
+            else {
+                continue;
+            };
+
+            backups.push((timestamp, entry.path()));
+        }
+
+        // Newest backups first
+        backups.sort_by(|a, b| b.0.cmp(&a.0));
+        for (_timestamp, backup_path) in backups.into_iter().skip(MAX_CONFIG_BACKUPS) {
+            tokio::fs::remove_file(backup_path).await?;
+        }
+
+        Ok(())
+    }
+
     pub fn reward_address(&self) -> &str {
         let Self::V0 { reward_address, .. } = self;
         reward_address
@@ -154,6 +675,11 @@ impl RawConfig {
         node_path
     }
 
+    pub fn farms_mut(&mut self) -> &mut Vec<Farm> {
+        let Self::V0 { farms, .. } = self;
+        farms
+    }
+
     pub fn farms(&self) -> &[Farm] {
         let Self::V0 { farms, .. } = self;
         farms
@@ -167,9 +693,179 @@ impl RawConfig {
         *reduce_plotting_cpu_load
     }
 
+    pub fn plotting_threads(&self) -> Option<usize> {
+        let Self::V0 {
+            plotting_threads, ..
+        } = self;
+        *plotting_threads
+    }
+
+    pub fn sector_encoding_concurrency(&self) -> Option<usize> {
+        let Self::V0 {
+            sector_encoding_concurrency,
+            ..
+        } = self;
+        *sector_encoding_concurrency
+    }
+
+    pub fn auditing_threads(&self) -> Option<usize> {
+        let Self::V0 {
+            auditing_threads, ..
+        } = self;
+        *auditing_threads
+    }
+
+    pub fn piece_cache_size(&self) -> Option<&str> {
+        let Self::V0 {
+            piece_cache_size, ..
+        } = self;
+        piece_cache_size.as_deref()
+    }
+
+    pub fn piece_cache_path(&self) -> Option<&Path> {
+        let Self::V0 {
+            piece_cache_path, ..
+        } = self;
+        piece_cache_path.as_deref()
+    }
+
     pub fn network(&self) -> NetworkConfiguration {
         let Self::V0 { network, .. } = self;
-        *network
+        network.clone()
+    }
+
+    pub fn recent_farm_directories(&self) -> &[PathBuf] {
+        let Self::V0 {
+            recent_farm_directories,
+            ..
+        } = self;
+        recent_farm_directories
+    }
+
+    pub fn node_rpc_url(&self) -> Option<&str> {
+        let Self::V0 { node_rpc_url, .. } = self;
+        node_rpc_url.as_deref()
+    }
+
+    pub fn disable_reward_notifications(&self) -> bool {
+        let Self::V0 {
+            disable_reward_notifications,
+            ..
+        } = self;
+        *disable_reward_notifications
+    }
+
+    pub fn disable_plotting_notifications(&self) -> bool {
+        let Self::V0 {
+            disable_plotting_notifications,
+            ..
+        } = self;
+        *disable_plotting_notifications
+    }
+
+    pub fn disable_farm_health_notifications(&self) -> bool {
+        let Self::V0 {
+            disable_farm_health_notifications,
+            ..
+        } = self;
+        *disable_farm_health_notifications
+    }
+
+    pub fn disable_tray_icon(&self) -> bool {
+        let Self::V0 {
+            disable_tray_icon, ..
+        } = self;
+        *disable_tray_icon
+    }
+
+    pub fn minimize_to_tray(&self) -> bool {
+        let Self::V0 {
+            minimize_to_tray, ..
+        } = self;
+        *minimize_to_tray
+    }
+
+    pub fn log_level(&self) -> Option<LogLevel> {
+        let Self::V0 { log_level, .. } = self;
+        *log_level
+    }
+
+    pub fn theme(&self) -> Theme {
+        let Self::V0 { theme, .. } = self;
+        *theme
+    }
+
+    pub fn language(&self) -> Option<&str> {
+        let Self::V0 { language, .. } = self;
+        language.as_deref()
+    }
+
+    pub fn metrics(&self) -> &MetricsConfiguration {
+        let Self::V0 { metrics, .. } = self;
+        metrics
+    }
+
+    pub fn node_rpc(&self) -> &NodeRpcConfiguration {
+        let Self::V0 { node_rpc, .. } = self;
+        node_rpc
+    }
+
+    pub fn enable_control_api(&self) -> bool {
+        let Self::V0 {
+            enable_control_api, ..
+        } = self;
+        *enable_control_api
+    }
+
+    pub fn updates(&self) -> UpdatesConfiguration {
+        let Self::V0 { updates, .. } = self;
+        *updates
+    }
+
+    pub fn offline_mode(&self) -> bool {
+        let Self::V0 { offline_mode, .. } = self;
+        *offline_mode
+    }
+
+    /// What needs to be restarted for changes compared to previous config to take effect
+    pub fn restart_requirement(&self, previous: &Self) -> RestartRequirement {
+        if self.language() != previous.language()
+            || self.disable_tray_icon() != previous.disable_tray_icon()
+            || self.minimize_to_tray() != previous.minimize_to_tray()
+        {
+            // These are only read when application starts
+            return RestartRequirement::Application;
+        }
+
+        if self.without_live_settings() != previous.without_live_settings() {
+            RestartRequirement::Services
+        } else {
+            RestartRequirement::None
+        }
+    }
+
+    /// Config with settings that are applied while running reset to defaults, such that remaining
+    /// differences require node and farmer restart
+    fn without_live_settings(&self) -> Self {
+        let mut raw_config = self.clone();
+        let Self::V0 {
+            recent_farm_directories,
+            disable_reward_notifications,
+            disable_plotting_notifications,
+            disable_farm_health_notifications,
+            log_level,
+            theme,
+            updates,
+            ..
+        } = &mut raw_config;
+        recent_farm_directories.clear();
+        *disable_reward_notifications = false;
+        *disable_plotting_notifications = false;
+        *disable_farm_health_notifications = false;
+        *log_level = None;
+        *theme = Theme::default();
+        *updates = UpdatesConfiguration::default();
+        raw_config
     }
 }
 
@@ -191,6 +887,113 @@ pub enum ConfigError {
     /// Invalid size format
     #[error("Invalid size format \"{size}\": {error}")]
     InvalidSizeFormat { size: String, error: String },
+    /// Invalid node RPC URL
+    #[error("Invalid node RPC URL \"{url}\", must start with ws:// or wss://")]
+    InvalidNodeRpcUrl { url: String },
+    /// Invalid port
+    #[error("Invalid port {port}, must be between 1 and 65535")]
+    InvalidPort { port: u16 },
+    /// Invalid external address
+    #[error(
+        "Invalid external address \"{address}\", must be a multiaddr like /ip4/1.2.3.4/tcp/30433"
+    )]
+    InvalidExternalAddress { address: String },
+    /// Invalid metrics endpoint address
+    #[error("Invalid metrics endpoint address \"{address}\", must be IP address and port like 127.0.0.1:9616")]
+    InvalidMetricsAddress { address: String },
+    /// Invalid embedded node RPC address
+    #[error("Invalid node RPC address \"{address}\", must be IP address and non-zero port like 127.0.0.1:9944")]
+    InvalidNodeRpcAddress { address: String },
+    /// Invalid number of plotting threads
+    #[error("Invalid number of plotting threads {threads}, must be between 1 and {max}")]
+    InvalidPlottingThreads { threads: usize, max: usize },
+    /// Invalid number of sectors encoded concurrently
+    #[error("Invalid sector encoding concurrency {concurrency}, must be between 1 and {max}")]
+    InvalidSectorEncodingConcurrency { concurrency: usize, max: usize },
+    /// Invalid number of auditing threads
+    #[error("Invalid number of auditing threads {threads}, must be between 1 and {max}")]
+    InvalidAuditingThreads { threads: usize, max: usize },
+    /// Auditing threads are split between farms, each farm needs at least one
+    #[error(
+        "Number of auditing threads {threads} is less than number of farms {farms}, each farm \
+        needs at least one thread"
+    )]
+    TooFewAuditingThreads { threads: usize, farms: usize },
+    /// No farms configured
+    #[error("At least one farm must be configured")]
+    NoFarms,
+    /// Farm is smaller than minimum farm size
+    #[error("Farm \"{path}\" size {size} is smaller than minimum farm size {min_size}")]
+    FarmTooSmall {
+        path: String,
+        size: String,
+        min_size: String,
+    },
+    /// The same path is used by multiple farms
+    #[error("Path \"{path}\" is used by multiple farms")]
+    DuplicateFarmPath { path: String },
+    /// Path is the same as farm path or is nested with it
+    #[error("Path \"{path}\" is the same as farm path \"{farm_path}\", or is inside of it or contains it")]
+    FarmPathConflict { path: String, farm_path: String },
+    /// Piece cache would occupy too much of farms allocated space
+    #[error(
+        "Piece cache size {size} is larger than {max_percentage}% of space allocated to farms"
+    )]
+    PieceCacheTooLarge { size: String, max_percentage: u8 },
+}
+
+/// Check whether external address is a valid multiaddr
+pub fn is_external_address_valid(address: &str) -> bool {
+    Multiaddr::from_str(address).is_ok()
+}
+
+/// Check whether metrics endpoint address is a valid socket address
+pub fn is_metrics_address_valid(address: &str) -> bool {
+    SocketAddr::from_str(address).is_ok()
+}
+
+/// Check whether embedded node RPC address is a valid socket address with non-zero port
+pub fn is_node_rpc_address_valid(address: &str) -> bool {
+    SocketAddr::from_str(address).is_ok_and(|address| address.port() != 0)
+}
+
+/// Check whether embedded node RPC address is reachable from other machines, in which case
+/// anyone who can reach it is able to query the node
+pub fn is_node_rpc_address_public(address: &str) -> bool {
+    SocketAddr::from_str(address).is_ok_and(|address| !address.ip().is_loopback())
+}
+
+/// Number of CPU threads available on this machine
+pub fn available_cpu_threads() -> usize {
+    thread::available_parallelism()
+        .map(NonZeroUsize::get)
+        .unwrap_or(1)
+}
+
+/// Number of sectors encoded concurrently when not specified explicitly: one per L3 cache group or
+/// just one if number of plotting threads is limited
+pub fn default_sector_encoding_concurrency(plotting_threads: Option<usize>) -> usize {
+    if plotting_threads.is_some() {
+        1
+    } else {
+        thread_pool_core_indices(None, None).len().max(1)
+    }
+}
+
+/// Estimated amount of RAM used by plotting with specified sector encoding concurrency.
+///
+/// Each sector being encoded is kept in memory alongside with its downloaded pieces, one more
+/// sector is downloaded ahead of time.
+pub fn estimated_plotting_memory(sector_encoding_concurrency: usize) -> ByteSize {
+    ByteSize::b((sector_encoding_concurrency as u64 * 2 + 1) * SECTOR_PLOTTING_MEMORY)
+}
+
+/// Check whether node RPC URL looks like a WebSocket URL
+pub fn is_node_rpc_url_valid(url: &str) -> bool {
+    url.strip_prefix("ws://")
+        .or_else(|| url.strip_prefix("wss://"))
+        .map(|rest| !rest.is_empty() && !rest.contains(char::is_whitespace))
+        .unwrap_or_default()
 }
 
 #[derive(Debug, Clone)]
@@ -199,7 +1002,26 @@ pub struct Config {
     pub node_path: PathBuf,
     pub farms: Vec<DiskFarm>,
     pub reduce_plotting_cpu_load: bool,
+    pub plotting_threads: Option<NonZeroUsize>,
+    /// Number of sectors encoded concurrently, derived from CPU topology if `None`
+    pub sector_encoding_concurrency: Option<NonZeroUsize>,
+    /// Total number of CPU threads used for auditing and proving across all farms, each farm uses
+    /// thread pool sized for the CPU if `None`
+    pub auditing_threads: Option<NonZeroUsize>,
+    /// Percentage of allocated space of each farm used for piece cache
+    pub cache_percentage: NonZeroU8,
+    /// Dedicated piece cache, piece cache is stored within farms if `None`
+    pub piece_cache: Option<DiskCache>,
     pub network: NetworkConfiguration,
+    pub node_rpc_url: Option<String>,
+    /// Address to serve Prometheus metrics on, `None` if disabled
+    pub metrics_endpoint: Option<SocketAddr>,
+    /// Address for embedded node to serve RPC on, internal localhost-only port is used if `None`
+    pub node_rpc_address: Option<SocketAddr>,
+    /// Whether local control socket is enabled
+    pub control_api: bool,
+    /// Whether outbound connections that are not necessary for farming are suppressed
+    pub offline_mode: bool,
 }
 
 impl Config {
@@ -207,20 +1029,30 @@ impl Config {
     ///
     /// It will check that path exists or parent directory can be accesses.
     pub async fn try_from_raw_config(raw_config: &RawConfig) -> Result<Self, ConfigError> {
-        let reward_address = raw_config.reward_address();
-        let reward_address = parse_ss58_reward_address(reward_address).map_err(|error| {
-            ConfigError::InvalidSs58RewardAddress {
-                reward_address: reward_address.to_string(),
-                error,
-            }
-        })?;
+        let reward_address = parse_reward_address(raw_config.reward_address())?;
 
         let node_path = raw_config.node_path().clone();
         check_path(node_path.clone()).await?;
 
+        let node_rpc_url = parse_node_rpc_url(raw_config.node_rpc_url())?;
+
+        let network = raw_config.network();
+        check_network(&network)?;
+
+        let metrics_endpoint = parse_metrics_endpoint(raw_config.metrics())?;
+        let node_rpc_address = parse_node_rpc_address(raw_config.node_rpc())?;
+
+        let plotting_threads = parse_plotting_threads(raw_config.plotting_threads())?;
+        let sector_encoding_concurrency =
+            parse_sector_encoding_concurrency(raw_config.sector_encoding_concurrency())?;
+        let auditing_threads =
+            parse_auditing_threads(raw_config.auditing_threads(), enabled_farms(raw_config))?;
+
+        let piece_cache_size = parse_piece_cache_size(raw_config.piece_cache_size())?;
+
         let mut farms = Vec::with_capacity(raw_config.farms().len());
 
-        for farm in raw_config.farms() {
+        for farm in raw_config.farms().iter().filter(|farm| !farm.disabled) {
             check_path(farm.path.clone()).await?;
 
             let farm_details_fut = task::spawn_blocking({
@@ -259,26 +1091,19 @@ impl Config {
                     });
                 }
             };
+            let reserve = match &farm.reserve {
+                Some(reserve) => parse_size(reserve)?,
+                None => 0,
+            };
             // Includes "virtual" free space that corresponds to the space farm already occupies,
-            // which simplifies logic below when checking amount of space farm is able to occupy
-            let available_space = fs_stats.available_space() + effective_disk_usage;
-
-            let target_size = if farm.size.ends_with("%") {
-                let size_percentage =
-                    f64::from_str(farm.size.trim_end_matches('%')).map_err(|error| {
-                        ConfigError::InvalidSizeFormat {
-                            size: farm.size.clone(),
-                            error: error.to_string(),
-                        }
-                    })?;
-                if size_percentage <= 0.0 || size_percentage > 100.0 {
-                    return Err(ConfigError::InvalidSizeFormat {
-                        size: farm.size.clone(),
-                        error: "Size percentage should be above 0% and not exceed 100%".to_string(),
-                    });
-                }
+            // which simplifies logic below when checking amount of space farm is able to occupy,
+            // reserve is excluded since it must remain free
+            let available_space =
+                (fs_stats.available_space() + effective_disk_usage).saturating_sub(reserve);
 
-                let target_size = (available_space - FARM_SIZE_ALLOCATION_MARGIN) as f64
+            let target_size = if let Some(size_percentage) = parse_size_percentage(&farm.size)? {
+                let target_size = available_space.saturating_sub(FARM_SIZE_ALLOCATION_MARGIN)
+                    as f64
                     * size_percentage
                     / 100.0;
                 let target_size = MIN_FARM_SIZE.max(target_size.round() as u64);
@@ -289,16 +1114,11 @@ impl Config {
                     target_size
                 }
             } else {
-                ByteSize::from_str(&farm.size)
-                    .map_err(|error| ConfigError::InvalidSizeFormat {
-                        size: farm.size.clone(),
-                        error,
-                    })?
-                    .as_u64()
+                parse_size(&farm.size)?
             };
 
             let size = if target_size > available_space {
-                let new_size = available_space - FARM_SIZE_ALLOCATION_MARGIN;
+                let new_size = available_space.saturating_sub(FARM_SIZE_ALLOCATION_MARGIN);
                 warn!(
                     target_size,
                     available_space,
@@ -314,17 +1134,454 @@ impl Config {
             farms.push(DiskFarm {
                 directory: farm.path.clone(),
                 allocated_space: size,
+                reward_address: farm
+                    .reward_address
+                    .as_deref()
+                    .map(parse_reward_address)
+                    .transpose()?,
             });
         }
 
+        if let Some(piece_cache_size) = piece_cache_size
+            && piece_cache_size < PIECE_CACHE_SIZE_WARNING_THRESHOLD
+        {
+            warn!(
+                piece_cache_size,
+                "Piece cache size is very low, syncing and plotting might be slow"
+            );
+        }
+        let total_allocated_space = farms.iter().map(|farm| farm.allocated_space).sum::<u64>();
+
+        let (cache_percentage, piece_cache) = match raw_config.piece_cache_path() {
+            Some(piece_cache_path) => {
+                let piece_cache_path = piece_cache_path.to_path_buf();
+                check_path(piece_cache_path.clone()).await?;
+
+                // Same amount of space as would be used for piece cache within farms by default
+                let target_size = piece_cache_size.unwrap_or_else(|| {
+                    (total_allocated_space / 100 * u64::from(CACHE_PERCENTAGE.get()))
+                        .max(PIECE_CACHE_SIZE_WARNING_THRESHOLD)
+                });
+                let available_space = piece_cache_available_space(piece_cache_path.clone())
+                    .await
+                    .map_err(|error| ConfigError::PathError {
+                        path: piece_cache_path.display().to_string(),
+                        error,
+                    })?;
+                let allocated_space = if target_size > available_space {
+                    let new_size = available_space.saturating_sub(FARM_SIZE_ALLOCATION_MARGIN);
+                    warn!(
+                        target_size,
+                        available_space,
+                        new_size,
+                        "Overriding piece cache size due to not enough available space"
+                    );
+
+                    new_size
+                } else {
+                    target_size
+                };
+
+                (
+                    CACHE_PERCENTAGE,
+                    Some(DiskCache {
+                        directory: piece_cache_path,
+                        allocated_space,
+                    }),
+                )
+            }
+            None => (
+                match piece_cache_size {
+                    Some(piece_cache_size) => {
+                        piece_cache_percentage(piece_cache_size, total_allocated_space).ok_or_else(
+                            || ConfigError::PieceCacheTooLarge {
+                                size: ByteSize::b(piece_cache_size).to_string_as(true),
+                                max_percentage: MAX_CACHE_PERCENTAGE,
+                            },
+                        )?
+                    }
+                    None => CACHE_PERCENTAGE,
+                },
+                None,
+            ),
+        };
+
         Ok(Self {
             reward_address,
             node_path,
             farms,
             reduce_plotting_cpu_load: raw_config.reduce_plotting_cpu_load(),
-            network: raw_config.network(),
+            plotting_threads,
+            sector_encoding_concurrency,
+            auditing_threads,
+            cache_percentage,
+            piece_cache,
+            network,
+            node_rpc_url,
+            metrics_endpoint,
+            node_rpc_address,
+            control_api: raw_config.enable_control_api(),
+            offline_mode: raw_config.offline_mode(),
+        })
+    }
+
+    /// Global reward address followed by distinct reward addresses of individual farms, balance
+    /// and rewards are tracked across all of them
+    pub fn reward_addresses(&self) -> Vec<PublicKey> {
+        let mut reward_addresses = vec![self.reward_address];
+        for reward_address in self.farms.iter().filter_map(|farm| farm.reward_address) {
+            if !reward_addresses.contains(&reward_address) {
+                reward_addresses.push(reward_address);
+            }
+        }
+
+        reward_addresses
+    }
+}
+
+/// Check raw config the same way as [`Config::try_from_raw_config()`] and also the way
+/// configuration screen does, but collect all errors instead of stopping at the first one
+pub async fn validate_raw_config(raw_config: &RawConfig) -> Vec<ConfigError> {
+    let mut errors = Vec::new();
+
+    if let Err(error) = parse_reward_address(raw_config.reward_address()) {
+        errors.push(error);
+    }
+    if let Err(error) = check_path(raw_config.node_path().clone()).await {
+        errors.push(error);
+    }
+    if let Err(error) = parse_node_rpc_url(raw_config.node_rpc_url()) {
+        errors.push(error);
+    }
+    if let Err(error) = check_network(&raw_config.network()) {
+        errors.push(error);
+    }
+    if let Err(error) = parse_metrics_endpoint(raw_config.metrics()) {
+        errors.push(error);
+    }
+    if let Err(error) = parse_node_rpc_address(raw_config.node_rpc()) {
+        errors.push(error);
+    }
+    if let Err(error) = parse_plotting_threads(raw_config.plotting_threads()) {
+        errors.push(error);
+    }
+    if let Err(error) = parse_sector_encoding_concurrency(raw_config.sector_encoding_concurrency())
+    {
+        errors.push(error);
+    }
+    if let Err(error) =
+        parse_auditing_threads(raw_config.auditing_threads(), enabled_farms(raw_config))
+    {
+        errors.push(error);
+    }
+    if let Err(error) = parse_piece_cache_size(raw_config.piece_cache_size()) {
+        errors.push(error);
+    }
+    if let Some(piece_cache_path) = raw_config.piece_cache_path()
+        && let Err(error) = check_path(piece_cache_path.to_path_buf()).await
+    {
+        errors.push(error);
+    }
+
+    if raw_config.farms().is_empty() {
+        errors.push(ConfigError::NoFarms);
+    }
+    for (index, farm) in raw_config.farms().iter().enumerate() {
+        if raw_config.farms()[..index]
+            .iter()
+            .any(|other_farm| other_farm.path == farm.path)
+        {
+            errors.push(ConfigError::DuplicateFarmPath {
+                path: farm.path.display().to_string(),
+            });
+        }
+        // Empty paths are reported as invalid separately
+        let other_paths = iter::once(raw_config.node_path().as_path())
+            .chain(raw_config.piece_cache_path())
+            .filter(|path| !path.as_os_str().is_empty() && !farm.path.as_os_str().is_empty());
+        for path in other_paths {
+            if farm.path.starts_with(path) || path.starts_with(&farm.path) {
+                errors.push(ConfigError::FarmPathConflict {
+                    path: path.display().to_string(),
+                    farm_path: farm.path.display().to_string(),
+                });
+            }
+        }
+
+        if let Some(reward_address) = &farm.reward_address
+            && let Err(error) = parse_reward_address(reward_address)
+        {
+            errors.push(error);
+        }
+
+        match parse_size_percentage(&farm.size) {
+            Ok(Some(_size_percentage)) => {
+                // Actual size depends on available space and is checked below
+            }
+            Ok(None) => match parse_size(&farm.size) {
+                Ok(size) => {
+                    if size < MIN_FARM_SIZE {
+                        errors.push(ConfigError::FarmTooSmall {
+                            path: farm.path.display().to_string(),
+                            size: farm.size.clone(),
+                            min_size: ByteSize::b(MIN_FARM_SIZE).to_string_as(true),
+                        });
+                    }
+                }
+                Err(error) => {
+                    errors.push(error);
+                }
+            },
+            Err(error) => {
+                errors.push(error);
+            }
+        }
+        if let Some(reserve) = &farm.reserve
+            && let Err(error) = parse_size(reserve)
+        {
+            errors.push(error);
+        }
+
+        // Disabled farms are not opened, so their directories might not be accessible
+        if !farm.disabled
+            && let Err(error) = check_path(farm.path.clone()).await
+        {
+            errors.push(error);
+        }
+    }
+
+    // Checks that depend on the state of disks, like available space
+    if errors.is_empty()
+        && let Err(error) = Config::try_from_raw_config(raw_config).await
+    {
+        errors.push(error);
+    }
+
+    errors
+}
+
+fn parse_reward_address(reward_address: &str) -> Result<PublicKey, ConfigError> {
+    parse_ss58_reward_address(reward_address).map_err(|error| {
+        ConfigError::InvalidSs58RewardAddress {
+            reward_address: reward_address.to_string(),
+            error,
+        }
+    })
+}
+
+fn parse_node_rpc_url(node_rpc_url: Option<&str>) -> Result<Option<String>, ConfigError> {
+    match node_rpc_url {
+        Some(url) if !is_node_rpc_url_valid(url) => Err(ConfigError::InvalidNodeRpcUrl {
+            url: url.to_string(),
+        }),
+        maybe_url => Ok(maybe_url.map(str::to_string)),
+    }
+}
+
+fn check_network(network: &NetworkConfiguration) -> Result<(), ConfigError> {
+    for port in [network.substrate_port, network.subspace_port] {
+        if port == 0 {
+            return Err(ConfigError::InvalidPort { port });
+        }
+    }
+    if let Some(address) = &network.external_address
+        && !is_external_address_valid(address)
+    {
+        return Err(ConfigError::InvalidExternalAddress {
+            address: address.clone(),
+        });
+    }
+
+    Ok(())
+}
+
+fn parse_metrics_endpoint(
+    metrics: &MetricsConfiguration,
+) -> Result<Option<SocketAddr>, ConfigError> {
+    if !metrics.enabled {
+        return Ok(None);
+    }
+
+    SocketAddr::from_str(&metrics.address)
+        .map(Some)
+        .map_err(|_error| ConfigError::InvalidMetricsAddress {
+            address: metrics.address.clone(),
+        })
+}
+
+fn parse_node_rpc_address(
+    node_rpc: &NodeRpcConfiguration,
+) -> Result<Option<SocketAddr>, ConfigError> {
+    if !node_rpc.enabled {
+        return Ok(None);
+    }
+
+    SocketAddr::from_str(&node_rpc.address)
+        .ok()
+        .filter(|address| address.port() != 0)
+        .map(Some)
+        .ok_or_else(|| ConfigError::InvalidNodeRpcAddress {
+            address: node_rpc.address.clone(),
         })
+}
+
+fn parse_plotting_threads(
+    plotting_threads: Option<usize>,
+) -> Result<Option<NonZeroUsize>, ConfigError> {
+    let Some(threads) = plotting_threads else {
+        return Ok(None);
+    };
+
+    let max = available_cpu_threads();
+    match NonZeroUsize::new(threads) {
+        Some(plotting_threads) if threads <= max => Ok(Some(plotting_threads)),
+        _ => Err(ConfigError::InvalidPlottingThreads { threads, max }),
+    }
+}
+
+fn parse_sector_encoding_concurrency(
+    sector_encoding_concurrency: Option<usize>,
+) -> Result<Option<NonZeroUsize>, ConfigError> {
+    let Some(concurrency) = sector_encoding_concurrency else {
+        return Ok(None);
+    };
+
+    let max = MAX_SECTOR_ENCODING_CONCURRENCY;
+    match NonZeroUsize::new(concurrency) {
+        Some(sector_encoding_concurrency) if concurrency <= max => {
+            Ok(Some(sector_encoding_concurrency))
+        }
+        _ => Err(ConfigError::InvalidSectorEncodingConcurrency { concurrency, max }),
+    }
+}
+
+fn parse_auditing_threads(
+    auditing_threads: Option<usize>,
+    farms: usize,
+) -> Result<Option<NonZeroUsize>, ConfigError> {
+    let Some(threads) = auditing_threads else {
+        return Ok(None);
+    };
+
+    let max = available_cpu_threads();
+    match NonZeroUsize::new(threads) {
+        Some(_) if threads < farms => Err(ConfigError::TooFewAuditingThreads { threads, farms }),
+        Some(auditing_threads) if threads <= max => Ok(Some(auditing_threads)),
+        _ => Err(ConfigError::InvalidAuditingThreads { threads, max }),
+    }
+}
+
+/// Number of farms that are not disabled and will be started
+fn enabled_farms(raw_config: &RawConfig) -> usize {
+    raw_config
+        .farms()
+        .iter()
+        .filter(|farm| !farm.disabled)
+        .count()
+}
+
+fn parse_piece_cache_size(piece_cache_size: Option<&str>) -> Result<Option<u64>, ConfigError> {
+    piece_cache_size.map(parse_size).transpose()
+}
+
+fn parse_size(size: &str) -> Result<u64, ConfigError> {
+    ByteSize::from_str(size)
+        .map(|size| size.as_u64())
+        .map_err(|error| ConfigError::InvalidSizeFormat {
+            size: size.to_string(),
+            error,
+        })
+}
+
+/// Parse size specified as percentage of free disk space, returns `None` for absolute sizes
+fn parse_size_percentage(size: &str) -> Result<Option<f64>, ConfigError> {
+    let Some(size_percentage) = size.strip_suffix('%') else {
+        return Ok(None);
+    };
+
+    let size_percentage =
+        f64::from_str(size_percentage).map_err(|error| ConfigError::InvalidSizeFormat {
+            size: size.to_string(),
+            error: error.to_string(),
+        })?;
+    if size_percentage <= 0.0 || size_percentage > 100.0 {
+        return Err(ConfigError::InvalidSizeFormat {
+            size: size.to_string(),
+            error: "Size percentage should be above 0% and not exceed 100%".to_string(),
+        });
+    }
+
+    Ok(Some(size_percentage))
+}
+
+/// Piece cache is allocated on every farm proportionally to its size, so it is specified as the same
+/// percentage of allocated space for each farm, rounded up. Returns `None` if percentage exceeds
+/// [`MAX_CACHE_PERCENTAGE`].
+fn piece_cache_percentage(piece_cache_size: u64, total_allocated_space: u64) -> Option<NonZeroU8> {
+    let percentage =
+        (u128::from(piece_cache_size) * 100).div_ceil(u128::from(total_allocated_space.max(1)));
+    if percentage > u128::from(MAX_CACHE_PERCENTAGE) {
+        return None;
     }
+
+    Some(
+        NonZeroU8::new(percentage as u8)
+            .unwrap_or(CACHE_PERCENTAGE)
+            .max(CACHE_PERCENTAGE),
+    )
+}
+
+/// Percentage of allocated space of each farm that farmer will use for piece cache, determined the
+/// same way as in [`Config::try_from_raw_config()`], except too large piece cache is capped at
+/// [`MAX_CACHE_PERCENTAGE`] instead of being an error
+pub fn farm_cache_percentage(
+    piece_cache_size: Option<u64>,
+    dedicated_piece_cache: bool,
+    total_allocated_space: u64,
+) -> NonZeroU8 {
+    match piece_cache_size {
+        Some(piece_cache_size) if !dedicated_piece_cache => {
+            piece_cache_percentage(piece_cache_size, total_allocated_space)
+                .unwrap_or(NonZeroU8::new(MAX_CACHE_PERCENTAGE).expect("Not zero; qed"))
+        }
+        _ => CACHE_PERCENTAGE,
+    }
+}
+
+/// Space available for dedicated piece cache, including space piece cache already occupies
+fn is_toml_path(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case(TOML_CONFIG_EXTENSION))
+}
+
+async fn piece_cache_available_space(path: PathBuf) -> io::Result<u64> {
+    task::spawn_blocking(move || {
+        // Piece cache directory might not have been created yet
+        let existing_path = if path.exists() {
+            path.as_path()
+        } else {
+            path.parent().unwrap_or(&path)
+        };
+        let available_space = fs4::available_space(existing_path)?;
+        let occupied_space = std::fs::read_dir(&path)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .filter_map(|entry| entry.metadata().ok())
+            .filter(|metadata| metadata.is_file())
+            .map(|metadata| metadata.len())
+            .sum::<u64>();
+
+        Ok(available_space + occupied_space)
+    })
+    .await
+    .map_err(|error| {
+        io::Error::new(
+            io::ErrorKind::Other,
+            format!("Failed to spawn tokio task: {error}"),
+        )
+    })
+    .flatten()
 }
 
 async fn check_path(path: PathBuf) -> Result<(), ConfigError> {
@@ -0,0 +1,208 @@
+use crate::app_dirs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Profile that uses configuration file in the root of config directory, it always exists and
+/// can't be deleted
+pub const DEFAULT_PROFILE_NAME: &str = "default";
+/// File in config directory with the name of the active profile
+const ACTIVE_PROFILE_FILE_NAME: &str = "active-profile";
+/// Each non-default profile is stored in its own subdirectory of this directory
+const PROFILES_DIRECTORY_NAME: &str = "profiles";
+const MAX_PROFILE_NAME_LENGTH: usize = 32;
+
+/// Configuration profile error
+#[derive(Debug, thiserror::Error)]
+pub enum ProfileError {
+    /// Failed to determine config directory
+    #[error("Failed to determine config directory")]
+    FailedToDetermineConfigDirectory,
+    /// Profile name is not valid
+    #[error("Profile name \"{name}\" is invalid")]
+    InvalidName { name: String },
+    /// Profile with this name already exists
+    #[error("Profile \"{name}\" already exists")]
+    AlreadyExists { name: String },
+    /// Profile with this name doesn't exist
+    #[error("Profile \"{name}\" doesn't exist")]
+    NotFound { name: String },
+    /// Default profile can't be deleted
+    #[error("Default profile can't be deleted")]
+    DeletingDefault,
+    /// Active profile can't be deleted
+    #[error("Profile \"{name}\" is active and can't be deleted")]
+    DeletingActive { name: String },
+    /// I/O error
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+}
+
+/// Profile name is used as directory name, so only a conservative set of characters is allowed
+pub fn is_valid_profile_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= MAX_PROFILE_NAME_LENGTH
+        && name
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || char == '-' || char == '_')
+}
+
+/// Directory where configuration of the profile is stored
+pub(in super::super) fn profile_directory(app_config_dir: &Path, name: &str) -> PathBuf {
+    if name == DEFAULT_PROFILE_NAME {
+        app_config_dir.to_path_buf()
+    } else {
+        app_config_dir.join(PROFILES_DIRECTORY_NAME).join(name)
+    }
+}
+
+/// Directory of the active profile, where its configuration and other state is stored, `None` if
+/// config directory can't be determined
+pub async fn active_profile_directory() -> Option<PathBuf> {
+    let app_config_dir = app_dirs::config_dir()?;
+
+    Some(profile_directory(&app_config_dir, &active_profile().await))
+}
+
+fn app_config_dir() -> Result<PathBuf, ProfileError> {
+    app_dirs::config_dir().ok_or(ProfileError::FailedToDetermineConfigDirectory)
+}
+
+/// Name of the active profile, default profile is used if active profile is not set or was removed
+pub async fn active_profile() -> String {
+    let Some(app_config_dir) = app_dirs::config_dir() else {
+        return DEFAULT_PROFILE_NAME.to_string();
+    };
+    let Ok(contents) =
+        tokio::fs::read_to_string(app_config_dir.join(ACTIVE_PROFILE_FILE_NAME)).await
+    else {
+        return DEFAULT_PROFILE_NAME.to_string();
+    };
+    let name = contents.trim();
+
+    if is_valid_profile_name(name)
+        && tokio::fs::try_exists(profile_directory(&app_config_dir, name))
+            .await
+            .unwrap_or_default()
+    {
+        name.to_string()
+    } else {
+        DEFAULT_PROFILE_NAME.to_string()
+    }
+}
+
+/// Blocking version of [`active_profile()`], for use before async runtime is available
+pub fn active_profile_blocking() -> String {
+    let Some(app_config_dir) = app_dirs::config_dir() else {
+        return DEFAULT_PROFILE_NAME.to_string();
+    };
+    let Ok(contents) = std::fs::read_to_string(app_config_dir.join(ACTIVE_PROFILE_FILE_NAME))
+    else {
+        return DEFAULT_PROFILE_NAME.to_string();
+    };
+    let name = contents.trim();
+
+    if is_valid_profile_name(name) && profile_directory(&app_config_dir, name).exists() {
+        name.to_string()
+    } else {
+        DEFAULT_PROFILE_NAME.to_string()
+    }
+}
+
+/// Make profile active, it is used on next start of node and farmer
+pub async fn set_active_profile(name: &str) -> Result<(), ProfileError> {
+    let app_config_dir = app_config_dir()?;
+
+    if !is_valid_profile_name(name) {
+        return Err(ProfileError::InvalidName {
+            name: name.to_string(),
+        });
+    }
+    if !tokio::fs::try_exists(profile_directory(&app_config_dir, name)).await? {
+        return Err(ProfileError::NotFound {
+            name: name.to_string(),
+        });
+    }
+
+    tokio::fs::create_dir_all(&app_config_dir).await?;
+    tokio::fs::write(app_config_dir.join(ACTIVE_PROFILE_FILE_NAME), name).await?;
+
+    Ok(())
+}
+
+/// All profiles, default profile comes first and the rest are sorted by name
+pub async fn list_profiles() -> Result<Vec<String>, ProfileError> {
+    let profiles_dir = app_config_dir()?.join(PROFILES_DIRECTORY_NAME);
+
+    let mut profiles = Vec::new();
+    match tokio::fs::read_dir(&profiles_dir).await {
+        Ok(mut entries) => {
+            while let Some(entry) = entries.next_entry().await? {
+                if !entry.file_type().await?.is_dir() {
+                    continue;
+                }
+                if let Some(name) = entry.file_name().to_str()
+                    && name != DEFAULT_PROFILE_NAME
+                    && is_valid_profile_name(name)
+                {
+                    profiles.push(name.to_string());
+                }
+            }
+        }
+        Err(error) => {
+            if error.kind() != io::ErrorKind::NotFound {
+                return Err(error.into());
+            }
+        }
+    }
+    profiles.sort();
+    profiles.insert(0, DEFAULT_PROFILE_NAME.to_string());
+
+    Ok(profiles)
+}
+
+/// Create new empty profile, it needs to be configured from scratch once switched to
+pub async fn create_profile(name: &str) -> Result<(), ProfileError> {
+    let app_config_dir = app_config_dir()?;
+
+    if !is_valid_profile_name(name) {
+        return Err(ProfileError::InvalidName {
+            name: name.to_string(),
+        });
+    }
+    let profile_directory = profile_directory(&app_config_dir, name);
+    if name == DEFAULT_PROFILE_NAME || tokio::fs::try_exists(&profile_directory).await? {
+        return Err(ProfileError::AlreadyExists {
+            name: name.to_string(),
+        });
+    }
+
+    tokio::fs::create_dir_all(profile_directory).await?;
+
+    Ok(())
+}
+
+/// Delete profile with its configuration, node and farms data it was using are left on disk
+pub async fn delete_profile(name: &str) -> Result<(), ProfileError> {
+    if name == DEFAULT_PROFILE_NAME {
+        return Err(ProfileError::DeletingDefault);
+    }
+    if !is_valid_profile_name(name) {
+        return Err(ProfileError::InvalidName {
+            name: name.to_string(),
+        });
+    }
+    if active_profile().await == name {
+        return Err(ProfileError::DeletingActive {
+            name: name.to_string(),
+        });
+    }
+
+    let profile_directory = profile_directory(&app_config_dir()?, name);
+    match tokio::fs::remove_dir_all(&profile_directory).await {
+        Ok(()) => Ok(()),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => Err(ProfileError::NotFound {
+            name: name.to_string(),
+        }),
+        Err(error) => Err(error.into()),
+    }
+}
@@ -0,0 +1,429 @@
+use crate::app_dirs;
+use crate::backend::farmer::{DiskFarm, FarmerAction, FarmerNotification, InitialFarmState};
+use crate::backend::node::SyncState;
+use crate::backend::{FarmIndex, NodeNotification};
+use futures::channel::mpsc;
+use futures::SinkExt;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Arc;
+use subspace_core_primitives::{BlockNumber, SectorIndex};
+use subspace_farmer::farm::{SectorPlottingDetails, SectorUpdate};
+use subspace_runtime_primitives::Balance;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader};
+use tracing::{debug, info};
+
+/// Max size of a single command, connection is closed if client sends longer line
+const MAX_COMMAND_SIZE: u64 = 4 * 1024;
+/// Name of the directory in app data directory that control socket is created in, only accessible
+/// by current user
+#[cfg(unix)]
+const CONTROL_DIRECTORY_NAME: &str = "control";
+/// Name of the control socket in control directory
+#[cfg(unix)]
+const CONTROL_SOCKET_FILE_NAME: &str = "control.sock";
+/// Name of the named pipe
+#[cfg(windows)]
+const CONTROL_PIPE_NAME: &str = r"\\.\pipe\space-acres-control";
+
+/// Command sent by client, one JSON object per line
+#[derive(Debug, Deserialize)]
+#[serde(tag = "command", rename_all = "camelCase")]
+enum ControlCommand {
+    GetStatus,
+    ListFarms,
+    GetRewards,
+    PauseFarming,
+    ResumeFarming,
+    PausePlotting,
+    ResumePlotting,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FarmStatus {
+    index: FarmIndex,
+    directory: PathBuf,
+    allocated_space: u64,
+    sectors_total: SectorIndex,
+    sectors_plotted: SectorIndex,
+    /// Last error farm exited with, `None` if farm is running
+    error: Option<String>,
+}
+
+#[derive(Debug)]
+struct State {
+    best_block_number: BlockNumber,
+    sync_state: SyncState,
+    peers: usize,
+    initial_reward_address_balance: Balance,
+    reward_address_balance: Balance,
+    plotting_paused: bool,
+    farming_paused: bool,
+    farms: Vec<FarmStatus>,
+}
+
+/// State of the node and farmer as seen by clients of control socket, kept up to date using the
+/// same notifications frontend receives
+#[derive(Debug)]
+pub(super) struct ControlState {
+    state: Mutex<State>,
+}
+
+impl ControlState {
+    pub(super) fn new(
+        farms: &[DiskFarm],
+        initial_farm_states: &[InitialFarmState],
+        best_block_number: BlockNumber,
+        reward_address_balance: Balance,
+    ) -> Self {
+        let farms = farms
+            .iter()
+            .zip(initial_farm_states)
+            .enumerate()
+            .map(|(index, (farm, initial_farm_state))| FarmStatus {
+                index: index as FarmIndex,
+                directory: farm.directory.clone(),
+                allocated_space: farm.allocated_space,
+                sectors_total: initial_farm_state.total_sectors_count,
+                sectors_plotted: initial_farm_state.plotted_sectors_count,
+                error: None,
+            })
+            .collect();
+
+        Self {
+            state: Mutex::new(State {
+                best_block_number,
+                sync_state: SyncState::default(),
+                peers: 0,
+                initial_reward_address_balance: reward_address_balance,
+                reward_address_balance,
+                plotting_paused: false,
+                farming_paused: false,
+                farms,
+            }),
+        }
+    }
+
+    pub(super) fn on_node_notification(&self, notification: &NodeNotification) {
+        let mut state = self.state.lock();
+
+        match notification {
+            NodeNotification::SyncStateUpdate(sync_state) => {
+                state.sync_state = *sync_state;
+            }
+            NodeNotification::BlockImported(block_imported) => {
+                state.best_block_number = block_imported.number;
+                // Same as in UI, balance decrease (typically due to reorg) is not considered to
+                // be a negative reward
+                if let Some(decreased_by) = state
+                    .reward_address_balance
+                    .checked_sub(block_imported.reward_address_balance)
+                {
+                    state.initial_reward_address_balance = state
+                        .initial_reward_address_balance
+                        .saturating_sub(decreased_by);
+                }
+                state.reward_address_balance = block_imported.reward_address_balance;
+            }
+            NodeNotification::PeersUpdate(peers) => {
+                state.peers = peers.len();
+            }
+            NodeNotification::ClockOffset(_clock_offset) => {
+                // Not exposed
+            }
+        }
+    }
+
+    pub(super) fn on_farmer_notification(&self, notification: &FarmerNotification<FarmIndex>) {
+        let mut state = self.state.lock();
+
+        match notification {
+            FarmerNotification::SectorUpdate {
+                farm_index, update, ..
+            } => {
+                // Same condition as in UI, replotted sectors don't change number of plotted sectors
+                if matches!(
+                    update,
+                    SectorUpdate::Plotting(SectorPlottingDetails::Finished {
+                        old_plotted_sector: None,
+                        ..
+                    })
+                ) && let Some(farm) = state.farms.get_mut(usize::from(*farm_index))
+                {
+                    farm.sectors_plotted += 1;
+                }
+            }
+            FarmerNotification::FarmError { farm_index, error } => {
+                if let Some(farm) = state.farms.get_mut(usize::from(*farm_index)) {
+                    farm.error.replace(error.to_string());
+                }
+            }
+            FarmerNotification::FarmResized {
+                farm_index,
+                total_sectors_count,
+                ..
+            } => {
+                if let Some(farm) = state.farms.get_mut(usize::from(*farm_index)) {
+                    farm.sectors_total = *total_sectors_count;
+                    // Farm is started again after resizing
+                    farm.error.take();
+                }
+            }
+            FarmerNotification::PauseChanged {
+                plotting_paused,
+                farming_paused,
+            } => {
+                state.plotting_paused = *plotting_paused;
+                state.farming_paused = *farming_paused;
+            }
+            FarmerNotification::FarmingNotification { .. }
+            | FarmerNotification::FarmerCacheSyncProgress { .. } => {
+                // Not tracked
+            }
+        }
+    }
+
+    fn status(&self) -> serde_json::Value {
+        let state = self.state.lock();
+
+        json!({
+            "bestBlockNumber": state.best_block_number,
+            "synced": state.sync_state.is_synced(),
+            "syncTarget": match state.sync_state {
+                SyncState::Syncing { target, .. } => Some(target),
+                SyncState::Unknown | SyncState::Idle => None,
+            },
+            "peers": state.peers,
+            "plottingPaused": state.plotting_paused,
+            "farmingPaused": state.farming_paused,
+            "farmsCount": state.farms.len(),
+            "sectorsTotal": state
+                .farms
+                .iter()
+                .map(|farm| u64::from(farm.sectors_total))
+                .sum::<u64>(),
+            "sectorsPlotted": state
+                .farms
+                .iter()
+                .map(|farm| u64::from(farm.sectors_plotted))
+                .sum::<u64>(),
+        })
+    }
+
+    fn farms(&self) -> serde_json::Value {
+        json!(self.state.lock().farms)
+    }
+
+    fn rewards(&self) -> serde_json::Value {
+        let state = self.state.lock();
+
+        // Balances are strings, since they don't fit into numbers many JSON parsers support
+        json!({
+            "rewardAddressBalance": state.reward_address_balance.to_string(),
+            "rewardsSinceStart": state
+                .reward_address_balance
+                .saturating_sub(state.initial_reward_address_balance)
+                .to_string(),
+        })
+    }
+}
+
+/// Location of the control socket (named pipe on Windows)
+#[cfg(unix)]
+fn control_socket_path() -> Option<PathBuf> {
+    app_dirs::data_dir().map(|data_dir| {
+        data_dir
+            .join(CONTROL_DIRECTORY_NAME)
+            .join(CONTROL_SOCKET_FILE_NAME)
+    })
+}
+
+/// Location of the control socket (named pipe on Windows)
+#[cfg(windows)]
+fn control_socket_path() -> Option<PathBuf> {
+    Some(PathBuf::from(CONTROL_PIPE_NAME))
+}
+
+/// Location of the control socket (named pipe on Windows)
+#[cfg(not(any(unix, windows)))]
+fn control_socket_path() -> Option<PathBuf> {
+    None
+}
+
+/// Serve control commands on local socket, only local clients can connect to it
+#[cfg(unix)]
+pub(super) async fn serve_control(
+    state: Arc<ControlState>,
+    farmer_action_sender: mpsc::Sender<FarmerAction>,
+) -> io::Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    use tokio::net::UnixListener;
+
+    let Some(path) = control_socket_path() else {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            "Failed to determine app data directory",
+        ));
+    };
+
+    // Only current user is allowed to control the application. Socket is created inside of a
+    // directory that only current user can access, such that there is no window between binding
+    // and restricting permissions of the socket itself during which others could connect to it
+    let directory = path
+        .parent()
+        .expect("Control socket is always inside of control directory; qed");
+    tokio::fs::DirBuilder::new()
+        .recursive(true)
+        .mode(0o700)
+        .create(directory)
+        .await?;
+    // Directory might have existed before with different permissions
+    tokio::fs::set_permissions(directory, std::fs::Permissions::from_mode(0o700)).await?;
+
+    // Only one instance is running at a time, so socket left behind is stale
+    match tokio::fs::remove_file(&path).await {
+        Ok(()) => {}
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+        Err(error) => {
+            return Err(error);
+        }
+    }
+    let listener = UnixListener::bind(&path)?;
+    tokio::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600)).await?;
+    info!(path = %path.display(), "Serving control socket");
+
+    loop {
+        let (stream, _address) = listener.accept().await?;
+
+        tokio::spawn(handle_connection(
+            stream,
+            Arc::clone(&state),
+            farmer_action_sender.clone(),
+        ));
+    }
+}
+
+/// Serve control commands on local named pipe, remote clients are rejected
+#[cfg(windows)]
+pub(super) async fn serve_control(
+    state: Arc<ControlState>,
+    farmer_action_sender: mpsc::Sender<FarmerAction>,
+) -> io::Result<()> {
+    use tokio::net::windows::named_pipe::ServerOptions;
+
+    let mut server = ServerOptions::new()
+        .first_pipe_instance(true)
+        .reject_remote_clients(true)
+        .create(CONTROL_PIPE_NAME)?;
+    info!(path = %CONTROL_PIPE_NAME, "Serving control named pipe");
+
+    loop {
+        server.connect().await?;
+        // New instance must exist before the client is handed over, so that other clients can
+        // connect in the meantime
+        let client = std::mem::replace(
+            &mut server,
+            ServerOptions::new()
+                .reject_remote_clients(true)
+                .create(CONTROL_PIPE_NAME)?,
+        );
+
+        tokio::spawn(handle_connection(
+            client,
+            Arc::clone(&state),
+            farmer_action_sender.clone(),
+        ));
+    }
+}
+
+#[cfg(not(any(unix, windows)))]
+pub(super) async fn serve_control(
+    _state: Arc<ControlState>,
+    _farmer_action_sender: mpsc::Sender<FarmerAction>,
+) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "Control socket is not supported on this platform",
+    ))
+}
+
+/// Answer every command (one JSON object per line) with a response on a separate line until
+/// client disconnects
+async fn handle_connection<S>(
+    stream: S,
+    state: Arc<ControlState>,
+    mut farmer_action_sender: mpsc::Sender<FarmerAction>,
+) where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let (reader, mut writer) = tokio::io::split(stream);
+    let mut reader = BufReader::new(reader);
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+        let result = (&mut reader)
+            .take(MAX_COMMAND_SIZE)
+            .read_line(&mut line)
+            .await;
+        let response = match result {
+            Ok(0) => {
+                // Client disconnected
+                return;
+            }
+            Ok(_) if !line.ends_with('\n') && line.len() as u64 == MAX_COMMAND_SIZE => {
+                let response = json!({ "ok": false, "error": "Command is too long" });
+                let _ = writer.write_all(format!("{response}\n").as_bytes()).await;
+                return;
+            }
+            Ok(_) => process_command(line.trim(), &state, &mut farmer_action_sender).await,
+            Err(error) => {
+                debug!(%error, "Failed to read control command");
+                return;
+            }
+        };
+
+        if let Err(error) = writer.write_all(format!("{response}\n").as_bytes()).await {
+            debug!(%error, "Failed to write control response");
+            return;
+        }
+    }
+}
+
+async fn process_command(
+    command: &str,
+    state: &ControlState,
+    farmer_action_sender: &mut mpsc::Sender<FarmerAction>,
+) -> serde_json::Value {
+    let command = match serde_json::from_str::<ControlCommand>(command) {
+        Ok(command) => command,
+        Err(error) => {
+            return json!({ "ok": false, "error": format!("Invalid command: {error}") });
+        }
+    };
+
+    let farmer_action = match command {
+        ControlCommand::GetStatus => {
+            return json!({ "ok": true, "result": state.status() });
+        }
+        ControlCommand::ListFarms => {
+            return json!({ "ok": true, "result": state.farms() });
+        }
+        ControlCommand::GetRewards => {
+            return json!({ "ok": true, "result": state.rewards() });
+        }
+        ControlCommand::PauseFarming => FarmerAction::PauseFarming(true),
+        ControlCommand::ResumeFarming => FarmerAction::PauseFarming(false),
+        ControlCommand::PausePlotting => FarmerAction::PausePlotting(true),
+        ControlCommand::ResumePlotting => FarmerAction::PausePlotting(false),
+    };
+
+    match farmer_action_sender.send(farmer_action).await {
+        Ok(()) => json!({ "ok": true }),
+        Err(error) => json!({ "ok": false, "error": format!("Farmer is not running: {error}") }),
+    }
+}
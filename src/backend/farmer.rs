@@ -1,7 +1,9 @@
 pub(super) mod direct_node_client;
 pub(super) mod maybe_node_client;
+pub mod reconciliation;
 
 use crate::backend::farmer::maybe_node_client::MaybeNodeClient;
+use crate::backend::node::GENESIS_HASH;
 use crate::backend::utils::{Handler, HandlerFn};
 use crate::backend::PieceGetterWrapper;
 use crate::{PosTable, PosTableLegacy};
@@ -14,25 +16,28 @@ use futures::future::BoxFuture;
 use futures::stream::FuturesUnordered;
 use futures::{select, FutureExt, StreamExt};
 use parking_lot::Mutex;
+use std::collections::HashMap;
 use std::future::pending;
 use std::hash::Hash;
 use std::num::{NonZeroU8, NonZeroUsize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Duration;
-use std::{fmt, fs};
+use std::{fmt, fs, io, mem};
 use subspace_core_primitives::crypto::kzg::Kzg;
 use subspace_core_primitives::{PublicKey, Record, SectorIndex};
 use subspace_erasure_coding::ErasureCoding;
+use subspace_farmer::disk_piece_cache::DiskPieceCache;
 use subspace_farmer::farm::plotted_pieces::PlottedPieces;
 use subspace_farmer::farm::{
-    FarmingNotification, PlottedSectors, SectorPlottingDetails, SectorUpdate,
+    FarmingNotification, PieceCache, PlotCache, PlottedSectors, SectorPlottingDetails, SectorUpdate,
 };
 use subspace_farmer::farmer_cache::{FarmerCache, FarmerCacheWorker};
+use subspace_farmer::node_client::node_rpc_client::NodeRpcClient;
 use subspace_farmer::node_client::NodeClient;
 use subspace_farmer::plotter::cpu::CpuPlotter;
 use subspace_farmer::single_disk_farm::{
-    SingleDiskFarm, SingleDiskFarmError, SingleDiskFarmOptions,
+    SingleDiskFarm, SingleDiskFarmError, SingleDiskFarmInfo, SingleDiskFarmOptions,
 };
 use subspace_farmer::utils::{
     create_plotting_thread_pool_manager, recommended_number_of_farming_threads,
@@ -43,17 +48,35 @@ use thread_priority::ThreadPriority;
 use tokio::sync::{watch, Barrier, Semaphore};
 use tracing::{debug, error, info, info_span, Instrument};
 
-/// Minimal cache percentage, there is no need in setting it higher
+/// Minimal cache percentage, used unless piece cache size is configured explicitly
 pub(super) const CACHE_PERCENTAGE: NonZeroU8 = NonZeroU8::MIN;
 /// NOTE: for large gaps between the plotted part and the end of the file plot cache will result in
 /// very long period of writing zeroes on Windows, see https://stackoverflow.com/q/78058306/3806795
 const MAX_SPACE_PLEDGED_FOR_PLOT_CACHE_ON_WINDOWS: u64 = ByteSize::tib(7).as_u64();
 const FARM_ERROR_PRINT_INTERVAL: Duration = Duration::from_secs(30);
+/// How often farm directory is checked for being accessible
+pub const FARM_DIRECTORY_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+/// OS error codes that indicate there is no space left on the disk (`ENOSPC`)
+#[cfg(unix)]
+const DISK_FULL_OS_ERRORS: &[i32] = &[28];
+/// OS error codes that indicate there is no space left on the disk (`ERROR_HANDLE_DISK_FULL` and
+/// `ERROR_DISK_FULL`)
+#[cfg(windows)]
+const DISK_FULL_OS_ERRORS: &[i32] = &[39, 112];
+/// OS error codes that indicate there is no space left on the disk
+#[cfg(not(any(unix, windows)))]
+const DISK_FULL_OS_ERRORS: &[i32] = &[];
+/// Messages that indicate there is no space left on the disk, for errors that were converted to
+/// strings before reaching Space Acres
+const DISK_FULL_ERROR_MESSAGES: &[&str] =
+    &["No space left on device", "not enough space on the disk"];
 
 #[derive(Debug, Default, Copy, Clone, PartialEq)]
 pub struct InitialFarmState {
     pub total_sectors_count: SectorIndex,
     pub plotted_sectors_count: SectorIndex,
+    /// Number of pieces piece cache of the farm can hold
+    pub piece_cache_capacity: u32,
 }
 
 #[derive(Debug, Clone)]
@@ -75,15 +98,114 @@ pub enum FarmerNotification<FarmIndex> {
         farm_index: FarmIndex,
         error: Arc<anyhow::Error>,
     },
+    /// Farm was opened again with larger allocated space
+    FarmResized {
+        farm_index: FarmIndex,
+        previous_total_sectors_count: SectorIndex,
+        total_sectors_count: SectorIndex,
+    },
+    /// Plotting or farming was paused or resumed, not necessarily from UI
+    PauseChanged {
+        plotting_paused: bool,
+        farming_paused: bool,
+    },
+}
+
+/// Farm directory became inaccessible while farm was running, for example because external drive
+/// was disconnected
+#[derive(Debug, thiserror::Error)]
+#[error("Farm directory {} is not accessible anymore", directory.display())]
+pub struct FarmDirectoryInaccessible {
+    pub directory: PathBuf,
+}
+
+/// Check whether farm error was caused by farm directory becoming inaccessible
+pub fn is_farm_directory_inaccessible_error(error: &anyhow::Error) -> bool {
+    error.downcast_ref::<FarmDirectoryInaccessible>().is_some()
+}
+
+/// Check whether farm directory is accessible and still contains the farm
+pub async fn is_farm_directory_accessible(directory: PathBuf) -> bool {
+    tokio::task::spawn_blocking(move || {
+        matches!(SingleDiskFarmInfo::load_from(&directory), Ok(Some(_)))
+    })
+    .await
+    .unwrap_or_default()
+}
+
+/// Resolves once farm directory is not accessible anymore
+async fn farm_directory_inaccessible(directory: PathBuf) {
+    loop {
+        tokio::time::sleep(FARM_DIRECTORY_CHECK_INTERVAL).await;
+
+        if !is_farm_directory_accessible(directory.clone()).await {
+            return;
+        }
+    }
+}
+
+/// Check whether farm error was caused by disk running out of space
+pub fn is_disk_full_error(error: &anyhow::Error) -> bool {
+    error.chain().any(|error| {
+        let os_error = error
+            .downcast_ref::<io::Error>()
+            .and_then(io::Error::raw_os_error);
+        if let Some(os_error) = os_error {
+            return DISK_FULL_OS_ERRORS.contains(&os_error);
+        }
+
+        let error = error.to_string();
+        DISK_FULL_ERROR_MESSAGES
+            .iter()
+            .any(|message| error.contains(message))
+    })
 }
 
 #[derive(Debug, Clone)]
 pub enum FarmerAction {
     /// Pause (or resume) plotting
     PausePlotting(bool),
+    /// Pause (or resume) farming, plotting is paused too while farming is paused
+    PauseFarming(bool),
+    /// Stop farm with specified index, its files are closed and it is not used for piece cache and
+    /// plotted pieces anymore
+    StopFarm(usize),
+    /// Open and start farm with specified index again after it was stopped with
+    /// [`FarmerAction::StopFarm`]
+    StartFarm(usize),
+    /// Open and start farm with specified index again after it has exited with an error (like
+    /// running out of disk space)
+    RestartFarm(usize),
+    /// Stop farm with specified index and open it again with larger allocated space, new sectors
+    /// will be plotted while already plotted sectors are preserved
+    ResizeFarm {
+        farm_index: usize,
+        allocated_space: u64,
+    },
 }
 
 type Notifications<FarmIndex> = Handler<FarmerNotification<FarmIndex>>;
+type FarmRunFuture<FarmIndex> = BoxFuture<'static, (FarmIndex, anyhow::Result<()>)>;
+
+/// Farm that was started by the farmer
+struct RunningFarm {
+    stop_sender: oneshot::Sender<()>,
+    /// Resolves (with an error) once farm has exited and released its resources
+    exited_receiver: oneshot::Receiver<()>,
+}
+
+impl RunningFarm {
+    /// Stand-in for farm that failed to start, it is considered to have exited already
+    fn exited() -> Self {
+        let (stop_sender, _stop_receiver) = oneshot::channel();
+        let (_exited_sender, exited_receiver) = oneshot::channel();
+
+        Self {
+            stop_sender,
+            exited_receiver,
+        }
+    }
+}
 
 pub(super) struct Farmer<FarmIndex>
 where
@@ -92,6 +214,8 @@ where
     farmer_fut: BoxFuture<'static, anyhow::Result<()>>,
     farmer_cache_worker_fut: BoxFuture<'static, ()>,
     initial_farm_states: Vec<InitialFarmState>,
+    /// Number of pieces piece cache can hold across all farms or in dedicated piece cache
+    piece_cache_capacity: u64,
     notifications: Arc<Notifications<FarmIndex>>,
     action_sender: mpsc::Sender<FarmerAction>,
 }
@@ -105,6 +229,7 @@ where
             farmer_fut,
             farmer_cache_worker_fut,
             initial_farm_states,
+            piece_cache_capacity: _,
             notifications,
             action_sender,
         } = self;
@@ -153,6 +278,10 @@ where
         &self.initial_farm_states
     }
 
+    pub(super) fn piece_cache_capacity(&self) -> u64 {
+        self.piece_cache_capacity
+    }
+
     pub(super) fn action_sender(&self) -> mpsc::Sender<FarmerAction> {
         self.action_sender.clone()
     }
@@ -175,6 +304,69 @@ impl<FarmIndex> fmt::Debug for Farmer<FarmIndex> {
 pub struct DiskFarm {
     pub directory: PathBuf,
     pub allocated_space: u64,
+    /// Reward address of the farm, global reward address is used if `None`
+    pub reward_address: Option<PublicKey>,
+}
+
+/// Piece cache stored in its own directory rather than within farms
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    pub directory: PathBuf,
+    pub allocated_space: u64,
+}
+
+/// State of the directory that is about to be used for a farm
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FarmDirectoryState {
+    /// Directory is empty or doesn't exist yet, new farm will be created
+    Empty,
+    /// Directory contains existing farm that will be resumed
+    ExistingFarm,
+    /// Directory contains files that don't belong to a farm
+    UnrelatedFiles,
+}
+
+/// Inspect directory to find out whether it contains an existing farm, blocking
+pub fn farm_directory_state(directory: &Path) -> io::Result<FarmDirectoryState> {
+    if !directory.try_exists()? {
+        return Ok(FarmDirectoryState::Empty);
+    }
+
+    if SingleDiskFarmInfo::load_from(directory)?.is_some() {
+        return Ok(FarmDirectoryState::ExistingFarm);
+    }
+
+    for entry in fs::read_dir(directory)? {
+        let file_name = entry?.file_name();
+        let file_name = file_name.to_string_lossy();
+        // Hidden files and file system service directories are created by OS and can be ignored
+        if !(file_name.starts_with('.')
+            || file_name == "lost+found"
+            || file_name == "System Volume Information"
+            || file_name == "$RECYCLE.BIN")
+        {
+            return Ok(FarmDirectoryState::UnrelatedFiles);
+        }
+    }
+
+    Ok(FarmDirectoryState::Empty)
+}
+
+/// Connect to external node over RPC and make sure it is a node of the expected chain
+pub async fn connect_to_node_rpc(url: &str) -> anyhow::Result<NodeRpcClient> {
+    let node_client = NodeRpcClient::new(url)
+        .await
+        .map_err(|error| anyhow!("Failed to connect to node RPC: {error}"))?;
+    let farmer_app_info = node_client
+        .farmer_app_info()
+        .await
+        .map_err(|error| anyhow!("Failed to get farmer app info from node RPC: {error}"))?;
+
+    if hex::encode(farmer_app_info.genesis_hash) != GENESIS_HASH {
+        return Err(anyhow!("Node is running a different chain"));
+    }
+
+    Ok(node_client)
 }
 
 /// Arguments for farmer
@@ -189,6 +381,17 @@ pub(super) struct FarmerOptions<FarmIndex, CacheIndex, OnFarmInitialized> {
     pub(super) farmer_cache_worker: FarmerCacheWorker<MaybeNodeClient, CacheIndex>,
     pub(super) kzg: Kzg,
     pub(super) reduce_plotting_cpu_load: bool,
+    /// Number of CPU threads to use for plotting, all CPU threads are used if `None`
+    pub(super) plotting_threads: Option<NonZeroUsize>,
+    /// Number of sectors encoded concurrently, derived from CPU topology if `None`
+    pub(super) sector_encoding_concurrency: Option<NonZeroUsize>,
+    /// Total number of CPU threads used for auditing and proving across all farms, each farm uses
+    /// thread pool sized for the CPU if `None`
+    pub(super) auditing_threads: Option<NonZeroUsize>,
+    /// Percentage of allocated space of each farm used for piece cache
+    pub(super) cache_percentage: NonZeroU8,
+    /// Dedicated piece cache, piece caches of farms are used if `None`
+    pub(super) piece_cache: Option<DiskCache>,
     pub(super) on_farm_initialized: OnFarmInitialized,
 }
 
@@ -217,6 +420,11 @@ where
         farmer_cache_worker,
         kzg,
         reduce_plotting_cpu_load,
+        plotting_threads,
+        sector_encoding_concurrency,
+        auditing_threads,
+        cache_percentage,
+        piece_cache,
         on_farm_initialized,
     } = farmer_options;
 
@@ -224,6 +432,11 @@ where
         return Err(anyhow!("There must be at least one disk farm provided"));
     }
 
+    let dedicated_piece_cache = match piece_cache {
+        Some(piece_cache) => Some(open_dedicated_piece_cache(piece_cache).await?),
+        None => None,
+    };
+
     for farm in &disk_farms {
         if !farm.directory.exists() {
             if let Err(error) = fs::create_dir(&farm.directory) {
@@ -236,6 +449,18 @@ where
         }
     }
 
+    // Farm creates its own thread pool for auditing and proving, so explicitly specified auditing
+    // threads are split between farms such that their total is exactly the configured number, there
+    // is at least as many threads as farms according to configuration validation
+    let farms_count = disk_farms.len();
+    let farming_thread_pool_size = move |farm_index: usize| {
+        auditing_threads.map_or_else(recommended_number_of_farming_threads, |auditing_threads| {
+            let auditing_threads = auditing_threads.get();
+            auditing_threads / farms_count
+                + usize::from(farm_index < auditing_threads % farms_count)
+        })
+    };
+
     let plot_cache = !cfg!(windows)
         || disk_farms
             .iter()
@@ -260,9 +485,15 @@ where
             .in_current_span(),
     );
 
-    let mut plotting_thread_pool_core_indices = thread_pool_core_indices(None, None);
+    // Each thread pool encodes one sector at a time. Explicitly specified number of threads results
+    // in a single thread pool unless concurrency is specified too, otherwise there is one thread
+    // pool per L3 cache group.
+    let sector_encoding_concurrency =
+        sector_encoding_concurrency.or(plotting_threads.map(|_| NonZeroUsize::MIN));
+    let mut plotting_thread_pool_core_indices =
+        thread_pool_core_indices(plotting_threads, sector_encoding_concurrency);
     let replotting_thread_pool_core_indices = {
-        let mut replotting_thread_pool_core_indices = thread_pool_core_indices(None, None);
+        let mut replotting_thread_pool_core_indices = plotting_thread_pool_core_indices.clone();
         // The default behavior is to use all CPU cores, but for replotting we just want half
         replotting_thread_pool_core_indices
             .iter_mut()
@@ -328,6 +559,57 @@ where
         None,
     ));
 
+    let farm_directories = disk_farms
+        .iter()
+        .map(|disk_farm| disk_farm.directory.clone())
+        .collect::<Vec<_>>();
+    // Allocated space can change at runtime when farm is resized
+    let mut current_disk_farms = disk_farms.clone();
+
+    // Farms are opened again with the same options when restarted after exiting with an error or
+    // resized
+    let reopen_farm = {
+        let node_client = node_client.clone();
+        let farmer_app_info = farmer_app_info.clone();
+        let kzg = kzg.clone();
+        let erasure_coding = erasure_coding.clone();
+        let legacy_cpu_plotter = Arc::clone(&legacy_cpu_plotter);
+        let modern_cpu_plotter = Arc::clone(&modern_cpu_plotter);
+        let global_mutex = Arc::clone(&global_mutex);
+
+        move |farm_index: usize, disk_farm: &DiskFarm| {
+            let options = SingleDiskFarmOptions {
+                directory: disk_farm.directory.clone(),
+                farmer_app_info: farmer_app_info.clone(),
+                allocated_space: disk_farm.allocated_space,
+                max_pieces_in_sector: farmer_app_info.protocol_info.max_pieces_in_sector,
+                node_client: node_client.clone(),
+                reward_address: disk_farm.reward_address.unwrap_or(reward_address),
+                plotter_legacy: Arc::clone(&legacy_cpu_plotter),
+                plotter: Arc::clone(&modern_cpu_plotter),
+                kzg: kzg.clone(),
+                erasure_coding: erasure_coding.clone(),
+                cache_percentage: cache_percentage.get(),
+                farming_thread_pool_size: farming_thread_pool_size(farm_index),
+                // Piece cache was synchronized already, no need to delay plotting
+                plotting_delay: None,
+                global_mutex: Arc::clone(&global_mutex),
+                disable_farm_locking: false,
+                read_sector_record_chunks_mode: None,
+                faster_read_sector_record_chunks_mode_barrier: Arc::new(Barrier::new(1)),
+                faster_read_sector_record_chunks_mode_concurrency: Arc::new(Semaphore::new(1)),
+                registry: None,
+                create: false,
+            };
+
+            async move {
+                SingleDiskFarm::new::<_, PosTableLegacy, PosTable>(options, farm_index)
+                    .await
+                    .map_err(anyhow::Error::from)
+            }
+        }
+    };
+
     let (farms, plotting_delay_senders) = {
         let farms_total = disk_farms.len();
         let info_mutex = &AsyncMutex::new(());
@@ -365,13 +647,13 @@ where
                             allocated_space: disk_farm.allocated_space,
                             max_pieces_in_sector,
                             node_client,
-                            reward_address,
+                            reward_address: disk_farm.reward_address.unwrap_or(reward_address),
                             plotter_legacy,
                             plotter,
                             kzg,
                             erasure_coding,
-                            cache_percentage: CACHE_PERCENTAGE.get(),
-                            farming_thread_pool_size: recommended_number_of_farming_threads(),
+                            cache_percentage: cache_percentage.get(),
+                            farming_thread_pool_size: farming_thread_pool_size(farm_index),
                             plotting_delay: Some(plotting_delay_receiver),
                             global_mutex,
                             disable_farm_locking: false,
@@ -482,20 +764,31 @@ where
                 }
             })));
     }
+    let dedicated_piece_cache_capacity = dedicated_piece_cache
+        .as_ref()
+        .map(|piece_cache| u64::from(piece_cache.max_num_elements()));
+    // Backing caches are kept around to replace caches of restarted farms later, dedicated piece
+    // cache is used instead of piece caches of farms when configured, caches of stopped farms are
+    // `None`
+    let mut piece_caches = match dedicated_piece_cache {
+        Some(piece_cache) => vec![Some(Arc::new(piece_cache) as Arc<dyn PieceCache>)],
+        None => farms
+            .iter()
+            .map(|farm| Some(Arc::new(farm.piece_cache()) as Arc<dyn PieceCache>))
+            .collect::<Vec<_>>(),
+    };
+    let mut plot_caches = if plot_cache {
+        farms
+            .iter()
+            .map(|farm| Some(Arc::new(farm.plot_cache()) as Arc<dyn PlotCache>))
+            .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
     farmer_cache
         .replace_backing_caches(
-            farms
-                .iter()
-                .map(|farm| Arc::new(farm.piece_cache()) as Arc<_>)
-                .collect(),
-            if plot_cache {
-                farms
-                    .iter()
-                    .map(|farm| Arc::new(farm.plot_cache()) as Arc<_>)
-                    .collect()
-            } else {
-                Vec::new()
-            },
+            piece_caches.iter().flatten().cloned().collect(),
+            plot_caches.iter().flatten().cloned().collect(),
         )
         .await;
 
@@ -505,37 +798,30 @@ where
     let mut initial_farm_states = Vec::with_capacity(farms.len());
 
     for (farm_index, farm) in farms.iter().enumerate() {
-        let mut plotted_pieces = plotted_pieces.write().await;
         let farm_index = farm_index
             .try_into()
             .map_err(|_error| anyhow!("More than 256 plots are not supported by Space Acres"))?;
 
-        plotted_pieces.add_farm(farm_index, Arc::new(farm.piece_reader()));
-
         let total_sectors_count = farm.total_sectors_count();
-        let mut plotted_sectors_count = 0;
-        let plotted_sectors = farm.plotted_sectors();
-        let mut plotted_sectors = plotted_sectors.get().await.map_err(|error| {
-            anyhow!("Failed to get plotted sectors for farm {farm_index}: {error}")
-        })?;
-
-        while let Some(plotted_sector_result) = plotted_sectors.next().await {
-            plotted_sectors_count += 1;
-            plotted_pieces.add_sector(
-                farm_index,
-                &plotted_sector_result.map_err(|error| {
-                    anyhow!(
-                        "Failed reading plotted sector on startup for farm {farm_index}: {error}"
-                    )
-                })?,
-            )
-        }
+        let plotted_sectors_count =
+            add_farm_plotted_pieces(farm_index, farm, &plotted_pieces).await?;
 
         initial_farm_states.push(InitialFarmState {
             total_sectors_count,
             plotted_sectors_count,
+            piece_cache_capacity: if dedicated_piece_cache_capacity.is_some() {
+                0
+            } else {
+                farm.piece_cache().max_num_elements()
+            },
         });
     }
+    let piece_cache_capacity = dedicated_piece_cache_capacity.unwrap_or_else(|| {
+        initial_farm_states
+            .iter()
+            .map(|initial_farm_state| u64::from(initial_farm_state.piece_cache_capacity))
+            .sum()
+    });
 
     info!("Finished collecting already plotted pieces successfully");
 
@@ -553,16 +839,17 @@ where
         }))
         .detach();
 
-    let mut farms_stream = farms
-        .into_iter()
-        .enumerate()
-        .map(|(farm_index, farm)| {
-            let Ok(farm_index) = FarmIndex::try_from(farm_index) else {
-                unreachable!(
-                    "More than 256 plots are not supported, this is checked above already; qed"
-                );
-            };
+    // Subscribes to farm events and returns sender for stopping the farm alongside the future that
+    // runs it
+    let start_farm = {
+        let notifications = Arc::clone(&notifications);
+        let plotted_pieces = Arc::clone(&plotted_pieces);
+
+        move |farm_index: FarmIndex,
+              farm: SingleDiskFarm|
+              -> (RunningFarm, FarmRunFuture<FarmIndex>) {
             let plotted_pieces = Arc::clone(&plotted_pieces);
+            let directory = farm_directories[usize::from(farm_index)].clone();
             let span = info_span!("farm", %farm_index);
 
             farm.on_sector_update(Arc::new({
@@ -616,13 +903,75 @@ where
             }))
             .detach();
 
-            farm.run().map(move |result| (farm_index, result))
+            let (stop_farm_sender, stop_farm_receiver) = oneshot::channel::<()>();
+            let (farm_exited_sender, farm_exited_receiver) = oneshot::channel::<()>();
+            let stop_farm_fut = async move {
+                // Sender being dropped is not a request to stop the farm
+                if stop_farm_receiver.await.is_err() {
+                    pending::<()>().await;
+                }
+            };
+            let farm_fut = farm.run();
+
+            let farm_fut = async move {
+                let result = select! {
+                    result = farm_fut.fuse() => result,
+                    _ = stop_farm_fut.fuse() => {
+                        info!(%farm_index, "Farm stopped");
+                        Ok(())
+                    }
+                    _ = farm_directory_inaccessible(directory.clone()).fuse() => {
+                        // Farm is dropped here, which stops all of its I/O
+                        Err(FarmDirectoryInaccessible { directory: directory.clone() }.into())
+                    }
+                };
+                // Farm was dropped already, let anyone waiting know that it has exited
+                drop(farm_exited_sender);
+
+                // Farm may fail on I/O before periodic check notices that directory is gone,
+                // report the actual reason in this case
+                let result = match result {
+                    Err(error) if !is_farm_directory_inaccessible_error(&error) => {
+                        if is_farm_directory_accessible(directory.clone()).await {
+                            Err(error)
+                        } else {
+                            debug!(%farm_index, %error, "Farm failed with inaccessible directory");
+                            Err(FarmDirectoryInaccessible { directory }.into())
+                        }
+                    }
+                    result => result,
+                };
+
+                (farm_index, result)
+            };
+
+            let running_farm = RunningFarm {
+                stop_sender: stop_farm_sender,
+                exited_receiver: farm_exited_receiver,
+            };
+
+            (running_farm, farm_fut.boxed())
+        }
+    };
+
+    let mut running_farms = Vec::with_capacity(farms.len());
+    let mut farms_stream = farms
+        .into_iter()
+        .enumerate()
+        .map(|(farm_index, farm)| {
+            let Ok(farm_index) = FarmIndex::try_from(farm_index) else {
+                unreachable!(
+                    "More than 256 plots are not supported, this is checked above already; qed"
+                );
+            };
+
+            let (running_farm, farm_fut) = start_farm(farm_index, farm);
+            running_farms.push(Some(running_farm));
+            farm_fut
         })
         .collect::<FuturesUnordered<_>>();
 
-    // Drop original instance such that the only remaining instances are in `SingleDiskFarm`
-    // event handlers
-    drop(plotted_pieces);
+    let (restarted_farms_sender, mut restarted_farms_receiver) = mpsc::unbounded();
 
     let (action_sender, mut action_receiver) = mpsc::channel(1);
     let (pause_plotting_sender, mut pause_plotting_receiver) = watch::channel(false);
@@ -649,26 +998,240 @@ where
         }
     };
 
-    let process_actions_fut = async move {
-        while let Some(action) = action_receiver.next().await {
-            match action {
-                FarmerAction::PausePlotting(pause_plotting) => {
-                    if let Err(error) = pause_plotting_sender.send(pause_plotting) {
+    let mut total_sectors_counts = initial_farm_states
+        .iter()
+        .map(|initial_farm_state| initial_farm_state.total_sectors_count)
+        .collect::<Vec<_>>();
+
+    let process_actions_fut = {
+        let notifications = Arc::clone(&notifications);
+
+        async move {
+            let mut plotting_paused = false;
+            let mut farming_paused = false;
+
+            while let Some(action) = action_receiver.next().await {
+                // Allocated space farm had before resize, restored if farm fails to open
+                let mut previous_allocated_space = None;
+                // Index of the farm that needs to be opened and started again
+                let farm_index = match action {
+                    FarmerAction::PausePlotting(pause_plotting) => {
+                        plotting_paused = pause_plotting;
+                        None
+                    }
+                    FarmerAction::PauseFarming(pause_farming) => {
+                        farming_paused = pause_farming;
+                        node_client.pause_farming(pause_farming);
+                        None
+                    }
+                    FarmerAction::StopFarm(farm_index) => {
+                        let Some(running_farm) =
+                            running_farms.get_mut(farm_index).and_then(Option::take)
+                        else {
+                            debug!(%farm_index, "Farm is unknown or already stopped");
+                            continue;
+                        };
+                        let Ok(typed_farm_index) = FarmIndex::try_from(farm_index) else {
+                            unreachable!(
+                                "More than 256 plots are not supported, this is checked above \
+                                already; qed"
+                            );
+                        };
+
+                        info!(%farm_index, "Stopping farm");
+
+                        // Doesn't matter if farm has already exited
+                        let _ = running_farm.stop_sender.send(());
+                        let _ = running_farm.exited_receiver.await;
+
+                        // Release remaining files of the farm, they are opened again on start
+                        plotted_pieces.write().await.delete_farm(typed_farm_index);
+                        if dedicated_piece_cache_capacity.is_none() {
+                            piece_caches[farm_index] = None;
+                        }
+                        if plot_cache {
+                            plot_caches[farm_index] = None;
+                        }
+                        farmer_cache
+                            .replace_backing_caches(
+                                piece_caches.iter().flatten().cloned().collect(),
+                                plot_caches.iter().flatten().cloned().collect(),
+                            )
+                            .await;
+
+                        continue;
+                    }
+                    FarmerAction::StartFarm(farm_index) => {
+                        let farm_stopped = running_farms
+                            .get(farm_index)
+                            .is_some_and(|running_farm| running_farm.is_none());
+                        if !farm_stopped {
+                            debug!(%farm_index, "Farm is unknown or not stopped");
+                            continue;
+                        }
+
+                        info!(%farm_index, "Starting farm");
+                        Some(farm_index)
+                    }
+                    FarmerAction::RestartFarm(farm_index) => {
+                        // Only farms that have exited on their own can be restarted, receiver is
+                        // dropped together with farm future
+                        let farm_exited = running_farms
+                            .get(farm_index)
+                            .and_then(Option::as_ref)
+                            .is_some_and(|running_farm| running_farm.stop_sender.is_canceled());
+                        if !farm_exited {
+                            debug!(%farm_index, "Farm is unknown, stopped or still running");
+                            continue;
+                        }
+
+                        info!(%farm_index, "Restarting farm");
+                        Some(farm_index)
+                    }
+                    FarmerAction::ResizeFarm {
+                        farm_index,
+                        allocated_space,
+                    } => {
+                        let Some(disk_farm) = current_disk_farms.get_mut(farm_index) else {
+                            debug!(%farm_index, "Farm is unknown, not resizing");
+                            continue;
+                        };
+                        if allocated_space <= disk_farm.allocated_space {
+                            debug!(
+                                %farm_index,
+                                allocated_space,
+                                current_allocated_space = disk_farm.allocated_space,
+                                "Shrinking farm is not supported"
+                            );
+                            continue;
+                        }
+                        // Stopped farms need to be started first
+                        let Some(running_farm) = running_farms[farm_index].take() else {
+                            debug!(%farm_index, "Farm is stopped, not resizing");
+                            continue;
+                        };
+
+                        info!(
+                            %farm_index,
+                            allocated_space = %bytesize::to_string(allocated_space, true),
+                            "Resizing farm"
+                        );
+
+                        // Farm must release its files before it can be opened again
+                        let _ = running_farm.stop_sender.send(());
+                        let _ = running_farm.exited_receiver.await;
+                        previous_allocated_space = Some(mem::replace(
+                            &mut disk_farm.allocated_space,
+                            allocated_space,
+                        ));
+
+                        Some(farm_index)
+                    }
+                };
+
+                let Some(farm_index) = farm_index else {
+                    if let Err(error) =
+                        pause_plotting_sender.send(plotting_paused || farming_paused)
+                    {
                         debug!(%error, "Failed to forward pause plotting");
                     }
+                    notifications.call_simple(&FarmerNotification::PauseChanged {
+                        plotting_paused,
+                        farming_paused,
+                    });
+                    continue;
+                };
+                let Ok(typed_farm_index) = FarmIndex::try_from(farm_index) else {
+                    unreachable!(
+                        "More than 256 plots are not supported, this is checked above already; qed"
+                    );
+                };
+
+                let result = async {
+                    let farm = reopen_farm(farm_index, &current_disk_farms[farm_index]).await?;
+
+                    plotted_pieces.write().await.delete_farm(typed_farm_index);
+                    add_farm_plotted_pieces(typed_farm_index, &farm, &plotted_pieces).await?;
+
+                    anyhow::Ok(farm)
+                }
+                .await;
+
+                let farm_fut = match result {
+                    Ok(farm) => {
+                        let total_sectors_count = farm.total_sectors_count();
+                        let previous_total_sectors_count = mem::replace(
+                            &mut total_sectors_counts[farm_index],
+                            total_sectors_count,
+                        );
+                        // Resize is confirmed even if farm can't fit more sectors yet
+                        if total_sectors_count != previous_total_sectors_count
+                            || previous_allocated_space.is_some()
+                        {
+                            notifications.call_simple(&FarmerNotification::FarmResized {
+                                farm_index: typed_farm_index,
+                                previous_total_sectors_count,
+                                total_sectors_count,
+                            });
+                        }
+
+                        if dedicated_piece_cache_capacity.is_none() {
+                            piece_caches[farm_index] = Some(Arc::new(farm.piece_cache()) as Arc<_>);
+                        }
+                        if plot_cache {
+                            plot_caches[farm_index] = Some(Arc::new(farm.plot_cache()) as Arc<_>);
+                        }
+                        farmer_cache
+                            .replace_backing_caches(
+                                piece_caches.iter().flatten().cloned().collect(),
+                                plot_caches.iter().flatten().cloned().collect(),
+                            )
+                            .await;
+
+                        let (running_farm, farm_fut) = start_farm(typed_farm_index, farm);
+                        running_farms[farm_index] = Some(running_farm);
+                        farm_fut
+                    }
+                    Err(error) => {
+                        if let Some(previous_allocated_space) = previous_allocated_space {
+                            current_disk_farms[farm_index].allocated_space =
+                                previous_allocated_space;
+                        }
+                        // Allows to try restarting it again later
+                        running_farms[farm_index] = Some(RunningFarm::exited());
+                        // Reported the same way as farm that exited with an error
+                        async move { (typed_farm_index, Err(error)) }.boxed()
+                    }
+                };
+
+                if let Err(error) =
+                    restarted_farms_sender.unbounded_send((typed_farm_index, farm_fut))
+                {
+                    debug!(%error, "Failed to forward restarted farm");
                 }
             }
+            anyhow::Ok(())
         }
-        anyhow::Ok(())
     };
 
-    let mut farm_errors = Vec::new();
+    let mut farm_errors = HashMap::new();
 
     let farms_fut = {
         let notifications = Arc::clone(&notifications);
 
         async move {
-            while let Some((farm_index, result)) = farms_stream.next().await {
+            loop {
+                let (farm_index, result) = select! {
+                    (farm_index, farm_fut) = restarted_farms_receiver.select_next_some() => {
+                        // Error of the previous run is no longer relevant
+                        farm_errors.remove(&farm_index);
+                        farms_stream.push(farm_fut);
+                        continue;
+                    }
+                    result = farms_stream.select_next_some() => result,
+                    complete => break,
+                };
+
                 match result {
                     Ok(()) => {
                         info!(%farm_index, "Farm exited successfully");
@@ -678,24 +1241,27 @@ where
 
                         let error = Arc::new(error);
 
-                        farm_errors.push(AsyncJoinOnDrop::new(
-                            tokio::spawn({
-                                let error = Arc::clone(&error);
-
-                                async move {
-                                    loop {
-                                        tokio::time::sleep(FARM_ERROR_PRINT_INTERVAL).await;
-
-                                        error!(
-                                            %farm_index,
-                                            %error,
-                                            "Farm errored and stopped"
-                                        );
+                        farm_errors.insert(
+                            farm_index,
+                            AsyncJoinOnDrop::new(
+                                tokio::spawn({
+                                    let error = Arc::clone(&error);
+
+                                    async move {
+                                        loop {
+                                            tokio::time::sleep(FARM_ERROR_PRINT_INTERVAL).await;
+
+                                            error!(
+                                                %farm_index,
+                                                %error,
+                                                "Farm errored and stopped"
+                                            );
+                                        }
                                     }
-                                }
-                            }),
-                            true,
-                        ));
+                                }),
+                                true,
+                            ),
+                        );
 
                         notifications
                             .call_simple(&FarmerNotification::FarmError { farm_index, error });
@@ -728,7 +1294,78 @@ where
         farmer_fut,
         farmer_cache_worker_fut,
         initial_farm_states,
+        piece_cache_capacity,
         notifications,
         action_sender,
     })
 }
+
+async fn open_dedicated_piece_cache(piece_cache: DiskCache) -> anyhow::Result<DiskPieceCache> {
+    let DiskCache {
+        directory,
+        allocated_space,
+    } = piece_cache;
+
+    tokio::task::spawn_blocking(move || {
+        if !directory.exists() {
+            fs::create_dir(&directory).map_err(|error| {
+                anyhow!(
+                    "Piece cache directory {} doesn't exist and can't be created: {}",
+                    directory.display(),
+                    error
+                )
+            })?;
+        }
+
+        let capacity = u32::try_from(allocated_space / u64::from(DiskPieceCache::element_size()))
+            .unwrap_or(u32::MAX);
+        info!(
+            directory = %directory.display(),
+            %capacity,
+            "Opening dedicated piece cache"
+        );
+
+        DiskPieceCache::open(&directory, capacity, None, None).map_err(|error| {
+            anyhow!(
+                "Failed to open piece cache in {}: {}",
+                directory.display(),
+                error
+            )
+        })
+    })
+    .await?
+}
+
+/// Add farm with its already plotted sectors to plotted pieces, returns number of plotted sectors
+async fn add_farm_plotted_pieces<FarmIndex>(
+    farm_index: FarmIndex,
+    farm: &SingleDiskFarm,
+    plotted_pieces: &AsyncRwLock<PlottedPieces<FarmIndex>>,
+) -> anyhow::Result<SectorIndex>
+where
+    FarmIndex: Hash + Eq + Copy + fmt::Display + fmt::Debug + Send + Sync + 'static,
+    usize: From<FarmIndex>,
+{
+    let mut plotted_pieces = plotted_pieces.write().await;
+
+    plotted_pieces.add_farm(farm_index, Arc::new(farm.piece_reader()));
+
+    let mut plotted_sectors_count = 0;
+    let plotted_sectors = farm.plotted_sectors();
+    let mut plotted_sectors = plotted_sectors
+        .get()
+        .await
+        .map_err(|error| anyhow!("Failed to get plotted sectors for farm {farm_index}: {error}"))?;
+
+    while let Some(plotted_sector_result) = plotted_sectors.next().await {
+        plotted_sectors_count += 1;
+        plotted_pieces.add_sector(
+            farm_index,
+            &plotted_sector_result.map_err(|error| {
+                anyhow!("Failed reading plotted sector for farm {farm_index}: {error}")
+            })?,
+        )
+    }
+
+    Ok(plotted_sectors_count)
+}
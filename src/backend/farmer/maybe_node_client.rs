@@ -1,6 +1,8 @@
 use arc_swap::ArcSwapOption;
-use futures::Stream;
+use futures::{Stream, StreamExt};
+use std::future;
 use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use subspace_core_primitives::SegmentHeader;
 use subspace_farmer::node_client::{Error, NodeClient, NodeClientExt};
@@ -9,9 +11,13 @@ use subspace_rpc_primitives::{
 };
 
 /// Wrapper node client that allows injecting real inner `NodeClientExt` implementation.
+///
+/// It also allows pausing farming, while paused slot info notifications are dropped, such that
+/// farms do not audit or prove anything.
 #[derive(Debug, Clone, Default)]
 pub(in super::super) struct MaybeNodeClient {
     inner: Arc<ArcSwapOption<Box<dyn NodeClientExt>>>,
+    farming_paused: Arc<AtomicBool>,
 }
 
 #[async_trait::async_trait]
@@ -27,7 +33,13 @@ impl NodeClient for MaybeNodeClient {
         &self,
     ) -> Result<Pin<Box<dyn Stream<Item = SlotInfo> + Send + 'static>>, Error> {
         match &*self.inner.load() {
-            Some(inner) => inner.subscribe_slot_info().await,
+            Some(inner) => {
+                let farming_paused = Arc::clone(&self.farming_paused);
+
+                Ok(Box::pin(inner.subscribe_slot_info().await?.filter(
+                    move |_slot_info| future::ready(!farming_paused.load(Ordering::Relaxed)),
+                )))
+            }
             None => Err("Inner node client not injected yet".into()),
         }
     }
@@ -122,4 +134,8 @@ impl MaybeNodeClient {
     pub(in super::super) fn inject(&self, inner: Box<dyn NodeClientExt>) {
         self.inner.store(Some(Arc::new(inner)))
     }
+
+    pub(in super::super) fn pause_farming(&self, pause_farming: bool) {
+        self.farming_paused.store(pause_farming, Ordering::Relaxed);
+    }
 }
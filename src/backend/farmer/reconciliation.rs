@@ -0,0 +1,223 @@
+use crate::backend::config::profiles;
+use crate::backend::farmer::DiskFarm;
+use crate::backend::node::GENESIS_HASH;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use subspace_farmer::single_disk_farm::{SingleDiskFarm, SingleDiskFarmInfo};
+use tokio::task;
+use tracing::warn;
+
+/// File in directory of the active profile where farms that were started successfully are
+/// remembered
+const KNOWN_FARMS_FILE_NAME: &str = "known-farms.json";
+
+/// Farms that were started successfully before, used to detect farms that disappeared from disk or
+/// were replaced with different farms, for example after moving disks around
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct KnownFarms {
+    /// Farm ID by farm directory
+    #[serde(default)]
+    farms: HashMap<PathBuf, String>,
+}
+
+impl KnownFarms {
+    /// Each profile has its own farms, so they are remembered separately for each profile
+    async fn path() -> Option<PathBuf> {
+        let profile_directory = profiles::active_profile_directory().await?;
+
+        Some(profile_directory.join(KNOWN_FARMS_FILE_NAME))
+    }
+
+    /// Read known farms, empty list is returned if file doesn't exist or can't be read
+    async fn read() -> Self {
+        let Some(path) = Self::path().await else {
+            return Self::default();
+        };
+
+        match tokio::fs::read_to_string(&path).await {
+            Ok(known_farms) => serde_json::from_str(&known_farms).unwrap_or_default(),
+            Err(_error) => Self::default(),
+        }
+    }
+
+    async fn write(&self) -> io::Result<()> {
+        let Some(path) = Self::path().await else {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "Failed to determine config directory",
+            ));
+        };
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        tokio::fs::write(
+            path,
+            serde_json::to_string_pretty(self)
+                .expect("Known farms serialization is infallible; qed"),
+        )
+        .await
+    }
+}
+
+/// Kind of mismatch between configured farm and farm found on disk
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum FarmMismatchKind {
+    /// Farm was started from this directory before, but there is no farm there anymore
+    MissingPlot,
+    /// Directory contains a different farm than the one started from it before
+    UnexpectedPlot {
+        /// Farm belongs to a different chain and can't be used as is
+        different_chain: bool,
+    },
+    /// Configured size is smaller than size of the farm on disk, shrinking farm is not supported
+    SizeMismatch {
+        configured_size: u64,
+        farm_size: u64,
+    },
+}
+
+/// Mismatch between configured farm and farm found on disk
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FarmMismatch {
+    pub directory: PathBuf,
+    pub kind: FarmMismatchKind,
+}
+
+impl FarmMismatch {
+    /// Whether configuration can be fixed to match farm on disk without re-plotting
+    pub fn fixable(&self) -> bool {
+        !matches!(
+            self.kind,
+            FarmMismatchKind::UnexpectedPlot {
+                different_chain: true
+            }
+        )
+    }
+}
+
+/// What to do with farm that doesn't match configuration
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FarmMismatchResolution {
+    /// Delete whatever is on disk and plot farm from scratch
+    Replot,
+    /// Disable farm in configuration, it can be enabled again later
+    Skip,
+    /// Change configuration to match the farm on disk
+    Fix,
+}
+
+/// Check configured farms against farms on disk
+pub async fn find_farm_mismatches(farms: &[DiskFarm]) -> Vec<FarmMismatch> {
+    let known_farms = KnownFarms::read().await;
+    let farms = farms.to_vec();
+
+    task::spawn_blocking(move || {
+        farms
+            .into_iter()
+            .filter_map(|farm| {
+                let known_farm_id = known_farms.farms.get(&farm.directory);
+                let farm_info = match SingleDiskFarmInfo::load_from(&farm.directory) {
+                    Ok(farm_info) => farm_info,
+                    Err(error) => {
+                        // Farm will fail to start and error will be shown at that point
+                        warn!(
+                            %error,
+                            directory = %farm.directory.display(),
+                            "Failed to read farm info during reconciliation"
+                        );
+                        return None;
+                    }
+                };
+
+                let kind = match farm_info {
+                    None => {
+                        known_farm_id?;
+                        FarmMismatchKind::MissingPlot
+                    }
+                    Some(farm_info) => {
+                        if hex::encode(farm_info.genesis_hash()) != GENESIS_HASH {
+                            FarmMismatchKind::UnexpectedPlot {
+                                different_chain: true,
+                            }
+                        } else if known_farm_id
+                            .is_some_and(|farm_id| *farm_id != farm_info.id().to_string())
+                        {
+                            FarmMismatchKind::UnexpectedPlot {
+                                different_chain: false,
+                            }
+                        } else if farm.allocated_space < farm_info.allocated_space() {
+                            FarmMismatchKind::SizeMismatch {
+                                configured_size: farm.allocated_space,
+                                farm_size: farm_info.allocated_space(),
+                            }
+                        } else {
+                            return None;
+                        }
+                    }
+                };
+
+                Some(FarmMismatch {
+                    directory: farm.directory,
+                    kind,
+                })
+            })
+            .collect()
+    })
+    .await
+    .unwrap_or_default()
+}
+
+/// Remember farms that were started successfully, farms that are no longer configured are
+/// forgotten
+pub async fn remember_farms(farms: &[DiskFarm], configured_directories: &[PathBuf]) {
+    let mut known_farms = KnownFarms::read().await;
+    let directories = farms
+        .iter()
+        .map(|farm| farm.directory.clone())
+        .collect::<Vec<_>>();
+
+    let farm_ids = task::spawn_blocking(move || {
+        directories
+            .into_iter()
+            .filter_map(|directory| {
+                let farm_info = SingleDiskFarmInfo::load_from(&directory).ok()??;
+                Some((directory, farm_info.id().to_string()))
+            })
+            .collect::<Vec<_>>()
+    })
+    .await
+    .unwrap_or_default();
+
+    known_farms
+        .farms
+        .retain(|directory, _farm_id| configured_directories.contains(directory));
+    known_farms.farms.extend(farm_ids);
+
+    if let Err(error) = known_farms.write().await {
+        warn!(%error, "Failed to write known farms");
+    }
+}
+
+/// Forget farm in directory, such that whatever farm is there next time is accepted
+pub async fn forget_farm(directory: &Path) -> io::Result<()> {
+    let mut known_farms = KnownFarms::read().await;
+    if known_farms.farms.remove(directory).is_some() {
+        known_farms.write().await?;
+    }
+
+    Ok(())
+}
+
+/// Delete farm in directory, such that it is plotted from scratch on next start
+pub async fn wipe_farm(directory: PathBuf) -> io::Result<()> {
+    forget_farm(&directory).await?;
+
+    task::spawn_blocking(move || SingleDiskFarm::wipe(&directory))
+        .await
+        .map_err(io::Error::other)?
+}
@@ -0,0 +1,355 @@
+use crate::backend::farmer::{FarmerNotification, InitialFarmState};
+use crate::backend::node::SyncState;
+use crate::backend::{FarmIndex, NodeNotification};
+use parking_lot::Mutex;
+use prometheus_client::encoding::text::encode;
+use prometheus_client::encoding::EncodeLabelSet;
+use prometheus_client::metrics::counter::Counter;
+use prometheus_client::metrics::family::Family;
+use prometheus_client::metrics::gauge::Gauge;
+use prometheus_client::metrics::histogram::{exponential_buckets, Histogram};
+use prometheus_client::registry::Registry;
+use std::io;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use subspace_farmer::farm::{
+    FarmingNotification, ProvingResult, SectorPlottingDetails, SectorUpdate,
+};
+use subspace_runtime_primitives::{Balance, SSC};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::{debug, info};
+
+/// Max size of HTTP request headers, larger requests are rejected, the request itself is ignored
+const MAX_REQUEST_SIZE: usize = 8 * 1024;
+/// Time client has to send request and receive response before connection is dropped
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq, EncodeLabelSet)]
+struct FarmLabels {
+    farm_index: String,
+}
+
+impl FarmLabels {
+    fn new(farm_index: FarmIndex) -> Self {
+        Self {
+            farm_index: farm_index.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq, EncodeLabelSet)]
+struct ProvingLabels {
+    farm_index: String,
+    result: &'static str,
+}
+
+type HistogramFamily<Labels> = Family<Labels, Histogram, fn() -> Histogram>;
+
+#[derive(Debug)]
+pub(super) struct Metrics {
+    registry: Registry,
+    best_block_number: Gauge,
+    sync_target: Gauge,
+    peers: Gauge,
+    reward_address_balance: Gauge<f64, AtomicU64>,
+    rewards: Counter,
+    rewards_amount: Counter<f64, AtomicU64>,
+    /// Last known balance of reward address, increases are counted as rewards
+    last_reward_address_balance: Mutex<Option<Balance>>,
+    /// Only balance increases while synced are counted as rewards, same as in UI
+    node_synced: AtomicBool,
+    farm_sectors_total: Family<FarmLabels, Gauge>,
+    farm_sectors_plotted: Family<FarmLabels, Gauge>,
+    farm_auditing_time: HistogramFamily<FarmLabels>,
+    farm_proving_time: HistogramFamily<FarmLabels>,
+    farm_proving: Family<ProvingLabels, Counter>,
+}
+
+impl Metrics {
+    pub(super) fn new(initial_farm_states: &[InitialFarmState]) -> Self {
+        let mut registry = Registry::with_prefix("space_acres");
+
+        let best_block_number = Gauge::default();
+        registry.register(
+            "best_block_number",
+            "Best block number of the node",
+            best_block_number.clone(),
+        );
+        let sync_target = Gauge::default();
+        registry.register(
+            "sync_target",
+            "Block number node is syncing to, 0 when not syncing",
+            sync_target.clone(),
+        );
+        let peers = Gauge::default();
+        registry.register("peers", "Number of connected node peers", peers.clone());
+        let reward_address_balance = Gauge::default();
+        registry.register(
+            "reward_address_balance",
+            "Total balance of all reward addresses in whole tokens",
+            reward_address_balance.clone(),
+        );
+        let rewards = Counter::default();
+        registry.register(
+            "rewards",
+            "Number of rewards (blocks and votes) received since start",
+            rewards.clone(),
+        );
+        let rewards_amount = Counter::default();
+        registry.register(
+            "rewards_amount",
+            "Amount of rewards received since start in whole tokens",
+            rewards_amount.clone(),
+        );
+        let farm_sectors_total = Family::<FarmLabels, Gauge>::default();
+        registry.register(
+            "farm_sectors_total",
+            "Number of sectors farm can hold",
+            farm_sectors_total.clone(),
+        );
+        let farm_sectors_plotted = Family::default();
+        registry.register(
+            "farm_sectors_plotted",
+            "Number of sectors plotted in farm",
+            farm_sectors_plotted.clone(),
+        );
+        let farm_auditing_time: HistogramFamily<FarmLabels> =
+            Family::new_with_constructor(|| Histogram::new(exponential_buckets(0.01, 2.0, 10)));
+        registry.register(
+            "farm_auditing_time_seconds",
+            "Time it took farm to audit a slot",
+            farm_auditing_time.clone(),
+        );
+        let farm_proving_time: HistogramFamily<FarmLabels> =
+            Family::new_with_constructor(|| Histogram::new(exponential_buckets(0.01, 2.0, 10)));
+        registry.register(
+            "farm_proving_time_seconds",
+            "Time it took farm to create a proof",
+            farm_proving_time.clone(),
+        );
+        let farm_proving = Family::default();
+        registry.register(
+            "farm_proving",
+            "Number of proving attempts by result",
+            farm_proving.clone(),
+        );
+
+        for (farm_index, initial_farm_state) in initial_farm_states.iter().enumerate() {
+            let labels = FarmLabels::new(farm_index as FarmIndex);
+            farm_sectors_total
+                .get_or_create(&labels)
+                .set(i64::from(initial_farm_state.total_sectors_count));
+            farm_sectors_plotted
+                .get_or_create(&labels)
+                .set(i64::from(initial_farm_state.plotted_sectors_count));
+        }
+
+        Self {
+            registry,
+            best_block_number,
+            sync_target,
+            peers,
+            reward_address_balance,
+            rewards,
+            rewards_amount,
+            last_reward_address_balance: Mutex::default(),
+            node_synced: AtomicBool::new(false),
+            farm_sectors_total,
+            farm_sectors_plotted,
+            farm_auditing_time,
+            farm_proving_time,
+            farm_proving,
+        }
+    }
+
+    pub(super) fn on_node_notification(&self, notification: &NodeNotification) {
+        match notification {
+            NodeNotification::SyncStateUpdate(sync_state) => {
+                let target = match sync_state {
+                    SyncState::Syncing { target, .. } => *target,
+                    SyncState::Unknown | SyncState::Idle => 0,
+                };
+                self.sync_target.set(i64::from(target));
+                self.node_synced
+                    .store(matches!(sync_state, SyncState::Idle), Ordering::Relaxed);
+            }
+            NodeNotification::BlockImported(block_imported) => {
+                self.best_block_number.set(i64::from(block_imported.number));
+                self.reward_address_balance
+                    .set(block_imported.reward_address_balance as f64 / SSC as f64);
+
+                let previous_balance = self
+                    .last_reward_address_balance
+                    .lock()
+                    .replace(block_imported.reward_address_balance);
+                if self.node_synced.load(Ordering::Relaxed)
+                    && let Some(previous_balance) = previous_balance
+                    && let Some(amount) = block_imported
+                        .reward_address_balance
+                        .checked_sub(previous_balance)
+                    && amount > 0
+                {
+                    self.rewards.inc();
+                    self.rewards_amount.inc_by(amount as f64 / SSC as f64);
+                }
+            }
+            NodeNotification::PeersUpdate(peers) => {
+                self.peers.set(peers.len() as i64);
+            }
+            NodeNotification::ClockOffset(_clock_offset) => {
+                // Not exported
+            }
+        }
+    }
+
+    pub(super) fn on_farmer_notification(&self, notification: &FarmerNotification<FarmIndex>) {
+        match notification {
+            FarmerNotification::SectorUpdate {
+                farm_index, update, ..
+            } => {
+                // Same condition as in UI, replotted sectors don't change number of plotted sectors
+                if matches!(
+                    update,
+                    SectorUpdate::Plotting(SectorPlottingDetails::Finished {
+                        old_plotted_sector: None,
+                        ..
+                    })
+                ) {
+                    self.farm_sectors_plotted
+                        .get_or_create(&FarmLabels::new(*farm_index))
+                        .inc();
+                }
+            }
+            FarmerNotification::FarmingNotification {
+                farm_index,
+                notification,
+            } => match notification {
+                FarmingNotification::Auditing(auditing_details) => {
+                    self.farm_auditing_time
+                        .get_or_create(&FarmLabels::new(*farm_index))
+                        .observe(auditing_details.time.as_secs_f64());
+                }
+                FarmingNotification::Proving(proving_details) => {
+                    self.farm_proving_time
+                        .get_or_create(&FarmLabels::new(*farm_index))
+                        .observe(proving_details.time.as_secs_f64());
+                    self.farm_proving
+                        .get_or_create(&ProvingLabels {
+                            farm_index: farm_index.to_string(),
+                            result: match proving_details.result {
+                                ProvingResult::Success => "success",
+                                ProvingResult::Timeout => "timeout",
+                                ProvingResult::Rejected => "rejected",
+                                ProvingResult::Failed => "failed",
+                            },
+                        })
+                        .inc();
+                }
+                FarmingNotification::NonFatalError(_error) => {
+                    // Not tracked
+                }
+            },
+            FarmerNotification::FarmResized {
+                farm_index,
+                total_sectors_count,
+                ..
+            } => {
+                self.farm_sectors_total
+                    .get_or_create(&FarmLabels::new(*farm_index))
+                    .set(i64::from(*total_sectors_count));
+            }
+            FarmerNotification::FarmerCacheSyncProgress { .. }
+            | FarmerNotification::FarmError { .. }
+            | FarmerNotification::PauseChanged { .. } => {
+                // Not tracked
+            }
+        }
+    }
+
+    fn encode(&self) -> String {
+        let mut buffer = String::new();
+        encode(&mut buffer, &self.registry).expect("Writing into string never fails; qed");
+        buffer
+    }
+}
+
+/// Serve metrics over HTTP on specified address, every request is answered with metrics
+/// regardless of its path
+pub(super) async fn serve_metrics(address: SocketAddr, metrics: Arc<Metrics>) -> io::Result<()> {
+    let listener = TcpListener::bind(address).await?;
+    info!(%address, "Serving Prometheus metrics");
+
+    loop {
+        let (stream, peer_address) = listener.accept().await?;
+        let metrics = Arc::clone(&metrics);
+
+        tokio::spawn(async move {
+            match tokio::time::timeout(REQUEST_TIMEOUT, respond_with_metrics(stream, &metrics))
+                .await
+            {
+                Ok(Ok(())) => {
+                    // Responded successfully
+                }
+                Ok(Err(error)) => {
+                    debug!(%error, %peer_address, "Failed to respond to metrics request");
+                }
+                Err(_elapsed) => {
+                    debug!(%peer_address, "Metrics request timed out");
+                }
+            }
+        });
+    }
+}
+
+async fn respond_with_metrics(mut stream: TcpStream, metrics: &Metrics) -> io::Result<()> {
+    // Read request headers, their content doesn't matter
+    let mut request = vec![0; MAX_REQUEST_SIZE];
+    let mut read = 0;
+    while !request[..read]
+        .windows(4)
+        .any(|window| window == b"\r\n\r\n")
+    {
+        if read == request.len() {
+            stream
+                .write_all(
+                    b"HTTP/1.1 431 Request Header Fields Too Large\r\n\
+                    Content-Length: 0\r\n\
+                    Connection: close\r\n\
+                    \r\n",
+                )
+                .await?;
+            stream.shutdown().await?;
+
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "Request is too large",
+            ));
+        }
+
+        let bytes = stream.read(&mut request[read..]).await?;
+        if bytes == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "Connection closed before request was received",
+            ));
+        }
+        read += bytes;
+    }
+
+    let body = metrics.encode();
+    let response = format!(
+        "HTTP/1.1 200 OK\r\n\
+        Content-Type: application/openmetrics-text; version=1.0.0; charset=utf-8\r\n\
+        Content-Length: {}\r\n\
+        Connection: close\r\n\
+        \r\n\
+        {body}",
+        body.len()
+    );
+
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
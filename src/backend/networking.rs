@@ -218,6 +218,8 @@ where
         bootstrap_addresses: bootstrap_nodes,
         kademlia_mode: KademliaMode::Dynamic,
         external_addresses,
+        // Listening on a different port than configured is confusing when port is forwarded
+        listen_on_fallback_to_random_port: false,
         metrics: None,
         ..default_config
     };
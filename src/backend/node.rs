@@ -1,7 +1,9 @@
+pub(super) mod external;
 mod utils;
 
 use crate::backend::farmer::direct_node_client::{DirectNodeClient, NodeClientConfig};
 use crate::backend::farmer::maybe_node_client::MaybeNodeClient;
+use crate::backend::node::external::ExternalConsensusNode;
 use crate::backend::node::utils::account_storage_key;
 use crate::backend::utils::{Handler, HandlerFn};
 use crate::PosTable;
@@ -17,6 +19,8 @@ use sc_client_db::PruningMode;
 use sc_consensus_slots::SlotProportion;
 use sc_informant::OutputFormat;
 use sc_network::config::{Ed25519Secret, NodeKeyConfig, NonReservedPeerMode, SetConfig, SyncMode};
+use sc_network::network_state::PeerEndpoint;
+use sc_network::NetworkStatusProvider;
 use sc_service::{BlocksPruning, Configuration, GenericChainSpec, NoExtension};
 use sc_storage_monitor::{StorageMonitorParams, StorageMonitorService};
 use serde_json::Value;
@@ -26,6 +30,7 @@ use sp_core::crypto::Ss58AddressFormat;
 use sp_core::storage::StorageKey;
 use sp_core::H256;
 use sp_runtime::traits::Header;
+use std::collections::HashMap;
 use std::fmt;
 use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4};
 use std::path::PathBuf;
@@ -48,6 +53,7 @@ use tokio::fs;
 use tokio::time::MissedTickBehavior;
 use tracing::error;
 
+/// Genesis hash of the supported chain, hex-encoded
 pub(super) const GENESIS_HASH: &str =
     "0c121c75f4ef450f40619e1fca9d1e8e7fbabc42c895bc4790801e85d5a91c34";
 pub(super) const RPC_PORT: u16 = 19944;
@@ -86,6 +92,8 @@ impl fmt::Debug for ChainSpec {
 #[derive(Debug, Default, Clone)]
 pub struct ChainInfo {
     pub chain_name: String,
+    /// Hex-encoded genesis hash of the chain node is running
+    pub genesis_hash: String,
     pub protocol_id: String,
     pub token_symbol: String,
 }
@@ -125,15 +133,44 @@ impl SyncState {
 #[derive(Debug, Copy, Clone)]
 pub struct BlockImportedNotification {
     pub number: BlockNumber,
+    /// Total balance of all reward addresses
     pub reward_address_balance: Balance,
     pub solution_range: SolutionRange,
     pub voting_solution_range: SolutionRange,
 }
 
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PeerRole {
+    Authority,
+    Full,
+    Light,
+}
+
+impl PeerRole {
+    /// Selector for translations, must not be displayed as is
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PeerRole::Authority => "authority",
+            PeerRole::Full => "full",
+            PeerRole::Light => "light",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PeerInfo {
+    pub peer_id: String,
+    /// Address of the connection to the peer, if known
+    pub address: Option<String>,
+    pub role: PeerRole,
+    pub best_block_number: BlockNumber,
+}
+
 #[derive(Default, Debug)]
 struct Handlers {
     sync_state_change: Handler<SyncState>,
     block_imported: Handler<BlockImportedNotification>,
+    peers_update: Handler<Vec<PeerInfo>>,
 }
 
 pub(super) struct ConsensusNode {
@@ -166,7 +203,10 @@ impl ConsensusNode {
         }
     }
 
-    pub(super) async fn run(mut self, reward_address: &PublicKey) -> Result<(), sc_service::Error> {
+    pub(super) async fn run(
+        mut self,
+        reward_addresses: &[PublicKey],
+    ) -> Result<(), sc_service::Error> {
         self.full_node.network_starter.start_network();
 
         let spawn_essential_handle = self.full_node.task_manager.spawn_essential_handle();
@@ -175,7 +215,10 @@ impl ConsensusNode {
             Some("space-acres-node"),
             {
                 let client = self.full_node.client.clone();
-                let reward_address_storage_key = account_storage_key(reward_address);
+                let reward_address_storage_keys = reward_addresses
+                    .iter()
+                    .map(account_storage_key)
+                    .collect::<Vec<_>>();
 
                 async move {
                     let mut block_import_stream = client.every_import_notification_stream();
@@ -191,12 +234,17 @@ impl ConsensusNode {
                                 number: *block_import.header.number(),
                                 // TODO: This is not pretty that we do it here, but not clear what
                                 //  would be a nicer API
-                                reward_address_balance: get_total_account_balance(
-                                    &client,
-                                    block_import.header.hash(),
-                                    &reward_address_storage_key,
-                                )
-                                .unwrap_or_default(),
+                                reward_address_balance: reward_address_storage_keys
+                                    .iter()
+                                    .map(|reward_address_storage_key| {
+                                        get_total_account_balance(
+                                            &client,
+                                            block_import.header.hash(),
+                                            reward_address_storage_key,
+                                        )
+                                        .unwrap_or_default()
+                                    })
+                                    .sum(),
                                 solution_range: solution_ranges.current,
                                 voting_solution_range: solution_ranges.voting_current,
                             };
@@ -216,6 +264,7 @@ impl ConsensusNode {
             self.handlers
                 .sync_state_change
                 .call_simple(&last_sync_state);
+            let mut last_peers = Vec::new();
 
             loop {
                 sync_status_interval.tick().await;
@@ -243,6 +292,13 @@ impl ConsensusNode {
                         last_sync_state = sync_state;
                     }
                 }
+
+                let peers = self.connected_peers().await;
+                if peers != last_peers {
+                    self.handlers.peers_update.call_simple(&peers);
+
+                    last_peers = peers;
+                }
             }
         };
 
@@ -260,6 +316,56 @@ impl ConsensusNode {
         Ok(())
     }
 
+    async fn connected_peers(&self) -> Vec<PeerInfo> {
+        let Ok(peers_info) = self.full_node.sync_service.peers_info().await else {
+            return Vec::new();
+        };
+        let mut peer_addresses = self
+            .full_node
+            .network_service
+            .network_state()
+            .await
+            .map(|network_state| {
+                network_state
+                    .connected_peers
+                    .into_iter()
+                    .map(|(peer_id, peer)| {
+                        let address = match peer.endpoint {
+                            PeerEndpoint::Dialing(address, _) => address,
+                            PeerEndpoint::Listening { send_back_addr, .. } => send_back_addr,
+                        };
+
+                        (peer_id, address.to_string())
+                    })
+                    .collect::<HashMap<_, _>>()
+            })
+            .unwrap_or_default();
+
+        let mut peers = peers_info
+            .into_iter()
+            .map(|(peer_id, peer_info)| {
+                let peer_id = peer_id.to_string();
+
+                PeerInfo {
+                    address: peer_addresses.remove(&peer_id),
+                    role: if peer_info.roles.is_authority() {
+                        PeerRole::Authority
+                    } else if peer_info.roles.is_light() {
+                        PeerRole::Light
+                    } else {
+                        PeerRole::Full
+                    },
+                    best_block_number: peer_info.best_number,
+                    peer_id,
+                }
+            })
+            .collect::<Vec<_>>();
+        // Stable order for display and comparison
+        peers.sort_by(|a, b| a.peer_id.cmp(&b.peer_id));
+
+        peers
+    }
+
     pub(super) fn best_block_number(&self) -> BlockNumber {
         self.full_node.client.info().best_number
     }
@@ -293,6 +399,92 @@ impl ConsensusNode {
     ) -> HandlerId {
         self.handlers.block_imported.add(callback)
     }
+
+    pub(super) fn on_peers_update(&self, callback: HandlerFn<Vec<PeerInfo>>) -> HandlerId {
+        self.handlers.peers_update.add(callback)
+    }
+}
+
+/// Consensus node that node status, balance and chain constants are obtained from
+pub(super) enum AnyConsensusNode {
+    /// Node embedded into the application
+    Embedded(ConsensusNode),
+    /// Node running elsewhere and accessed via RPC, embedded node is not created in this case
+    External(ExternalConsensusNode),
+}
+
+impl AnyConsensusNode {
+    pub(super) async fn run(self, reward_addresses: &[PublicKey]) -> anyhow::Result<()> {
+        match self {
+            Self::Embedded(consensus_node) => consensus_node
+                .run(reward_addresses)
+                .await
+                .map_err(|error| anyhow::anyhow!("{error}")),
+            Self::External(consensus_node) => consensus_node.run(reward_addresses).await,
+        }
+    }
+
+    pub(super) async fn best_block_number(&self) -> BlockNumber {
+        match self {
+            Self::Embedded(consensus_node) => consensus_node.best_block_number(),
+            Self::External(consensus_node) => consensus_node.best_block_number().await,
+        }
+    }
+
+    pub(super) async fn account_balance(&self, account: &PublicKey) -> Balance {
+        match self {
+            Self::Embedded(consensus_node) => consensus_node.account_balance(account),
+            Self::External(consensus_node) => consensus_node.account_balance(account).await,
+        }
+    }
+
+    /// Total balance of all specified accounts
+    pub(super) async fn accounts_balance(&self, accounts: &[PublicKey]) -> Balance {
+        let mut balance = 0;
+        for account in accounts {
+            balance += self.account_balance(account).await;
+        }
+
+        balance
+    }
+
+    pub(super) fn chain_info(&self) -> &ChainInfo {
+        match self {
+            Self::Embedded(consensus_node) => consensus_node.chain_info(),
+            Self::External(consensus_node) => consensus_node.chain_info(),
+        }
+    }
+
+    pub(super) fn chain_constants(&self) -> &ChainConstants {
+        match self {
+            Self::Embedded(consensus_node) => consensus_node.chain_constants(),
+            Self::External(consensus_node) => consensus_node.chain_constants(),
+        }
+    }
+
+    pub(super) fn on_sync_state_change(&self, callback: HandlerFn<SyncState>) -> HandlerId {
+        match self {
+            Self::Embedded(consensus_node) => consensus_node.on_sync_state_change(callback),
+            Self::External(consensus_node) => consensus_node.on_sync_state_change(callback),
+        }
+    }
+
+    pub(super) fn on_block_imported(
+        &self,
+        callback: HandlerFn<BlockImportedNotification>,
+    ) -> HandlerId {
+        match self {
+            Self::Embedded(consensus_node) => consensus_node.on_block_imported(callback),
+            Self::External(consensus_node) => consensus_node.on_block_imported(callback),
+        }
+    }
+
+    pub(super) fn on_peers_update(&self, callback: HandlerFn<Vec<PeerInfo>>) -> HandlerId {
+        match self {
+            Self::Embedded(consensus_node) => consensus_node.on_peers_update(callback),
+            Self::External(consensus_node) => consensus_node.on_peers_update(callback),
+        }
+    }
 }
 
 fn get_total_account_balance(
@@ -308,15 +500,18 @@ fn get_total_account_balance(
         }
     };
 
-    let account_info = match AccountInfo::<Nonce, AccountData<Balance>>::decode(
-        &mut encoded_account_info.0.as_slice(),
-    ) {
-        Ok(account_info) => account_info,
-        Err(error) => {
-            error!(%error, "Failed to decode account info");
-            return None;
-        }
-    };
+    decode_total_account_balance(&encoded_account_info.0)
+}
+
+fn decode_total_account_balance(mut encoded_account_info: &[u8]) -> Option<Balance> {
+    let account_info =
+        match AccountInfo::<Nonce, AccountData<Balance>>::decode(&mut encoded_account_info) {
+            Ok(account_info) => account_info,
+            Err(error) => {
+                error!(%error, "Failed to decode account info");
+                return None;
+            }
+        };
 
     let account_data = account_info.data;
     Some(account_data.free + account_data.reserved + account_data.frozen)
@@ -327,6 +522,22 @@ pub(super) fn load_chain_specification(chain_spec: &'static [u8]) -> Result<Chai
         .map(|chain_spec| ChainSpec(Box::new(chain_spec)))
 }
 
+/// Chain info as described by chain spec, genesis hash is left empty
+fn chain_info_from_spec(chain_spec: &ChainSpec) -> ChainInfo {
+    ChainInfo {
+        chain_name: chain_spec.0.name().to_string(),
+        genesis_hash: String::new(),
+        protocol_id: chain_spec.0.protocol_id().unwrap_or_default().to_string(),
+        token_symbol: chain_spec
+            .0
+            .properties()
+            .get("tokenSymbol")
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string(),
+    }
+}
+
 fn set_default_ss58_version(chain_spec: &ChainSpec) {
     let maybe_ss58_address_format = chain_spec
         .0
@@ -398,9 +609,15 @@ fn create_consensus_chain_config(
     keypair: &Keypair,
     base_path: PathBuf,
     substrate_port: u16,
+    rpc_address: Option<SocketAddr>,
     chain_spec: ChainSpec,
+    telemetry: bool,
 ) -> SubstrateConfiguration {
-    let telemetry_endpoints = chain_spec.0.telemetry_endpoints().clone();
+    let telemetry_endpoints = if telemetry {
+        chain_spec.0.telemetry_endpoints().clone()
+    } else {
+        None
+    };
 
     SubstrateConfiguration {
         impl_name: env!("CARGO_PKG_NAME").to_string(),
@@ -443,10 +660,31 @@ fn create_consensus_chain_config(
         state_pruning: PruningMode::blocks_pruning(MIN_STATE_PRUNING),
         blocks_pruning: BlocksPruning::Some(256),
         rpc_options: SubstrateRpcConfiguration {
-            listen_on: SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, RPC_PORT)),
+            listen_on: rpc_address.unwrap_or(SocketAddr::V4(SocketAddrV4::new(
+                Ipv4Addr::LOCALHOST,
+                RPC_PORT,
+            ))),
             // Substrate's default
             max_connections: 100,
-            cors: Some(Vec::new()),
+            // RPC exposed explicitly by user is accessible from any origin, otherwise Substrate's
+            // default list of local origins is used
+            cors: if rpc_address.is_some() {
+                None
+            } else {
+                Some(
+                    [
+                        "http://localhost:*",
+                        "http://127.0.0.1:*",
+                        "https://localhost:*",
+                        "https://127.0.0.1:*",
+                        "https://polkadot.js.org",
+                    ]
+                    .into_iter()
+                    .map(String::from)
+                    .collect(),
+                )
+            },
+            // Unsafe methods are only allowed when listening on loopback interface
             methods: Default::default(),
             // Substrate's default
             rate_limit: None,
@@ -467,34 +705,34 @@ fn create_consensus_chain_config(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(super) async fn create_consensus_node(
     keypair: &Keypair,
     base_path: PathBuf,
     substrate_port: u16,
+    rpc_address: Option<SocketAddr>,
     chain_spec: ChainSpec,
     piece_getter: Arc<dyn DsnSyncPieceGetter + Send + Sync + 'static>,
     node: Node,
     maybe_node_client: &MaybeNodeClient,
+    telemetry: bool,
 ) -> Result<ConsensusNode, ConsensusNodeCreationError> {
     set_default_ss58_version(&chain_spec);
 
     let pot_external_entropy = pot_external_entropy(&chain_spec)?;
     let dsn_bootstrap_nodes = dsn_bootstrap_nodes(&chain_spec)?;
 
-    let chain_info = ChainInfo {
-        chain_name: chain_spec.0.name().to_string(),
-        protocol_id: chain_spec.0.protocol_id().unwrap_or_default().to_string(),
-        token_symbol: chain_spec
-            .0
-            .properties()
-            .get("tokenSymbol")
-            .and_then(|v| v.as_str())
-            .unwrap_or_default()
-            .to_string(),
-    };
-
-    let consensus_chain_config =
-        create_consensus_chain_config(keypair, base_path.clone(), substrate_port, chain_spec);
+    // Genesis hash is filled once node's client is created below
+    let mut chain_info = chain_info_from_spec(&chain_spec);
+
+    let consensus_chain_config = create_consensus_chain_config(
+        keypair,
+        base_path.clone(),
+        substrate_port,
+        rpc_address,
+        chain_spec,
+        telemetry,
+    );
     let sync = consensus_chain_config.network.sync_mode;
     let consensus_chain_config = Configuration::from(consensus_chain_config);
     let pause_sync = Arc::clone(&consensus_chain_config.network.pause_sync);
@@ -568,11 +806,13 @@ pub(super) async fn create_consensus_node(
             }
         };
 
-        if hex::encode(partial_components.client.info().genesis_hash) != GENESIS_HASH {
+        let genesis_hash = hex::encode(partial_components.client.info().genesis_hash);
+        if genesis_hash != GENESIS_HASH {
             return Err(ConsensusNodeCreationError::IncompatibleChain {
                 compatible_chain: consensus_chain_config.base.chain_spec.name().to_string(),
             });
         }
+        chain_info.genesis_hash = genesis_hash;
 
         let client = partial_components.client.clone();
         let segment_headers_store = partial_components.other.segment_headers_store.clone();
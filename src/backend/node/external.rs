@@ -0,0 +1,341 @@
+use crate::backend::node::utils::account_storage_key;
+use crate::backend::node::{
+    chain_info_from_spec, decode_total_account_balance, set_default_ss58_version,
+    BlockImportedNotification, ChainInfo, ChainSpec, Handlers, PeerInfo, PeerRole, SyncKind,
+    SyncState, GENESIS_HASH, SYNC_STATUS_EVENT_INTERVAL,
+};
+use crate::backend::utils::HandlerFn;
+use anyhow::anyhow;
+use event_listener_primitives::HandlerId;
+use futures::{select, FutureExt, StreamExt};
+use jsonrpsee::core::client::{ClientT, SubscriptionClientT};
+use jsonrpsee::rpc_params;
+use jsonrpsee::ws_client::{WsClient, WsClientBuilder};
+use parity_scale_codec::Decode;
+use serde::Deserialize;
+use sp_consensus_subspace::{ChainConstants, SolutionRanges};
+use sp_core::{Bytes, H256};
+use subspace_core_primitives::{BlockNumber, PublicKey};
+use subspace_runtime_primitives::Balance;
+use tokio::time::MissedTickBehavior;
+use tracing::{debug, error};
+
+#[derive(Debug, Deserialize)]
+struct RpcHeader {
+    /// Hex-encoded block number
+    number: String,
+}
+
+impl RpcHeader {
+    fn number(&self) -> anyhow::Result<BlockNumber> {
+        Ok(BlockNumber::from_str_radix(
+            self.number.trim_start_matches("0x"),
+            16,
+        )?)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcHealth {
+    peers: usize,
+    is_syncing: bool,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcSyncState {
+    highest_block: BlockNumber,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RpcPeerInfo {
+    peer_id: String,
+    roles: String,
+    best_number: BlockNumber,
+}
+
+/// Consensus node running outside of Space Acres, accessed via its RPC
+pub(in super::super) struct ExternalConsensusNode {
+    client: WsClient,
+    chain_info: ChainInfo,
+    chain_constants: ChainConstants,
+    handlers: Handlers,
+}
+
+impl ExternalConsensusNode {
+    pub(in super::super) async fn connect(
+        node_rpc_url: &str,
+        chain_spec: &ChainSpec,
+    ) -> anyhow::Result<Self> {
+        let client = WsClientBuilder::default()
+            .build(node_rpc_url)
+            .await
+            .map_err(|error| anyhow!("Failed to connect to node RPC: {error}"))?;
+
+        let genesis_hash = client
+            .request::<H256, _>("chain_getBlockHash", rpc_params![0])
+            .await
+            .map_err(|error| anyhow!("Failed to get genesis hash from node RPC: {error}"))?;
+        let genesis_hash = hex::encode(genesis_hash);
+        if genesis_hash != GENESIS_HASH {
+            return Err(anyhow!("Node is running a different chain"));
+        }
+
+        let chain_constants =
+            runtime_api_call::<ChainConstants>(&client, "SubspaceApi_chain_constants", None)
+                .await
+                .map_err(|error| anyhow!("Failed to get chain constants from node RPC: {error}"))?;
+
+        set_default_ss58_version(chain_spec);
+
+        let mut chain_info = chain_info_from_spec(chain_spec);
+        chain_info.genesis_hash = genesis_hash;
+
+        Ok(Self {
+            client,
+            chain_info,
+            chain_constants,
+            handlers: Handlers::default(),
+        })
+    }
+
+    pub(in super::super) async fn run(self, reward_addresses: &[PublicKey]) -> anyhow::Result<()> {
+        let mut new_heads = self
+            .client
+            .subscribe::<RpcHeader, _>(
+                "chain_subscribeNewHeads",
+                rpc_params![],
+                "chain_unsubscribeNewHeads",
+            )
+            .await?;
+
+        let block_import_notifications_fut = async {
+            while let Some(header) = new_heads.next().await {
+                let number = header?.number()?;
+                let block_hash = self
+                    .client
+                    .request::<Option<H256>, _>("chain_getBlockHash", rpc_params![number])
+                    .await?;
+                let solution_ranges = runtime_api_call::<SolutionRanges>(
+                    &self.client,
+                    "SubspaceApi_solution_ranges",
+                    block_hash,
+                )
+                .await
+                .unwrap_or_default();
+
+                let mut reward_address_balance = 0;
+                for reward_address in reward_addresses {
+                    reward_address_balance += self
+                        .account_balance_at(reward_address, block_hash)
+                        .await
+                        .unwrap_or_default();
+                }
+
+                let block_imported_notification = BlockImportedNotification {
+                    number,
+                    reward_address_balance,
+                    solution_range: solution_ranges.current,
+                    voting_solution_range: solution_ranges.voting_current,
+                };
+                self.handlers
+                    .block_imported
+                    .call_simple(&block_imported_notification);
+            }
+
+            Err::<(), _>(anyhow!("Connection to external node was lost"))
+        };
+        let sync_status_notifications_fut = async {
+            let mut sync_status_interval = tokio::time::interval(SYNC_STATUS_EVENT_INTERVAL);
+            sync_status_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+            let mut last_sync_state = SyncState::Unknown;
+            self.handlers
+                .sync_state_change
+                .call_simple(&last_sync_state);
+            let mut last_peers = Vec::new();
+
+            loop {
+                sync_status_interval.tick().await;
+
+                if let Ok(health) = self
+                    .client
+                    .request::<RpcHealth, _>("system_health", rpc_params![])
+                    .await
+                {
+                    let sync_state = if health.is_syncing {
+                        SyncState::Syncing {
+                            // Whether external node syncs from DSN is not exposed via RPC
+                            kind: SyncKind::Regular,
+                            target: self
+                                .client
+                                .request::<RpcSyncState, _>("system_syncState", rpc_params![])
+                                .await
+                                .map(|sync_state| sync_state.highest_block)
+                                .unwrap_or_default(),
+                        }
+                    } else if health.peers > 0 {
+                        SyncState::Idle
+                    } else {
+                        SyncState::Unknown
+                    };
+
+                    if sync_state != last_sync_state {
+                        self.handlers.sync_state_change.call_simple(&sync_state);
+
+                        last_sync_state = sync_state;
+                    }
+                }
+
+                let peers = self.connected_peers().await;
+                if peers != last_peers {
+                    self.handlers.peers_update.call_simple(&peers);
+
+                    last_peers = peers;
+                }
+            }
+        };
+
+        select! {
+            result = block_import_notifications_fut.fuse() => {
+                result
+            }
+            _ = sync_status_notifications_fut.fuse() => {
+                // Nothing else to do
+                Ok(())
+            }
+        }
+    }
+
+    async fn connected_peers(&self) -> Vec<PeerInfo> {
+        // Unsafe RPC method, external node might not allow it, in which case peers are not shown
+        let peers_info = match self
+            .client
+            .request::<Vec<RpcPeerInfo>, _>("system_peers", rpc_params![])
+            .await
+        {
+            Ok(peers_info) => peers_info,
+            Err(error) => {
+                debug!(%error, "Failed to get peers of external node");
+                return Vec::new();
+            }
+        };
+
+        let mut peers = peers_info
+            .into_iter()
+            .map(|peer_info| PeerInfo {
+                peer_id: peer_info.peer_id,
+                // Not exposed via RPC
+                address: None,
+                role: if peer_info.roles.contains("AUTHORITY") {
+                    PeerRole::Authority
+                } else if peer_info.roles.contains("LIGHT") {
+                    PeerRole::Light
+                } else {
+                    PeerRole::Full
+                },
+                best_block_number: peer_info.best_number,
+            })
+            .collect::<Vec<_>>();
+        // Stable order for display and comparison
+        peers.sort_by(|a, b| a.peer_id.cmp(&b.peer_id));
+
+        peers
+    }
+
+    pub(in super::super) async fn best_block_number(&self) -> BlockNumber {
+        let header = match self
+            .client
+            .request::<RpcHeader, _>("chain_getHeader", rpc_params![])
+            .await
+        {
+            Ok(header) => header,
+            Err(error) => {
+                error!(%error, "Failed to query best block of external node");
+                return 0;
+            }
+        };
+
+        header.number().unwrap_or_default()
+    }
+
+    pub(in super::super) async fn account_balance(&self, account: &PublicKey) -> Balance {
+        self.account_balance_at(account, None)
+            .await
+            .unwrap_or_default()
+    }
+
+    /// Balance at specified block or best block if `None`
+    async fn account_balance_at(
+        &self,
+        account: &PublicKey,
+        block_hash: Option<H256>,
+    ) -> Option<Balance> {
+        let encoded_account_info = match self
+            .client
+            .request::<Option<Bytes>, _>(
+                "state_getStorage",
+                rpc_params![account_storage_key(account), block_hash],
+            )
+            .await
+        {
+            Ok(maybe_encoded_account_info) => maybe_encoded_account_info?,
+            Err(error) => {
+                error!(%error, "Failed to query account balance");
+                return None;
+            }
+        };
+
+        decode_total_account_balance(&encoded_account_info)
+    }
+
+    pub(in super::super) fn chain_info(&self) -> &ChainInfo {
+        &self.chain_info
+    }
+
+    pub(in super::super) fn chain_constants(&self) -> &ChainConstants {
+        &self.chain_constants
+    }
+
+    pub(in super::super) fn on_sync_state_change(
+        &self,
+        callback: HandlerFn<SyncState>,
+    ) -> HandlerId {
+        self.handlers.sync_state_change.add(callback)
+    }
+
+    pub(in super::super) fn on_block_imported(
+        &self,
+        callback: HandlerFn<BlockImportedNotification>,
+    ) -> HandlerId {
+        self.handlers.block_imported.add(callback)
+    }
+
+    pub(in super::super) fn on_peers_update(
+        &self,
+        callback: HandlerFn<Vec<PeerInfo>>,
+    ) -> HandlerId {
+        self.handlers.peers_update.add(callback)
+    }
+}
+
+/// Call runtime API method without arguments at specified block or best block if `None`
+async fn runtime_api_call<T>(
+    client: &WsClient,
+    method: &str,
+    block_hash: Option<H256>,
+) -> anyhow::Result<T>
+where
+    T: Decode,
+{
+    let encoded = client
+        .request::<Bytes, _>(
+            "state_call",
+            rpc_params![method, Bytes(Vec::new()), block_hash],
+        )
+        .await?;
+
+    Ok(T::decode(&mut encoded.0.as_slice())?)
+}
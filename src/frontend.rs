@@ -1,22 +1,42 @@
 pub mod configuration;
+pub mod diagnostics;
+pub mod event_log;
+mod farm_mismatches;
 pub mod loading;
+pub mod log_viewer;
 pub mod new_version;
+pub mod profiles;
 pub mod running;
+pub mod toasts;
 pub mod translations;
+mod ui_state;
 mod widgets;
 
-use crate::backend::config::RawConfig;
+use crate::backend::config::profiles::{active_profile, DEFAULT_PROFILE_NAME};
+use crate::backend::config::{RawConfig, RestartRequirement, Theme};
+use crate::backend::farmer::reconciliation::FarmMismatchResolution;
 use crate::backend::farmer::FarmerAction;
-use crate::backend::{wipe, BackendAction, BackendNotification};
+use crate::backend::{wipe, BackendAction, BackendNotification, ShutdownStep};
 use crate::frontend::configuration::{ConfigurationInput, ConfigurationOutput, ConfigurationView};
+use crate::frontend::diagnostics::{Diagnostics, DiagnosticsInit, DiagnosticsInput};
+use crate::frontend::event_log::{EventKind, EventLog, EventLogInit, EventLogInput};
+use crate::frontend::farm_mismatches::show_farm_mismatches_dialog;
 use crate::frontend::loading::{LoadingInput, LoadingView};
-use crate::frontend::new_version::NewVersion;
-use crate::frontend::running::{RunningInit, RunningInput, RunningOutput, RunningView};
+use crate::frontend::log_viewer::{LogViewer, LogViewerInit, LogViewerInput};
+use crate::frontend::new_version::{NewVersion, NewVersionInput, NewVersionOutput};
+use crate::frontend::profiles::{Profiles, ProfilesInit, ProfilesInput, ProfilesOutput};
+use crate::frontend::running::{
+    RunningInit, RunningInput, RunningOutput, RunningStatus, RunningView,
+};
+use crate::frontend::toasts::{Toast, Toasts, ToastsInput};
 use crate::frontend::translations::{AsDefaultStr, T};
-use crate::AppStatusCode;
+use crate::frontend::ui_state::{UiState, WindowState};
+use crate::log_buffer::LogBuffer;
+use crate::log_filter::LogFilterHandle;
+use crate::{app_dirs, AppStatusCode, LOG_FILE_NAME};
 #[cfg(any(target_os = "linux", windows))]
 use betrayer::Icon;
-use betrayer::{Menu, MenuItem, TrayEvent, TrayIcon, TrayIconBuilder};
+use betrayer::{ClickType, Menu, MenuItem, TrayEvent, TrayIcon, TrayIconBuilder};
 use futures::channel::mpsc;
 use futures::{SinkExt, StreamExt};
 use gtk::glib;
@@ -24,19 +44,32 @@ use gtk::prelude::*;
 use notify_rust::Notification;
 use relm4::actions::{RelmAction, RelmActionGroup};
 use relm4::prelude::*;
-use relm4::{Sender, ShutdownReceiver};
+use relm4::{MessageBroker, Sender, ShutdownReceiver};
 use relm4_icons::icon_name;
 use std::cell::{Cell, LazyCell};
 use std::future::Future;
 use std::path::PathBuf;
 use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Duration;
 use std::{env, fmt};
 use tracing::{debug, error, warn};
 
 pub const GLOBAL_CSS: &str = include_str!("../res/app.css");
+/// Applied on top of [`GLOBAL_CSS`] when high contrast is enabled
+const HIGH_CONTRAST_CSS: &str = include_str!("../res/app-high-contrast.css");
+/// Name of the high contrast theme that GTK recognizes on all platforms
+const HIGH_CONTRAST_GTK_THEME_NAME: &str = "HighContrast";
 #[cfg(all(unix, not(target_os = "macos")))]
 const ICON: &[u8] = include_bytes!("../res/icon.png");
 const ABOUT_IMAGE: &[u8] = include_bytes!("../res/about.png");
+const MIN_WINDOW_WIDTH: i32 = 800;
+const MIN_WINDOW_HEIGHT: i32 = 600;
+/// How long to wait for graceful shutdown before offering to force quit
+const FORCE_QUIT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Allows sending messages to the main component from anywhere, see [`App::show_toast()`]
+pub static APP_BROKER: MessageBroker<AppInput> = MessageBroker::new();
 
 #[cfg(all(unix, not(target_os = "macos")))]
 #[thread_local]
@@ -44,6 +77,79 @@ static PIXBUF_ICON: LazyCell<gtk::gdk_pixbuf::Pixbuf> = LazyCell::new(|| {
     gtk::gdk_pixbuf::Pixbuf::from_read(ICON).expect("Statically correct image; qed")
 });
 
+/// GTK theme configured in the environment before application changed anything
+#[thread_local]
+static SYSTEM_GTK_THEME_NAME: LazyCell<Option<String>> = LazyCell::new(|| {
+    gtk::Settings::default()?
+        .gtk_theme_name()
+        .map(|theme_name| theme_name.to_string())
+});
+
+#[thread_local]
+static HIGH_CONTRAST_CSS_PROVIDER: LazyCell<gtk::CssProvider> = LazyCell::new(|| {
+    let provider = gtk::CssProvider::new();
+    provider.load_from_data(HIGH_CONTRAST_CSS);
+    provider
+});
+
+/// Apply color scheme to the whole application
+pub fn apply_theme(theme: Theme) {
+    let Some(settings) = gtk::Settings::default() else {
+        return;
+    };
+
+    let system_theme_name = SYSTEM_GTK_THEME_NAME.as_deref();
+    // High contrast themes are called `HighContrast` and `HighContrastInverse` by convention
+    let system_high_contrast = system_theme_name
+        .is_some_and(|theme_name| theme_name.starts_with(HIGH_CONTRAST_GTK_THEME_NAME));
+
+    let prefer_dark_theme = match theme {
+        // Prefer dark theme in cross-platform way if environment is configured that way
+        Theme::System | Theme::HighContrast => {
+            matches!(dark_light::detect(), dark_light::Mode::Dark)
+        }
+        Theme::Light => false,
+        Theme::Dark => true,
+    };
+    settings.set_gtk_application_prefer_dark_theme(prefer_dark_theme);
+
+    let high_contrast = match theme {
+        Theme::System => system_high_contrast,
+        Theme::Light | Theme::Dark => false,
+        Theme::HighContrast => true,
+    };
+    if theme == Theme::HighContrast && !system_high_contrast {
+        settings.set_gtk_theme_name(Some(HIGH_CONTRAST_GTK_THEME_NAME));
+    } else if settings.gtk_theme_name().as_deref() != system_theme_name {
+        settings.set_gtk_theme_name(system_theme_name);
+    }
+
+    if let Some(display) = gtk::gdk::Display::default() {
+        if high_contrast {
+            gtk::style_context_add_provider_for_display(
+                &display,
+                &*HIGH_CONTRAST_CSS_PROVIDER,
+                gtk::STYLE_PROVIDER_PRIORITY_USER,
+            );
+        } else {
+            gtk::style_context_remove_provider_for_display(&display, &*HIGH_CONTRAST_CSS_PROVIDER);
+        }
+    }
+}
+
+/// Let user know that application is already running when its window can't be brought to the front
+pub fn show_already_running_notification() {
+    let mut notification = Notification::new();
+    notification
+        .summary(&T.notification_already_running())
+        .body(&T.notification_already_running_body())
+        .with_typical_options();
+
+    if let Err(error) = notification.show() {
+        warn!(%error, "Failed to show desktop notification");
+    }
+}
+
 trait NotificationExt {
     fn with_typical_options(&mut self) -> &mut Self;
 }
@@ -108,43 +214,151 @@ static PIXBUF_ABOUT_IMG: LazyCell<gtk::gdk_pixbuf::Pixbuf> = LazyCell::new(|| {
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 enum TrayMenuSignal {
     Open,
-    Close,
+    TogglePause,
+    Quit,
+}
+
+/// State reflected by the tray icon
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum TrayIconState {
+    Loading,
+    Running(RunningStatus),
+    Error,
+}
+
+impl TrayIconState {
+    fn tooltip(&self) -> String {
+        match self {
+            Self::Loading => T.tray_icon_tooltip_loading(),
+            Self::Running(RunningStatus::Syncing) => T.tray_icon_tooltip_syncing(),
+            Self::Running(RunningStatus::Plotting) => T.tray_icon_tooltip_plotting(),
+            Self::Running(RunningStatus::Farming) => T.tray_icon_tooltip_farming(),
+            Self::Running(RunningStatus::Paused) => T.tray_icon_tooltip_paused(),
+            Self::Running(RunningStatus::Error) | Self::Error => T.tray_icon_tooltip_error(),
+        }
+        .to_string()
+    }
+
+    fn menu(&self) -> Menu<TrayMenuSignal> {
+        let mut items = vec![MenuItem::button(T.tray_icon_open(), TrayMenuSignal::Open)];
+        if let Self::Running(status) = self {
+            items.push(MenuItem::button(
+                if *status == RunningStatus::Paused {
+                    T.tray_icon_resume()
+                } else {
+                    T.tray_icon_pause()
+                },
+                TrayMenuSignal::TogglePause,
+            ));
+        }
+        items.push(MenuItem::button(T.tray_icon_quit(), TrayMenuSignal::Quit));
+
+        Menu::new(items)
+    }
+
+    /// Icon with a small badge in the corner colored according to the state
+    #[cfg(target_os = "linux")]
+    fn icon(&self) -> Option<Icon> {
+        let badge_color = match self {
+            Self::Loading => {
+                return Icon::from_png_bytes(ICON).ok();
+            }
+            Self::Running(RunningStatus::Syncing) => 0x3584e4ff,
+            Self::Running(RunningStatus::Plotting) => 0xf5c211ff,
+            Self::Running(RunningStatus::Farming) => 0x2ec27eff,
+            Self::Running(RunningStatus::Paused) => 0x9a9996ff,
+            Self::Running(RunningStatus::Error) | Self::Error => 0xe01b24ff,
+        };
+
+        let icon = PIXBUF_ICON.copy()?;
+        let badge_size = icon.width().min(icon.height()) / 3;
+        icon.new_subpixbuf(
+            icon.width() - badge_size,
+            icon.height() - badge_size,
+            badge_size,
+            badge_size,
+        )
+        .fill(badge_color);
+
+        let png_bytes = icon
+            .save_to_bufferv("png", &[])
+            .map_err(|error| {
+                warn!(%error, "Failed to encode tray icon");
+            })
+            .ok()?;
+
+        Icon::from_png_bytes(&png_bytes)
+            .map_err(|error| {
+                warn!(%error, "Failed to create tray icon");
+            })
+            .ok()
+    }
 }
 
 #[derive(Debug)]
 pub enum AppInput {
     Configuration(ConfigurationOutput),
     Running(RunningOutput),
+    NewVersion(NewVersionOutput),
+    Profiles(ProfilesOutput),
+    ShowToast(Toast),
     OpenLogsFolder,
+    OpenLogViewer,
+    OpenEventLog,
+    OpenProfiles,
+    ExportDiagnostics,
     ChangeConfiguration,
     OpenFeedbackLink,
     OpenCommunityHelpLink,
     ShowAboutDialog,
     InitialConfiguration,
     StartUpgrade,
+    RetryStartupNow,
+    CancelStartupRetry,
+    ResolveFarmMismatches {
+        resolutions: Vec<(PathBuf, FarmMismatchResolution)>,
+    },
     Restart,
+    RestartServices,
     CloseStatusBarWarning,
     HideWindow,
     ShowWindow,
+    ToggleWindow,
+    TogglePauseFarming,
     ShutDown,
+    ForceQuit,
 }
 
 #[derive(Debug)]
 pub enum AppCommandOutput {
     BackendNotification(BackendNotification),
     Restart,
+    ForceQuitAvailable,
     Quit,
 }
 
 enum View {
     Welcome,
-    Upgrade { chain_name: String },
+    Upgrade {
+        chain_name: String,
+    },
     Loading,
     Configuration,
     Reconfiguration,
     Running,
-    ShuttingDown,
+    ShuttingDown {
+        step: Option<ShutdownStep>,
+        force_quit_available: bool,
+    },
+    RestartingServices {
+        step: Option<ShutdownStep>,
+    },
     Stopped(Option<anyhow::Error>),
+    StartupRetry {
+        error: String,
+        attempt: u32,
+        retry_in: Duration,
+    },
     Error(String),
 }
 
@@ -157,8 +371,10 @@ impl View {
             Self::Configuration => T.configuration_title(),
             Self::Reconfiguration => T.reconfiguration_title(),
             Self::Running => T.running_title(),
-            Self::ShuttingDown => T.shutting_down_title(),
+            Self::ShuttingDown { .. } => T.shutting_down_title(),
+            Self::RestartingServices { .. } => T.restarting_services_title(),
             Self::Stopped(_) => T.stopped_title(),
+            Self::StartupRetry { .. } => T.startup_retry_title(),
             Self::Error(_) => T.error_title(),
         }
     }
@@ -172,8 +388,8 @@ enum StatusBarContents {
         message: String,
         /// Whether to show ok button
         ok: bool,
-        /// Whether to show restart button
-        restart: bool,
+        /// What restart buttons to show
+        restart: RestartRequirement,
     },
     Error(String),
 }
@@ -207,7 +423,14 @@ impl StatusBarContents {
 
     fn restart_button(&self) -> bool {
         match self {
-            Self::Warning { restart, .. } => *restart,
+            Self::Warning { restart, .. } => *restart != RestartRequirement::None,
+            _ => false,
+        }
+    }
+
+    fn restart_services_button(&self) -> bool {
+        match self {
+            Self::Warning { restart, .. } => *restart == RestartRequirement::Services,
             _ => false,
         }
     }
@@ -221,6 +444,8 @@ pub struct RunBackendResult {
 
 pub struct AppInit {
     pub app_data_dir: Option<PathBuf>,
+    pub log_buffer: Arc<LogBuffer>,
+    pub log_filter_handle: LogFilterHandle,
     pub exit_status_code: Rc<Cell<AppStatusCode>>,
     pub minimize_on_start: bool,
     pub crash_notification: bool,
@@ -228,12 +453,17 @@ pub struct AppInit {
 }
 
 relm4::new_action_group!(MainMenu, "main_menu");
+relm4::new_stateless_action!(MainMenuLogViewer, MainMenu, "log_viewer");
 relm4::new_stateless_action!(MainMenuShowLogs, MainMenu, "show_logs");
+relm4::new_stateless_action!(MainMenuEventLog, MainMenu, "event_log");
+relm4::new_stateless_action!(MainMenuProfiles, MainMenu, "profiles");
 relm4::new_stateless_action!(
     MainMenuChangeConfiguration,
     MainMenu,
     "change_configuration"
 );
+relm4::new_stateless_action!(MainMenuRestartServices, MainMenu, "restart_services");
+relm4::new_stateless_action!(MainMenuExportDiagnostics, MainMenu, "export_diagnostics");
 relm4::new_stateless_action!(MainMenuShareFeedback, MainMenu, "share_feedback");
 relm4::new_stateless_action!(MainMenuAbout, MainMenu, "about");
 relm4::new_stateless_action!(MainMenuExit, MainMenu, "exit");
@@ -243,6 +473,8 @@ pub struct App {
     #[no_eq]
     current_view: View,
     current_raw_config: Option<RawConfig>,
+    /// Name of the configuration profile node and farmer are started with
+    active_profile: String,
     status_bar_contents: StatusBarContents,
     #[do_not_track]
     backend_action_sender: mpsc::Sender<BackendAction>,
@@ -255,18 +487,37 @@ pub struct App {
     #[do_not_track]
     running_view: Controller<RunningView>,
     #[do_not_track]
+    log_viewer: Controller<LogViewer>,
+    #[do_not_track]
+    event_log: Controller<EventLog>,
+    #[do_not_track]
+    toasts: Controller<Toasts>,
+    #[do_not_track]
+    profiles: Controller<Profiles>,
+    #[do_not_track]
+    diagnostics: Controller<Diagnostics>,
+    #[do_not_track]
     about_dialog: gtk::AboutDialog,
     #[do_not_track]
     app_data_dir: Option<PathBuf>,
     #[do_not_track]
+    log_filter_handle: LogFilterHandle,
+    #[do_not_track]
     exit_status_code: Rc<Cell<AppStatusCode>>,
     #[do_not_track]
     loaded: bool,
+    /// What needs to be restarted for saved configuration changes to take effect
+    #[do_not_track]
+    restart_requirement: RestartRequirement,
     #[do_not_track]
     backend_fut: Option<Box<dyn Future<Output = ()> + Send>>,
     // Keep it around so it doesn't disappear
     #[do_not_track]
-    _tray_icon: Option<TrayIcon<TrayMenuSignal>>,
+    tray_icon: Option<TrayIcon<TrayMenuSignal>>,
+    #[do_not_track]
+    tray_icon_state: TrayIconState,
+    #[do_not_track]
+    running_status: Option<RunningStatus>,
 }
 
 #[relm4::component(pub async)]
@@ -279,8 +530,7 @@ impl AsyncComponent for App {
     view! {
         gtk::Window {
             set_decorated: false,
-            set_resizable: false,
-            set_size_request: (800, 600),
+            set_size_request: (MIN_WINDOW_WIDTH, MIN_WINDOW_HEIGHT),
             #[track = "model.changed_current_view()"]
             set_title: Some(&format!("{} - Space Acres {}", model.current_view.title(), env!("CARGO_PKG_VERSION"))),
 
@@ -288,6 +538,25 @@ impl AsyncComponent for App {
                 set_orientation: gtk::Orientation::Vertical,
 
                 gtk::HeaderBar {
+                    pack_start = &gtk::Label {
+                        add_css_class: "warning-label",
+                        set_label: &T.offline_mode_indicator(),
+                        set_tooltip: &T.offline_mode_indicator_tooltip(),
+                        #[track = "model.changed_current_raw_config()"]
+                        set_visible: model
+                            .current_raw_config
+                            .as_ref()
+                            .is_some_and(RawConfig::offline_mode),
+                    },
+
+                    pack_start = &gtk::Label {
+                        #[track = "model.changed_active_profile()"]
+                        set_label: T.profile_indicator(model.active_profile.as_str()).as_str(),
+                        set_tooltip: &T.profile_indicator_tooltip(),
+                        #[track = "model.changed_active_profile()"]
+                        set_visible: model.active_profile != DEFAULT_PROFILE_NAME,
+                    },
+
                     pack_end = &gtk::Box {
                         set_spacing: 10,
 
@@ -313,175 +582,290 @@ impl AsyncComponent for App {
                     },
                 },
 
-                gtk::Box {
-                    set_margin_all: 10,
-                    set_orientation: gtk::Orientation::Vertical,
-                    set_spacing: 10,
-
-                    #[transition = "SlideLeftRight"]
-                    match &model.current_view {
-                        View::Welcome => gtk::Box {
-                            set_margin_all: 10,
-                            set_orientation: gtk::Orientation::Vertical,
-                            set_spacing: 20,
-
-                            gtk::Image {
-                                set_height_request: 256,
-                                set_from_pixbuf: Some(&*PIXBUF_ABOUT_IMG),
-                            },
+                gtk::Overlay {
+                    add_overlay: model.toasts.widget(),
 
-                            gtk::Label {
-                                set_label: &T.welcome_message(),
-                                set_wrap: true,
-                            },
+                    #[wrap(Some)]
+                    set_child = &gtk::Box {
+                        set_margin_all: 10,
+                        set_orientation: gtk::Orientation::Vertical,
+                        set_spacing: 10,
 
-                            gtk::Box {
-                                set_halign: gtk::Align::End,
+                        #[transition = "SlideLeftRight"]
+                        match &model.current_view {
+                            View::Welcome => gtk::Box {
+                                set_margin_all: 10,
+                                set_orientation: gtk::Orientation::Vertical,
+                                set_spacing: 20,
 
+                                gtk::Image {
+                                    set_height_request: 256,
+                                    set_from_pixbuf: Some(&*PIXBUF_ABOUT_IMG),
+                                },
+
+                                gtk::Label {
+                                    set_label: &T.welcome_message(),
+                                    set_wrap: true,
+                                },
+
+                                gtk::Box {
+                                    set_halign: gtk::Align::End,
 
-                                gtk::Button {
-                                    add_css_class: "suggested-action",
-                                    connect_clicked => AppInput::InitialConfiguration,
 
-                                    gtk::Label {
-                                        set_label: &T.welcome_button_continue(),
-                                        set_margin_all: 10,
+                                    gtk::Button {
+                                        add_css_class: "suggested-action",
+                                        connect_clicked => AppInput::InitialConfiguration,
+
+                                        gtk::Label {
+                                            set_label: &T.welcome_button_continue(),
+                                            set_margin_all: 10,
+                                        },
                                     },
                                 },
                             },
-                        },
-                        View::Upgrade { chain_name } => gtk::Box {
-                            set_margin_all: 10,
-                            set_orientation: gtk::Orientation::Vertical,
-                            set_spacing: 20,
-
-                            gtk::Image {
-                                set_height_request: 256,
-                                set_from_pixbuf: Some(&*PIXBUF_ABOUT_IMG),
-                            },
+                            View::Upgrade { chain_name } => gtk::Box {
+                                set_margin_all: 10,
+                                set_orientation: gtk::Orientation::Vertical,
+                                set_spacing: 20,
+
+                                gtk::Image {
+                                    set_height_request: 256,
+                                    set_from_pixbuf: Some(&*PIXBUF_ABOUT_IMG),
+                                },
 
-                            gtk::Label {
-                                set_label: &T.upgrade_message(),
-                                set_wrap: true,
-                            },
+                                gtk::Label {
+                                    set_label: &T.upgrade_message(),
+                                    set_wrap: true,
+                                },
 
-                            gtk::Box {
-                                set_halign: gtk::Align::End,
+                                gtk::Box {
+                                    set_halign: gtk::Align::End,
 
 
-                                gtk::Button {
-                                    add_css_class: "destructive-action",
-                                    connect_clicked => AppInput::StartUpgrade,
+                                    gtk::Button {
+                                        add_css_class: "destructive-action",
+                                        connect_clicked => AppInput::StartUpgrade,
 
-                                    gtk::Label {
-                                        #[track = "model.changed_current_view()"]
-                                        set_label: T.upgrade_button_upgrade(chain_name).as_str(),
-                                        set_margin_all: 10,
+                                        gtk::Label {
+                                            #[track = "model.changed_current_view()"]
+                                            set_label: T.upgrade_button_upgrade(chain_name).as_str(),
+                                            set_margin_all: 10,
+                                        },
                                     },
                                 },
                             },
-                        },
-                        View::Loading => model.loading_view.widget().clone(),
-                        View::Configuration | View::Reconfiguration => model.configuration_view.widget().clone(),
-                        View::Running=> model.running_view.widget().clone(),
-                        View::ShuttingDown=> gtk::Box {
-                            set_halign: gtk::Align::Center,
-                            set_valign: gtk::Align::Center,
-                            set_vexpand: true,
-                            set_orientation: gtk::Orientation::Vertical,
+                            View::Loading => model.loading_view.widget().clone(),
+                            View::Configuration | View::Reconfiguration => model.configuration_view.widget().clone(),
+                            View::Running=> model.running_view.widget().clone(),
+                            View::ShuttingDown { step, force_quit_available } => gtk::Box {
+                                set_halign: gtk::Align::Center,
+                                set_valign: gtk::Align::Center,
+                                set_vexpand: true,
+                                set_orientation: gtk::Orientation::Vertical,
+                                set_spacing: 10,
 
-                            gtk::Spinner {
-                                start: (),
-                                set_size_request: (50, 50),
-                            },
+                                gtk::Spinner {
+                                    start: (),
+                                    set_size_request: (50, 50),
+                                },
 
-                            gtk::Label {
-                                set_label: &T.shutting_down_description(),
-                            },
-                        },
-                        View::Stopped(Some(error)) => gtk::Box {
-                            set_orientation: gtk::Orientation::Vertical,
-                            set_spacing: 20,
-                            set_valign: gtk::Align::Center,
+                                gtk::Label {
+                                    #[track = "model.changed_current_view()"]
+                                    set_label: &match step {
+                                        Some(ShutdownStep::StoppingFarmer) => {
+                                            T.shutting_down_step_stopping_farmer()
+                                        }
+                                        Some(ShutdownStep::StoppingNode) => {
+                                            T.shutting_down_step_stopping_node()
+                                        }
+                                        Some(ShutdownStep::StoppingNetworking) => {
+                                            T.shutting_down_step_stopping_networking()
+                                        }
+                                        None => T.shutting_down_step_stopping(),
+                                    },
+                                },
 
-                            gtk::Label {
-                                #[track = "model.changed_current_view()"]
-                                set_label: T.stopped_message_with_error(error.to_string()).as_str(),
-                            },
+                                gtk::Label {
+                                    add_css_class: "dim-label",
+                                    set_label: &T.shutting_down_description(),
+                                },
 
-                            gtk::Box {
+                                gtk::Button {
+                                    add_css_class: "destructive-action",
+                                    connect_clicked => AppInput::ForceQuit,
+                                    set_halign: gtk::Align::Center,
+                                    set_label: &T.shutting_down_button_force_quit(),
+                                    set_margin_top: 10,
+                                    set_tooltip: &T.shutting_down_button_force_quit_tooltip(),
+                                    #[track = "model.changed_current_view()"]
+                                    set_visible: *force_quit_available,
+                                },
+                            },
+                            View::RestartingServices { step } => gtk::Box {
                                 set_halign: gtk::Align::Center,
+                                set_valign: gtk::Align::Center,
+                                set_vexpand: true,
+                                set_orientation: gtk::Orientation::Vertical,
                                 set_spacing: 10,
 
-                                gtk::Button {
-                                    set_label: &T.stopped_button_show_logs(),
-                                    connect_clicked => AppInput::OpenLogsFolder,
+                                gtk::Spinner {
+                                    start: (),
+                                    set_size_request: (50, 50),
                                 },
 
-                                gtk::Button {
-                                    set_label: &T.stopped_button_help_from_community(),
-                                    connect_clicked => AppInput::OpenCommunityHelpLink,
+                                gtk::Label {
+                                    #[track = "model.changed_current_view()"]
+                                    set_label: &match step {
+                                        Some(ShutdownStep::StoppingFarmer) => {
+                                            T.shutting_down_step_stopping_farmer()
+                                        }
+                                        Some(ShutdownStep::StoppingNode) => {
+                                            T.shutting_down_step_stopping_node()
+                                        }
+                                        Some(ShutdownStep::StoppingNetworking) => {
+                                            T.shutting_down_step_stopping_networking()
+                                        }
+                                        None => T.shutting_down_step_stopping(),
+                                    },
                                 },
-                            },
-                        },
-                        View::Stopped(None) => {
-                            gtk::Label {
-                                set_label: &T.stopped_message(),
-                            }
-                        },
-                        View::Error(error) => gtk::Box {
-                            set_orientation: gtk::Orientation::Vertical,
-                            set_spacing: 20,
-                            set_valign: gtk::Align::Center,
 
-                            gtk::Label {
-                                #[track = "model.changed_current_view()"]
-                                set_label: &T.error_message(error.to_string()).as_str(),
+                                gtk::Label {
+                                    add_css_class: "dim-label",
+                                    set_label: &T.restarting_services_description(),
+                                },
                             },
+                            View::Stopped(Some(error)) => gtk::Box {
+                                set_orientation: gtk::Orientation::Vertical,
+                                set_spacing: 20,
+                                set_valign: gtk::Align::Center,
+
+                                gtk::Label {
+                                    #[track = "model.changed_current_view()"]
+                                    set_label: T.stopped_message_with_error(error.to_string()).as_str(),
+                                },
 
-                            gtk::Box {
-                                set_halign: gtk::Align::Center,
-                                set_spacing: 10,
+                                gtk::Box {
+                                    set_halign: gtk::Align::Center,
+                                    set_spacing: 10,
 
-                                gtk::Button {
-                                    set_label: &T.error_button_show_logs(),
-                                    connect_clicked => AppInput::OpenLogsFolder,
+                                    gtk::Button {
+                                        set_label: &T.stopped_button_show_logs(),
+                                        connect_clicked => AppInput::OpenLogsFolder,
+                                    },
+
+                                    gtk::Button {
+                                        set_label: &T.stopped_button_help_from_community(),
+                                        connect_clicked => AppInput::OpenCommunityHelpLink,
+                                    },
+                                },
+                            },
+                            View::Stopped(None) => {
+                                gtk::Label {
+                                    set_label: &T.stopped_message(),
+                                }
+                            },
+                            View::StartupRetry { error, attempt, retry_in } => gtk::Box {
+                                set_orientation: gtk::Orientation::Vertical,
+                                set_spacing: 20,
+                                set_valign: gtk::Align::Center,
+
+                                gtk::Label {
+                                    #[track = "model.changed_current_view()"]
+                                    set_label: &T.startup_retry_message(error.as_str()).as_str(),
+                                    set_wrap: true,
                                 },
 
-                                gtk::Button {
-                                    set_label: &T.error_button_help_from_community(),
-                                    connect_clicked => AppInput::OpenCommunityHelpLink,
+                                gtk::Label {
+                                    #[track = "model.changed_current_view()"]
+                                    set_label: &T
+                                        .startup_retry_countdown(*attempt, retry_in.as_secs_f32().ceil() as u64)
+                                        .as_str(),
+                                },
+
+                                gtk::Box {
+                                    set_halign: gtk::Align::Center,
+                                    set_spacing: 10,
+
+                                    gtk::Button {
+                                        add_css_class: "suggested-action",
+                                        set_label: &T.startup_retry_button_retry_now(),
+                                        connect_clicked => AppInput::RetryStartupNow,
+                                    },
+
+                                    gtk::Button {
+                                        set_label: &T.startup_retry_button_cancel(),
+                                        connect_clicked => AppInput::CancelStartupRetry,
+                                    },
+
+                                    gtk::Button {
+                                        set_label: &T.error_button_show_logs(),
+                                        connect_clicked => AppInput::OpenLogsFolder,
+                                    },
                                 },
                             },
-                        },
-                    },
+                            View::Error(error) => gtk::Box {
+                                set_orientation: gtk::Orientation::Vertical,
+                                set_spacing: 20,
+                                set_valign: gtk::Align::Center,
+
+                                gtk::Label {
+                                    #[track = "model.changed_current_view()"]
+                                    set_label: &T.error_message(error.to_string()).as_str(),
+                                },
 
-                    gtk::Box {
-                        set_halign: gtk::Align::Center,
-                        set_spacing: 10,
-                        #[track = "model.changed_status_bar_contents()"]
-                        set_visible: !model.status_bar_contents.is_none(),
+                                gtk::Box {
+                                    set_halign: gtk::Align::Center,
+                                    set_spacing: 10,
 
-                        gtk::Label {
-                            #[track = "model.changed_status_bar_contents()"]
-                            set_css_classes: &[model.status_bar_contents.css_class()],
-                            #[track = "model.changed_status_bar_contents()"]
-                            set_label: model.status_bar_contents.message(),
-                        },
+                                    gtk::Button {
+                                        set_label: &T.error_button_show_logs(),
+                                        connect_clicked => AppInput::OpenLogsFolder,
+                                    },
 
-                        gtk::Button {
-                            add_css_class: "suggested-action",
-                            connect_clicked => AppInput::Restart,
-                            set_label: &T.status_bar_button_restart(),
-                            #[track = "model.changed_status_bar_contents()"]
-                            set_visible: model.status_bar_contents.restart_button(),
+                                    gtk::Button {
+                                        set_label: &T.error_button_help_from_community(),
+                                        connect_clicked => AppInput::OpenCommunityHelpLink,
+                                    },
+                                },
+                            },
                         },
 
-                        gtk::Button {
-                            connect_clicked => AppInput::CloseStatusBarWarning,
-                            set_label: &T.status_bar_button_ok(),
+                        gtk::Box {
+                            set_halign: gtk::Align::Center,
+                            set_spacing: 10,
                             #[track = "model.changed_status_bar_contents()"]
-                            set_visible: model.status_bar_contents.ok_button(),
+                            set_visible: !model.status_bar_contents.is_none(),
+
+                            gtk::Label {
+                                #[track = "model.changed_status_bar_contents()"]
+                                set_css_classes: &[model.status_bar_contents.css_class()],
+                                #[track = "model.changed_status_bar_contents()"]
+                                set_label: model.status_bar_contents.message(),
+                            },
+
+                            gtk::Button {
+                                add_css_class: "suggested-action",
+                                connect_clicked => AppInput::Restart,
+                                set_label: &T.status_bar_button_restart(),
+                                #[track = "model.changed_status_bar_contents()"]
+                                set_visible: model.status_bar_contents.restart_button(),
+                            },
+
+                            gtk::Button {
+                                connect_clicked => AppInput::RestartServices,
+                                set_label: &T.status_bar_button_restart_services(),
+                                set_tooltip: &T.status_bar_button_restart_services_tooltip(),
+                                #[track = "model.changed_status_bar_contents() || model.changed_current_view()"]
+                                set_visible: model.status_bar_contents.restart_services_button()
+                                    && matches!(model.current_view, View::Running),
+                            },
+
+                            gtk::Button {
+                                connect_clicked => AppInput::CloseStatusBarWarning,
+                                set_label: &T.status_bar_button_ok(),
+                                #[track = "model.changed_status_bar_contents()"]
+                                set_visible: model.status_bar_contents.ok_button(),
+                            },
                         },
                     },
                 },
@@ -491,15 +875,24 @@ impl AsyncComponent for App {
 
     menu! {
         main_menu_without_change_configuration: {
+            &T.main_menu_log_viewer() => MainMenuLogViewer,
             &T.main_menu_show_logs() => MainMenuShowLogs,
+            &T.main_menu_event_log() => MainMenuEventLog,
+            &T.main_menu_profiles() => MainMenuProfiles,
+            &T.main_menu_export_diagnostics() => MainMenuExportDiagnostics,
             &T.main_menu_share_feedback() => MainMenuShareFeedback,
             &T.main_menu_about() => MainMenuAbout,
             &T.main_menu_exit() => MainMenuExit,
         },
 
         main_menu: {
+            &T.main_menu_log_viewer() => MainMenuLogViewer,
             &T.main_menu_show_logs() => MainMenuShowLogs,
+            &T.main_menu_event_log() => MainMenuEventLog,
+            &T.main_menu_profiles() => MainMenuProfiles,
             &T.main_menu_change_configuration() => MainMenuChangeConfiguration,
+            &T.main_menu_restart_services() => MainMenuRestartServices,
+            &T.main_menu_export_diagnostics() => MainMenuExportDiagnostics,
             &T.main_menu_share_feedback() => MainMenuShareFeedback,
             &T.main_menu_about() => MainMenuAbout,
             &T.main_menu_exit() => MainMenuExit,
@@ -513,6 +906,8 @@ impl AsyncComponent for App {
     ) -> AsyncComponentParts<Self> {
         let AppInit {
             app_data_dir,
+            log_buffer,
+            log_filter_handle,
             exit_status_code,
             minimize_on_start,
             crash_notification,
@@ -540,7 +935,9 @@ impl AsyncComponent for App {
                 .drop_on_shutdown()
         });
 
-        let new_version = NewVersion::builder().launch(()).detach();
+        let new_version = NewVersion::builder()
+            .launch(())
+            .forward(sender.input_sender(), AppInput::NewVersion);
 
         let loading_view = LoadingView::builder().launch(()).detach();
 
@@ -548,13 +945,52 @@ impl AsyncComponent for App {
             .launch(root.clone())
             .forward(sender.input_sender(), AppInput::Configuration);
 
+        let ui_state = UiState::read().await;
+
         let running_view = RunningView::builder()
             .launch(RunningInit {
                 // Not paused on start
                 plotting_paused: false,
+                farming_paused: false,
+                farm_sort_order: ui_state.farm_sort_order,
+                compact_farms: ui_state.compact_farms,
             })
             .forward(sender.input_sender(), AppInput::Running);
 
+        let log_file = app_data_dir
+            .as_ref()
+            .map(|app_data_dir| app_data_dir.join(LOG_FILE_NAME));
+
+        let log_viewer = LogViewer::builder()
+            .launch(LogViewerInit {
+                log_buffer: Arc::clone(&log_buffer),
+                log_file: log_file.clone(),
+                parent: root.clone(),
+            })
+            .detach();
+
+        let event_log = EventLog::builder()
+            .launch(EventLogInit {
+                parent: root.clone(),
+            })
+            .detach();
+
+        let toasts = Toasts::builder().launch(()).detach();
+
+        let profiles = Profiles::builder()
+            .launch(ProfilesInit {
+                parent: root.clone(),
+            })
+            .forward(sender.input_sender(), AppInput::Profiles);
+
+        let diagnostics = Diagnostics::builder()
+            .launch(DiagnosticsInit {
+                log_buffer,
+                log_file,
+                parent: root.clone(),
+            })
+            .detach();
+
         let about_dialog = gtk::AboutDialog::builder()
             .title("About")
             .program_name("Space Acres")
@@ -567,21 +1003,11 @@ impl AsyncComponent for App {
             .comments(env!("CARGO_PKG_DESCRIPTION"))
             .logo(&gtk::gdk::Texture::for_pixbuf(&PIXBUF_ABOUT_IMG))
             .system_information({
-                let config_directory = dirs::config_local_dir()
-                    .map(|config_local_dir| {
-                        config_local_dir
-                            .join(env!("CARGO_PKG_NAME"))
-                            .display()
-                            .to_string()
-                    })
+                let config_directory = app_dirs::config_dir()
+                    .map(|config_dir| config_dir.display().to_string())
                     .unwrap_or_else(|| "Unknown".to_string());
-                let data_directory = dirs::data_local_dir()
-                    .map(|data_local_dir| {
-                        data_local_dir
-                            .join(env!("CARGO_PKG_NAME"))
-                            .display()
-                            .to_string()
-                    })
+                let data_directory = app_dirs::data_dir()
+                    .map(|data_dir| data_dir.display().to_string())
                     .unwrap_or_else(|| "Unknown".to_string());
 
                 T.about_system_information(config_directory, data_directory)
@@ -596,7 +1022,25 @@ impl AsyncComponent for App {
 
         // TODO: Re-enable macOS once https://github.com/subspace/space-acres/issues/183 and/or
         //  https://github.com/subspace/space-acres/issues/222 are resolved
-        let tray_icon = if cfg!(target_os = "macos") {
+        // Tray-related settings are read once on start, changing them requires restart
+        let maybe_raw_config = match RawConfig::default_path().await {
+            Ok(config_file_path) => RawConfig::read_from_path(&config_file_path)
+                .await
+                .ok()
+                .flatten(),
+            Err(_) => None,
+        };
+        let tray_icon_disabled = maybe_raw_config
+            .as_ref()
+            .map(RawConfig::disable_tray_icon)
+            .unwrap_or_default();
+        let minimize_to_tray = maybe_raw_config
+            .as_ref()
+            .map(RawConfig::minimize_to_tray)
+            .unwrap_or_default();
+        let tray_icon_state = TrayIconState::Loading;
+
+        let tray_icon = if cfg!(target_os = "macos") || tray_icon_disabled {
             None
         } else {
             let tray_icon = TrayIconBuilder::new();
@@ -607,20 +1051,22 @@ impl AsyncComponent for App {
             let tray_icon = tray_icon
                 .with_icon(Icon::from_resource(1, None).expect("Tray icon is a valid ICO; qed"));
             tray_icon
-                .with_tooltip("Space Acres")
-                .with_menu(Menu::new([
-                    MenuItem::button(T.tray_icon_open(), TrayMenuSignal::Open),
-                    MenuItem::button(T.tray_icon_close(), TrayMenuSignal::Close),
-                ]))
+                .with_tooltip(tray_icon_state.tooltip())
+                .with_menu(tray_icon_state.menu())
                 .build({
                     let sender = sender.clone();
-                    move |tray_event| {
-                        if let TrayEvent::Menu(signal) = tray_event {
-                            match signal {
-                                TrayMenuSignal::Open => sender.input(AppInput::ShowWindow),
-                                TrayMenuSignal::Close => sender.input(AppInput::ShutDown),
-                            }
+                    move |tray_event| match tray_event {
+                        TrayEvent::Tray(ClickType::Left) => {
+                            sender.input(AppInput::ToggleWindow);
                         }
+                        TrayEvent::Menu(signal) => match signal {
+                            TrayMenuSignal::Open => sender.input(AppInput::ShowWindow),
+                            TrayMenuSignal::TogglePause => {
+                                sender.input(AppInput::TogglePauseFarming)
+                            }
+                            TrayMenuSignal::Quit => sender.input(AppInput::ShutDown),
+                        },
+                        _ => {}
                     }
                 })
                 .map_err(|error| {
@@ -633,11 +1079,12 @@ impl AsyncComponent for App {
         let model = Self {
             current_view: View::Loading,
             current_raw_config: None,
+            active_profile: active_profile().await,
             status_bar_contents: if crash_notification {
                 StatusBarContents::Warning {
                     message: T.status_bar_message_restarted_after_crash().to_string(),
                     ok: true,
-                    restart: false,
+                    restart: RestartRequirement::None,
                 }
             } else {
                 StatusBarContents::None
@@ -647,18 +1094,44 @@ impl AsyncComponent for App {
             loading_view,
             configuration_view,
             running_view,
+            log_viewer,
+            event_log,
+            toasts,
+            profiles,
+            diagnostics,
             about_dialog,
             app_data_dir,
+            log_filter_handle,
             exit_status_code,
             loaded: false,
+            restart_requirement: RestartRequirement::None,
             backend_fut: Some(backend_fut),
-            _tray_icon: tray_icon,
+            tray_icon,
+            tray_icon_state,
+            running_status: None,
             tracker: u8::MAX,
         };
 
         let widgets = view_output!();
 
+        // Restore window size from previous run, but make sure it still fits on the screen in
+        // case monitor configuration changed since then
+        if let Some(window_state) = ui_state.window {
+            let window_state = window_state.clamp_to_monitors(MIN_WINDOW_WIDTH, MIN_WINDOW_HEIGHT);
+            root.set_default_size(window_state.width, window_state.height);
+            if window_state.maximized {
+                root.maximize();
+            }
+        }
+
         let mut menu_actions_group = RelmActionGroup::<MainMenu>::new();
+        menu_actions_group.add_action(RelmAction::<MainMenuLogViewer>::new_stateless({
+            let sender = sender.clone();
+
+            move |_| {
+                sender.input(AppInput::OpenLogViewer);
+            }
+        }));
         menu_actions_group.add_action(RelmAction::<MainMenuShowLogs>::new_stateless({
             let sender = sender.clone();
 
@@ -666,6 +1139,20 @@ impl AsyncComponent for App {
                 sender.input(AppInput::OpenLogsFolder);
             }
         }));
+        menu_actions_group.add_action(RelmAction::<MainMenuEventLog>::new_stateless({
+            let sender = sender.clone();
+
+            move |_| {
+                sender.input(AppInput::OpenEventLog);
+            }
+        }));
+        menu_actions_group.add_action(RelmAction::<MainMenuProfiles>::new_stateless({
+            let sender = sender.clone();
+
+            move |_| {
+                sender.input(AppInput::OpenProfiles);
+            }
+        }));
         menu_actions_group.add_action(RelmAction::<MainMenuChangeConfiguration>::new_stateless({
             let sender = sender.clone();
 
@@ -673,6 +1160,20 @@ impl AsyncComponent for App {
                 sender.input(AppInput::ChangeConfiguration);
             }
         }));
+        menu_actions_group.add_action(RelmAction::<MainMenuRestartServices>::new_stateless({
+            let sender = sender.clone();
+
+            move |_| {
+                sender.input(AppInput::RestartServices);
+            }
+        }));
+        menu_actions_group.add_action(RelmAction::<MainMenuExportDiagnostics>::new_stateless({
+            let sender = sender.clone();
+
+            move |_| {
+                sender.input(AppInput::ExportDiagnostics);
+            }
+        }));
         menu_actions_group.add_action(RelmAction::<MainMenuShareFeedback>::new_stateless({
             let sender = sender.clone();
 
@@ -716,7 +1217,7 @@ impl AsyncComponent for App {
             let sender = sender.clone();
 
             move |_root| {
-                sender.input(if has_tray_icon {
+                sender.input(if has_tray_icon && minimize_to_tray {
                     AppInput::HideWindow
                 } else {
                     AppInput::ShutDown
@@ -761,9 +1262,29 @@ impl AsyncComponent for App {
         self.reset();
 
         match input {
+            AppInput::ShowToast(toast) => {
+                self.toasts.emit(ToastsInput::Show(toast));
+            }
             AppInput::OpenLogsFolder => {
                 self.open_log_folder();
             }
+            AppInput::OpenLogViewer => {
+                self.log_viewer.emit(LogViewerInput::Show);
+            }
+            AppInput::OpenEventLog => {
+                self.event_log.emit(EventLogInput::Show);
+            }
+            AppInput::OpenProfiles => {
+                self.profiles.emit(ProfilesInput::Show);
+            }
+            AppInput::Profiles(ProfilesOutput::Switched { name }) => {
+                self.switch_profile(name, &sender).await;
+            }
+            AppInput::ExportDiagnostics => {
+                self.diagnostics.emit(DiagnosticsInput::Show {
+                    raw_config: self.current_raw_config.clone(),
+                });
+            }
             AppInput::Configuration(configuration_output) => {
                 self.process_configuration_output(configuration_output)
                     .await;
@@ -771,6 +1292,10 @@ impl AsyncComponent for App {
             AppInput::Running(running_output) => {
                 self.process_running_output(running_output).await;
             }
+            AppInput::NewVersion(NewVersionOutput::LatestVersion(version)) => {
+                self.configuration_view
+                    .emit(ConfigurationInput::LatestVersion(version.to_string()));
+            }
             AppInput::ChangeConfiguration => {
                 let configuration_already_opened = matches!(
                     self.current_view,
@@ -790,11 +1315,17 @@ impl AsyncComponent for App {
             AppInput::OpenFeedbackLink => {
                 if let Err(error) = open::that_detached("https://linktr.ee/autonomys_network") {
                     error!(%error, "Failed to open share feedback page in default browser");
+                    self.toasts.emit(ToastsInput::Show(Toast::warning(
+                        T.toast_failed_to_open(error.to_string()).to_string(),
+                    )));
                 }
             }
             AppInput::OpenCommunityHelpLink => {
                 if let Err(error) = open::that_detached("https://docs.autonomys.xyz/docs/farming-&-staking/farming/space-acres/space-acres-install#troubleshooting") {
                     error!(%error, "Failed to open share community help in default browser");
+                    self.toasts.emit(ToastsInput::Show(Toast::warning(
+                        T.toast_failed_to_open(error.to_string()).to_string(),
+                    )));
                 }
             }
             AppInput::ShowAboutDialog => {
@@ -813,11 +1344,78 @@ impl AsyncComponent for App {
                 });
                 self.set_current_view(View::Loading);
             }
+            AppInput::RetryStartupNow => {
+                if let Err(error) = self
+                    .backend_action_sender
+                    .send(BackendAction::RetryStartupNow)
+                    .await
+                {
+                    self.set_current_view(View::Error(
+                        T.error_message_failed_to_send_startup_retry_to_backend(error.to_string())
+                            .to_string(),
+                    ));
+                }
+            }
+            AppInput::CancelStartupRetry => {
+                // Backend will report original error as irrecoverable in response
+                if let Err(error) = self
+                    .backend_action_sender
+                    .send(BackendAction::CancelStartupRetry)
+                    .await
+                {
+                    self.set_current_view(View::Error(
+                        T.error_message_failed_to_send_startup_retry_to_backend(error.to_string())
+                            .to_string(),
+                    ));
+                }
+            }
+            AppInput::ResolveFarmMismatches { resolutions } => {
+                if let Err(error) = self
+                    .backend_action_sender
+                    .send(BackendAction::ResolveFarmMismatches { resolutions })
+                    .await
+                {
+                    self.set_current_view(View::Error(
+                        T.error_message_failed_to_send_farm_mismatch_resolutions_to_backend(
+                            error.to_string(),
+                        )
+                        .to_string(),
+                    ));
+                }
+            }
             AppInput::Restart => {
                 self.exit_status_code.set(AppStatusCode::Restart);
                 // Delegate to exit to do the rest
                 sender.input(AppInput::ShutDown);
             }
+            AppInput::RestartServices => {
+                // Node and farmer can only be restarted once they are running
+                if !matches!(self.current_view, View::Running) {
+                    return;
+                }
+
+                self.set_current_view(View::RestartingServices { step: None });
+                self.event_log
+                    .emit(EventLogInput::Record(EventKind::ServicesRestarting));
+                if self.restart_requirement == RestartRequirement::Services {
+                    // Configuration from disk will be applied, no need to ask for restart anymore
+                    self.restart_requirement = RestartRequirement::None;
+                    self.set_status_bar_contents(StatusBarContents::None);
+                }
+
+                if let Err(error) = self
+                    .backend_action_sender
+                    .send(BackendAction::RestartServices)
+                    .await
+                {
+                    self.set_current_view(View::Error(
+                        T.error_message_failed_to_send_restart_services_to_backend(
+                            error.to_string(),
+                        )
+                        .to_string(),
+                    ));
+                }
+            }
             AppInput::CloseStatusBarWarning => {
                 self.set_status_bar_contents(StatusBarContents::None);
             }
@@ -827,19 +1425,77 @@ impl AsyncComponent for App {
             AppInput::ShowWindow => {
                 root.present();
             }
+            AppInput::ToggleWindow => {
+                if root.is_visible() {
+                    root.hide();
+                } else {
+                    root.present();
+                }
+            }
+            AppInput::TogglePauseFarming => {
+                if matches!(self.current_view, View::Running) {
+                    self.running_view.emit(RunningInput::TogglePauseFarming);
+                }
+            }
             AppInput::ShutDown => {
-                self.set_current_view(View::ShuttingDown);
+                let window_state = WindowState {
+                    width: root.default_width(),
+                    height: root.default_height(),
+                    maximized: root.is_maximized(),
+                };
+                if let Err(error) = UiState::update(|ui_state| {
+                    ui_state.window.replace(window_state);
+                })
+                .await
+                {
+                    warn!(%error, "Failed to save UI state");
+                }
+
                 // Make sure user sees that shutdown is happening in case it is called from tray
                 // icon
                 root.present();
 
-                let backend_fut = self.backend_fut.take();
-                sender.spawn_oneshot_command(|| {
-                    drop(backend_fut);
-                    AppCommandOutput::Quit
-                });
+                // Shutdown might be requested again while already in progress
+                if let Some(backend_fut) = self.backend_fut.take() {
+                    self.set_current_view(View::ShuttingDown {
+                        step: None,
+                        force_quit_available: false,
+                    });
+
+                    if self.loaded {
+                        // Backend is running (or has already exited), ask it to stop gracefully and
+                        // wait for it to finish flushing everything to disk
+                        let mut backend_action_sender = self.backend_action_sender.clone();
+                        sender.oneshot_command(async move {
+                            if let Err(error) =
+                                backend_action_sender.send(BackendAction::Shutdown).await
+                            {
+                                debug!(%error, "Failed to request backend shutdown");
+                            }
+                            Box::into_pin(backend_fut).await;
+                            AppCommandOutput::Quit
+                        });
+                    } else {
+                        // Backend is still loading, there is nothing to flush yet
+                        sender.spawn_oneshot_command(|| {
+                            drop(backend_fut);
+                            AppCommandOutput::Quit
+                        });
+                    }
+
+                    sender.oneshot_command(async {
+                        tokio::time::sleep(FORCE_QUIT_TIMEOUT).await;
+                        AppCommandOutput::ForceQuitAvailable
+                    });
+                }
+            }
+            AppInput::ForceQuit => {
+                warn!("Force quitting before backend has stopped, data may not be fully flushed");
+                relm4::main_application().quit();
             }
         }
+
+        self.update_tray_icon();
     }
 
     async fn update_cmd(
@@ -852,22 +1508,114 @@ impl AsyncComponent for App {
         self.reset();
 
         self.process_command(input, sender);
+
+        self.update_tray_icon();
     }
 }
 
 impl App {
+    /// Show transient notification on top of the main window, can be called from anywhere in the
+    /// UI
+    pub fn show_toast(toast: Toast) {
+        APP_BROKER.send(AppInput::ShowToast(toast));
+    }
+
+    fn update_tray_icon(&mut self) {
+        let Some(tray_icon) = &self.tray_icon else {
+            return;
+        };
+
+        let tray_icon_state = match &self.current_view {
+            View::Running => self
+                .running_status
+                .map_or(TrayIconState::Loading, TrayIconState::Running),
+            View::Stopped(Some(_)) | View::Error(_) => TrayIconState::Error,
+            _ => TrayIconState::Loading,
+        };
+        if tray_icon_state == self.tray_icon_state {
+            return;
+        }
+        self.tray_icon_state = tray_icon_state;
+
+        tray_icon.set_tooltip(tray_icon_state.tooltip());
+        tray_icon.set_menu(Some(tray_icon_state.menu()));
+        #[cfg(target_os = "linux")]
+        if let Some(icon) = tray_icon_state.icon() {
+            tray_icon.set_icon(Some(icon));
+        }
+    }
+
+    /// Configuration of the new profile is applied by starting node and farmer again, whole
+    /// application is restarted when backend is not in a state where it can do that
+    async fn switch_profile(&mut self, name: String, sender: &AsyncComponentSender<Self>) {
+        self.event_log
+            .emit(EventLogInput::Record(EventKind::ProfileSwitched {
+                profile: name.clone(),
+            }));
+        self.set_active_profile(name);
+        // Configuration of the previous profile must not be offered for the new one
+        self.set_current_raw_config(None);
+        self.configuration_view
+            .emit(ConfigurationInput::Reinitialize {
+                raw_config: RawConfig::default(),
+                reconfiguration: false,
+            });
+
+        match self.current_view {
+            View::Running | View::Reconfiguration => {
+                self.set_current_view(View::Running);
+                sender.input(AppInput::RestartServices);
+            }
+            View::Welcome | View::Configuration => {
+                // Backend is waiting for configuration, restart makes it read configuration of the
+                // new profile instead
+                self.set_current_view(View::Loading);
+                if let Err(error) = self
+                    .backend_action_sender
+                    .send(BackendAction::RestartServices)
+                    .await
+                {
+                    self.set_current_view(View::Error(
+                        T.error_message_failed_to_send_restart_services_to_backend(
+                            error.to_string(),
+                        )
+                        .to_string(),
+                    ));
+                }
+            }
+            _ => {
+                sender.input(AppInput::Restart);
+            }
+        }
+    }
+
+    /// Apply settings that take effect without restart
+    fn apply_immediate_settings(&self, raw_config: &RawConfig) {
+        self.log_filter_handle.set_log_level(raw_config.log_level());
+        apply_theme(raw_config.theme());
+        let mut updates = raw_config.updates();
+        // Offline mode overrides update settings without changing them
+        updates.disable_checks |= raw_config.offline_mode();
+        self.new_version
+            .emit(NewVersionInput::UpdatesConfiguration(updates));
+    }
+
     fn open_log_folder(&mut self) {
         let Some(app_data_dir) = &self.app_data_dir else {
             return;
         };
         if let Err(error) = open::that_detached(app_data_dir) {
             error!(%error, path = %app_data_dir.display(), "Failed to open logs folder");
+            self.toasts.emit(ToastsInput::Show(Toast::warning(
+                T.toast_failed_to_open(error.to_string()).to_string(),
+            )));
         }
     }
 
     async fn process_configuration_output(&mut self, configuration_output: ConfigurationOutput) {
         match configuration_output {
             ConfigurationOutput::StartWithNewConfig(raw_config) => {
+                self.apply_immediate_settings(&raw_config);
                 if let Err(error) = self
                     .backend_action_sender
                     .send(BackendAction::NewConfig { raw_config })
@@ -880,6 +1628,18 @@ impl App {
                 }
             }
             ConfigurationOutput::ConfigUpdate(raw_config) => {
+                let restart_requirement = self
+                    .current_raw_config
+                    .as_ref()
+                    .map_or(RestartRequirement::Application, |previous_raw_config| {
+                        raw_config.restart_requirement(previous_raw_config)
+                    });
+                self.restart_requirement = self.restart_requirement.max(restart_requirement);
+                // Settings that don't require restart are applied right away
+                self.apply_immediate_settings(&raw_config);
+                self.running_view.emit(RunningInput::ConfigUpdate {
+                    raw_config: raw_config.clone(),
+                });
                 self.get_mut_current_raw_config()
                     .replace(raw_config.clone());
                 // Config is updated when application is already running, switch to corresponding screen
@@ -896,10 +1656,19 @@ impl App {
                 }
             }
             ConfigurationOutput::Back => {
+                // Revert theme preview since configuration was not saved
+                apply_theme(Theme::default());
                 // Back to welcome screen
                 self.set_current_view(View::Welcome);
             }
             ConfigurationOutput::Close => {
+                // Revert theme preview since configuration was not saved
+                apply_theme(
+                    self.current_raw_config
+                        .as_ref()
+                        .map(RawConfig::theme)
+                        .unwrap_or_default(),
+                );
                 // Configuration view is closed when application is already running, switch to
                 // corresponding screen
                 if self.loaded {
@@ -913,6 +1682,12 @@ impl App {
 
     async fn process_running_output(&mut self, running_output: RunningOutput) {
         match running_output {
+            RunningOutput::StatusChanged(status) => {
+                self.running_status.replace(status);
+            }
+            RunningOutput::Event(event_kind) => {
+                self.event_log.emit(EventLogInput::Record(event_kind));
+            }
             RunningOutput::PausePlotting(pause_plotting) => {
                 if let Err(error) = self
                     .backend_action_sender
@@ -927,6 +1702,129 @@ impl App {
                     ));
                 }
             }
+            RunningOutput::PauseFarming(pause_farming) => {
+                if let Err(error) = self
+                    .backend_action_sender
+                    .send(BackendAction::Farmer(FarmerAction::PauseFarming(
+                        pause_farming,
+                    )))
+                    .await
+                {
+                    self.set_current_view(View::Error(
+                        T.error_message_failed_to_send_pause_farming_to_backend(error.to_string())
+                            .to_string(),
+                    ));
+                }
+            }
+            RunningOutput::RestartFarm { farm_index } => {
+                if let Err(error) = self
+                    .backend_action_sender
+                    .send(BackendAction::Farmer(FarmerAction::RestartFarm(
+                        usize::from(farm_index),
+                    )))
+                    .await
+                {
+                    self.set_current_view(View::Error(
+                        T.error_message_failed_to_send_restart_farm_to_backend(error.to_string())
+                            .to_string(),
+                    ));
+                }
+            }
+            RunningOutput::ResizeFarm {
+                farm_index,
+                allocated_space,
+            } => {
+                if let Err(error) = self
+                    .backend_action_sender
+                    .send(BackendAction::Farmer(FarmerAction::ResizeFarm {
+                        farm_index: usize::from(farm_index),
+                        allocated_space,
+                    }))
+                    .await
+                {
+                    self.set_current_view(View::Error(
+                        T.error_message_failed_to_send_resize_farm_to_backend(error.to_string())
+                            .to_string(),
+                    ));
+                }
+            }
+            RunningOutput::FarmResized { path, size } => {
+                let Some(raw_config) = self.get_mut_current_raw_config() else {
+                    return;
+                };
+                for farm in raw_config.farms_mut() {
+                    if farm.path == path {
+                        farm.size.clone_from(&size);
+                    }
+                }
+                let raw_config = raw_config.clone();
+
+                if let Err(error) = self
+                    .backend_action_sender
+                    .send(BackendAction::NewConfig { raw_config })
+                    .await
+                {
+                    self.set_current_view(View::Error(
+                        T.error_message_failed_to_send_config_to_backend(error.to_string())
+                            .to_string(),
+                    ));
+                }
+            }
+            RunningOutput::SetFarmDisabled {
+                farm_index,
+                path,
+                disabled,
+                known_to_farmer,
+            } => {
+                if !known_to_farmer {
+                    // Farmer only opens farms that were enabled on startup
+                    self.restart_requirement =
+                        self.restart_requirement.max(RestartRequirement::Services);
+                }
+                let farm_index = usize::from(farm_index);
+                let farmer_action = if disabled {
+                    FarmerAction::StopFarm(farm_index)
+                } else {
+                    FarmerAction::StartFarm(farm_index)
+                };
+                if known_to_farmer
+                    && let Err(error) = self
+                        .backend_action_sender
+                        .send(BackendAction::Farmer(farmer_action))
+                        .await
+                {
+                    let error = error.to_string();
+                    self.set_current_view(View::Error(if disabled {
+                        T.error_message_failed_to_send_stop_farm_to_backend(error)
+                            .to_string()
+                    } else {
+                        T.error_message_failed_to_send_start_farm_to_backend(error)
+                            .to_string()
+                    }));
+                    return;
+                }
+
+                let Some(raw_config) = self.get_mut_current_raw_config() else {
+                    return;
+                };
+                for farm in raw_config.farms_mut() {
+                    if farm.path == path {
+                        farm.disabled = disabled;
+                    }
+                }
+                let raw_config = raw_config.clone();
+
+                if let Err(error) = self
+                    .backend_action_sender
+                    .send(BackendAction::NewConfig { raw_config })
+                    .await
+                {
+                    self.set_current_view(View::Error(
+                        T.error_message_failed_to_send_config_to_backend(error.to_string())
+                            .to_string(),
+                    ));
+                }
+            }
         }
     }
 
@@ -938,6 +1836,15 @@ impl App {
             AppCommandOutput::Restart => {
                 sender.input(AppInput::Restart);
             }
+            AppCommandOutput::ForceQuitAvailable => {
+                if let View::ShuttingDown { step, .. } = &self.current_view {
+                    let step = *step;
+                    self.set_current_view(View::ShuttingDown {
+                        step,
+                        force_quit_available: true,
+                    });
+                }
+            }
             AppCommandOutput::Quit => {
                 relm4::main_application().quit();
             }
@@ -951,12 +1858,32 @@ impl App {
     ) {
         debug!(?notification, "New backend notification");
 
+        if let View::ShuttingDown {
+            force_quit_available,
+            ..
+        } = &self.current_view
+        {
+            // Only shutdown progress is relevant at this point
+            if let BackendNotification::ShuttingDown(step) = notification {
+                let force_quit_available = *force_quit_available;
+                self.set_current_view(View::ShuttingDown {
+                    step: Some(step),
+                    force_quit_available,
+                });
+            }
+            return;
+        }
+
         match notification {
             BackendNotification::Loading(step) => {
+                // Backend might be loading again after restart of node and farmer, in which case
+                // there is nothing to flush on shutdown until it is running again
+                self.loaded = false;
                 self.set_current_view(View::Loading);
                 self.loading_view.emit(LoadingInput::BackendLoading(step));
             }
             BackendNotification::ConfigurationFound { raw_config } => {
+                self.apply_immediate_settings(&raw_config);
                 self.get_mut_current_raw_config()
                     .replace(raw_config.clone());
             }
@@ -967,6 +1894,9 @@ impl App {
             }
             BackendNotification::NotConfigured => {
                 if self.current_raw_config.is_none() {
+                    // Nothing was configured yet, so checks are done with default settings
+                    self.new_version
+                        .emit(NewVersionInput::UpdatesConfiguration(Default::default()));
                     self.set_current_view(View::Welcome);
                 } else {
                     self.set_current_view(View::Configuration);
@@ -986,17 +1916,30 @@ impl App {
                         .as_str()
                         .to_string(),
                     ok: true,
-                    restart: false,
+                    restart: RestartRequirement::None,
                 });
             }
             BackendNotification::ConfigSaveResult(result) => match result {
                 Ok(()) => {
+                    let message = match self.restart_requirement {
+                        RestartRequirement::None => {
+                            // Changes were already applied while running
+                            self.toasts.emit(ToastsInput::Show(Toast::info(
+                                T.toast_configuration_saved().to_string(),
+                            )));
+                            return;
+                        }
+                        RestartRequirement::Services => {
+                            T.status_bar_message_services_restart_is_needed_for_configuration()
+                        }
+                        RestartRequirement::Application => {
+                            T.status_bar_message_restart_is_needed_for_configuration()
+                        }
+                    };
                     self.set_status_bar_contents(StatusBarContents::Warning {
-                        message: T
-                            .status_bar_message_restart_is_needed_for_configuration()
-                            .to_string(),
+                        message: message.to_string(),
                         ok: false,
-                        restart: true,
+                        restart: self.restart_requirement,
                     });
                 }
                 Err(error) => {
@@ -1012,23 +1955,29 @@ impl App {
                 best_block_number,
                 reward_address_balance,
                 initial_farm_states,
+                piece_cache_capacity,
                 cache_percentage,
                 chain_info,
                 chain_constants,
+                peer_id,
             } => {
                 self.loaded = true;
                 self.get_mut_current_raw_config()
                     .replace(raw_config.clone());
                 self.set_current_view(View::Running);
+                self.event_log
+                    .emit(EventLogInput::Record(EventKind::ServicesStarted));
                 self.running_view.emit(RunningInput::Initialize {
                     best_block_number,
                     reward_address_balance,
                     initial_farm_states,
+                    piece_cache_capacity,
                     cache_percentage,
                     config,
                     raw_config,
                     chain_info,
                     chain_constants,
+                    peer_id,
                 });
             }
             BackendNotification::Node(node_notification) => {
@@ -1040,6 +1989,11 @@ impl App {
                     .emit(RunningInput::FarmerNotification(farmer_notification));
             }
             BackendNotification::Stopped { error } => {
+                self.event_log
+                    .emit(EventLogInput::Record(EventKind::ServicesStopped {
+                        error: error.as_ref().map(|error| error.to_string()),
+                    }));
+
                 sender.spawn_command(|_sender| {
                     let mut notification = Notification::new();
                     notification
@@ -1055,7 +2009,39 @@ impl App {
 
                 self.set_current_view(View::Stopped(error));
             }
+            BackendNotification::FarmMismatches { mismatches } => {
+                show_farm_mismatches_dialog(mismatches, move |resolutions| {
+                    sender.input(AppInput::ResolveFarmMismatches { resolutions });
+                });
+            }
+            BackendNotification::StartupRetry {
+                error,
+                attempt,
+                retry_in,
+            } => {
+                self.event_log
+                    .emit(EventLogInput::Record(EventKind::StartupFailed {
+                        attempt,
+                        error: error.clone(),
+                    }));
+                self.set_current_view(View::StartupRetry {
+                    error,
+                    attempt,
+                    retry_in,
+                });
+            }
+            BackendNotification::ShuttingDown(step) => {
+                // Only expected after shutdown was requested, which is handled above, or during
+                // restart of node and farmer
+                if let View::RestartingServices { .. } = &self.current_view {
+                    self.set_current_view(View::RestartingServices { step: Some(step) });
+                }
+            }
             BackendNotification::IrrecoverableError { error } => {
+                self.event_log
+                    .emit(EventLogInput::Record(EventKind::IrrecoverableError {
+                        error: error.to_string(),
+                    }));
                 self.set_current_view(View::Error(error.to_string()));
             }
         }
@@ -1,12 +1,27 @@
 mod farm;
-mod utils;
-
-use crate::backend::config::{NetworkConfiguration, RawConfig};
+pub(super) mod utils;
+
+use crate::backend::config::{
+    available_cpu_threads, default_sector_encoding_concurrency, estimated_plotting_memory,
+    farm_cache_percentage, is_external_address_valid, is_metrics_address_valid,
+    is_node_rpc_address_public, is_node_rpc_address_valid, is_node_rpc_url_valid, LogLevel,
+    MetricsConfiguration, NetworkConfiguration, NodeRpcConfiguration, RawConfig, Theme,
+    UpdateCheckInterval, UpdatesConfiguration, MAX_SECTOR_ENCODING_CONCURRENCY,
+    PIECE_CACHE_SIZE_WARNING_THRESHOLD,
+};
+use crate::backend::farmer::connect_to_node_rpc;
 use crate::frontend::configuration::farm::{
     FarmWidget, FarmWidgetInit, FarmWidgetInput, FarmWidgetOutput,
 };
-use crate::frontend::configuration::utils::is_directory_writable;
-use crate::frontend::translations::{AsDefaultStr, T};
+use crate::frontend::configuration::utils::{
+    available_memory, available_space, find_duplicate_paths, find_node_path_conflicts,
+    is_directory_writable, retain_existing_paths, suggest_farms, FarmSuggestion,
+};
+use crate::frontend::toasts::Toast;
+use crate::frontend::translations::{available_languages, language_name, AsDefaultStr, T};
+use crate::frontend::ui_state::UiState;
+use crate::frontend::{apply_theme, App};
+use bytesize::ByteSize;
 use gtk::glib;
 use gtk::prelude::*;
 use relm4::factory::AsyncFactoryVecDeque;
@@ -14,43 +29,171 @@ use relm4::prelude::*;
 use relm4_components::open_dialog::{
     OpenDialog, OpenDialogMsg, OpenDialogResponse, OpenDialogSettings,
 };
+use relm4_components::save_dialog::{
+    SaveDialog, SaveDialogMsg, SaveDialogResponse, SaveDialogSettings,
+};
 use relm4_icons::icon_name;
+use sp_core::crypto::Ss58Codec;
+use sp_core::sr25519::Public;
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::iter;
+use std::num::NonZeroU8;
 use std::ops::Deref;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::str::FromStr;
 use subspace_farmer::utils::ss58::parse_ss58_reward_address;
 use tracing::{debug, error, warn};
 
+/// Percentage of currently available RAM plotting is allowed to use before user is warned, the rest
+/// is left for node, farming and other applications
+const PLOTTING_MEMORY_SAFE_PERCENTAGE: u64 = 75;
+/// Size of suggested farms, leaves some space on the disk for other uses
+const SUGGESTED_FARM_SIZE: &str = "90%";
+/// Maximum number of recently selected farm directories to remember
+const MAX_RECENT_FARM_DIRECTORIES: usize = 10;
+/// Free space on node path below which warning is shown, node database grows steadily over time
+const NODE_PATH_FREE_SPACE_WARNING_THRESHOLD: u64 = ByteSize::gib(100).as_u64();
+/// Default file name suggested when exporting configuration
+const EXPORTED_CONFIG_FILE_NAME: &str = "space-acres-config.json";
+/// SS58 prefix of the Subspace network
+const SUBSPACE_SS58_PREFIX: u16 = 2254;
+/// Generic Substrate SS58 prefix, the same key is usable on any Substrate-based chain with it
+const GENERIC_SUBSTRATE_SS58_PREFIX: u16 = 42;
+
+/// SS58 prefix of the reward address, `None` if address is not in SS58 format
+fn reward_address_ss58_prefix(reward_address: &str) -> Option<u16> {
+    Public::from_ss58check_with_version(reward_address)
+        .ok()
+        .map(|(_public, format)| format.prefix())
+}
+
+fn is_reward_address_prefix_supported(prefix: u16) -> bool {
+    prefix == SUBSPACE_SS58_PREFIX || prefix == GENERIC_SUBSTRATE_SS58_PREFIX
+}
+
+fn is_reward_address_valid(reward_address: &str) -> bool {
+    parse_ss58_reward_address(reward_address).is_ok()
+        && reward_address_ss58_prefix(reward_address)
+            .map(is_reward_address_prefix_supported)
+            .unwrap_or_default()
+}
+
+/// Empty piece cache size means minimal cache is used
+fn is_piece_cache_size_valid(piece_cache_size: &str) -> bool {
+    piece_cache_size.is_empty() || ByteSize::from_str(piece_cache_size).is_ok()
+}
+
+fn is_piece_cache_size_low(piece_cache_size: &str) -> bool {
+    ByteSize::from_str(piece_cache_size)
+        .map(|piece_cache_size| piece_cache_size.as_u64() < PIECE_CACHE_SIZE_WARNING_THRESHOLD)
+        .unwrap_or_default()
+}
+
+fn json_file_filter() -> gtk::FileFilter {
+    let filter = gtk::FileFilter::new();
+    filter.set_name(Some(&T.configuration_dialog_filter_json()));
+    filter.add_suffix("json");
+    filter
+}
+
+fn toml_file_filter() -> gtk::FileFilter {
+    let filter = gtk::FileFilter::new();
+    filter.set_name(Some(&T.configuration_dialog_filter_toml()));
+    filter.add_suffix("toml");
+    filter
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum DirectoryKind {
     NodePath,
+    PieceCachePath,
     FarmPath(DynamicIndex),
 }
 
 #[derive(Debug)]
 pub enum ConfigurationInput {
     AddFarm,
+    SuggestFarms,
+    FarmSearchChanged(String),
+    FarmCollapsedChanged {
+        path: PathBuf,
+        collapsed: bool,
+    },
     RewardAddressChanged(String),
     CreateWallet,
     OpenDirectory(DirectoryKind),
     DirectorySelected(PathBuf),
+    OpenImportConfig,
+    ImportConfig(PathBuf),
+    OpenExportConfig,
+    ExportConfig(PathBuf),
     SubstratePortChanged(u16),
     SubspacePortChanged(u16),
     FasterNetworkingChanged(bool),
+    NodeRpcUrlChanged(String),
+    ExternalAddressChanged(String),
+    CheckNodeRpcUrl,
+    MetricsEnabledChanged(bool),
+    MetricsAddressChanged(String),
+    NodeRpcEnabledChanged(bool),
+    NodeRpcAddressChanged(String),
+    ControlApiChanged(bool),
     ReducePlottingCpuLoadChanged(bool),
+    PlottingThreadsChanged(usize),
+    SectorEncodingConcurrencyChanged(usize),
+    AuditingThreadsChanged(usize),
+    /// Reduce sector encoding concurrency to fit into available RAM
+    ReduceSectorEncodingConcurrency(usize),
+    PieceCacheSizeChanged(String),
+    PieceCachePathCleared,
+    RewardNotificationsChanged(bool),
+    PlottingNotificationsChanged(bool),
+    FarmHealthNotificationsChanged(bool),
+    TrayIconChanged(bool),
+    MinimizeToTrayChanged(bool),
+    ThemeChanged(Theme),
+    LanguageChanged(Option<String>),
+    LogLevelChanged(LogLevel),
+    OfflineModeChanged(bool),
+    UpdateChecksChanged(bool),
+    UpdateCheckIntervalChanged(UpdateCheckInterval),
+    UpdateAutoDownloadChanged(bool),
+    /// Latest released version of the application
+    LatestVersion(String),
     Delete(DynamicIndex),
+    MoveFarm {
+        from: usize,
+        to: DynamicIndex,
+    },
     Reinitialize {
         raw_config: RawConfig,
         reconfiguration: bool,
     },
     Help,
     Start,
+    /// Start after plotting memory usage was checked or confirmed by user
+    StartConfirmed,
     Back,
     Cancel,
     Save,
+    /// Save after plotting memory usage was checked or confirmed by user
+    SaveConfirmed,
     UpdateFarms,
+    UpdateFarmsSummary,
     Ignore,
 }
 
+#[derive(Debug)]
+pub enum ConfigurationCommandOutput {
+    NodeRpcUrlChecked {
+        url: String,
+        result: Result<(), String>,
+    },
+    FarmsSuggested(Vec<FarmSuggestion>),
+}
+
 #[derive(Debug)]
 pub enum ConfigurationOutput {
     StartWithNewConfig(RawConfig),
@@ -136,6 +279,15 @@ impl From<NetworkConfiguration> for NetworkConfigurationWrapper {
     }
 }
 
+/// Result of connection attempt to external node
+#[derive(Debug, Clone, PartialEq)]
+enum NodeRpcCheck {
+    NotChecked,
+    Checking,
+    Connected,
+    Failed(String),
+}
+
 #[tracker::track]
 #[derive(Debug)]
 pub struct ConfigurationView {
@@ -143,16 +295,91 @@ pub struct ConfigurationView {
     reward_address: MaybeValid<String>,
     #[do_not_track]
     node_path: MaybeValid<PathBuf>,
+    node_path_free_space: Option<u64>,
+    /// Whether node path is the same as one of the farm paths or nested with it
+    node_path_conflict: bool,
     #[no_eq]
     farms: AsyncFactoryVecDeque<FarmWidget>,
+    /// Search query farms are filtered by, empty if all farms are shown
+    #[do_not_track]
+    farm_search: String,
+    /// Whether farm at corresponding index matches search query, shared with filter function of
+    /// the list box
+    #[do_not_track]
+    farms_visibility: Rc<RefCell<Vec<bool>>>,
     #[do_not_track]
     network_configuration: NetworkConfigurationWrapper,
+    /// Optional RPC URL of external node, empty if embedded node is used
+    #[do_not_track]
+    node_rpc_url: MaybeValid<String>,
+    node_rpc_check: NodeRpcCheck,
+    /// Optional external DSN address, empty if not used
+    #[do_not_track]
+    external_address: MaybeValid<String>,
+    /// Whether Prometheus metrics endpoint is enabled
+    metrics_enabled: bool,
+    #[do_not_track]
+    metrics_address: MaybeValid<String>,
+    /// Whether embedded node RPC is exposed on custom address
+    node_rpc_enabled: bool,
+    #[do_not_track]
+    node_rpc_address: MaybeValid<String>,
+    /// Whether local control socket is enabled
+    control_api: bool,
     reduce_plotting_cpu_load: bool,
+    /// Number of CPU threads for plotting, all available threads used by default
+    plotting_threads: usize,
+    /// Number of sectors encoded concurrently, derived from CPU topology by default
+    sector_encoding_concurrency: usize,
+    /// Total number of CPU threads for auditing across all farms, each farm uses thread pool sized
+    /// for the CPU when all available threads are selected
+    auditing_threads: usize,
+    /// Number of enabled farms if explicitly specified auditing threads are fewer than farms, each
+    /// farm needs at least one thread
+    too_few_auditing_threads: Option<usize>,
+    /// Optional piece cache size, empty if minimal cache is used
+    #[do_not_track]
+    piece_cache_size: MaybeValid<String>,
+    /// Whether piece cache size is below recommended minimum
+    piece_cache_size_low: bool,
+    /// Optional dedicated piece cache directory, empty if piece cache is stored within farms
+    #[do_not_track]
+    piece_cache_path: MaybeValid<PathBuf>,
+    /// Whether piece cache path is the same as one of the farm paths or nested with it
+    piece_cache_path_conflict: bool,
+    reward_notifications: bool,
+    plotting_notifications: bool,
+    farm_health_notifications: bool,
+    tray_icon: bool,
+    minimize_to_tray: bool,
+    theme: Theme,
+    /// Language of user interface, `None` if system language is used
+    language: Option<String>,
+    /// Log level, `None` if not selected explicitly
+    log_level: Option<LogLevel>,
+    updates: UpdatesConfiguration,
+    /// Whether outbound connections that are not necessary for farming are suppressed
+    offline_mode: bool,
+    /// Latest released version of the application, `None` if not checked yet
+    latest_version: Option<String>,
+    /// Recently selected farm directories, most recent first
+    #[do_not_track]
+    recent_farm_directories: Vec<PathBuf>,
     #[do_not_track]
     pending_directory_selection: Option<DirectoryKind>,
     #[do_not_track]
     open_dialog: Controller<OpenDialog>,
     #[do_not_track]
+    import_dialog: Controller<OpenDialog>,
+    #[do_not_track]
+    export_dialog: Controller<SaveDialog>,
+    /// Error that happened during last configuration import or export
+    config_file_error: Option<String>,
+    /// Disks are being scanned for farm suggestions
+    suggesting_farms: bool,
+    /// Last scan didn't find any disks that are not used by farms already
+    no_farm_suggestions: bool,
+    #[do_not_track]
     reconfiguration: bool,
 }
 
@@ -161,7 +388,7 @@ impl AsyncComponent for ConfigurationView {
     type Init = gtk::Window;
     type Input = ConfigurationInput;
     type Output = ConfigurationOutput;
-    type CommandOutput = ();
+    type CommandOutput = ConfigurationCommandOutput;
 
     view! {
         #[root]
@@ -237,6 +464,22 @@ impl AsyncComponent for ConfigurationView {
                                         ),
                                         set_label: &T.configuration_node_path_button_select(),
                                     },
+
+                                    gtk::Label {
+                                        add_css_class: "dim-label",
+                                        set_margin_start: 10,
+                                        #[track = "model.changed_node_path_free_space()"]
+                                        set_label: &match model.node_path_free_space {
+                                            Some(free_space) => T
+                                                .configuration_node_path_free_space(
+                                                    ByteSize::b(free_space).to_string_as(true),
+                                                )
+                                                .to_string(),
+                                            None => T.configuration_node_path_free_space_unknown().to_string(),
+                                        },
+                                        #[track = "model.node_path.changed_value()"]
+                                        set_visible: model.node_path.value != PathBuf::new(),
+                                    },
                                 },
 
                                 gtk::Label {
@@ -246,6 +489,30 @@ impl AsyncComponent for ConfigurationView {
                                     #[track = "self.node_path.changed_is_valid()"]
                                     set_visible: !model.node_path.is_valid && model.node_path.value != PathBuf::new(),
                                 },
+
+                                gtk::Label {
+                                    add_css_class: "error-label",
+                                    set_halign: gtk::Align::Start,
+                                    set_label: &T.configuration_node_path_error_farm_path_conflict(),
+                                    #[track = "model.changed_node_path_conflict()"]
+                                    set_visible: model.node_path_conflict,
+                                },
+
+                                gtk::Label {
+                                    add_css_class: "warning-label",
+                                    set_halign: gtk::Align::Start,
+                                    set_label: T
+                                        .configuration_node_path_warning_low_free_space(
+                                            ByteSize::b(NODE_PATH_FREE_SPACE_WARNING_THRESHOLD).to_string_as(true),
+                                        )
+                                        .as_str(),
+                                    #[track = "model.node_path.changed_is_valid() || model.changed_node_path_free_space()"]
+                                    set_visible: model.node_path.is_valid
+                                        && model.node_path_free_space
+                                            .map(|free_space| free_space < NODE_PATH_FREE_SPACE_WARNING_THRESHOLD)
+                                            .unwrap_or_default(),
+                                    set_wrap: true,
+                                },
                             },
                         },
                         gtk::ListBoxRow {
@@ -317,16 +584,52 @@ impl AsyncComponent for ConfigurationView {
                                     #[track = "model.reward_address.changed_value() || self.reward_address.changed_is_valid()"]
                                     set_visible: !model.reward_address.is_valid && model.reward_address.value.starts_with("0x"),
                                 },
+
+                                gtk::Label {
+                                    add_css_class: "error-label",
+                                    set_halign: gtk::Align::Start,
+                                    #[track = "model.reward_address.changed_value() || model.reward_address.changed_is_valid()"]
+                                    set_label: &reward_address_ss58_prefix(&model.reward_address)
+                                        .map(|prefix| {
+                                            T
+                                                .configuration_reward_address_error_wrong_network(
+                                                    prefix,
+                                                    SUBSPACE_SS58_PREFIX,
+                                                )
+                                                .as_str()
+                                                .to_string()
+                                        })
+                                        .unwrap_or_default(),
+                                    #[track = "model.reward_address.changed_value() || model.reward_address.changed_is_valid()"]
+                                    set_visible: reward_address_ss58_prefix(&model.reward_address)
+                                        .map(|prefix| !is_reward_address_prefix_supported(prefix))
+                                        .unwrap_or_default(),
+                                    set_wrap: true,
+                                },
                             },
                         },
                     },
 
+                    gtk::SearchEntry {
+                        connect_search_changed[sender] => move |entry| {
+                            sender.input(ConfigurationInput::FarmSearchChanged(entry.text().into()));
+                        },
+                        set_placeholder_text: Some(&T.configuration_farms_search_placeholder()),
+                    },
+
                     // TODO: This should be the same list box as above, but then farms will
                     //  unfortunately render before other fields
                     #[local_ref]
                     configuration_list_box -> gtk::ListBox {
                     },
 
+                    gtk::Label {
+                        add_css_class: "dim-label",
+                        set_halign: gtk::Align::Start,
+                        #[track = "model.changed_farms()"]
+                        set_label: &model.farms_summary(),
+                    },
+
                     gtk::Expander {
                         set_label: Some(&T.configuration_advanced()),
 
@@ -368,45 +671,34 @@ impl AsyncComponent for ConfigurationView {
                                             set_tooltip: &T.configuration_advanced_farmer_reduce_plotting_cpu_load_tooltip(),
                                         },
                                     },
-                                },
-
-                                gtk::Label {
-                                    add_css_class: "heading",
-                                    set_halign: gtk::Align::Start,
-                                    set_label: &T.configuration_advanced_network(),
-                                },
-
-                                gtk::Box {
-                                    set_orientation: gtk::Orientation::Vertical,
-                                    set_spacing: 10,
 
                                     gtk::Box {
                                         set_spacing: 10,
 
                                         gtk::Label {
-                                            set_label: &T.configuration_advanced_network_substrate_port()
+                                            set_label: &T.configuration_advanced_farmer_plotting_threads(),
                                         },
                                         gtk::SpinButton {
                                             connect_value_changed[sender] => move |entry| {
-                                                sender.input(ConfigurationInput::SubstratePortChanged(
-                                                    entry.value().round() as u16
+                                                sender.input(ConfigurationInput::PlottingThreadsChanged(
+                                                    entry.value().round() as usize
                                                 ));
                                             },
                                             set_adjustment: &gtk::Adjustment::new(
-                                                0.0,
-                                                0.0,
-                                                u16::MAX as f64,
+                                                available_cpu_threads() as f64,
+                                                1.0,
+                                                available_cpu_threads() as f64,
                                                 1.0,
                                                 0.0,
                                                 0.0,
                                             ),
                                             set_tooltip: T
-                                                .configuration_advanced_network_default_port_number_tooltip(
-                                                    NetworkConfiguration::default().substrate_port
+                                                .configuration_advanced_farmer_plotting_threads_tooltip(
+                                                    available_cpu_threads()
                                                 )
                                                 .as_str(),
-                                            #[track = "model.network_configuration.changed_substrate_port()"]
-                                            set_value: model.network_configuration.substrate_port as f64,
+                                            #[track = "model.changed_plotting_threads()"]
+                                            set_value: model.plotting_threads as f64,
                                             set_width_chars: 5,
                                         },
                                     },
@@ -415,152 +707,998 @@ impl AsyncComponent for ConfigurationView {
                                         set_spacing: 10,
 
                                         gtk::Label {
-                                            set_label: &T.configuration_advanced_network_subspace_port(),
+                                            set_label: &T.configuration_advanced_farmer_sector_encoding_concurrency(),
                                         },
                                         gtk::SpinButton {
                                             connect_value_changed[sender] => move |entry| {
-                                                sender.input(ConfigurationInput::SubspacePortChanged(
-                                                    entry.value().round() as u16
+                                                sender.input(ConfigurationInput::SectorEncodingConcurrencyChanged(
+                                                    entry.value().round() as usize
                                                 ));
                                             },
                                             set_adjustment: &gtk::Adjustment::new(
+                                                default_sector_encoding_concurrency(None) as f64,
+                                                1.0,
+                                                MAX_SECTOR_ENCODING_CONCURRENCY as f64,
+                                                1.0,
                                                 0.0,
                                                 0.0,
-                                                u16::MAX as f64,
+                                            ),
+                                            set_tooltip: &T.configuration_advanced_farmer_sector_encoding_concurrency_tooltip(),
+                                            #[track = "model.changed_sector_encoding_concurrency()"]
+                                            set_value: model.sector_encoding_concurrency as f64,
+                                            set_width_chars: 5,
+                                        },
+                                        gtk::Label {
+                                            add_css_class: "dim-label",
+                                            #[track = "model.changed_sector_encoding_concurrency()"]
+                                            set_label: T
+                                                .configuration_advanced_farmer_sector_encoding_concurrency_memory(
+                                                    estimated_plotting_memory(
+                                                        model.sector_encoding_concurrency
+                                                    )
+                                                    .to_string_as(true),
+                                                )
+                                                .as_str(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_farmer_auditing_threads(),
+                                        },
+                                        gtk::SpinButton {
+                                            connect_value_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::AuditingThreadsChanged(
+                                                    entry.value().round() as usize
+                                                ));
+                                            },
+                                            set_adjustment: &gtk::Adjustment::new(
+                                                available_cpu_threads() as f64,
+                                                1.0,
+                                                available_cpu_threads() as f64,
                                                 1.0,
                                                 0.0,
                                                 0.0,
                                             ),
                                             set_tooltip: T
-                                                .configuration_advanced_network_default_port_number_tooltip(
-                                                    NetworkConfiguration::default().subspace_port
+                                                .configuration_advanced_farmer_auditing_threads_tooltip(
+                                                    available_cpu_threads()
                                                 )
                                                 .as_str(),
-                                            #[track = "model.network_configuration.changed_subspace_port()"]
-                                            set_value: model.network_configuration.subspace_port as f64,
+                                            #[track = "model.changed_auditing_threads()"]
+                                            set_value: model.auditing_threads as f64,
                                             set_width_chars: 5,
                                         },
+                                        gtk::Label {
+                                            add_css_class: "error-label",
+                                            #[track = "model.changed_too_few_auditing_threads()"]
+                                            set_label: &T
+                                                .configuration_advanced_farmer_auditing_threads_error_too_few(
+                                                    model.too_few_auditing_threads.unwrap_or_default()
+                                                )
+                                                .to_string(),
+                                            #[track = "model.changed_too_few_auditing_threads()"]
+                                            set_visible: model.too_few_auditing_threads.is_some(),
+                                        },
                                     },
 
                                     gtk::Box {
                                         set_spacing: 10,
 
                                         gtk::Label {
-                                            set_label: &T.configuration_advanced_network_faster_networking(),
+                                            set_label: &T.configuration_advanced_farmer_piece_cache_size(),
                                         },
-                                        gtk::Switch {
-                                            connect_state_set[sender] => move |_switch, state| {
-                                                sender.input(ConfigurationInput::FasterNetworkingChanged(
-                                                    state
+                                        gtk::Entry {
+                                            connect_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::PieceCacheSizeChanged(
+                                                    entry.text().into()
                                                 ));
-
-                                                glib::Propagation::Proceed
                                             },
-                                            #[track = "model.network_configuration.changed_faster_networking()"]
-                                            set_active: model.network_configuration.faster_networking,
-                                            set_tooltip: &T.configuration_advanced_network_faster_networking_tooltip(),
+                                            #[track = "model.piece_cache_size.changed_is_valid()"]
+                                            set_css_classes: if model.piece_cache_size.is_valid {
+                                                &["valid-input"]
+                                            } else {
+                                                &["invalid-input"]
+                                            },
+                                            set_placeholder_text: Some("10 GiB"),
+                                            #[track = "model.piece_cache_size.changed_is_valid()"]
+                                            set_secondary_icon_name: model.piece_cache_size.icon(),
+                                            set_secondary_icon_activatable: false,
+                                            set_secondary_icon_sensitive: false,
+                                            #[track = "model.piece_cache_size.changed_value()"]
+                                            set_text: &model.piece_cache_size,
+                                            set_tooltip: &T.configuration_advanced_farmer_piece_cache_size_tooltip(),
+                                            set_width_chars: 12,
+                                        },
+                                        gtk::Label {
+                                            add_css_class: "warning-label",
+                                            set_label: &T.configuration_advanced_farmer_piece_cache_size_low(),
+                                            #[track = "model.changed_piece_cache_size_low()"]
+                                            set_visible: model.piece_cache_size_low,
+                                            set_wrap: true,
                                         },
                                     },
-                                },
-                            },
-                        },
-                    },
 
-                    gtk::Box {
-                        gtk::Box {
-                            set_halign: gtk::Align::Start,
-                            set_hexpand: true,
-                            set_spacing: 10,
-
-                            gtk::Button {
-                                connect_clicked => ConfigurationInput::AddFarm,
+                                    gtk::Box {
+                                        set_spacing: 10,
 
-                                gtk::Label {
-                                    set_label: &T.configuration_button_add_farm(),
-                                    set_margin_all: 10,
-                                },
-                            },
-                        },
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_farmer_piece_cache_path(),
+                                        },
+                                        gtk::Box {
+                                            add_css_class: "linked",
+
+                                            gtk::Entry {
+                                                set_can_focus: false,
+                                                #[track = "model.piece_cache_path.changed_is_valid()"]
+                                                set_css_classes: if model.piece_cache_path.is_valid {
+                                                    &["valid-input"]
+                                                } else {
+                                                    &["invalid-input"]
+                                                },
+                                                set_editable: false,
+                                                set_hexpand: true,
+                                                set_placeholder_text: Some(
+                                                    &T.configuration_advanced_farmer_piece_cache_path_placeholder(),
+                                                ),
+                                                set_primary_icon_name: Some(icon_name::SSD),
+                                                set_primary_icon_activatable: false,
+                                                set_primary_icon_sensitive: false,
+                                                #[track = "model.piece_cache_path.changed_value() || model.piece_cache_path.changed_is_valid()"]
+                                                set_secondary_icon_name: if model.piece_cache_path.value == PathBuf::new() {
+                                                    None
+                                                } else {
+                                                    model.piece_cache_path.icon()
+                                                },
+                                                set_secondary_icon_activatable: false,
+                                                set_secondary_icon_sensitive: false,
+                                                #[track = "model.piece_cache_path.changed_value()"]
+                                                set_text: model.piece_cache_path.display().to_string().as_str(),
+                                                set_tooltip: &T.configuration_advanced_farmer_piece_cache_path_tooltip(),
+                                            },
 
-                        if model.reconfiguration {
-                            gtk::Box {
-                                set_halign: gtk::Align::End,
-                                set_spacing: 10,
+                                            gtk::Button {
+                                                connect_clicked => ConfigurationInput::OpenDirectory(
+                                                    DirectoryKind::PieceCachePath
+                                                ),
+                                                set_label: &T.configuration_advanced_farmer_piece_cache_path_button_select(),
+                                            },
 
-                                gtk::Button {
-                                    connect_clicked => ConfigurationInput::Help,
+                                            gtk::Button {
+                                                connect_clicked => ConfigurationInput::PieceCachePathCleared,
+                                                set_icon_name: icon_name::CROSS,
+                                                set_tooltip: &T.configuration_advanced_farmer_piece_cache_path_button_clear(),
+                                                #[track = "model.piece_cache_path.changed_value()"]
+                                                set_visible: model.piece_cache_path.value != PathBuf::new(),
+                                            },
+                                        },
+                                    },
 
                                     gtk::Label {
-                                        set_label: &T.configuration_button_help(),
-                                        set_margin_all: 10,
+                                        add_css_class: "error-label",
+                                        set_halign: gtk::Align::Start,
+                                        set_label: &T.configuration_node_path_error_doesnt_exist_or_write_permissions(),
+                                        #[track = "model.piece_cache_path.changed_is_valid()"]
+                                        set_visible: !model.piece_cache_path.is_valid,
                                     },
-                                },
-
-                                gtk::Button {
-                                    connect_clicked => ConfigurationInput::Cancel,
 
                                     gtk::Label {
-                                        set_label: &T.configuration_button_cancel(),
-                                        set_margin_all: 10,
+                                        add_css_class: "error-label",
+                                        set_halign: gtk::Align::Start,
+                                        set_label: &T.configuration_advanced_farmer_piece_cache_path_error_farm_path_conflict(),
+                                        #[track = "model.changed_piece_cache_path_conflict()"]
+                                        set_visible: model.piece_cache_path_conflict,
+                                        set_wrap: true,
                                     },
-                                },
 
-                                gtk::Button {
-                                    add_css_class: "suggested-action",
-                                    connect_clicked => ConfigurationInput::Save,
-                                    #[track = "model.reward_address.changed_is_valid() || model.node_path.changed_is_valid() || model.changed_farms()"]
-                                    set_sensitive:
-                                        model.reward_address.is_valid
-                                            && model.node_path.is_valid
-                                            && !model.farms.is_empty()
-                                            && model.farms.iter().all(|maybe_farm| maybe_farm.map(FarmWidget::valid).unwrap_or_default()),
+                                    gtk::Box {
+                                        set_spacing: 10,
 
-                                    gtk::Label {
-                                        set_label: &T.configuration_button_save(),
-                                        set_margin_all: 10,
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_farmer_reward_notifications(),
+                                        },
+                                        gtk::Switch {
+                                            connect_state_set[sender] => move |_switch, state| {
+                                                sender.input(ConfigurationInput::RewardNotificationsChanged(
+                                                    state
+                                                ));
+
+                                                glib::Propagation::Proceed
+                                            },
+                                            #[track = "model.changed_reward_notifications()"]
+                                            set_active: model.reward_notifications,
+                                            set_tooltip: &T.configuration_advanced_farmer_reward_notifications_tooltip(),
+                                        },
                                     },
-                                },
-                            }
-                        } else {
-                            gtk::Box {
-                                set_halign: gtk::Align::End,
-                                set_spacing: 10,
 
-                                gtk::Button {
-                                    connect_clicked => ConfigurationInput::Help,
+                                    gtk::Box {
+                                        set_spacing: 10,
 
-                                    gtk::Label {
-                                        set_label: &T.configuration_button_help(),
-                                        set_margin_all: 10,
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_farmer_plotting_notifications(),
+                                        },
+                                        gtk::Switch {
+                                            connect_state_set[sender] => move |_switch, state| {
+                                                sender.input(ConfigurationInput::PlottingNotificationsChanged(
+                                                    state
+                                                ));
+
+                                                glib::Propagation::Proceed
+                                            },
+                                            #[track = "model.changed_plotting_notifications()"]
+                                            set_active: model.plotting_notifications,
+                                            set_tooltip: &T.configuration_advanced_farmer_plotting_notifications_tooltip(),
+                                        },
                                     },
-                                },
 
-                                gtk::Button {
-                                    connect_clicked => ConfigurationInput::Back,
+                                    gtk::Box {
+                                        set_spacing: 10,
 
-                                    gtk::Label {
-                                        set_label: &T.configuration_button_back(),
-                                        set_margin_all: 10,
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_farmer_farm_health_notifications(),
+                                        },
+                                        gtk::Switch {
+                                            connect_state_set[sender] => move |_switch, state| {
+                                                sender.input(ConfigurationInput::FarmHealthNotificationsChanged(
+                                                    state
+                                                ));
+
+                                                glib::Propagation::Proceed
+                                            },
+                                            #[track = "model.changed_farm_health_notifications()"]
+                                            set_active: model.farm_health_notifications,
+                                            set_tooltip: &T.configuration_advanced_farmer_farm_health_notifications_tooltip(),
+                                        },
                                     },
                                 },
 
-                                gtk::Button {
-                                    add_css_class: "suggested-action",
-                                    connect_clicked => ConfigurationInput::Start,
-                                    #[track = "model.reward_address.changed_is_valid() || model.node_path.changed_is_valid() || model.changed_farms()"]
-                                    set_sensitive:
-                                        model.reward_address.is_valid
-                                            && model.node_path.is_valid
-                                            && !model.farms.is_empty()
-                                            && model.farms.iter().all(|maybe_farm| maybe_farm.map(FarmWidget::valid).unwrap_or_default()),
-
-                                    gtk::Label {
-                                        set_label: &T.configuration_button_start(),
-                                        set_margin_all: 10,
-                                    },
+                                gtk::Label {
+                                    add_css_class: "heading",
+                                    set_halign: gtk::Align::Start,
+                                    set_label: &T.configuration_advanced_application(),
                                 },
-                            }
-                        },
+
+                                gtk::Box {
+                                    set_orientation: gtk::Orientation::Vertical,
+                                    set_spacing: 10,
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_application_tray_icon(),
+                                        },
+                                        gtk::Switch {
+                                            connect_state_set[sender] => move |_switch, state| {
+                                                sender.input(ConfigurationInput::TrayIconChanged(
+                                                    state
+                                                ));
+
+                                                glib::Propagation::Proceed
+                                            },
+                                            #[track = "model.changed_tray_icon()"]
+                                            set_active: model.tray_icon,
+                                            set_tooltip: &T.configuration_advanced_application_tray_icon_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_application_minimize_to_tray(),
+                                        },
+                                        gtk::Switch {
+                                            connect_state_set[sender] => move |_switch, state| {
+                                                sender.input(ConfigurationInput::MinimizeToTrayChanged(
+                                                    state
+                                                ));
+
+                                                glib::Propagation::Proceed
+                                            },
+                                            #[track = "model.changed_minimize_to_tray()"]
+                                            set_active: model.minimize_to_tray,
+                                            #[track = "model.changed_tray_icon()"]
+                                            set_sensitive: model.tray_icon,
+                                            set_tooltip: &T.configuration_advanced_application_minimize_to_tray_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_application_theme(),
+                                        },
+                                        gtk::DropDown::from_strings(&[
+                                            &*T.configuration_advanced_application_theme_system(),
+                                            &*T.configuration_advanced_application_theme_light(),
+                                            &*T.configuration_advanced_application_theme_dark(),
+                                            &*T.configuration_advanced_application_theme_high_contrast(),
+                                        ]) {
+                                            connect_selected_notify[sender] => move |drop_down| {
+                                                if let Some(&theme) = Theme::ALL.get(drop_down.selected() as usize) {
+                                                    sender.input(ConfigurationInput::ThemeChanged(theme));
+                                                }
+                                            },
+                                            #[track = "model.changed_theme()"]
+                                            set_selected: Theme::ALL
+                                                .iter()
+                                                .position(|theme| *theme == model.theme)
+                                                .unwrap_or_default() as u32,
+                                            set_tooltip: &T.configuration_advanced_application_theme_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_application_language(),
+                                        },
+                                        gtk::DropDown::from_strings(
+                                            &iter::once(&*T.configuration_advanced_application_language_system())
+                                                .chain(available_languages().iter().map(|language| language_name(language)))
+                                                .collect::<Vec<_>>()
+                                        ) {
+                                            connect_selected_notify[sender] => move |drop_down| {
+                                                // First option is system language
+                                                let language = (drop_down.selected() as usize)
+                                                    .checked_sub(1)
+                                                    .and_then(|index| available_languages().get(index))
+                                                    .map(|language| language.to_string());
+                                                sender.input(ConfigurationInput::LanguageChanged(language));
+                                            },
+                                            #[track = "model.changed_language()"]
+                                            set_selected: model
+                                                .language
+                                                .as_ref()
+                                                .and_then(|language| {
+                                                    available_languages()
+                                                        .iter()
+                                                        .position(|available_language| available_language == language)
+                                                })
+                                                .map_or(0, |index| index + 1) as u32,
+                                            set_tooltip: &T.configuration_advanced_application_language_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_application_log_level(),
+                                        },
+                                        gtk::DropDown::from_strings(&[
+                                            &*T.configuration_advanced_application_log_level_error(),
+                                            &*T.configuration_advanced_application_log_level_warn(),
+                                            &*T.configuration_advanced_application_log_level_info(),
+                                            &*T.configuration_advanced_application_log_level_debug(),
+                                            &*T.configuration_advanced_application_log_level_trace(),
+                                        ]) {
+                                            connect_selected_notify[sender] => move |drop_down| {
+                                                if let Some(&log_level) = LogLevel::ALL.get(drop_down.selected() as usize) {
+                                                    sender.input(ConfigurationInput::LogLevelChanged(log_level));
+                                                }
+                                            },
+                                            #[track = "model.changed_log_level()"]
+                                            set_selected: LogLevel::ALL
+                                                .iter()
+                                                .position(|log_level| *log_level == model.log_level.unwrap_or_default())
+                                                .unwrap_or_default() as u32,
+                                            set_tooltip: &T.configuration_advanced_application_log_level_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Label {
+                                        add_css_class: "warning-label",
+                                        set_halign: gtk::Align::Start,
+                                        set_label: &T.configuration_advanced_application_log_level_verbose_warning(),
+                                        #[track = "model.changed_log_level()"]
+                                        set_visible: model.log_level.is_some_and(|log_level| log_level.is_verbose()),
+                                        set_wrap: true,
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_application_offline_mode(),
+                                        },
+                                        gtk::Switch {
+                                            connect_state_set[sender] => move |_switch, state| {
+                                                sender.input(ConfigurationInput::OfflineModeChanged(
+                                                    state
+                                                ));
+
+                                                glib::Propagation::Proceed
+                                            },
+                                            #[track = "model.changed_offline_mode()"]
+                                            set_active: model.offline_mode,
+                                            set_tooltip: &T.configuration_advanced_application_offline_mode_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_application_update_checks(),
+                                        },
+                                        gtk::Switch {
+                                            connect_state_set[sender] => move |_switch, state| {
+                                                sender.input(ConfigurationInput::UpdateChecksChanged(
+                                                    state
+                                                ));
+
+                                                glib::Propagation::Proceed
+                                            },
+                                            #[track = "model.changed_updates()"]
+                                            set_active: !model.updates.disable_checks,
+                                            #[track = "model.changed_offline_mode()"]
+                                            set_sensitive: !model.offline_mode,
+                                            set_tooltip: &T.configuration_advanced_application_update_checks_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_application_update_check_interval(),
+                                        },
+                                        gtk::DropDown::from_strings(&[
+                                            &*T.configuration_advanced_application_update_check_interval_hourly(),
+                                            &*T.configuration_advanced_application_update_check_interval_daily(),
+                                            &*T.configuration_advanced_application_update_check_interval_weekly(),
+                                        ]) {
+                                            connect_selected_notify[sender] => move |drop_down| {
+                                                if let Some(&check_interval) = UpdateCheckInterval::ALL.get(drop_down.selected() as usize) {
+                                                    sender.input(ConfigurationInput::UpdateCheckIntervalChanged(check_interval));
+                                                }
+                                            },
+                                            #[track = "model.changed_updates()"]
+                                            set_selected: UpdateCheckInterval::ALL
+                                                .iter()
+                                                .position(|check_interval| *check_interval == model.updates.check_interval)
+                                                .unwrap_or_default() as u32,
+                                            #[track = "model.changed_updates() || model.changed_offline_mode()"]
+                                            set_sensitive: !(model.updates.disable_checks || model.offline_mode),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_application_update_auto_download(),
+                                        },
+                                        gtk::Switch {
+                                            connect_state_set[sender] => move |_switch, state| {
+                                                sender.input(ConfigurationInput::UpdateAutoDownloadChanged(
+                                                    state
+                                                ));
+
+                                                glib::Propagation::Proceed
+                                            },
+                                            #[track = "model.changed_updates()"]
+                                            set_active: model.updates.auto_download,
+                                            #[track = "model.changed_updates() || model.changed_offline_mode()"]
+                                            set_sensitive: !(model.updates.disable_checks || model.offline_mode),
+                                            set_tooltip: &T.configuration_advanced_application_update_auto_download_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Label {
+                                        add_css_class: "dim-label",
+                                        set_halign: gtk::Align::Start,
+                                        #[track = "model.changed_latest_version()"]
+                                        set_label: &match &model.latest_version {
+                                            Some(latest_version) => T
+                                                .configuration_advanced_application_versions(
+                                                    env!("CARGO_PKG_VERSION"),
+                                                    latest_version.as_str(),
+                                                )
+                                                .to_string(),
+                                            None => T
+                                                .configuration_advanced_application_versions_latest_unknown(
+                                                    env!("CARGO_PKG_VERSION"),
+                                                )
+                                                .to_string(),
+                                        },
+                                    },
+                                },
+
+                                gtk::Label {
+                                    add_css_class: "heading",
+                                    set_halign: gtk::Align::Start,
+                                    set_label: &T.configuration_advanced_network(),
+                                },
+
+                                gtk::Box {
+                                    set_orientation: gtk::Orientation::Vertical,
+                                    set_spacing: 10,
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_substrate_port()
+                                        },
+                                        gtk::SpinButton {
+                                            connect_value_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::SubstratePortChanged(
+                                                    entry.value().round() as u16
+                                                ));
+                                            },
+                                            set_adjustment: &gtk::Adjustment::new(
+                                                0.0,
+                                                1.0,
+                                                u16::MAX as f64,
+                                                1.0,
+                                                0.0,
+                                                0.0,
+                                            ),
+                                            set_tooltip: T
+                                                .configuration_advanced_network_default_port_number_tooltip(
+                                                    NetworkConfiguration::default().substrate_port
+                                                )
+                                                .as_str(),
+                                            #[track = "model.network_configuration.changed_substrate_port()"]
+                                            set_value: model.network_configuration.substrate_port as f64,
+                                            set_width_chars: 5,
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_subspace_port(),
+                                        },
+                                        gtk::SpinButton {
+                                            connect_value_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::SubspacePortChanged(
+                                                    entry.value().round() as u16
+                                                ));
+                                            },
+                                            set_adjustment: &gtk::Adjustment::new(
+                                                0.0,
+                                                1.0,
+                                                u16::MAX as f64,
+                                                1.0,
+                                                0.0,
+                                                0.0,
+                                            ),
+                                            set_tooltip: T
+                                                .configuration_advanced_network_default_port_number_tooltip(
+                                                    NetworkConfiguration::default().subspace_port
+                                                )
+                                                .as_str(),
+                                            #[track = "model.network_configuration.changed_subspace_port()"]
+                                            set_value: model.network_configuration.subspace_port as f64,
+                                            set_width_chars: 5,
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_external_address(),
+                                        },
+                                        gtk::Entry {
+                                            connect_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::ExternalAddressChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            #[track = "model.external_address.changed_is_valid()"]
+                                            set_css_classes: if model.external_address.is_valid {
+                                                &["valid-input"]
+                                            } else {
+                                                &["invalid-input"]
+                                            },
+                                            set_hexpand: true,
+                                            set_placeholder_text: Some("/ip4/203.0.113.1/tcp/30433"),
+                                            #[track = "model.external_address.changed_is_valid()"]
+                                            set_secondary_icon_name: model.external_address.icon(),
+                                            set_secondary_icon_activatable: false,
+                                            set_secondary_icon_sensitive: false,
+                                            #[track = "model.external_address.changed_value()"]
+                                            set_text: &model.external_address,
+                                            set_tooltip: &T.configuration_advanced_network_external_address_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_faster_networking(),
+                                        },
+                                        gtk::Switch {
+                                            connect_state_set[sender] => move |_switch, state| {
+                                                sender.input(ConfigurationInput::FasterNetworkingChanged(
+                                                    state
+                                                ));
+
+                                                glib::Propagation::Proceed
+                                            },
+                                            #[track = "model.network_configuration.changed_faster_networking()"]
+                                            set_active: model.network_configuration.faster_networking,
+                                            set_tooltip: &T.configuration_advanced_network_faster_networking_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_node_rpc_url(),
+                                        },
+                                        gtk::Box {
+                                            add_css_class: "linked",
+                                            set_hexpand: true,
+
+                                            gtk::Entry {
+                                                connect_changed[sender] => move |entry| {
+                                                    sender.input(ConfigurationInput::NodeRpcUrlChanged(
+                                                        entry.text().into()
+                                                    ));
+                                                },
+                                                #[track = "model.node_rpc_url.changed_is_valid()"]
+                                                set_css_classes: if model.node_rpc_url.is_valid {
+                                                    &["valid-input"]
+                                                } else {
+                                                    &["invalid-input"]
+                                                },
+                                                set_hexpand: true,
+                                                set_placeholder_text: Some("ws://127.0.0.1:9944"),
+                                                #[track = "model.node_rpc_url.changed_is_valid()"]
+                                                set_secondary_icon_name: model.node_rpc_url.icon(),
+                                                set_secondary_icon_activatable: false,
+                                                set_secondary_icon_sensitive: false,
+                                                #[track = "model.node_rpc_url.changed_value()"]
+                                                set_text: &model.node_rpc_url,
+                                                set_tooltip: &T.configuration_advanced_network_node_rpc_url_tooltip(),
+                                            },
+
+                                            gtk::Button {
+                                                connect_clicked => ConfigurationInput::CheckNodeRpcUrl,
+                                                set_label: &T.configuration_advanced_network_node_rpc_url_button_check(),
+                                                #[track = "model.node_rpc_url.changed_is_valid() || model.changed_node_rpc_check()"]
+                                                set_sensitive: model.node_rpc_url.is_valid
+                                                    && !model.node_rpc_url.is_empty()
+                                                    && model.node_rpc_check != NodeRpcCheck::Checking,
+                                            },
+                                        },
+
+                                        gtk::Spinner {
+                                            #[track = "model.changed_node_rpc_check()"]
+                                            set_spinning: model.node_rpc_check == NodeRpcCheck::Checking,
+                                            #[track = "model.changed_node_rpc_check()"]
+                                            set_visible: model.node_rpc_check == NodeRpcCheck::Checking,
+                                        },
+                                    },
+
+                                    gtk::Label {
+                                        #[track = "model.changed_node_rpc_check()"]
+                                        set_css_classes: match model.node_rpc_check {
+                                            NodeRpcCheck::Connected => &["success-label"],
+                                            _ => &["error-label"],
+                                        },
+                                        set_halign: gtk::Align::Start,
+                                        #[track = "model.changed_node_rpc_check()"]
+                                        set_label: &match &model.node_rpc_check {
+                                            NodeRpcCheck::NotChecked | NodeRpcCheck::Checking => String::new(),
+                                            NodeRpcCheck::Connected => {
+                                                T.configuration_advanced_network_node_rpc_url_connected().to_string()
+                                            }
+                                            NodeRpcCheck::Failed(error) => {
+                                                T
+                                                    .configuration_advanced_network_node_rpc_url_failed(error.as_str())
+                                                    .as_str()
+                                                    .to_string()
+                                            }
+                                        },
+                                        #[track = "model.changed_node_rpc_check()"]
+                                        set_visible: matches!(
+                                            model.node_rpc_check,
+                                            NodeRpcCheck::Connected | NodeRpcCheck::Failed(_)
+                                        ),
+                                        set_wrap: true,
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_metrics(),
+                                        },
+                                        gtk::Switch {
+                                            connect_state_set[sender] => move |_switch, state| {
+                                                sender.input(ConfigurationInput::MetricsEnabledChanged(
+                                                    state
+                                                ));
+
+                                                glib::Propagation::Proceed
+                                            },
+                                            #[track = "model.changed_metrics_enabled()"]
+                                            set_active: model.metrics_enabled,
+                                            set_tooltip: &T.configuration_advanced_network_metrics_tooltip(),
+                                            set_valign: gtk::Align::Center,
+                                        },
+                                        gtk::Entry {
+                                            connect_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::MetricsAddressChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            #[track = "model.metrics_address.changed_is_valid()"]
+                                            set_css_classes: if model.metrics_address.is_valid {
+                                                &["valid-input"]
+                                            } else {
+                                                &["invalid-input"]
+                                            },
+                                            set_hexpand: true,
+                                            set_placeholder_text: Some("127.0.0.1:9616"),
+                                            #[track = "model.metrics_address.changed_is_valid()"]
+                                            set_secondary_icon_name: model.metrics_address.icon(),
+                                            set_secondary_icon_activatable: false,
+                                            set_secondary_icon_sensitive: false,
+                                            #[track = "model.changed_metrics_enabled()"]
+                                            set_sensitive: model.metrics_enabled,
+                                            #[track = "model.metrics_address.changed_value()"]
+                                            set_text: &model.metrics_address,
+                                            set_tooltip: &T.configuration_advanced_network_metrics_address_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_node_rpc(),
+                                        },
+                                        gtk::Switch {
+                                            connect_state_set[sender] => move |_switch, state| {
+                                                sender.input(ConfigurationInput::NodeRpcEnabledChanged(
+                                                    state
+                                                ));
+
+                                                glib::Propagation::Proceed
+                                            },
+                                            #[track = "model.changed_node_rpc_enabled()"]
+                                            set_active: model.node_rpc_enabled,
+                                            set_tooltip: &T.configuration_advanced_network_node_rpc_tooltip(),
+                                            set_valign: gtk::Align::Center,
+                                        },
+                                        gtk::Entry {
+                                            connect_changed[sender] => move |entry| {
+                                                sender.input(ConfigurationInput::NodeRpcAddressChanged(
+                                                    entry.text().into()
+                                                ));
+                                            },
+                                            #[track = "model.node_rpc_address.changed_is_valid()"]
+                                            set_css_classes: if model.node_rpc_address.is_valid {
+                                                &["valid-input"]
+                                            } else {
+                                                &["invalid-input"]
+                                            },
+                                            set_hexpand: true,
+                                            set_placeholder_text: Some("127.0.0.1:9944"),
+                                            #[track = "model.node_rpc_address.changed_is_valid()"]
+                                            set_secondary_icon_name: model.node_rpc_address.icon(),
+                                            set_secondary_icon_activatable: false,
+                                            set_secondary_icon_sensitive: false,
+                                            #[track = "model.changed_node_rpc_enabled()"]
+                                            set_sensitive: model.node_rpc_enabled,
+                                            #[track = "model.node_rpc_address.changed_value()"]
+                                            set_text: &model.node_rpc_address,
+                                            set_tooltip: &T.configuration_advanced_network_node_rpc_address_tooltip(),
+                                        },
+                                    },
+
+                                    gtk::Label {
+                                        add_css_class: "warning-label",
+                                        set_halign: gtk::Align::Start,
+                                        set_label: &T.configuration_advanced_network_node_rpc_warning_public(),
+                                        #[track = "model.changed_node_rpc_enabled() || model.node_rpc_address.changed_is_valid()"]
+                                        set_visible: model.node_rpc_enabled
+                                            && is_node_rpc_address_public(&model.node_rpc_address),
+                                        set_wrap: true,
+                                    },
+
+                                    gtk::Box {
+                                        set_spacing: 10,
+
+                                        gtk::Label {
+                                            set_label: &T.configuration_advanced_network_control_api(),
+                                        },
+                                        gtk::Switch {
+                                            connect_state_set[sender] => move |_switch, state| {
+                                                sender.input(ConfigurationInput::ControlApiChanged(
+                                                    state
+                                                ));
+
+                                                glib::Propagation::Proceed
+                                            },
+                                            #[track = "model.changed_control_api()"]
+                                            set_active: model.control_api,
+                                            set_tooltip: &T.configuration_advanced_network_control_api_tooltip(),
+                                            set_valign: gtk::Align::Center,
+                                        },
+                                    },
+                                },
+                            },
+                        },
+                    },
+
+                    gtk::Box {
+                        gtk::Box {
+                            set_halign: gtk::Align::Start,
+                            set_hexpand: true,
+                            set_spacing: 10,
+
+                            gtk::Button {
+                                connect_clicked => ConfigurationInput::AddFarm,
+                                set_tooltip: &T.configuration_button_add_farm_tooltip(),
+
+                                gtk::Label {
+                                    set_label: &T.configuration_button_add_farm(),
+                                    set_margin_all: 10,
+                                },
+                            },
+
+                            gtk::Button {
+                                connect_clicked => ConfigurationInput::SuggestFarms,
+                                #[track = "model.changed_suggesting_farms()"]
+                                set_sensitive: !model.suggesting_farms,
+                                set_tooltip: &T.configuration_button_suggest_farms_tooltip(),
+
+                                gtk::Label {
+                                    set_label: &T.configuration_button_suggest_farms(),
+                                    set_margin_all: 10,
+                                },
+                            },
+
+                            gtk::Button {
+                                connect_clicked => ConfigurationInput::OpenImportConfig,
+                                set_tooltip: &T.configuration_button_import_config_tooltip(),
+
+                                gtk::Label {
+                                    set_label: &T.configuration_button_import_config(),
+                                    set_margin_all: 10,
+                                },
+                            },
+
+                            gtk::Button {
+                                connect_clicked => ConfigurationInput::OpenExportConfig,
+                                set_tooltip: &T.configuration_button_export_config_tooltip(),
+
+                                gtk::Label {
+                                    set_label: &T.configuration_button_export_config(),
+                                    set_margin_all: 10,
+                                },
+                            },
+
+                            gtk::Label {
+                                set_label: &T.configuration_suggest_farms_none_found(),
+                                #[track = "model.changed_no_farm_suggestions()"]
+                                set_visible: model.no_farm_suggestions,
+                                set_wrap: true,
+                            },
+
+                            gtk::Label {
+                                add_css_class: "error-label",
+                                #[track = "model.changed_config_file_error()"]
+                                set_label: model.config_file_error.as_deref().unwrap_or_default(),
+                                #[track = "model.changed_config_file_error()"]
+                                set_visible: model.config_file_error.is_some(),
+                                set_wrap: true,
+                            },
+                        },
+
+                        if model.reconfiguration {
+                            gtk::Box {
+                                set_halign: gtk::Align::End,
+                                set_spacing: 10,
+
+                                gtk::Button {
+                                    connect_clicked => ConfigurationInput::Help,
+
+                                    gtk::Label {
+                                        set_label: &T.configuration_button_help(),
+                                        set_margin_all: 10,
+                                    },
+                                },
+
+                                gtk::Button {
+                                    connect_clicked => ConfigurationInput::Cancel,
+
+                                    gtk::Label {
+                                        set_label: &T.configuration_button_cancel(),
+                                        set_margin_all: 10,
+                                    },
+                                },
+
+                                gtk::Button {
+                                    add_css_class: "suggested-action",
+                                    connect_clicked => ConfigurationInput::Save,
+                                    #[track = "model.reward_address.changed_is_valid() || model.node_path.changed_is_valid() || model.changed_node_path_conflict() || model.node_rpc_url.changed_is_valid() || model.external_address.changed_is_valid() || model.changed_metrics_enabled() || model.metrics_address.changed_is_valid() || model.changed_node_rpc_enabled() || model.node_rpc_address.changed_is_valid() || model.piece_cache_size.changed_is_valid() || model.piece_cache_path.changed_is_valid() || model.changed_piece_cache_path_conflict() || model.changed_too_few_auditing_threads() || model.changed_farms()"]
+                                    set_sensitive:
+                                        model.reward_address.is_valid
+                                            && model.node_path.is_valid
+                                            && !model.node_path_conflict
+                                            && model.node_rpc_url.is_valid
+                                            && model.external_address.is_valid
+                                            && (!model.metrics_enabled || model.metrics_address.is_valid)
+                                            && (!model.node_rpc_enabled || model.node_rpc_address.is_valid)
+                                            && model.piece_cache_size.is_valid
+                                            && model.piece_cache_path.is_valid
+                                            && !model.piece_cache_path_conflict
+                                            && model.too_few_auditing_threads.is_none()
+                                            && !model.farms.is_empty()
+                                            && model.farms.iter().all(|maybe_farm| maybe_farm.map(FarmWidget::valid).unwrap_or_default()),
+
+                                    gtk::Label {
+                                        set_label: &T.configuration_button_save(),
+                                        set_margin_all: 10,
+                                    },
+                                },
+                            }
+                        } else {
+                            gtk::Box {
+                                set_halign: gtk::Align::End,
+                                set_spacing: 10,
+
+                                gtk::Button {
+                                    connect_clicked => ConfigurationInput::Help,
+
+                                    gtk::Label {
+                                        set_label: &T.configuration_button_help(),
+                                        set_margin_all: 10,
+                                    },
+                                },
+
+                                gtk::Button {
+                                    connect_clicked => ConfigurationInput::Back,
+
+                                    gtk::Label {
+                                        set_label: &T.configuration_button_back(),
+                                        set_margin_all: 10,
+                                    },
+                                },
+
+                                gtk::Button {
+                                    add_css_class: "suggested-action",
+                                    connect_clicked => ConfigurationInput::Start,
+                                    #[track = "model.reward_address.changed_is_valid() || model.node_path.changed_is_valid() || model.changed_node_path_conflict() || model.node_rpc_url.changed_is_valid() || model.external_address.changed_is_valid() || model.changed_metrics_enabled() || model.metrics_address.changed_is_valid() || model.changed_node_rpc_enabled() || model.node_rpc_address.changed_is_valid() || model.piece_cache_size.changed_is_valid() || model.piece_cache_path.changed_is_valid() || model.changed_piece_cache_path_conflict() || model.changed_too_few_auditing_threads() || model.changed_farms()"]
+                                    set_sensitive:
+                                        model.reward_address.is_valid
+                                            && model.node_path.is_valid
+                                            && !model.node_path_conflict
+                                            && model.node_rpc_url.is_valid
+                                            && model.external_address.is_valid
+                                            && (!model.metrics_enabled || model.metrics_address.is_valid)
+                                            && (!model.node_rpc_enabled || model.node_rpc_address.is_valid)
+                                            && model.piece_cache_size.is_valid
+                                            && model.piece_cache_path.is_valid
+                                            && !model.piece_cache_path_conflict
+                                            && model.too_few_auditing_threads.is_none()
+                                            && !model.farms.is_empty()
+                                            && model.farms.iter().all(|maybe_farm| maybe_farm.map(FarmWidget::valid).unwrap_or_default()),
+
+                                    gtk::Label {
+                                        set_label: &T.configuration_button_start(),
+                                        set_margin_all: 10,
+                                    },
+                                },
+                            }
+                        },
                     },
                 },
             },
@@ -585,6 +1723,33 @@ impl AsyncComponent for ConfigurationView {
                 OpenDialogResponse::Cancel => ConfigurationInput::Ignore,
             });
 
+        let import_dialog = OpenDialog::builder()
+            .transient_for_native(&parent_root)
+            .launch(OpenDialogSettings {
+                accept_label: T.configuration_dialog_button_import().to_string(),
+                cancel_label: T.configuration_dialog_button_cancel().to_string(),
+                filters: vec![json_file_filter(), toml_file_filter()],
+                ..OpenDialogSettings::default()
+            })
+            .forward(sender.input_sender(), |response| match response {
+                OpenDialogResponse::Accept(path) => ConfigurationInput::ImportConfig(path),
+                OpenDialogResponse::Cancel => ConfigurationInput::Ignore,
+            });
+
+        let export_dialog = SaveDialog::builder()
+            .transient_for_native(&parent_root)
+            .launch(SaveDialogSettings {
+                accept_label: T.configuration_dialog_button_export().to_string(),
+                cancel_label: T.configuration_dialog_button_cancel().to_string(),
+                create_folders: true,
+                is_modal: true,
+                filters: vec![json_file_filter(), toml_file_filter()],
+            })
+            .forward(sender.input_sender(), |response| match response {
+                SaveDialogResponse::Accept(path) => ConfigurationInput::ExportConfig(path),
+                SaveDialogResponse::Cancel => ConfigurationInput::Ignore,
+            });
+
         let mut farms = AsyncFactoryVecDeque::builder()
             .launch(gtk::ListBox::new())
             .forward(sender.input_sender(), |output| match output {
@@ -592,7 +1757,12 @@ impl AsyncComponent for ConfigurationView {
                     ConfigurationInput::OpenDirectory(DirectoryKind::FarmPath(index))
                 }
                 FarmWidgetOutput::ValidityUpdate => ConfigurationInput::UpdateFarms,
+                FarmWidgetOutput::SizeUpdate => ConfigurationInput::UpdateFarmsSummary,
                 FarmWidgetOutput::Delete(index) => ConfigurationInput::Delete(index),
+                FarmWidgetOutput::Move { from, to } => ConfigurationInput::MoveFarm { from, to },
+                FarmWidgetOutput::CollapsedChanged { path, collapsed } => {
+                    ConfigurationInput::FarmCollapsedChanged { path, collapsed }
+                }
             });
 
         farms.guard().push_back(FarmWidgetInit::default());
@@ -600,18 +1770,81 @@ impl AsyncComponent for ConfigurationView {
         let model = Self {
             reward_address: MaybeValid::no(String::new()),
             node_path: MaybeValid::no(PathBuf::new()),
+            node_path_free_space: None,
+            node_path_conflict: false,
             farms,
+            farm_search: String::new(),
+            farms_visibility: Rc::default(),
             network_configuration: Default::default(),
+            node_rpc_url: MaybeValid::yes(String::new()),
+            external_address: MaybeValid::yes(String::new()),
+            metrics_enabled: false,
+            metrics_address: MaybeValid::yes(MetricsConfiguration::default().address),
+            node_rpc_enabled: false,
+            node_rpc_address: MaybeValid::yes(NodeRpcConfiguration::default().address),
+            control_api: false,
+            node_rpc_check: NodeRpcCheck::NotChecked,
             reduce_plotting_cpu_load: false,
+            plotting_threads: available_cpu_threads(),
+            sector_encoding_concurrency: default_sector_encoding_concurrency(None),
+            auditing_threads: available_cpu_threads(),
+            piece_cache_size: MaybeValid::yes(String::new()),
+            too_few_auditing_threads: None,
+            piece_cache_size_low: false,
+            piece_cache_path: MaybeValid::yes(PathBuf::new()),
+            piece_cache_path_conflict: false,
+            reward_notifications: true,
+            plotting_notifications: true,
+            farm_health_notifications: true,
+            tray_icon: true,
+            minimize_to_tray: false,
+            theme: Theme::default(),
+            language: None,
+            log_level: None,
+            updates: UpdatesConfiguration::default(),
+            offline_mode: false,
+            latest_version: None,
+            recent_farm_directories: Vec::new(),
             pending_directory_selection: Default::default(),
             open_dialog,
+            import_dialog,
+            export_dialog,
+            config_file_error: None,
+            suggesting_farms: false,
+            no_farm_suggestions: false,
             reconfiguration: false,
-            tracker: u8::MAX,
+            tracker: u32::MAX,
         };
 
         let configuration_list_box = model.farms.widget();
+        configuration_list_box.set_filter_func({
+            let farms_visibility = Rc::clone(&model.farms_visibility);
+
+            move |row| {
+                usize::try_from(row.index())
+                    .ok()
+                    .and_then(|index| farms_visibility.borrow().get(index).copied())
+                    .unwrap_or(true)
+            }
+        });
         let widgets = view_output!();
 
+        // Only active while configuration view is shown
+        let shortcut_controller = gtk::ShortcutController::new();
+        shortcut_controller.set_scope(gtk::ShortcutScope::Managed);
+        shortcut_controller.add_shortcut(gtk::Shortcut::new(
+            gtk::ShortcutTrigger::parse_string("<Control>n"),
+            Some(gtk::CallbackAction::new({
+                let sender = sender.clone();
+
+                move |_widget, _args| {
+                    sender.input(ConfigurationInput::AddFarm);
+                    glib::Propagation::Stop
+                }
+            })),
+        ));
+        root.add_controller(shortcut_controller);
+
         AsyncComponentParts { model, widgets }
     }
 
@@ -626,9 +1859,50 @@ impl AsyncComponent for ConfigurationView {
         self.reward_address.reset();
         self.node_path.reset();
         self.network_configuration.reset();
+        self.node_rpc_url.reset();
+        self.external_address.reset();
+        self.metrics_address.reset();
+        self.node_rpc_address.reset();
+        self.piece_cache_size.reset();
+        self.piece_cache_path.reset();
 
         self.process_input(input, sender).await;
     }
+
+    async fn update_cmd(
+        &mut self,
+        command_output: Self::CommandOutput,
+        _sender: AsyncComponentSender<Self>,
+        _root: &Self::Root,
+    ) {
+        // Reset changes
+        self.reset();
+        self.reward_address.reset();
+        self.node_path.reset();
+        self.network_configuration.reset();
+        self.node_rpc_url.reset();
+        self.external_address.reset();
+        self.metrics_address.reset();
+        self.node_rpc_address.reset();
+        self.piece_cache_size.reset();
+        self.piece_cache_path.reset();
+
+        match command_output {
+            ConfigurationCommandOutput::NodeRpcUrlChecked { url, result } => {
+                // Ignore results for URL that was already changed
+                if url == self.node_rpc_url.value {
+                    self.set_node_rpc_check(match result {
+                        Ok(()) => NodeRpcCheck::Connected,
+                        Err(error) => NodeRpcCheck::Failed(error),
+                    });
+                }
+            }
+            ConfigurationCommandOutput::FarmsSuggested(suggestions) => {
+                self.set_suggesting_farms(false);
+                self.add_suggested_farms(suggestions).await;
+            }
+        }
+    }
 }
 
 impl ConfigurationView {
@@ -639,9 +1913,40 @@ impl ConfigurationView {
     ) {
         match input {
             ConfigurationInput::AddFarm => {
-                self.get_mut_farms()
-                    .guard()
-                    .push_back(FarmWidgetInit::default());
+                let recent_directories = self.recent_farm_directories.clone();
+                let cache_percentage = self.farm_cache_percentage();
+                self.get_mut_farms().guard().push_back(FarmWidgetInit {
+                    recent_directories,
+                    grab_focus: true,
+                    cache_percentage,
+                    ..FarmWidgetInit::default()
+                });
+            }
+            ConfigurationInput::FarmSearchChanged(farm_search) => {
+                self.farm_search = farm_search.trim().to_lowercase();
+                self.update_farms_filter();
+            }
+            ConfigurationInput::FarmCollapsedChanged { path, collapsed } => {
+                // Collapsed state is remembered by path, such that it survives reordering of farms
+                let result = UiState::update(|ui_state| {
+                    ui_state
+                        .collapsed_farms
+                        .retain(|collapsed_path| collapsed_path != &path);
+                    if collapsed {
+                        ui_state.collapsed_farms.push(path);
+                    }
+                })
+                .await;
+                if let Err(error) = result {
+                    warn!(%error, "Failed to save UI state");
+                }
+            }
+            ConfigurationInput::SuggestFarms => {
+                self.set_suggesting_farms(true);
+                self.set_no_farm_suggestions(false);
+                sender.oneshot_command(async {
+                    ConfigurationCommandOutput::FarmsSuggested(suggest_farms().await)
+                });
             }
             ConfigurationInput::OpenDirectory(directory_kind) => {
                 self.pending_directory_selection.replace(directory_kind);
@@ -650,17 +1955,19 @@ impl ConfigurationView {
             ConfigurationInput::DirectorySelected(path) => {
                 match self.pending_directory_selection.take() {
                     Some(DirectoryKind::NodePath) => {
-                        self.node_path = if is_directory_writable(path.clone()).await {
-                            MaybeValid::yes(path)
-                        } else {
-                            MaybeValid::no(path)
-                        };
+                        self.update_node_path(path).await;
+                        self.check_farm_path_conflicts().await;
+                    }
+                    Some(DirectoryKind::PieceCachePath) => {
+                        self.update_piece_cache_path(path).await;
+                        self.check_farm_path_conflicts().await;
                     }
                     Some(DirectoryKind::FarmPath(index)) => {
                         self.get_mut_farms().send(
                             index.current_index(),
-                            FarmWidgetInput::DirectorySelected(path),
+                            FarmWidgetInput::DirectorySelected(path.clone()),
                         );
+                        self.remember_recent_farm_directory(path);
                     }
                     None => {
                         warn!(
@@ -670,18 +1977,211 @@ impl ConfigurationView {
                     }
                 }
             }
+            ConfigurationInput::OpenImportConfig => {
+                self.import_dialog.emit(OpenDialogMsg::Open);
+            }
+            ConfigurationInput::ImportConfig(path) => {
+                match RawConfig::read_from_path(&path).await {
+                    Ok(Some(mut raw_config)) => {
+                        let RawConfig::V0 {
+                            recent_farm_directories,
+                            ..
+                        } = &mut raw_config;
+                        // Recent directories belong to this machine, not to imported configuration
+                        *recent_farm_directories = self.recent_farm_directories.clone();
+                        // Paths that do not exist on this machine will be shown as invalid by
+                        // corresponding widgets, so they can be fixed in place
+                        sender.input(ConfigurationInput::Reinitialize {
+                            raw_config,
+                            reconfiguration: self.reconfiguration,
+                        });
+                        App::show_toast(Toast::info(T.toast_configuration_imported().to_string()));
+                    }
+                    Ok(None) => {
+                        self.set_config_file_error(Some(
+                            T.configuration_import_config_error_not_found().to_string(),
+                        ));
+                    }
+                    Err(error) => {
+                        warn!(%error, path = %path.display(), "Failed to import configuration");
+                        self.set_config_file_error(Some(
+                            T.configuration_import_config_error(error.to_string())
+                                .as_str()
+                                .to_string(),
+                        ));
+                    }
+                }
+            }
+            ConfigurationInput::OpenExportConfig => {
+                self.export_dialog
+                    .emit(SaveDialogMsg::SaveAs(EXPORTED_CONFIG_FILE_NAME.to_string()));
+            }
+            ConfigurationInput::ExportConfig(path) => {
+                let Some(raw_config) = self.create_raw_config() else {
+                    self.set_config_file_error(Some(
+                        T.configuration_export_config_error_incomplete().to_string(),
+                    ));
+                    return;
+                };
+
+                match raw_config.write_to_path(&path).await {
+                    Ok(()) => {
+                        self.set_config_file_error(None);
+                        App::show_toast(Toast::info(
+                            T.toast_configuration_exported(path.display().to_string())
+                                .to_string(),
+                        ));
+                    }
+                    Err(error) => {
+                        warn!(%error, path = %path.display(), "Failed to export configuration");
+                        self.set_config_file_error(Some(
+                            T.configuration_export_config_error(error.to_string())
+                                .as_str()
+                                .to_string(),
+                        ));
+                    }
+                }
+            }
             ConfigurationInput::SubstratePortChanged(port) => {
                 self.network_configuration.substrate_port = port;
             }
             ConfigurationInput::SubspacePortChanged(port) => {
                 self.network_configuration.subspace_port = port;
             }
+            ConfigurationInput::NodeRpcUrlChanged(node_rpc_url) => {
+                let node_rpc_url = node_rpc_url.trim();
+                // Force change so it is possible to render updates as the input changes
+                *self.node_rpc_url.get_mut_is_valid() =
+                    node_rpc_url.is_empty() || is_node_rpc_url_valid(node_rpc_url);
+                self.node_rpc_url.value = node_rpc_url.to_string();
+                self.set_node_rpc_check(NodeRpcCheck::NotChecked);
+            }
+            ConfigurationInput::CheckNodeRpcUrl => {
+                let url = self.node_rpc_url.value.clone();
+                self.set_node_rpc_check(NodeRpcCheck::Checking);
+                sender.oneshot_command(async move {
+                    let result = connect_to_node_rpc(&url)
+                        .await
+                        .map(|_node_rpc_client| ())
+                        .map_err(|error| error.to_string());
+                    ConfigurationCommandOutput::NodeRpcUrlChecked { url, result }
+                });
+            }
+            ConfigurationInput::ExternalAddressChanged(external_address) => {
+                let external_address = external_address.trim();
+                // Force change so it is possible to render updates as the input changes
+                *self.external_address.get_mut_is_valid() =
+                    external_address.is_empty() || is_external_address_valid(external_address);
+                self.external_address.value = external_address.to_string();
+            }
+            ConfigurationInput::MetricsEnabledChanged(metrics_enabled) => {
+                self.set_metrics_enabled(metrics_enabled);
+            }
+            ConfigurationInput::MetricsAddressChanged(metrics_address) => {
+                let metrics_address = metrics_address.trim();
+                // Force change so it is possible to render updates as the input changes
+                *self.metrics_address.get_mut_is_valid() =
+                    is_metrics_address_valid(metrics_address);
+                self.metrics_address.value = metrics_address.to_string();
+            }
+            ConfigurationInput::NodeRpcEnabledChanged(node_rpc_enabled) => {
+                self.set_node_rpc_enabled(node_rpc_enabled);
+            }
+            ConfigurationInput::NodeRpcAddressChanged(node_rpc_address) => {
+                let node_rpc_address = node_rpc_address.trim();
+                // Force change so it is possible to render updates as the input changes
+                *self.node_rpc_address.get_mut_is_valid() =
+                    is_node_rpc_address_valid(node_rpc_address);
+                self.node_rpc_address.value = node_rpc_address.to_string();
+            }
+            ConfigurationInput::ControlApiChanged(control_api) => {
+                self.set_control_api(control_api);
+            }
             ConfigurationInput::FasterNetworkingChanged(faster_networking) => {
                 self.network_configuration.faster_networking = faster_networking;
             }
             ConfigurationInput::ReducePlottingCpuLoadChanged(reduce_plotting_cpu_load) => {
                 self.reduce_plotting_cpu_load = reduce_plotting_cpu_load;
             }
+            ConfigurationInput::PlottingThreadsChanged(plotting_threads) => {
+                // Keep following the default unless concurrency was changed explicitly
+                let follows_default = self.sector_encoding_concurrency
+                    == default_sector_encoding_concurrency(self.explicit_plotting_threads());
+                self.plotting_threads = plotting_threads;
+                if follows_default {
+                    self.set_sector_encoding_concurrency(default_sector_encoding_concurrency(
+                        self.explicit_plotting_threads(),
+                    ));
+                }
+            }
+            ConfigurationInput::SectorEncodingConcurrencyChanged(sector_encoding_concurrency) => {
+                self.sector_encoding_concurrency = sector_encoding_concurrency;
+            }
+            ConfigurationInput::AuditingThreadsChanged(auditing_threads) => {
+                self.auditing_threads = auditing_threads;
+                self.update_too_few_auditing_threads();
+            }
+            ConfigurationInput::ReduceSectorEncodingConcurrency(sector_encoding_concurrency) => {
+                self.set_sector_encoding_concurrency(sector_encoding_concurrency);
+            }
+            ConfigurationInput::PieceCacheSizeChanged(piece_cache_size) => {
+                let piece_cache_size = piece_cache_size.trim();
+                // Force change so it is possible to render updates as the input changes
+                *self.piece_cache_size.get_mut_is_valid() =
+                    is_piece_cache_size_valid(piece_cache_size);
+                self.piece_cache_size.value = piece_cache_size.to_string();
+                self.set_piece_cache_size_low(is_piece_cache_size_low(piece_cache_size));
+                self.update_farms_cache_percentage();
+            }
+            ConfigurationInput::PieceCachePathCleared => {
+                self.update_piece_cache_path(PathBuf::new()).await;
+                self.check_farm_path_conflicts().await;
+            }
+            ConfigurationInput::RewardNotificationsChanged(reward_notifications) => {
+                self.reward_notifications = reward_notifications;
+            }
+            ConfigurationInput::PlottingNotificationsChanged(plotting_notifications) => {
+                self.plotting_notifications = plotting_notifications;
+            }
+            ConfigurationInput::FarmHealthNotificationsChanged(farm_health_notifications) => {
+                self.farm_health_notifications = farm_health_notifications;
+            }
+            ConfigurationInput::TrayIconChanged(tray_icon) => {
+                self.set_tray_icon(tray_icon);
+            }
+            ConfigurationInput::MinimizeToTrayChanged(minimize_to_tray) => {
+                self.minimize_to_tray = minimize_to_tray;
+            }
+            ConfigurationInput::ThemeChanged(theme) => {
+                self.set_theme(theme);
+                // Preview theme right away, it'll be reverted if configuration is not saved
+                apply_theme(theme);
+            }
+            ConfigurationInput::LanguageChanged(language) => {
+                self.set_language(language);
+            }
+            ConfigurationInput::LogLevelChanged(log_level) => {
+                // Drop down shows default level when none is selected explicitly, don't turn it
+                // into explicit choice unless it was actually changed
+                if self.log_level.unwrap_or_default() != log_level {
+                    self.set_log_level(Some(log_level));
+                }
+            }
+            ConfigurationInput::OfflineModeChanged(offline_mode) => {
+                self.set_offline_mode(offline_mode);
+            }
+            ConfigurationInput::UpdateChecksChanged(update_checks) => {
+                self.get_mut_updates().disable_checks = !update_checks;
+            }
+            ConfigurationInput::UpdateCheckIntervalChanged(check_interval) => {
+                self.get_mut_updates().check_interval = check_interval;
+            }
+            ConfigurationInput::UpdateAutoDownloadChanged(auto_download) => {
+                self.get_mut_updates().auto_download = auto_download;
+            }
+            ConfigurationInput::LatestVersion(latest_version) => {
+                self.set_latest_version(Some(latest_version));
+            }
             ConfigurationInput::Delete(index) => {
                 let mut farms = self.get_mut_farms().guard();
                 farms.remove(index.current_index());
@@ -689,6 +2189,25 @@ impl ConfigurationView {
                 farms.iter_mut().for_each(|_| {
                     // Nothing
                 });
+                drop(farms);
+
+                self.update_farms_filter();
+                self.check_farm_path_conflicts().await;
+                self.update_too_few_auditing_threads();
+            }
+            ConfigurationInput::MoveFarm { from, to } => {
+                let to = to.current_index();
+                let mut farms = self.get_mut_farms().guard();
+                if from != to && from < farms.len() && to < farms.len() {
+                    farms.move_to(from, to);
+                    // Force re-rendering of all farms
+                    farms.iter_mut().for_each(|_| {
+                        // Nothing
+                    });
+                }
+                drop(farms);
+
+                self.update_farms_filter();
             }
             ConfigurationInput::CreateWallet => {
                 if let Err(error) =
@@ -701,37 +2220,124 @@ impl ConfigurationView {
                 let new_reward_address = new_reward_address.trim();
                 // Force change so it is possible to render updates as the input changes
                 *self.reward_address.get_mut_is_valid() =
-                    parse_ss58_reward_address(new_reward_address).is_ok();
+                    is_reward_address_valid(new_reward_address);
                 self.reward_address.value = new_reward_address.to_string();
             }
             ConfigurationInput::Reinitialize {
                 raw_config,
                 reconfiguration,
             } => {
+                self.set_config_file_error(None);
                 let new_reward_address = raw_config.reward_address().trim();
                 self.reward_address
-                    .set_is_valid(parse_ss58_reward_address(new_reward_address).is_ok());
+                    .set_is_valid(is_reward_address_valid(new_reward_address));
                 self.reward_address
                     .set_value(new_reward_address.to_string());
 
-                self.node_path = if is_directory_writable(raw_config.node_path().clone()).await {
-                    MaybeValid::yes(raw_config.node_path().clone())
-                } else {
-                    MaybeValid::no(raw_config.node_path().clone())
-                };
+                self.update_node_path(raw_config.node_path().clone()).await;
+                self.recent_farm_directories =
+                    retain_existing_paths(raw_config.recent_farm_directories().to_vec()).await;
                 {
+                    let recent_directories = self.recent_farm_directories.clone();
+                    let collapsed_farms = UiState::read().await.collapsed_farms;
+                    // Updated once farms are initialized and their sizes are known
+                    let cache_percentage = NonZeroU8::MIN;
                     let mut farms = self.get_mut_farms().guard();
                     farms.clear();
                     for farm in raw_config.farms() {
                         farms.push_back(FarmWidgetInit {
                             path: farm.path.clone(),
                             size: farm.size.clone(),
+                            name: farm.name.clone(),
+                            reserve: farm.reserve.clone(),
+                            reward_address: farm.reward_address.clone(),
+                            disabled: farm.disabled,
+                            recent_directories: recent_directories.clone(),
+                            grab_focus: false,
+                            collapsed: collapsed_farms.contains(&farm.path),
+                            cache_percentage,
+                            in_use: reconfiguration && !farm.disabled,
                         });
                     }
                 }
                 self.set_reduce_plotting_cpu_load(raw_config.reduce_plotting_cpu_load());
-                self.network_configuration =
-                    NetworkConfigurationWrapper::from(raw_config.network());
+                self.set_plotting_threads(
+                    raw_config
+                        .plotting_threads()
+                        .unwrap_or(usize::MAX)
+                        .clamp(1, available_cpu_threads()),
+                );
+                self.set_sector_encoding_concurrency(
+                    raw_config
+                        .sector_encoding_concurrency()
+                        .unwrap_or_else(|| {
+                            default_sector_encoding_concurrency(raw_config.plotting_threads())
+                        })
+                        .clamp(1, MAX_SECTOR_ENCODING_CONCURRENCY),
+                );
+                self.set_auditing_threads(
+                    raw_config
+                        .auditing_threads()
+                        .unwrap_or(usize::MAX)
+                        .clamp(1, available_cpu_threads()),
+                );
+                self.update_too_few_auditing_threads();
+                let piece_cache_size = raw_config.piece_cache_size().unwrap_or_default();
+                self.piece_cache_size = if is_piece_cache_size_valid(piece_cache_size) {
+                    MaybeValid::yes(piece_cache_size.to_string())
+                } else {
+                    MaybeValid::no(piece_cache_size.to_string())
+                };
+                self.set_piece_cache_size_low(is_piece_cache_size_low(piece_cache_size));
+                self.update_piece_cache_path(
+                    raw_config
+                        .piece_cache_path()
+                        .map(Path::to_path_buf)
+                        .unwrap_or_default(),
+                )
+                .await;
+                self.set_reward_notifications(!raw_config.disable_reward_notifications());
+                self.set_plotting_notifications(!raw_config.disable_plotting_notifications());
+                self.set_farm_health_notifications(!raw_config.disable_farm_health_notifications());
+                self.set_tray_icon(!raw_config.disable_tray_icon());
+                self.set_minimize_to_tray(raw_config.minimize_to_tray());
+                self.set_theme(raw_config.theme());
+                self.set_language(raw_config.language().map(str::to_string));
+                self.set_log_level(raw_config.log_level());
+                self.set_updates(raw_config.updates());
+                self.set_offline_mode(raw_config.offline_mode());
+                let network = raw_config.network();
+                let external_address = network.external_address.as_deref().unwrap_or_default();
+                self.external_address =
+                    if external_address.is_empty() || is_external_address_valid(external_address) {
+                        MaybeValid::yes(external_address.to_string())
+                    } else {
+                        MaybeValid::no(external_address.to_string())
+                    };
+                self.network_configuration = NetworkConfigurationWrapper::from(network);
+                let metrics = raw_config.metrics();
+                self.set_metrics_enabled(metrics.enabled);
+                self.metrics_address = if is_metrics_address_valid(&metrics.address) {
+                    MaybeValid::yes(metrics.address.clone())
+                } else {
+                    MaybeValid::no(metrics.address.clone())
+                };
+                let node_rpc = raw_config.node_rpc();
+                self.set_node_rpc_enabled(node_rpc.enabled);
+                self.node_rpc_address = if is_node_rpc_address_valid(&node_rpc.address) {
+                    MaybeValid::yes(node_rpc.address.clone())
+                } else {
+                    MaybeValid::no(node_rpc.address.clone())
+                };
+                self.set_control_api(raw_config.enable_control_api());
+                let node_rpc_url = raw_config.node_rpc_url().unwrap_or_default();
+                self.node_rpc_url =
+                    if node_rpc_url.is_empty() || is_node_rpc_url_valid(node_rpc_url) {
+                        MaybeValid::yes(node_rpc_url.to_string())
+                    } else {
+                        MaybeValid::no(node_rpc_url.to_string())
+                    };
+                self.set_node_rpc_check(NodeRpcCheck::NotChecked);
                 self.reconfiguration = reconfiguration;
             }
             ConfigurationInput::Help => {
@@ -742,6 +2348,9 @@ impl ConfigurationView {
                 }
             }
             ConfigurationInput::Start => {
+                self.check_plotting_memory(sender, false).await;
+            }
+            ConfigurationInput::StartConfirmed => {
                 if let Some(raw_config) = self.create_raw_config()
                     && sender
                         .output(ConfigurationOutput::StartWithNewConfig(raw_config))
@@ -761,6 +2370,9 @@ impl ConfigurationView {
                 }
             }
             ConfigurationInput::Save => {
+                self.check_plotting_memory(sender, true).await;
+            }
+            ConfigurationInput::SaveConfirmed => {
                 if let Some(raw_config) = self.create_raw_config()
                     && sender
                         .output(ConfigurationOutput::ConfigUpdate(raw_config))
@@ -772,6 +2384,17 @@ impl ConfigurationView {
             ConfigurationInput::UpdateFarms => {
                 // Mark as changed
                 let _ = self.get_mut_farms();
+
+                self.update_farms_filter();
+                self.check_farm_path_conflicts().await;
+                self.update_farms_cache_percentage();
+                self.update_too_few_auditing_threads();
+            }
+            ConfigurationInput::UpdateFarmsSummary => {
+                // Mark as changed
+                let _ = self.get_mut_farms();
+
+                self.update_farms_cache_percentage();
             }
             ConfigurationInput::Ignore => {
                 // Ignore
@@ -779,6 +2402,349 @@ impl ConfigurationView {
         }
     }
 
+    /// Warn user if plotting is likely to use more RAM than this machine can spare, offering to
+    /// reduce sector encoding concurrency, then start or save configuration
+    async fn check_plotting_memory(&self, sender: AsyncComponentSender<Self>, save: bool) {
+        let confirmed = move |sender: &AsyncComponentSender<Self>| {
+            sender.input(if save {
+                ConfigurationInput::SaveConfirmed
+            } else {
+                ConfigurationInput::StartConfirmed
+            });
+        };
+
+        let estimated_memory = estimated_plotting_memory(self.sector_encoding_concurrency);
+        let Some(available_memory) = available_memory().await else {
+            confirmed(&sender);
+            return;
+        };
+        let safe_memory = available_memory / 100 * PLOTTING_MEMORY_SAFE_PERCENTAGE;
+        if estimated_memory.as_u64() <= safe_memory {
+            confirmed(&sender);
+            return;
+        }
+
+        let fitting_concurrency = (1..self.sector_encoding_concurrency)
+            .rev()
+            .find(|&concurrency| estimated_plotting_memory(concurrency).as_u64() <= safe_memory);
+
+        let dialog = gtk::MessageDialog::builder()
+            .buttons(gtk::ButtonsType::None)
+            .message_type(gtk::MessageType::Warning)
+            .modal(true)
+            .text(
+                T.configuration_plotting_memory_warning(
+                    estimated_memory.to_string_as(true),
+                    ByteSize::b(available_memory).to_string_as(true),
+                )
+                .as_str(),
+            )
+            .build();
+        if let Some(fitting_concurrency) = fitting_concurrency {
+            dialog.set_secondary_text(Some(
+                T.configuration_plotting_memory_warning_reduce(
+                    fitting_concurrency,
+                    estimated_plotting_memory(fitting_concurrency).to_string_as(true),
+                )
+                .as_str(),
+            ));
+        }
+        if let Some(window) = relm4::main_application().active_window() {
+            dialog.set_transient_for(Some(&window));
+        }
+        dialog.add_button(
+            &T.configuration_plotting_memory_warning_button_cancel(),
+            gtk::ResponseType::Cancel,
+        );
+        dialog
+            .add_button(
+                &T.configuration_plotting_memory_warning_button_continue(),
+                gtk::ResponseType::Reject,
+            )
+            .add_css_class("destructive-action");
+        if fitting_concurrency.is_some() {
+            dialog
+                .add_button(
+                    &T.configuration_plotting_memory_warning_button_reduce(),
+                    gtk::ResponseType::Accept,
+                )
+                .add_css_class("suggested-action");
+        }
+        dialog.set_default_response(gtk::ResponseType::Cancel);
+
+        dialog.connect_response(move |dialog, response| {
+            match response {
+                gtk::ResponseType::Accept => {
+                    if let Some(fitting_concurrency) = fitting_concurrency {
+                        sender.input(ConfigurationInput::ReduceSectorEncodingConcurrency(
+                            fitting_concurrency,
+                        ));
+                    }
+                    confirmed(&sender);
+                }
+                gtk::ResponseType::Reject => {
+                    confirmed(&sender);
+                }
+                _ => {}
+            }
+            dialog.close();
+        });
+        dialog.present();
+    }
+
+    async fn update_node_path(&mut self, path: PathBuf) {
+        self.set_node_path_free_space(if path == PathBuf::new() {
+            None
+        } else {
+            available_space(path.clone()).await
+        });
+        self.node_path = if is_directory_writable(path.clone()).await {
+            MaybeValid::yes(path)
+        } else {
+            MaybeValid::no(path)
+        };
+    }
+
+    async fn update_piece_cache_path(&mut self, path: PathBuf) {
+        // Empty path means piece cache is stored within farms
+        self.piece_cache_path =
+            if path == PathBuf::new() || is_directory_writable(path.clone()).await {
+                MaybeValid::yes(path)
+            } else {
+                MaybeValid::no(path)
+            };
+        self.update_farms_cache_percentage();
+    }
+
+    /// Percentage of allocated space of each farm that will be used for piece cache with current
+    /// piece cache configuration and total size of farms
+    fn farm_cache_percentage(&self) -> NonZeroU8 {
+        let total_allocated_space = self
+            .farms
+            .iter()
+            .flatten()
+            .filter_map(|farm_widget| farm_widget.allocated_size())
+            .sum();
+
+        farm_cache_percentage(
+            ByteSize::from_str(&self.piece_cache_size)
+                .ok()
+                .map(|piece_cache_size| piece_cache_size.as_u64()),
+            self.piece_cache_path.value != PathBuf::new(),
+            total_allocated_space,
+        )
+    }
+
+    /// Auditing threads are split between enabled farms, so there must be at least as many of them
+    /// as farms
+    fn update_too_few_auditing_threads(&mut self) {
+        let enabled_farms = self
+            .farms
+            .iter()
+            .flatten()
+            .filter(|farm_widget| !farm_widget.disabled())
+            .count();
+
+        self.set_too_few_auditing_threads(
+            self.explicit_auditing_threads()
+                .is_some_and(|auditing_threads| auditing_threads < enabled_farms)
+                .then_some(enabled_farms),
+        );
+    }
+
+    /// Notify farms about piece cache percentage, it affects number of sectors they will have
+    fn update_farms_cache_percentage(&self) {
+        let cache_percentage = self.farm_cache_percentage();
+        for index in 0..self.farms.len() {
+            self.farms.send(
+                index,
+                FarmWidgetInput::CachePercentageChanged(cache_percentage),
+            );
+        }
+    }
+
+    /// Summary of total size of all farms
+    fn farms_summary(&self) -> String {
+        let mut total_size = 0;
+        let mut total_free_space = 0;
+        let mut device_ids = HashSet::new();
+        let mut distinct_disks = true;
+
+        for maybe_farm_widget in self.farms.iter() {
+            let Some(farm_widget) = maybe_farm_widget else {
+                continue;
+            };
+
+            total_size += farm_widget.allocated_size().unwrap_or_default();
+
+            match (farm_widget.free_space(), farm_widget.device_id()) {
+                (Some(free_space), Some(device_id)) if device_ids.insert(device_id) => {
+                    total_free_space += free_space;
+                }
+                _ => {
+                    distinct_disks = false;
+                }
+            }
+        }
+
+        let total_size = ByteSize::b(total_size).to_string_as(true);
+        if distinct_disks && !device_ids.is_empty() {
+            T.configuration_farms_summary_with_free_space(
+                total_size,
+                ByteSize::b(total_free_space).to_string_as(true),
+            )
+            .to_string()
+        } else {
+            T.configuration_farms_summary(total_size).to_string()
+        }
+    }
+
+    /// Re-evaluate which farms match search query by path or name.
+    ///
+    /// Farms without path (just added) and farms that are not initialized yet are always shown.
+    fn update_farms_filter(&self) {
+        *self.farms_visibility.borrow_mut() = self
+            .farms
+            .iter()
+            .map(|maybe_farm_widget| {
+                let Some(farm_widget) = maybe_farm_widget else {
+                    return true;
+                };
+
+                self.farm_search.is_empty()
+                    || farm_widget.path() == Path::new("")
+                    || farm_widget
+                        .path()
+                        .display()
+                        .to_string()
+                        .to_lowercase()
+                        .contains(&self.farm_search)
+                    || farm_widget
+                        .name()
+                        .to_lowercase()
+                        .contains(&self.farm_search)
+            })
+            .collect();
+
+        self.farms.widget().invalidate_filter();
+    }
+
+    /// Check farm paths for duplicates and for conflicts with node path
+    async fn check_farm_path_conflicts(&mut self) {
+        // Farms that are not initialized yet will trigger another check once initialized
+        let (indices, paths) = self
+            .farms
+            .iter()
+            .enumerate()
+            .filter_map(|(index, maybe_farm_widget)| {
+                Some((index, maybe_farm_widget?.path().to_path_buf()))
+            })
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+
+        let duplicates = find_duplicate_paths(paths.clone()).await;
+        let node_path_conflicts =
+            find_node_path_conflicts(self.node_path.value.clone(), paths.clone()).await;
+        // Piece cache files would clash with files of the farm, so the same check applies
+        let piece_cache_path_conflicts =
+            find_node_path_conflicts(self.piece_cache_path.value.clone(), paths).await;
+
+        self.set_node_path_conflict(node_path_conflicts.contains(&true));
+        self.set_piece_cache_path_conflict(piece_cache_path_conflicts.contains(&true));
+
+        for ((index, duplicate_path), node_path_conflict) in
+            indices.into_iter().zip(duplicates).zip(node_path_conflicts)
+        {
+            self.farms
+                .send(index, FarmWidgetInput::DuplicatePath(duplicate_path));
+            self.farms
+                .send(index, FarmWidgetInput::NodePathConflict(node_path_conflict));
+        }
+    }
+
+    /// Add farms for suggested disks that are not used by configured farms yet, replacing farms
+    /// without directory selected
+    async fn add_suggested_farms(&mut self, suggestions: Vec<FarmSuggestion>) {
+        let configured_farms = self
+            .farms
+            .iter()
+            .flatten()
+            .filter(|farm_widget| farm_widget.path() != Path::new(""))
+            .map(|farm_widget| (farm_widget.path().to_path_buf(), farm_widget.device_id()))
+            .collect::<Vec<_>>();
+        let suggestions = suggestions
+            .into_iter()
+            .filter(|suggestion| {
+                !configured_farms.iter().any(|(path, device_id)| {
+                    *path == suggestion.path
+                        || (device_id.is_some() && *device_id == suggestion.device_id)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if suggestions.is_empty() {
+            self.set_no_farm_suggestions(true);
+            return;
+        }
+
+        let empty_farm_indices = self
+            .farms
+            .iter()
+            .enumerate()
+            .filter_map(|(index, maybe_farm_widget)| {
+                (maybe_farm_widget?.path() == Path::new("")).then_some(index)
+            })
+            .collect::<Vec<_>>();
+        let recent_directories = self.recent_farm_directories.clone();
+        let cache_percentage = self.farm_cache_percentage();
+        App::show_toast(Toast::info(
+            T.toast_suggested_farms_added(suggestions.len()).to_string(),
+        ));
+        let mut farms = self.get_mut_farms().guard();
+        for index in empty_farm_indices.into_iter().rev() {
+            farms.remove(index);
+        }
+        for suggestion in suggestions {
+            farms.push_back(FarmWidgetInit {
+                path: suggestion.path,
+                size: SUGGESTED_FARM_SIZE.to_string(),
+                recent_directories: recent_directories.clone(),
+                cache_percentage,
+                ..FarmWidgetInit::default()
+            });
+        }
+        drop(farms);
+
+        self.check_farm_path_conflicts().await;
+    }
+
+    /// Remember farm directory as the most recently selected one and notify farms about it
+    fn remember_recent_farm_directory(&mut self, path: PathBuf) {
+        self.recent_farm_directories
+            .retain(|directory| directory != &path);
+        self.recent_farm_directories.insert(0, path);
+        self.recent_farm_directories
+            .truncate(MAX_RECENT_FARM_DIRECTORIES);
+
+        for index in 0..self.farms.len() {
+            self.farms.send(
+                index,
+                FarmWidgetInput::RecentDirectoriesUpdated(self.recent_farm_directories.clone()),
+            );
+        }
+    }
+
+    /// Number of plotting threads to store in config, using all threads is the default, so only
+    /// explicit limit is returned
+    fn explicit_plotting_threads(&self) -> Option<usize> {
+        (self.plotting_threads < available_cpu_threads()).then_some(self.plotting_threads)
+    }
+
+    /// Number of auditing threads to store in config, thread pool sized for the CPU in each farm is
+    /// the default, so only explicit limit is returned
+    fn explicit_auditing_threads(&self) -> Option<usize> {
+        (self.auditing_threads < available_cpu_threads()).then_some(self.auditing_threads)
+    }
+
     /// Create raw config from own state
     fn create_raw_config(&self) -> Option<RawConfig> {
         Some(RawConfig::V0 {
@@ -790,11 +2756,44 @@ impl ConfigurationView {
                 .map(|maybe_farm_widget| Some(maybe_farm_widget?.farm()))
                 .collect::<Option<Vec<_>>>()?,
             reduce_plotting_cpu_load: self.reduce_plotting_cpu_load,
+            plotting_threads: self.explicit_plotting_threads(),
+            // Only store concurrency that differs from the default
+            sector_encoding_concurrency: (self.sector_encoding_concurrency
+                != default_sector_encoding_concurrency(self.explicit_plotting_threads()))
+            .then_some(self.sector_encoding_concurrency),
+            auditing_threads: self.explicit_auditing_threads(),
+            piece_cache_size: (!self.piece_cache_size.is_empty())
+                .then(|| self.piece_cache_size.value.clone()),
+            piece_cache_path: (self.piece_cache_path.value != PathBuf::new())
+                .then(|| self.piece_cache_path.value.clone()),
             network: NetworkConfiguration {
                 substrate_port: self.network_configuration.substrate_port,
                 subspace_port: self.network_configuration.subspace_port,
                 faster_networking: self.network_configuration.faster_networking,
+                external_address: (!self.external_address.is_empty())
+                    .then(|| self.external_address.value.clone()),
+            },
+            recent_farm_directories: self.recent_farm_directories.clone(),
+            node_rpc_url: (!self.node_rpc_url.is_empty()).then(|| self.node_rpc_url.value.clone()),
+            disable_reward_notifications: !self.reward_notifications,
+            disable_plotting_notifications: !self.plotting_notifications,
+            disable_farm_health_notifications: !self.farm_health_notifications,
+            disable_tray_icon: !self.tray_icon,
+            minimize_to_tray: self.minimize_to_tray,
+            log_level: self.log_level,
+            theme: self.theme,
+            language: self.language.clone(),
+            metrics: MetricsConfiguration {
+                enabled: self.metrics_enabled,
+                address: self.metrics_address.value.clone(),
+            },
+            node_rpc: NodeRpcConfiguration {
+                enabled: self.node_rpc_enabled,
+                address: self.node_rpc_address.value.clone(),
             },
+            enable_control_api: self.control_api,
+            updates: self.updates,
+            offline_mode: self.offline_mode,
         })
     }
 }
@@ -1,27 +1,77 @@
-use crate::backend::config::{Farm, MIN_FARM_SIZE};
-use crate::frontend::configuration::MaybeValid;
+use crate::backend::config::{
+    farm_sector_size, farm_sectors, Farm, FARM_SIZE_ALLOCATION_MARGIN, MIN_FARM_SIZE,
+};
+use crate::backend::farmer::FarmDirectoryState;
+use crate::frontend::configuration::{is_reward_address_valid, MaybeValid};
 use bytesize::ByteSize;
 use gtk::prelude::*;
+use gtk::{gdk, gio};
+use std::cell::Cell;
 use std::fmt;
+use std::num::NonZeroU8;
 // TODO: Remove import once in prelude: https://github.com/Relm4/Relm4/issues/662
 use relm4::factory::AsyncFactoryComponent;
 use relm4::prelude::*;
 // TODO: Remove import once in prelude: https://github.com/Relm4/Relm4/issues/662
-use crate::frontend::configuration::utils::is_directory_writable;
+use crate::frontend::configuration::utils::{
+    available_space, benchmark_directory, delete_farm_data, farm_data_size, path_details,
+    DirectoryBenchmark, PathDetails, StorageKind,
+};
 use crate::frontend::translations::{AsDefaultStr, T};
 use relm4::AsyncFactorySender;
 use relm4_components::simple_combo_box::SimpleComboBox;
 use relm4_icons::icon_name;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
 use tracing::warn;
 
+/// Maximum length of user-defined farm name in characters
+const MAX_FARM_NAME_LENGTH: i32 = 32;
+/// Warn when more than this percentage of farm size doesn't fit into whole sectors
+const WASTED_SPACE_WARNING_PERCENTAGE: u64 = 1;
+/// Delay after last typed character before typed farm path is checked
+const TYPED_PATH_CHECK_DELAY: Duration = Duration::from_millis(500);
+
 fn is_fixed_size_valid(size: &str) -> bool {
     ByteSize::from_str(size)
         .map(|size| size.as_u64() >= MIN_FARM_SIZE)
         .unwrap_or_default()
 }
 
+fn is_reserve_valid(reserve: &str) -> bool {
+    reserve.is_empty() || ByteSize::from_str(reserve).is_ok()
+}
+
+/// Empty reward address means global reward address is used
+fn is_farm_reward_address_valid(reward_address: &str) -> bool {
+    reward_address.is_empty() || is_reward_address_valid(reward_address)
+}
+
+/// Invalid state for screen readers, changes to it are announced by the accessibility layer
+fn accessible_invalid_state(is_valid: bool) -> gtk::accessible::State {
+    gtk::accessible::State::Invalid(if is_valid {
+        gtk::AccessibleInvalidState::False
+    } else {
+        gtk::AccessibleInvalidState::True
+    })
+}
+
+/// Canonical representation of the fixed size that makes units unambiguous (for example `2tb`
+/// becomes `2.0 TB`), `None` if size is invalid or can't be represented without loss of precision
+fn normalize_fixed_size(size: &str) -> Option<String> {
+    if !is_fixed_size_valid(size) {
+        return None;
+    }
+
+    let size = ByteSize::from_str(size).ok()?;
+    // Formatting is lossy, so only use representation that parses back into the same value
+    [false, true]
+        .into_iter()
+        .map(|si_unit| size.to_string_as(si_unit))
+        .find(|formatted| ByteSize::from_str(formatted).ok() == Some(size))
+}
+
 fn is_free_percentage_size_valid(size: &str) -> bool {
     size.ends_with("%")
         && f32::from_str(size.trim_end_matches('%'))
@@ -55,6 +105,19 @@ impl SizeKind {
 pub(super) struct FarmWidgetInit {
     pub(super) path: PathBuf,
     pub(super) size: String,
+    pub(super) name: Option<String>,
+    pub(super) reserve: Option<String>,
+    pub(super) reward_address: Option<String>,
+    pub(super) disabled: bool,
+    pub(super) recent_directories: Vec<PathBuf>,
+    /// Move focus to the farm once it is shown
+    pub(super) grab_focus: bool,
+    /// Show farm as a single line with path and size
+    pub(super) collapsed: bool,
+    /// Percentage of farm's allocated space used for piece cache, determines number of sectors
+    pub(super) cache_percentage: NonZeroU8,
+    /// Farm is opened by running farmer, so its data can't be deleted
+    pub(super) in_use: bool,
 }
 
 impl Default for FarmWidgetInit {
@@ -62,23 +125,94 @@ impl Default for FarmWidgetInit {
         Self {
             path: PathBuf::new(),
             size: String::new(),
+            name: None,
+            reserve: None,
+            reward_address: None,
+            disabled: false,
+            recent_directories: Vec::new(),
+            grab_focus: false,
+            collapsed: false,
+            cache_percentage: NonZeroU8::MIN,
+            in_use: false,
         }
     }
 }
 
 #[derive(Debug)]
 pub(super) enum FarmWidgetInput {
+    ToggleCollapsed,
+    NameChanged(String),
     DirectorySelected(PathBuf),
+    RecentDirectoriesUpdated(Vec<PathBuf>),
+    PathTyped(String),
+    /// Typed path didn't change for a while and can be checked now
+    TypedPathSettled {
+        generation: u64,
+    },
+    PathChecked {
+        generation: u64,
+        path_details: PathDetails,
+    },
+    DuplicatePath(bool),
+    NodePathConflict(bool),
+    /// Piece cache configuration or total size of farms changed
+    CachePercentageChanged(NonZeroU8),
     SizeKindChanged(usize),
     FarmFixedSizeChanged(String),
+    /// Replace fixed size with canonical representation when input is complete
+    NormalizeFixedSize,
+    UseMaxFixedSize,
     FarmFreePercentageSizeChanged(String),
+    ReserveChanged(String),
+    RewardAddressChanged(String),
+    /// Run short benchmark of the selected directory
+    Benchmark,
+    BenchmarkFinished {
+        generation: u64,
+        result: Result<DirectoryBenchmark, String>,
+    },
+    /// Delete farm, asking for confirmation if farm is configured
+    Delete,
+    /// Delete farm together with its data on disk, already confirmed by user
+    DeleteWithData,
+    DeleteDataProgress(f64),
+    DeleteDataFinished(Result<(), String>),
 }
 
 #[derive(Debug)]
 pub(super) enum FarmWidgetOutput {
     OpenDirectory(DynamicIndex),
     ValidityUpdate,
+    /// Allocated size of the farm has changed
+    SizeUpdate,
     Delete(DynamicIndex),
+    /// Move farm from one position to another
+    Move {
+        from: usize,
+        to: DynamicIndex,
+    },
+    /// Farm was collapsed or expanded by user
+    CollapsedChanged {
+        path: PathBuf,
+        collapsed: bool,
+    },
+}
+
+#[derive(Debug)]
+pub(super) enum FarmWidgetCommandOutput {
+    TypedPathSettled {
+        generation: u64,
+    },
+    PathChecked {
+        generation: u64,
+        path_details: PathDetails,
+    },
+    BenchmarkFinished {
+        generation: u64,
+        result: Result<DirectoryBenchmark, String>,
+    },
+    DeleteDataProgress(f64),
+    DeleteDataFinished(Result<(), String>),
 }
 
 #[tracker::track]
@@ -88,7 +222,55 @@ pub(super) struct FarmWidget {
     #[do_not_track]
     index: DynamicIndex,
     #[do_not_track]
+    grab_focus: bool,
+    /// Whether farm is shown as a single line with path and size
+    collapsed: bool,
+    /// Percentage of allocated space used for piece cache
+    #[do_not_track]
+    cache_percentage: NonZeroU8,
+    /// User-defined name, empty if not set
+    #[do_not_track]
+    name: String,
+    #[do_not_track]
     path: MaybeValid<PathBuf>,
+    /// Incremented on every change of the path, used to ignore outdated asynchronous checks
+    #[do_not_track]
+    path_generation: u64,
+    /// Whether path is being checked right now
+    validating_path: bool,
+    /// Recently selected farm directories, most recent first
+    recent_directories: Vec<PathBuf>,
+    #[do_not_track]
+    recent_directories_list: gtk::Box,
+    /// Whether another farm uses the same path
+    duplicate_path: bool,
+    /// Whether farm path is the same as node path or nested with it
+    node_path_conflict: bool,
+    /// Available space on the file system where farm is located, `None` if unknown
+    free_space: Option<u64>,
+    storage_kind: StorageKind,
+    removable_or_network_drive: bool,
+    /// Whether farm is located on the same file system as operating system or home directory
+    system_disk: bool,
+    directory_state: Option<FarmDirectoryState>,
+    /// Path of the farm while it is opened by running farmer, data in it can't be deleted
+    #[do_not_track]
+    in_use_path: Option<PathBuf>,
+    #[do_not_track]
+    device_id: Option<u64>,
+    /// Space already occupied by existing farm in selected directory
+    #[do_not_track]
+    farm_disk_usage: u64,
+    /// Whether requested fixed size fits into available space
+    fits_on_disk: bool,
+    /// Whether directory benchmark is running right now
+    benchmarking: bool,
+    /// Result of the last directory benchmark for current path
+    benchmark: Option<Result<DirectoryBenchmark, String>>,
+    /// Progress of farm data deletion (0.0..=1.0), `None` if data is not being deleted
+    deleting_data: Option<f64>,
+    /// Error that happened during last attempt to delete farm data
+    delete_data_error: Option<String>,
     size_kind: SizeKind,
     #[do_not_track]
     size_kind_selector: Controller<SimpleComboBox<SizeKind>>,
@@ -97,6 +279,15 @@ pub(super) struct FarmWidget {
     /// 0.0%..=100.0%
     #[do_not_track]
     free_percentage_size: MaybeValid<String>,
+    /// Space that must remain free on disk, empty means no reserve
+    #[do_not_track]
+    reserve: MaybeValid<String>,
+    /// Reward address of the farm, empty means global reward address is used
+    #[do_not_track]
+    reward_address: MaybeValid<String>,
+    /// Toggled from the running screen, preserved as is during reconfiguration
+    #[do_not_track]
+    disabled: bool,
 }
 
 #[relm4::factory(pub(super) async)]
@@ -104,42 +295,205 @@ impl AsyncFactoryComponent for FarmWidget {
     type Init = FarmWidgetInit;
     type Input = FarmWidgetInput;
     type Output = FarmWidgetOutput;
-    type CommandOutput = ();
+    type CommandOutput = FarmWidgetCommandOutput;
     type ParentWidget = gtk::ListBox;
 
     view! {
         #[root]
         gtk::ListBoxRow {
+            add_css_class: "farm-drop-target",
             set_activatable: false,
             set_margin_bottom: 10,
             set_selectable: false,
 
+            add_controller = gtk::DropTarget::new(gio::File::static_type(), gdk::DragAction::COPY) {
+                connect_drop[sender] => move |_drop_target, value, _x, _y| {
+                    let Some(path) = value.get::<gio::File>().ok().and_then(|file| file.path()) else {
+                        return false;
+                    };
+                    // Farm can only be a directory, use parent directory if file was dropped
+                    let path = if path.is_dir() {
+                        path
+                    } else {
+                        match path.parent() {
+                            Some(parent) => parent.to_path_buf(),
+                            None => {
+                                return false;
+                            }
+                        }
+                    };
+
+                    sender.input(FarmWidgetInput::DirectorySelected(path));
+                    true
+                },
+            },
+
+            add_controller = gtk::DropTarget::new(u32::static_type(), gdk::DragAction::MOVE) {
+                connect_drop[sender, index] => move |_drop_target, value, _x, _y| {
+                    let Ok(from) = value.get::<u32>() else {
+                        return false;
+                    };
+
+                    if sender
+                        .output(FarmWidgetOutput::Move {
+                            from: from as usize,
+                            to: index.clone(),
+                        })
+                        .is_err()
+                    {
+                        warn!("Can't send move output");
+                    }
+                    true
+                },
+            },
+
             gtk::Box {
                 set_orientation: gtk::Orientation::Vertical,
                 set_spacing: 10,
 
+                gtk::Box {
+                    set_spacing: 10,
+
+                    gtk::Image {
+                        add_controller = gtk::DragSource {
+                            connect_prepare[index] => move |_drag_source, _x, _y| {
+                                Some(gdk::ContentProvider::for_value(
+                                    &(index.current_index() as u32).to_value(),
+                                ))
+                            },
+                            set_actions: gdk::DragAction::MOVE,
+                        },
+                        set_cursor_from_name: Some("grab"),
+                        set_icon_name: Some("list-drag-handle-symbolic"),
+                        set_tooltip: &T.configuration_farm_drag_handle_tooltip(),
+                    },
+
+                    gtk::Button {
+                        add_css_class: "flat",
+                        connect_clicked => FarmWidgetInput::ToggleCollapsed,
+                        #[track = "self.changed_collapsed()"]
+                        set_icon_name: if self.collapsed {
+                            "pan-end-symbolic"
+                        } else {
+                            "pan-down-symbolic"
+                        },
+                        #[track = "self.changed_collapsed()"]
+                        set_tooltip: &if self.collapsed {
+                            T.configuration_farm_expand_tooltip()
+                        } else {
+                            T.configuration_farm_collapse_tooltip()
+                        },
+                        #[track = "self.changed_collapsed()"]
+                        update_property: &[gtk::accessible::Property::Label(&if self.collapsed {
+                            T.configuration_farm_expand_tooltip()
+                        } else {
+                            T.configuration_farm_collapse_tooltip()
+                        })],
+                        #[track = "self.changed_collapsed()"]
+                        update_state: &[gtk::accessible::State::Expanded(Some(!self.collapsed))],
+                    },
+
+                    gtk::Label {
+                        add_css_class: "heading",
+                        set_halign: gtk::Align::Start,
+                        #[track = "self.changed_collapsed()"]
+                        set_hexpand: !self.collapsed,
+                        #[watch]
+                        set_label: &if self.name.is_empty() {
+                            T.configuration_farm(self.index.current_index()).to_string()
+                        } else {
+                            T.configuration_farm_named(self.name.as_str()).to_string()
+                        },
+                    },
+
+                    gtk::Image {
+                        add_css_class: "error-label",
+                        set_icon_name: Some(icon_name::CROSS),
+                        #[watch]
+                        set_visible: self.collapsed && !self.valid(),
+                    },
+
+                    gtk::Label {
+                        add_controller = gtk::GestureClick {
+                            connect_released[sender] => move |_gesture, _n_press, _x, _y| {
+                                sender.input(FarmWidgetInput::ToggleCollapsed);
+                            },
+                        },
+                        #[track = "self.changed_collapsed() || self.changed_deleting_data() || self.path.changed_is_valid() || self.changed_duplicate_path() || self.changed_node_path_conflict() || self.reserve.changed_is_valid() || self.reward_address.changed_is_valid() || self.changed_size_kind() || self.fixed_size.changed_is_valid() || self.free_percentage_size.changed_is_valid()"]
+                        set_css_classes: if self.valid() {
+                            &["dim-label"]
+                        } else {
+                            &["error-label"]
+                        },
+                        set_ellipsize: gtk::pango::EllipsizeMode::Middle,
+                        set_halign: gtk::Align::Start,
+                        set_hexpand: true,
+                        #[track = "self.changed_collapsed() || self.path.changed_value() || self.changed_size_kind() || self.fixed_size.changed_value() || self.free_percentage_size.changed_value()"]
+                        set_label: &self.collapsed_summary(),
+                        set_tooltip: &T.configuration_farm_expand_tooltip(),
+                        #[track = "self.changed_collapsed()"]
+                        set_visible: self.collapsed,
+                    },
+
+                    gtk::Entry {
+                        connect_changed[sender] => move |entry| {
+                            sender.input(FarmWidgetInput::NameChanged(entry.text().into()));
+                        },
+                        set_max_length: MAX_FARM_NAME_LENGTH,
+                        set_placeholder_text: Some(&T.configuration_farm_name_placeholder()),
+                        set_text: &self.name,
+                        set_tooltip: &T.configuration_farm_name_tooltip(),
+                        update_property: &[gtk::accessible::Property::Label(
+                            &T.configuration_farm_name_accessible_label(),
+                        )],
+                        #[track = "self.changed_collapsed()"]
+                        set_visible: !self.collapsed,
+                        set_width_chars: 20,
+                    },
+                },
+
+                gtk::ProgressBar {
+                    #[track = "self.changed_deleting_data()"]
+                    set_fraction: self.deleting_data.unwrap_or_default(),
+                    set_show_text: true,
+                    set_text: Some(&T.configuration_farm_delete_data_progress()),
+                    #[track = "self.changed_deleting_data()"]
+                    set_visible: self.deleting_data.is_some(),
+                },
+
                 gtk::Label {
-                    add_css_class: "heading",
+                    add_css_class: "error-label",
                     set_halign: gtk::Align::Start,
-                    #[watch]
-                    set_label: T.configuration_farm(self.index.current_index()).as_str(),
+                    #[track = "self.changed_delete_data_error()"]
+                    set_label: &self
+                        .delete_data_error
+                        .as_ref()
+                        .map(|error| T.configuration_farm_delete_data_error(error.as_str()).to_string())
+                        .unwrap_or_default(),
+                    #[track = "self.changed_delete_data_error()"]
+                    set_visible: self.delete_data_error.is_some(),
+                    set_wrap: true,
+                    set_xalign: 0.0,
                 },
 
                 gtk::Box {
                     set_spacing: 10,
+                    #[track = "self.changed_collapsed()"]
+                    set_visible: !self.collapsed,
 
                     gtk::Box {
                         add_css_class: "linked",
 
                         gtk::Entry {
+                            connect_changed[sender] => move |entry| {
+                                sender.input(FarmWidgetInput::PathTyped(entry.text().into()));
+                            },
                             #[track = "self.path.changed_is_valid()"]
                             set_css_classes: if self.path.is_valid {
                                 &["valid-input"]
                             } else {
                                 &["invalid-input"]
                             },
-                            set_can_focus: false,
-                            set_editable: false,
                             set_hexpand: true,
                             set_placeholder_text: Some(
                                 T
@@ -154,18 +508,47 @@ impl AsyncFactoryComponent for FarmWidget {
                                     )
                                     .as_str(),
                             ),
-                            set_primary_icon_name: Some(icon_name::SSD),
+                            #[track = "self.changed_storage_kind()"]
+                            set_primary_icon_name: Some(match self.storage_kind {
+                                StorageKind::Ssd | StorageKind::Unknown => icon_name::SSD,
+                                StorageKind::Hdd => "drive-harddisk-symbolic",
+                            }),
                             set_primary_icon_activatable: false,
                             set_primary_icon_sensitive: false,
-                            #[track = "self.path.changed_is_valid()"]
-                            set_secondary_icon_name: self.path.icon(),
+                            #[track = "self.changed_storage_kind()"]
+                            set_primary_icon_tooltip_text: Some(&match self.storage_kind {
+                                StorageKind::Ssd => T.configuration_farm_storage_kind_ssd(),
+                                StorageKind::Hdd => T.configuration_farm_storage_kind_hdd(),
+                                StorageKind::Unknown => T.configuration_farm_storage_kind_unknown(),
+                            }),
+                            #[watch]
+                            set_secondary_icon_name: if self.validating_path {
+                                None
+                            } else if self.path_error().is_some() {
+                                Some(icon_name::CROSS)
+                            } else {
+                                Some(icon_name::CHECKMARK)
+                            },
                             set_secondary_icon_activatable: false,
                             set_secondary_icon_sensitive: false,
+                            #[watch]
+                            set_secondary_icon_tooltip_text: self.path_error().as_deref(),
                             #[track = "self.path.changed_value()"]
                             set_text: self.path.display().to_string().as_str(),
                             set_tooltip_markup: Some(
                                 &T.configuration_farm_path_tooltip()
                             ),
+                            update_property: &[gtk::accessible::Property::Label(
+                                &T.configuration_farm_path_accessible_label(),
+                            )],
+                            #[watch]
+                            update_property: &[gtk::accessible::Property::Description(
+                                &self.path_error().unwrap_or_default(),
+                            )],
+                            #[watch]
+                            update_state: &[accessible_invalid_state(
+                                self.validating_path || self.path_error().is_none(),
+                            )],
                         },
 
                         gtk::Button {
@@ -174,8 +557,39 @@ impl AsyncFactoryComponent for FarmWidget {
                                     warn!("Can't send open directory output");
                                 }
                             },
+                            connect_map[grab_focus = Cell::new(self.grab_focus)] => move |button| {
+                                // Only the first time button is shown
+                                if grab_focus.take() {
+                                    button.grab_focus();
+                                }
+                            },
                             set_label: &T.configuration_farm_path_button_select(),
+                            update_property: &[gtk::accessible::Property::Label(
+                                &T.configuration_farm_path_button_select_accessible_label(),
+                            )],
                         },
+
+                        gtk::MenuButton {
+                            set_icon_name: "document-open-recent-symbolic",
+                            #[wrap(Some)]
+                            set_popover = &gtk::Popover {
+                                self.recent_directories_list.clone(),
+                            },
+                            #[track = "self.changed_recent_directories()"]
+                            set_sensitive: !self.recent_directories.is_empty(),
+                            set_tooltip: &T.configuration_farm_path_button_recent(),
+                            update_property: &[gtk::accessible::Property::Label(
+                                &T.configuration_farm_path_button_recent(),
+                            )],
+                        },
+                    },
+
+                    gtk::Spinner {
+                        #[track = "self.changed_validating_path()"]
+                        set_spinning: self.validating_path,
+                        set_tooltip: &T.configuration_farm_path_checking(),
+                        #[track = "self.changed_validating_path()"]
+                        set_visible: self.validating_path,
                     },
 
                     gtk::Box {
@@ -184,8 +598,14 @@ impl AsyncFactoryComponent for FarmWidget {
                         self.size_kind_selector.widget().clone(),
 
                         gtk::Entry {
+                            add_controller = gtk::EventControllerFocus {
+                                connect_leave[sender] => move |_| {
+                                    sender.input(FarmWidgetInput::NormalizeFixedSize);
+                                },
+                            },
                             connect_activate[sender] => move |entry| {
                                 sender.input(FarmWidgetInput::FarmFixedSizeChanged(entry.text().into()));
+                                sender.input(FarmWidgetInput::NormalizeFixedSize);
                             },
                             connect_changed[sender] => move |entry| {
                                 sender.input(FarmWidgetInput::FarmFixedSizeChanged(entry.text().into()));
@@ -202,12 +622,29 @@ impl AsyncFactoryComponent for FarmWidget {
                             set_primary_icon_name: Some(icon_name::SIZE_HORIZONTALLY),
                             set_primary_icon_activatable: false,
                             set_primary_icon_sensitive: false,
-                            #[track = "self.fixed_size.changed_is_valid()"]
-                            set_secondary_icon_name: self.fixed_size.icon(),
+                            #[watch]
+                            set_secondary_icon_name: if !self.fixed_size.is_valid {
+                                Some(icon_name::CROSS)
+                            } else if !self.fits_on_disk {
+                                Some(icon_name::WARNING)
+                            } else {
+                                Some(icon_name::CHECKMARK)
+                            },
                             set_secondary_icon_activatable: false,
                             set_secondary_icon_sensitive: false,
+                            #[watch]
+                            set_secondary_icon_tooltip_text: self.fixed_size_problem().as_deref(),
                             #[track = "self.fixed_size.changed_value()"]
                             set_text: self.fixed_size.as_str(),
+                            update_property: &[gtk::accessible::Property::Label(
+                                &T.configuration_farm_fixed_size_accessible_label(),
+                            )],
+                            #[watch]
+                            update_property: &[gtk::accessible::Property::Description(
+                                &self.fixed_size_problem().unwrap_or_default(),
+                            )],
+                            #[track = "self.fixed_size.changed_is_valid()"]
+                            update_state: &[accessible_invalid_state(self.fixed_size.is_valid)],
                             set_tooltip_markup: Some(
                                 &T.configuration_farm_fixed_size_tooltip()
                             ),
@@ -215,6 +652,16 @@ impl AsyncFactoryComponent for FarmWidget {
                             set_visible: self.size_kind == SizeKind::Fixed,
                         },
 
+                        gtk::Button {
+                            connect_clicked => FarmWidgetInput::UseMaxFixedSize,
+                            set_label: &T.configuration_farm_fixed_size_button_max(),
+                            #[track = "self.path.changed_is_valid()"]
+                            set_sensitive: self.path.is_valid,
+                            set_tooltip: &T.configuration_farm_fixed_size_button_max_tooltip(),
+                            #[track = "self.changed_size_kind()"]
+                            set_visible: self.size_kind == SizeKind::Fixed,
+                        },
+
                         gtk::Entry {
                             connect_activate[sender] => move |entry| {
                                 sender.input(FarmWidgetInput::FarmFreePercentageSizeChanged(entry.text().into()));
@@ -238,24 +685,237 @@ impl AsyncFactoryComponent for FarmWidget {
                             set_secondary_icon_name: self.free_percentage_size.icon(),
                             set_secondary_icon_activatable: false,
                             set_secondary_icon_sensitive: false,
+                            #[track = "self.free_percentage_size.changed_is_valid()"]
+                            set_secondary_icon_tooltip_text: (!self.free_percentage_size.is_valid)
+                                .then(|| T.configuration_farm_free_percentage_size_error_invalid())
+                                .as_deref(),
                             #[track = "self.free_percentage_size.changed_value()"]
                             set_text: self.free_percentage_size.as_str(),
-                            set_tooltip_markup: Some(
-                                &T.configuration_farm_free_percentage_size_tooltip()
-                            ),
+                            update_property: &[gtk::accessible::Property::Label(
+                                &T.configuration_farm_free_percentage_size_accessible_label(),
+                            )],
+                            #[track = "self.free_percentage_size.changed_is_valid()"]
+                            update_property: &[gtk::accessible::Property::Description(
+                                &(!self.free_percentage_size.is_valid)
+                                    .then(|| T.configuration_farm_free_percentage_size_error_invalid().to_string())
+                                    .unwrap_or_default(),
+                            )],
+                            #[track = "self.free_percentage_size.changed_is_valid()"]
+                            update_state: &[accessible_invalid_state(self.free_percentage_size.is_valid)],
+                            #[watch]
+                            set_tooltip_markup: Some(&match self.resolved_free_percentage_size() {
+                                Some(resolved_size) => format!(
+                                    "{}\n\n{}",
+                                    &*T.configuration_farm_free_percentage_size_tooltip(),
+                                    T.configuration_farm_free_percentage_size_resolved(
+                                        ByteSize::b(resolved_size).to_string_as(true),
+                                    )
+                                    .as_str(),
+                                ),
+                                None => T.configuration_farm_free_percentage_size_tooltip().to_string(),
+                            }),
                             #[track = "self.changed_size_kind()"]
                             set_visible: self.size_kind == SizeKind::FreePercentage,
                         },
                     },
 
-                    gtk::Button {
-                        connect_clicked[sender, index] => move |_| {
-                            if sender.output(FarmWidgetOutput::Delete(index.clone())).is_err() {
-                                warn!("Can't send delete output");
-                            }
+                    gtk::Label {
+                        add_css_class: "dim-label",
+                        #[track = "self.changed_free_space()"]
+                        set_label: &match self.free_space {
+                            Some(free_space) => T
+                                .configuration_farm_free_space(
+                                    ByteSize::b(free_space).to_string_as(true),
+                                )
+                                .to_string(),
+                            None => T.configuration_farm_free_space_unknown().to_string(),
                         },
+                        #[track = "self.path.changed_value()"]
+                        set_visible: self.path.value != PathBuf::new(),
+                    },
+
+                    gtk::Button {
+                        connect_clicked => FarmWidgetInput::Delete,
                         set_icon_name: icon_name::CROSS,
+                        #[track = "self.changed_deleting_data()"]
+                        set_sensitive: self.deleting_data.is_none(),
                         set_tooltip: &T.configuration_farm_delete(),
+                        update_property: &[gtk::accessible::Property::Label(
+                            &T.configuration_farm_delete(),
+                        )],
+                    },
+                },
+
+                gtk::Label {
+                    add_css_class: "dim-label",
+                    set_halign: gtk::Align::Start,
+                    #[watch]
+                    set_label: &self
+                        .whole_sectors()
+                        .map(|(sectors, _wasted)| {
+                            T.configuration_farm_sectors(sectors).to_string()
+                        })
+                        .unwrap_or_default(),
+                    #[watch]
+                    set_visible: !self.collapsed && self.whole_sectors().is_some(),
+                },
+
+                gtk::Label {
+                    add_css_class: "dim-label",
+                    set_halign: gtk::Align::Start,
+                    #[watch]
+                    set_label: &self.fixed_size_preview().unwrap_or_default(),
+                    #[watch]
+                    set_visible: !self.collapsed && self.size_kind == SizeKind::Fixed && self.fixed_size_preview().is_some(),
+                },
+
+                gtk::Label {
+                    #[watch]
+                    set_css_classes: if self.wastes_space() {
+                        &["warning-label"]
+                    } else {
+                        &["dim-label"]
+                    },
+                    set_halign: gtk::Align::Start,
+                    #[watch]
+                    set_label: &self.usable_size_description().unwrap_or_default(),
+                    #[watch]
+                    set_visible: !self.collapsed && self.whole_sectors().is_some(),
+                    set_wrap: true,
+                    set_xalign: 0.0,
+                },
+
+                gtk::Box {
+                    set_spacing: 10,
+                    #[track = "self.changed_collapsed()"]
+                    set_visible: !self.collapsed,
+
+                    gtk::Label {
+                        set_label: &T.configuration_farm_reserve(),
+                    },
+
+                    gtk::Entry {
+                        connect_activate[sender] => move |entry| {
+                            sender.input(FarmWidgetInput::ReserveChanged(entry.text().into()));
+                        },
+                        connect_changed[sender] => move |entry| {
+                            sender.input(FarmWidgetInput::ReserveChanged(entry.text().into()));
+                        },
+                        #[track = "self.reserve.changed_is_valid()"]
+                        set_css_classes: if self.reserve.is_valid {
+                            &["valid-input"]
+                        } else {
+                            &["invalid-input"]
+                        },
+                        set_placeholder_text: Some(&T.configuration_farm_reserve_placeholder()),
+                        #[track = "self.reserve.changed_is_valid()"]
+                        set_secondary_icon_name: self.reserve.icon(),
+                        set_secondary_icon_activatable: false,
+                        set_secondary_icon_sensitive: false,
+                        #[track = "self.reserve.changed_is_valid()"]
+                        set_secondary_icon_tooltip_text: (!self.reserve.is_valid)
+                            .then(|| T.configuration_farm_size_error_invalid_format())
+                            .as_deref(),
+                        #[track = "self.reserve.changed_value()"]
+                        set_text: self.reserve.as_str(),
+                        set_tooltip_markup: Some(&T.configuration_farm_reserve_tooltip()),
+                    },
+                },
+
+                gtk::Box {
+                    set_spacing: 10,
+                    #[track = "self.changed_collapsed()"]
+                    set_visible: !self.collapsed,
+
+                    gtk::Label {
+                        set_label: &T.configuration_farm_reward_address(),
+                    },
+
+                    gtk::Entry {
+                        connect_activate[sender] => move |entry| {
+                            sender.input(FarmWidgetInput::RewardAddressChanged(entry.text().into()));
+                        },
+                        connect_changed[sender] => move |entry| {
+                            sender.input(FarmWidgetInput::RewardAddressChanged(entry.text().into()));
+                        },
+                        #[track = "self.reward_address.changed_is_valid()"]
+                        set_css_classes: if self.reward_address.is_valid {
+                            &["valid-input"]
+                        } else {
+                            &["invalid-input"]
+                        },
+                        set_hexpand: true,
+                        set_placeholder_text: Some(&T.configuration_farm_reward_address_placeholder()),
+                        #[track = "self.reward_address.changed_is_valid()"]
+                        set_secondary_icon_name: self.reward_address.icon(),
+                        set_secondary_icon_activatable: false,
+                        set_secondary_icon_sensitive: false,
+                        #[track = "self.reward_address.changed_is_valid()"]
+                        set_secondary_icon_tooltip_text: (!self.reward_address.is_valid)
+                            .then(|| T.configuration_farm_reward_address_error_invalid())
+                            .as_deref(),
+                        #[track = "self.reward_address.changed_value()"]
+                        set_text: self.reward_address.as_str(),
+                        set_tooltip_markup: Some(&T.configuration_farm_reward_address_tooltip()),
+                    },
+                },
+
+                gtk::Box {
+                    set_spacing: 10,
+                    #[track = "self.changed_collapsed()"]
+                    set_visible: !self.collapsed,
+
+                    gtk::Button {
+                        connect_clicked => FarmWidgetInput::Benchmark,
+                        set_label: &T.configuration_farm_benchmark_button(),
+                        #[track = "self.path.changed_is_valid() || self.changed_benchmarking()"]
+                        set_sensitive: self.path.is_valid && !self.benchmarking,
+                        set_tooltip: &T.configuration_farm_benchmark_button_tooltip(),
+                    },
+
+                    gtk::Spinner {
+                        #[track = "self.changed_benchmarking()"]
+                        set_spinning: self.benchmarking,
+                        #[track = "self.changed_benchmarking()"]
+                        set_visible: self.benchmarking,
+                    },
+
+                    gtk::Label {
+                        #[track = "self.changed_benchmark() || self.changed_benchmarking()"]
+                        set_css_classes: match &self.benchmark {
+                            _ if self.benchmarking => &["dim-label"],
+                            Some(Ok(benchmark)) if benchmark.is_sufficient() => &["success-label"],
+                            Some(Ok(_benchmark)) => &["warning-label"],
+                            Some(Err(_error)) => &["error-label"],
+                            None => &["dim-label"],
+                        },
+                        set_halign: gtk::Align::Start,
+                        #[track = "self.changed_benchmark() || self.changed_benchmarking()"]
+                        set_label: &match &self.benchmark {
+                            _ if self.benchmarking => T.configuration_farm_benchmark_running().to_string(),
+                            Some(Ok(benchmark)) => {
+                                let speeds = T
+                                    .configuration_farm_benchmark_result(
+                                        benchmark.sequential_write,
+                                        benchmark.sequential_read,
+                                        benchmark.random_read,
+                                    )
+                                    .to_string();
+                                let verdict = if benchmark.is_sufficient() {
+                                    T.configuration_farm_benchmark_result_pass()
+                                } else {
+                                    T.configuration_farm_benchmark_result_warn()
+                                };
+
+                                format!("{speeds}. {}", &*verdict)
+                            }
+                            Some(Err(error)) => {
+                                T.configuration_farm_benchmark_error(error.as_str()).to_string()
+                            }
+                            None => String::new(),
+                        },
+                        set_wrap: true,
+                        set_xalign: 0.0,
                     },
                 },
 
@@ -263,8 +923,74 @@ impl AsyncFactoryComponent for FarmWidget {
                     add_css_class: "error-label",
                     set_halign: gtk::Align::Start,
                     set_label: &T.configuration_farm_path_error_doesnt_exist_or_write_permissions(),
-                    #[track = "self.path.changed_is_valid()"]
-                    set_visible: !self.path.is_valid && self.path.value != PathBuf::new(),
+                    #[track = "self.changed_collapsed() || self.path.changed_is_valid() || self.changed_validating_path()"]
+                    set_visible: !self.collapsed && !self.path.is_valid
+                        && !self.validating_path
+                        && self.path.value != PathBuf::new(),
+                },
+
+                gtk::Label {
+                    add_css_class: "error-label",
+                    set_halign: gtk::Align::Start,
+                    set_label: &T.configuration_farm_path_error_duplicate(),
+                    #[track = "self.changed_collapsed() || self.changed_duplicate_path()"]
+                    set_visible: !self.collapsed && self.duplicate_path,
+                },
+
+                gtk::Label {
+                    add_css_class: "error-label",
+                    set_halign: gtk::Align::Start,
+                    set_label: &T.configuration_farm_path_error_node_path_conflict(),
+                    #[track = "self.changed_collapsed() || self.changed_node_path_conflict()"]
+                    set_visible: !self.collapsed && self.node_path_conflict,
+                },
+
+                gtk::Label {
+                    #[track = "self.changed_directory_state()"]
+                    set_css_classes: match self.directory_state {
+                        Some(FarmDirectoryState::ExistingFarm) => &["success-label"],
+                        Some(FarmDirectoryState::UnrelatedFiles) => &["warning-label"],
+                        Some(FarmDirectoryState::Empty) | None => &["dim-label"],
+                    },
+                    set_halign: gtk::Align::Start,
+                    #[track = "self.changed_directory_state()"]
+                    set_label: &match self.directory_state {
+                        Some(FarmDirectoryState::Empty) => T.configuration_farm_path_state_empty(),
+                        Some(FarmDirectoryState::ExistingFarm) => {
+                            T.configuration_farm_path_state_existing_farm()
+                        }
+                        Some(FarmDirectoryState::UnrelatedFiles) => {
+                            T.configuration_farm_path_state_unrelated_files()
+                        }
+                        None => T.configuration_farm_path_state_empty(),
+                    },
+                    #[track = "self.changed_collapsed() || self.path.changed_is_valid() || self.changed_directory_state()"]
+                    set_visible: !self.collapsed && self.path.is_valid && self.directory_state.is_some(),
+                },
+
+                gtk::Label {
+                    add_css_class: "warning-label",
+                    set_halign: gtk::Align::Start,
+                    set_label: &T.configuration_farm_path_warning_removable_or_network_drive(),
+                    #[track = "self.changed_collapsed() || self.changed_removable_or_network_drive()"]
+                    set_visible: !self.collapsed && self.removable_or_network_drive,
+                },
+
+                gtk::Label {
+                    add_css_class: "warning-label",
+                    set_halign: gtk::Align::Start,
+                    set_label: &T.configuration_farm_path_warning_system_disk(),
+                    #[track = "self.changed_collapsed() || self.path.changed_is_valid() || self.changed_system_disk()"]
+                    set_visible: !self.collapsed && self.path.is_valid && self.system_disk,
+                    set_wrap: true,
+                },
+
+                gtk::Label {
+                    add_css_class: "warning-label",
+                    set_halign: gtk::Align::Start,
+                    set_label: &T.configuration_farm_fixed_size_warning_doesnt_fit(),
+                    #[track = "self.changed_collapsed() || self.changed_fits_on_disk()"]
+                    set_visible: !self.collapsed && !self.fits_on_disk,
                 },
             },
         }
@@ -297,6 +1023,20 @@ impl AsyncFactoryComponent for FarmWidget {
             )
         };
 
+        let reserve = value.reserve.unwrap_or_default();
+        let reserve = if is_reserve_valid(&reserve) {
+            MaybeValid::yes(reserve)
+        } else {
+            MaybeValid::no(reserve)
+        };
+
+        let reward_address = value.reward_address.unwrap_or_default();
+        let reward_address = if is_farm_reward_address_valid(&reward_address) {
+            MaybeValid::yes(reward_address)
+        } else {
+            MaybeValid::no(reward_address)
+        };
+
         let size_kind_selector = SimpleComboBox::builder()
             .launch({
                 let variants = SizeKind::all().to_vec();
@@ -311,19 +1051,48 @@ impl AsyncFactoryComponent for FarmWidget {
             })
             .forward(sender.input_sender(), FarmWidgetInput::SizeKindChanged);
 
-        let instance = Self {
+        let in_use_path = value.in_use.then(|| value.path.clone());
+        let mut instance = Self {
             index: index.clone(),
-            path: if is_directory_writable(value.path.clone()).await {
-                MaybeValid::yes(value.path)
-            } else {
-                MaybeValid::no(value.path)
-            },
+            grab_focus: value.grab_focus,
+            // Farm without path needs to be edited, so it is never collapsed
+            collapsed: value.collapsed && value.path != PathBuf::new(),
+            cache_percentage: value.cache_percentage,
+            name: value.name.unwrap_or_default(),
+            path: MaybeValid::no(value.path),
+            path_generation: 0,
+            validating_path: false,
+            recent_directories: Vec::new(),
+            recent_directories_list: gtk::Box::new(gtk::Orientation::Vertical, 0),
+            duplicate_path: false,
+            node_path_conflict: false,
+            free_space: None,
+            storage_kind: StorageKind::Unknown,
+            removable_or_network_drive: false,
+            system_disk: false,
+            directory_state: None,
+            in_use_path,
+            device_id: None,
+            farm_disk_usage: 0,
+            fits_on_disk: true,
+            benchmarking: false,
+            benchmark: None,
+            deleting_data: None,
+            delete_data_error: None,
             size_kind,
             size_kind_selector,
             fixed_size,
             free_percentage_size,
-            tracker: u8::MAX,
+            reserve,
+            reward_address,
+            disabled: value.disabled,
+            tracker: u32::MAX,
         };
+        if instance.path.value != PathBuf::new() {
+            instance.check_path(&sender);
+        }
+        instance.fits_on_disk = instance.size_fits_on_disk();
+        instance.update_recent_directories(value.recent_directories, &sender);
 
         // Send notification up that validity was updated, such that parent view can re-render
         // view if necessary, this is necessary due to async initialization of the model
@@ -340,43 +1109,428 @@ impl AsyncFactoryComponent for FarmWidget {
         self.path.reset();
         self.fixed_size.reset();
         self.free_percentage_size.reset();
+        self.reserve.reset();
+        self.reward_address.reset();
 
         let was_valid = self.valid();
+        let old_allocated_size = self.allocated_size();
+        let mut path_changed = false;
 
         match input {
+            FarmWidgetInput::ToggleCollapsed => {
+                self.set_collapsed(!self.collapsed);
+                if self.path.value != PathBuf::new()
+                    && sender
+                        .output(FarmWidgetOutput::CollapsedChanged {
+                            path: self.path.value.clone(),
+                            collapsed: self.collapsed,
+                        })
+                        .is_err()
+                {
+                    warn!("Can't send collapsed changed output");
+                }
+            }
+            FarmWidgetInput::NameChanged(name) => {
+                self.name = name.trim().to_string();
+            }
             FarmWidgetInput::DirectorySelected(path) => {
-                self.path = if is_directory_writable(path.clone()).await {
-                    MaybeValid::yes(path)
-                } else {
-                    MaybeValid::no(path)
-                };
+                self.path = MaybeValid::no(path);
+                self.check_path(&sender);
+                path_changed = true;
+            }
+            FarmWidgetInput::RecentDirectoriesUpdated(recent_directories) => {
+                self.update_recent_directories(recent_directories, &sender);
+            }
+            FarmWidgetInput::PathTyped(path) => {
+                let path = PathBuf::from(path.trim());
+                // Entry text is also updated programmatically when directory is selected
+                if path != self.path.value {
+                    // Not valid until checked, value is updated without marking it as changed to
+                    // avoid resetting entry text while user is typing
+                    *self.path.get_mut_is_valid() = false;
+                    self.path.value = path;
+                    path_changed = true;
+
+                    self.path_generation += 1;
+                    let generation = self.path_generation;
+                    sender.oneshot_command(async move {
+                        tokio::time::sleep(TYPED_PATH_CHECK_DELAY).await;
+
+                        FarmWidgetCommandOutput::TypedPathSettled { generation }
+                    });
+                }
+            }
+            FarmWidgetInput::TypedPathSettled { generation } => {
+                // Ignore stale checks, only the latest typed value matters
+                if generation == self.path_generation {
+                    self.check_path(&sender);
+                }
+            }
+            FarmWidgetInput::PathChecked {
+                generation,
+                path_details,
+            } => {
+                // Newer path supersedes checks that are still in progress
+                if generation == self.path_generation {
+                    let PathDetails {
+                        is_writable,
+                        free_space,
+                        storage_kind,
+                        removable_or_network_drive,
+                        system_disk,
+                        directory_state,
+                        farm_disk_usage,
+                        device_id,
+                    } = path_details;
+
+                    self.set_validating_path(false);
+                    *self.path.get_mut_is_valid() = is_writable;
+                    self.set_free_space(free_space);
+                    self.set_storage_kind(storage_kind);
+                    self.set_removable_or_network_drive(removable_or_network_drive);
+                    self.set_system_disk(system_disk);
+                    self.set_directory_state(directory_state);
+                    self.device_id = device_id;
+                    self.farm_disk_usage = farm_disk_usage;
+                    path_changed = true;
+                }
+            }
+            FarmWidgetInput::DuplicatePath(duplicate_path) => {
+                self.set_duplicate_path(duplicate_path);
+            }
+            FarmWidgetInput::NodePathConflict(node_path_conflict) => {
+                self.set_node_path_conflict(node_path_conflict);
+            }
+            FarmWidgetInput::CachePercentageChanged(cache_percentage) => {
+                self.cache_percentage = cache_percentage;
             }
             FarmWidgetInput::SizeKindChanged(index) => self.set_size_kind(SizeKind::all()[index]),
             FarmWidgetInput::FarmFixedSizeChanged(size) => {
                 self.fixed_size.set_is_valid(is_fixed_size_valid(&size));
                 self.fixed_size.value = size;
             }
+            FarmWidgetInput::NormalizeFixedSize => {
+                if let Some(normalized_size) = normalize_fixed_size(&self.fixed_size)
+                    && normalized_size != self.fixed_size.value
+                {
+                    self.fixed_size = MaybeValid::yes(normalized_size);
+                }
+            }
+            FarmWidgetInput::UseMaxFixedSize => {
+                // Free space might have changed since directory was selected
+                self.set_free_space(available_space(self.path.value.clone()).await);
+
+                if let Some(available_space) = self.farm_available_space() {
+                    // Reserve must remain free, whole GiBs only, such that value doesn't get
+                    // rounded up when formatted
+                    let size = format!(
+                        "{}GiB",
+                        available_space
+                            .saturating_sub(self.reserve_bytes())
+                            .saturating_sub(FARM_SIZE_ALLOCATION_MARGIN)
+                            / ByteSize::gib(1).as_u64()
+                    );
+                    self.fixed_size = if is_fixed_size_valid(&size) {
+                        MaybeValid::yes(size)
+                    } else {
+                        MaybeValid::no(size)
+                    };
+                }
+            }
             FarmWidgetInput::FarmFreePercentageSizeChanged(size) => {
                 self.free_percentage_size
                     .set_is_valid(is_free_percentage_size_valid(&size));
                 self.free_percentage_size.value = size;
             }
+            FarmWidgetInput::ReserveChanged(reserve) => {
+                let reserve = reserve.trim();
+                self.reserve.set_is_valid(is_reserve_valid(reserve));
+                self.reserve.value = reserve.to_string();
+            }
+            FarmWidgetInput::RewardAddressChanged(reward_address) => {
+                let reward_address = reward_address.trim();
+                self.reward_address
+                    .set_is_valid(is_farm_reward_address_valid(reward_address));
+                self.reward_address.value = reward_address.to_string();
+            }
+            FarmWidgetInput::Benchmark => {
+                self.set_benchmarking(true);
+
+                let generation = self.path_generation;
+                let path = self.path.value.clone();
+                sender.oneshot_command(async move {
+                    FarmWidgetCommandOutput::BenchmarkFinished {
+                        generation,
+                        result: benchmark_directory(path)
+                            .await
+                            .map_err(|error| error.to_string()),
+                    }
+                });
+            }
+            FarmWidgetInput::BenchmarkFinished { generation, result } => {
+                // Benchmark of the previous path is not relevant anymore
+                if generation == self.path_generation {
+                    self.set_benchmarking(false);
+                    self.set_benchmark(Some(result));
+                }
+            }
+            FarmWidgetInput::Delete => {
+                self.delete(sender.clone()).await;
+            }
+            FarmWidgetInput::DeleteWithData => {
+                self.set_deleting_data(Some(0.0));
+                self.set_delete_data_error(None);
+
+                let path = self.path.value.clone();
+                sender.command(move |sender, shutdown_receiver| {
+                    shutdown_receiver
+                        .register(async move {
+                            let result = tokio::task::spawn_blocking({
+                                let sender = sender.clone();
+
+                                move || {
+                                    delete_farm_data(&path, |progress| {
+                                        let _ = sender.send(
+                                            FarmWidgetCommandOutput::DeleteDataProgress(progress),
+                                        );
+                                    })
+                                }
+                            })
+                            .await
+                            .map_err(|error| error.to_string())
+                            .and_then(|result| result.map_err(|error| error.to_string()));
+
+                            let _ =
+                                sender.send(FarmWidgetCommandOutput::DeleteDataFinished(result));
+                        })
+                        .drop_on_shutdown()
+                });
+            }
+            FarmWidgetInput::DeleteDataProgress(progress) => {
+                if self.deleting_data.is_some() {
+                    self.set_deleting_data(Some(progress));
+                }
+            }
+            FarmWidgetInput::DeleteDataFinished(result) => {
+                self.set_deleting_data(None);
+                match result {
+                    Ok(()) => {
+                        if sender
+                            .output(FarmWidgetOutput::Delete(self.index.clone()))
+                            .is_err()
+                        {
+                            warn!("Can't send delete output");
+                        }
+                    }
+                    Err(error) => {
+                        warn!(%error, path = %self.path.display(), "Failed to delete farm data");
+                        self.set_delete_data_error(Some(error));
+                    }
+                }
+            }
         }
 
+        if path_changed && self.benchmark.is_some() {
+            self.set_benchmark(None);
+        }
+
+        self.set_fits_on_disk(self.size_fits_on_disk());
+
         let is_valid = self.valid();
-        if was_valid != is_valid {
+        if was_valid != is_valid || path_changed {
             // Send notification up that validity was updated, such that parent view can re-render
-            // view if necessary
+            // view if necessary, path change also affects validity of other farms due to possible
+            // duplicates
             if sender.output(FarmWidgetOutput::ValidityUpdate).is_err() {
                 warn!("Can't send validity update output");
             }
+        } else if old_allocated_size != self.allocated_size()
+            && sender.output(FarmWidgetOutput::SizeUpdate).is_err()
+        {
+            warn!("Can't send size update output");
+        }
+    }
+
+    async fn update_cmd(&mut self, input: Self::CommandOutput, sender: AsyncFactorySender<Self>) {
+        match input {
+            FarmWidgetCommandOutput::TypedPathSettled { generation } => {
+                sender.input(FarmWidgetInput::TypedPathSettled { generation });
+            }
+            FarmWidgetCommandOutput::PathChecked {
+                generation,
+                path_details,
+            } => {
+                sender.input(FarmWidgetInput::PathChecked {
+                    generation,
+                    path_details,
+                });
+            }
+            FarmWidgetCommandOutput::BenchmarkFinished { generation, result } => {
+                sender.input(FarmWidgetInput::BenchmarkFinished { generation, result });
+            }
+            FarmWidgetCommandOutput::DeleteDataProgress(progress) => {
+                sender.input(FarmWidgetInput::DeleteDataProgress(progress));
+            }
+            FarmWidgetCommandOutput::DeleteDataFinished(result) => {
+                sender.input(FarmWidgetInput::DeleteDataFinished(result));
+            }
         }
     }
 }
 
 impl FarmWidget {
+    fn update_recent_directories(
+        &mut self,
+        recent_directories: Vec<PathBuf>,
+        sender: &AsyncFactorySender<Self>,
+    ) {
+        while let Some(child) = self.recent_directories_list.first_child() {
+            self.recent_directories_list.remove(&child);
+        }
+
+        for directory in &recent_directories {
+            let button = gtk::Button::builder()
+                .has_frame(false)
+                .label(directory.display().to_string())
+                .build();
+            button.connect_clicked({
+                let sender = sender.clone();
+                let directory = directory.clone();
+
+                move |button| {
+                    if let Some(popover) = button
+                        .ancestor(gtk::Popover::static_type())
+                        .and_then(|widget| widget.downcast::<gtk::Popover>().ok())
+                    {
+                        popover.popdown();
+                    }
+                    sender.input(FarmWidgetInput::DirectorySelected(directory.clone()));
+                }
+            });
+            self.recent_directories_list.append(&button);
+        }
+
+        self.set_recent_directories(recent_directories);
+    }
+
+    /// Start asynchronous check of the path, result will arrive as [`FarmWidgetInput::PathChecked`]
+    fn check_path(&mut self, sender: &AsyncFactorySender<Self>) {
+        self.path_generation += 1;
+        self.set_validating_path(true);
+        // Benchmark results for previous path will be ignored
+        self.set_benchmarking(false);
+
+        let generation = self.path_generation;
+        let path = self.path.value.clone();
+        sender.oneshot_command(async move {
+            FarmWidgetCommandOutput::PathChecked {
+                generation,
+                path_details: path_details(path).await,
+            }
+        });
+    }
+
+    async fn delete(&self, sender: AsyncFactorySender<Self>) {
+        // Nothing to lose for farms that don't have path selected yet
+        if self.path.value == PathBuf::new() {
+            if sender
+                .output(FarmWidgetOutput::Delete(self.index.clone()))
+                .is_err()
+            {
+                warn!("Can't send delete output");
+            }
+            return;
+        }
+
+        let dialog = gtk::MessageDialog::builder()
+            .buttons(gtk::ButtonsType::None)
+            .message_type(gtk::MessageType::Question)
+            .modal(true)
+            .secondary_text(self.path.display().to_string())
+            .text(T.configuration_farm_delete_confirmation().as_str())
+            .build();
+        if let Some(window) = relm4::main_application().active_window() {
+            dialog.set_transient_for(Some(&window));
+        }
+        dialog.add_button(
+            &T.configuration_farm_delete_confirmation_button_cancel(),
+            gtk::ResponseType::Cancel,
+        );
+        dialog
+            .add_button(
+                &T.configuration_farm_delete_confirmation_button_delete(),
+                gtk::ResponseType::Accept,
+            )
+            .add_css_class("destructive-action");
+        dialog.set_default_response(gtk::ResponseType::Cancel);
+
+        // Data is kept by default to avoid accidental loss, deleting it is an explicit choice
+        let delete_data = if self.directory_state == Some(FarmDirectoryState::ExistingFarm) {
+            let data_size = farm_data_size(self.path.value.clone()).await;
+            let delete_data = gtk::CheckButton::builder()
+                .active(false)
+                .label(
+                    T.configuration_farm_delete_confirmation_delete_data(
+                        ByteSize::b(data_size).to_string_as(true),
+                    )
+                    .as_str(),
+                )
+                .build();
+            // Farmer keeps files of running farm open, deleting them would corrupt it
+            if self.in_use_path.as_ref() == Some(&self.path.value) {
+                delete_data.set_sensitive(false);
+                delete_data.set_tooltip_text(Some(
+                    &T.configuration_farm_delete_confirmation_delete_data_in_use(),
+                ));
+            }
+            if let Some(message_area) = dialog.message_area().downcast_ref::<gtk::Box>() {
+                message_area.append(&delete_data);
+            }
+
+            Some(delete_data)
+        } else {
+            None
+        };
+
+        let index = self.index.clone();
+        dialog.connect_response(move |dialog, response| {
+            if response == gtk::ResponseType::Accept {
+                if delete_data
+                    .as_ref()
+                    .is_some_and(|delete_data| delete_data.is_active())
+                {
+                    sender.input(FarmWidgetInput::DeleteWithData);
+                } else if sender
+                    .output(FarmWidgetOutput::Delete(index.clone()))
+                    .is_err()
+                {
+                    warn!("Can't send delete output");
+                }
+            }
+            dialog.close();
+        });
+        dialog.present();
+    }
+
+    pub(super) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub(super) fn name(&self) -> &str {
+        &self.name
+    }
+
     pub(super) fn valid(&self) -> bool {
-        if !self.path.is_valid {
+        // Farm that is being deleted must not be saved into configuration
+        if self.deleting_data.is_some() {
+            return false;
+        }
+
+        if !self.path.is_valid || self.duplicate_path || self.node_path_conflict {
+            return false;
+        }
+
+        if !self.reserve.is_valid || !self.reward_address.is_valid {
             return false;
         }
 
@@ -386,6 +1540,190 @@ impl FarmWidget {
         }
     }
 
+    /// Explanation why farm path can't be used, `None` if it is fine
+    fn path_error(&self) -> Option<String> {
+        let error = if self.path.value == PathBuf::new() {
+            T.configuration_farm_path_error_empty()
+        } else if !self.path.is_valid {
+            T.configuration_farm_path_error_doesnt_exist_or_write_permissions()
+        } else if self.duplicate_path {
+            T.configuration_farm_path_error_duplicate()
+        } else if self.node_path_conflict {
+            T.configuration_farm_path_error_node_path_conflict()
+        } else {
+            return None;
+        };
+
+        Some(error.to_string())
+    }
+
+    /// Explanation why fixed size is invalid or problematic, `None` if it is fine
+    fn fixed_size_problem(&self) -> Option<String> {
+        if self.fixed_size.is_valid {
+            return (!self.fits_on_disk).then(|| {
+                T.configuration_farm_fixed_size_warning_doesnt_fit()
+                    .to_string()
+            });
+        }
+
+        Some(match ByteSize::from_str(&self.fixed_size) {
+            Ok(_size) => T
+                .configuration_farm_fixed_size_error_too_small(
+                    ByteSize::b(MIN_FARM_SIZE).to_string_as(true),
+                )
+                .to_string(),
+            Err(_error) => T.configuration_farm_size_error_invalid_format().to_string(),
+        })
+    }
+
+    /// Single line description of the farm shown when it is collapsed
+    fn collapsed_summary(&self) -> String {
+        let size = match self.size_kind {
+            SizeKind::Fixed => self.fixed_size.as_str(),
+            SizeKind::FreePercentage => self.free_percentage_size.as_str(),
+        };
+
+        T.configuration_farm_collapsed_summary(self.path.display().to_string(), size)
+            .to_string()
+    }
+
+    /// Exact interpretation of the fixed size, to make it clear whether decimal or binary units
+    /// were used
+    fn fixed_size_preview(&self) -> Option<String> {
+        if !self.fixed_size.is_valid {
+            return None;
+        }
+
+        let size = ByteSize::from_str(&self.fixed_size).ok()?;
+        Some(
+            T.configuration_farm_fixed_size_preview(
+                size.as_u64(),
+                size.to_string_as(false),
+                size.to_string_as(true),
+            )
+            .to_string(),
+        )
+    }
+
+    /// Number of whole sectors that fit into allocated size and space that remains unused, `None`
+    /// if size is invalid or unknown
+    fn whole_sectors(&self) -> Option<(u64, u64)> {
+        let allocated_size = self.allocated_size()?;
+
+        Some(farm_sectors(allocated_size, self.cache_percentage))
+    }
+
+    /// Whether significant part of allocated size will not be used because it doesn't fit into
+    /// whole sectors
+    fn wastes_space(&self) -> bool {
+        self.allocated_size().zip(self.whole_sectors()).is_some_and(
+            |(allocated_size, (_sectors, wasted))| {
+                wasted * 100 > allocated_size * WASTED_SPACE_WARNING_PERCENTAGE
+            },
+        )
+    }
+
+    /// Effective size that will be used for plotting after rounding down to whole sectors
+    fn usable_size_description(&self) -> Option<String> {
+        let (sectors, wasted) = self.whole_sectors()?;
+        let usable_size = ByteSize::b(sectors * farm_sector_size()).to_string_as(true);
+
+        Some(if self.wastes_space() {
+            T.configuration_farm_usable_size_warning(
+                usable_size,
+                ByteSize::b(wasted).to_string_as(true),
+            )
+            .to_string()
+        } else {
+            T.configuration_farm_usable_size(usable_size).to_string()
+        })
+    }
+
+    /// Reserve in bytes, zero if not set or invalid
+    fn reserve_bytes(&self) -> u64 {
+        ByteSize::from_str(&self.reserve)
+            .map(|reserve| reserve.as_u64())
+            .unwrap_or_default()
+    }
+
+    /// Whether fixed size together with reserve fits into available space, returns `true` if either
+    /// size or available space is unknown
+    fn size_fits_on_disk(&self) -> bool {
+        if self.size_kind != SizeKind::Fixed || !self.fixed_size.is_valid {
+            return true;
+        }
+
+        let Some(free_space) = self.farm_available_space() else {
+            return true;
+        };
+
+        ByteSize::from_str(&self.fixed_size)
+            .map(|size| {
+                // Sizes this large certainly don't fit
+                size.as_u64()
+                    .checked_add(self.reserve_bytes())
+                    .is_some_and(|required_space| required_space <= free_space)
+            })
+            .unwrap_or(true)
+    }
+
+    /// Space farm is able to occupy, which in addition to free space includes space already
+    /// occupied by existing farm in selected directory, `None` if unknown
+    fn farm_available_space(&self) -> Option<u64> {
+        self.free_space
+            .map(|free_space| free_space.saturating_add(self.farm_disk_usage))
+    }
+
+    /// Approximate absolute size that free percentage size will resolve to with currently
+    /// available space, mirrors logic used by backend when applying configuration
+    fn resolved_free_percentage_size(&self) -> Option<u64> {
+        if !self.free_percentage_size.is_valid {
+            return None;
+        }
+
+        let free_space = self
+            .farm_available_space()?
+            .saturating_sub(self.reserve_bytes());
+        let size_percentage =
+            f64::from_str(self.free_percentage_size.trim_end_matches('%')).ok()?;
+        let target_size =
+            free_space.saturating_sub(FARM_SIZE_ALLOCATION_MARGIN) as f64 * size_percentage / 100.0;
+
+        Some(MIN_FARM_SIZE.max(target_size.round() as u64))
+    }
+
+    /// Size farm will occupy (approximate for sizes specified as percentage), `None` if invalid or
+    /// unknown
+    pub(super) fn allocated_size(&self) -> Option<u64> {
+        match self.size_kind {
+            SizeKind::Fixed => {
+                if !self.fixed_size.is_valid {
+                    return None;
+                }
+
+                ByteSize::from_str(&self.fixed_size)
+                    .ok()
+                    .map(|size| size.as_u64())
+            }
+            SizeKind::FreePercentage => self.resolved_free_percentage_size(),
+        }
+    }
+
+    /// Available space on the file system where farm is located, `None` if unknown
+    pub(super) fn free_space(&self) -> Option<u64> {
+        self.free_space
+    }
+
+    /// Identifier of the device farm is located on, `None` if unknown
+    pub(super) fn device_id(&self) -> Option<u64> {
+        self.device_id
+    }
+
+    /// Whether farm is disabled and will not be started
+    pub(super) fn disabled(&self) -> bool {
+        self.disabled
+    }
+
     pub(super) fn farm(&self) -> Farm {
         Farm {
             path: PathBuf::clone(&self.path),
@@ -393,6 +1731,11 @@ impl FarmWidget {
                 SizeKind::Fixed => &self.fixed_size,
                 SizeKind::FreePercentage => &self.free_percentage_size,
             }),
+            name: (!self.name.is_empty()).then(|| self.name.clone()),
+            reserve: (!self.reserve.is_empty()).then(|| self.reserve.value.clone()),
+            reward_address: (!self.reward_address.is_empty())
+                .then(|| self.reward_address.value.clone()),
+            disabled: self.disabled,
         }
     }
 }
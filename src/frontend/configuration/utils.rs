@@ -1,6 +1,55 @@
-use std::path::PathBuf;
+use crate::backend::farmer::{farm_directory_state, FarmDirectoryState, CACHE_PERCENTAGE};
+use bytesize::ByteSize;
+use std::collections::HashMap;
+use std::fs::OpenOptions;
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use std::{fs, io};
+use subspace_farmer::single_disk_farm::SingleDiskFarm;
 use tokio::task;
 
+/// Size of the test file used for directory benchmark
+const BENCHMARK_FILE_SIZE: u64 = ByteSize::mib(256).as_u64();
+/// Block size for sequential reads and writes during directory benchmark
+const BENCHMARK_SEQUENTIAL_BLOCK_SIZE: usize = ByteSize::mib(1).as_u64() as usize;
+/// Block size for random reads during directory benchmark
+const BENCHMARK_RANDOM_BLOCK_SIZE: usize = ByteSize::kib(4).as_u64() as usize;
+/// Number of random reads during directory benchmark
+const BENCHMARK_RANDOM_READS: usize = 4096;
+/// Alignment of buffers, offsets and sizes for reads that bypass OS cache
+const DIRECT_IO_ALIGNMENT: usize = 4096;
+/// Sequential write speed in MB/s below which plotting is likely to be bottlenecked by the disk
+const MIN_SEQUENTIAL_WRITE_SPEED: f64 = 100.0;
+/// Random read speed in MB/s (with 4 KiB blocks) below which farming is likely to be too slow
+const MIN_RANDOM_READ_SPEED: f64 = 20.0;
+
+/// Disks with less free space than this are not suggested for farming
+const MIN_SUGGESTED_FARM_FREE_SPACE: u64 = ByteSize::gib(100).as_u64();
+/// Name of the directory created for suggested farm in the root of the disk
+const SUGGESTED_FARM_DIRECTORY_NAME: &str = "space-acres-farm";
+
+/// Files farmer creates in farm directory, nothing else is deleted together with farm data
+const FARM_DATA_FILES: &[&str] = &[
+    "plot.bin",
+    "piece_cache.bin",
+    "metadata.bin",
+    "identity.bin",
+    "single_disk_farm.json",
+    "single_disk_farm.lock",
+];
+/// Large files are truncated in steps of this size before removal, such that deletion progress
+/// can be reported and space is freed gradually
+const FARM_DATA_DELETION_STEP: u64 = ByteSize::gib(16).as_u64();
+
+/// Kind of storage device farm is located on
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(super) enum StorageKind {
+    Ssd,
+    Hdd,
+    Unknown,
+}
+
 pub(super) async fn is_directory_writable(path: PathBuf) -> bool {
     if path == PathBuf::new() {
         return false;
@@ -23,3 +72,1040 @@ pub(super) async fn is_directory_writable(path: PathBuf) -> bool {
     .await
     .unwrap_or_default()
 }
+
+/// Details about directory and the file system and storage device it is located on
+#[derive(Debug)]
+pub(super) struct PathDetails {
+    pub(super) is_writable: bool,
+    pub(super) free_space: Option<u64>,
+    pub(super) storage_kind: StorageKind,
+    pub(super) removable_or_network_drive: bool,
+    /// Whether path is located on the same file system as operating system or home directory
+    pub(super) system_disk: bool,
+    /// `None` if state can't be determined
+    pub(super) directory_state: Option<FarmDirectoryState>,
+    /// Space already occupied by existing farm in this directory, `0` if there is no farm
+    pub(super) farm_disk_usage: u64,
+    /// Identifier of the device file system is located on, `None` if unknown
+    pub(super) device_id: Option<u64>,
+}
+
+/// Result of a short directory benchmark, all speeds are in MB/s
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(super) struct DirectoryBenchmark {
+    pub(super) sequential_write: f64,
+    pub(super) sequential_read: f64,
+    pub(super) random_read: f64,
+}
+
+impl DirectoryBenchmark {
+    /// Whether disk is fast enough for plotting and farming
+    pub(super) fn is_sufficient(&self) -> bool {
+        self.sequential_write >= MIN_SEQUENTIAL_WRITE_SPEED
+            && self.random_read >= MIN_RANDOM_READ_SPEED
+    }
+}
+
+pub(super) async fn path_details(path: PathBuf) -> PathDetails {
+    let device_id = device_id(path.clone()).await;
+    let directory_state = directory_state(path.clone()).await;
+    let farm_disk_usage = if directory_state == Some(FarmDirectoryState::ExistingFarm) {
+        farm_disk_usage(path.clone()).await
+    } else {
+        0
+    };
+
+    PathDetails {
+        is_writable: is_directory_writable(path.clone()).await,
+        free_space: available_space(path.clone()).await,
+        storage_kind: storage_kind(path.clone()).await,
+        removable_or_network_drive: is_removable_or_network_drive(path.clone()).await,
+        system_disk: is_on_system_disk(&path, device_id).await,
+        directory_state,
+        farm_disk_usage,
+        device_id,
+    }
+}
+
+/// Check whether `path` with file system located on device `path_device_id` is on the same file
+/// system as operating system or home directory, filling it up might break the system
+#[cfg(unix)]
+async fn is_on_system_disk(path: &Path, path_device_id: Option<u64>) -> bool {
+    if path == Path::new("") || path_device_id.is_none() {
+        return false;
+    }
+
+    let system_paths = std::iter::once(PathBuf::from("/")).chain(dirs::home_dir());
+    for system_path in system_paths {
+        if device_id(system_path).await == path_device_id {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Check whether `path` with file system located on volume `path_device_id` is on the same volume
+/// as operating system or home directory, filling it up might break the system
+#[cfg(windows)]
+async fn is_on_system_disk(path: &Path, path_device_id: Option<u64>) -> bool {
+    if path == Path::new("") || path_device_id.is_none() {
+        return false;
+    }
+
+    let system_drive = std::env::var("SystemDrive").unwrap_or_else(|_error| "C:".to_string());
+    let system_paths =
+        std::iter::once(PathBuf::from(format!("{system_drive}\\"))).chain(dirs::home_dir());
+    for system_path in system_paths {
+        if device_id(system_path).await == path_device_id {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(not(any(unix, windows)))]
+async fn is_on_system_disk(_path: &Path, _path_device_id: Option<u64>) -> bool {
+    false
+}
+
+async fn device_id(path: PathBuf) -> Option<u64> {
+    if path == PathBuf::new() {
+        return None;
+    }
+
+    task::spawn_blocking(move || {
+        // Directory may not exist yet, in which case check the closest existing ancestor
+        let existing_path = path.ancestors().find(|path| path.exists())?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+
+            existing_path.metadata().ok().map(|metadata| metadata.dev())
+        }
+        #[cfg(windows)]
+        {
+            volume_serial_number(existing_path).map(u64::from)
+        }
+        #[cfg(not(any(unix, windows)))]
+        {
+            let _ = existing_path;
+            None
+        }
+    })
+    .await
+    .ok()
+    .flatten()
+}
+
+async fn directory_state(path: PathBuf) -> Option<FarmDirectoryState> {
+    if path == PathBuf::new() {
+        return None;
+    }
+
+    task::spawn_blocking(move || farm_directory_state(&path).ok())
+        .await
+        .ok()
+        .flatten()
+}
+
+/// Space occupied by existing farm in `path`, computed the same way as backend does it when
+/// checking how much space farm is able to occupy, `0` if it can't be determined
+async fn farm_disk_usage(path: PathBuf) -> u64 {
+    task::spawn_blocking(move || {
+        SingleDiskFarm::effective_disk_usage(&path, CACHE_PERCENTAGE.get()).ok()
+    })
+    .await
+    .ok()
+    .flatten()
+    .unwrap_or_default()
+}
+
+/// Returns available space on the file system where `path` is (or will be) located, `None` if it
+/// can't be determined
+pub(super) async fn available_space(path: PathBuf) -> Option<u64> {
+    if path == PathBuf::new() {
+        return None;
+    }
+
+    task::spawn_blocking(move || {
+        // Directory may not exist yet, in which case check the closest existing ancestor
+        let existing_path = path.ancestors().find(|path| path.exists())?;
+        fs4::available_space(existing_path).ok()
+    })
+    .await
+    .ok()
+    .flatten()
+}
+
+/// Run short benchmark of sequential write, sequential read and random read in `path` (or the
+/// closest existing ancestor if it doesn't exist yet).
+///
+/// Test file is removed once benchmark is done. It is read back bypassing OS cache, such that read
+/// speeds reflect the disk rather than memory.
+pub(super) async fn benchmark_directory(path: PathBuf) -> io::Result<DirectoryBenchmark> {
+    task::spawn_blocking(move || {
+        let existing_path = path
+            .ancestors()
+            .find(|path| path.exists())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Folder doesn't exist"))?;
+        // Named, such that it can be opened again for reading with different flags
+        let mut test_file = tempfile::NamedTempFile::new_in(existing_path)?;
+
+        // Random-looking contents, such that file system compression doesn't skew results
+        let mut state = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_nanos() as u64)
+            .unwrap_or_default()
+            | 1;
+        let mut next_random = move || {
+            // xorshift64
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        // Over-allocated, such that aligned block can be used for reads that bypass OS cache
+        let mut buffer = vec![0u8; BENCHMARK_SEQUENTIAL_BLOCK_SIZE + DIRECT_IO_ALIGNMENT];
+        let aligned_offset = buffer.as_ptr().align_offset(DIRECT_IO_ALIGNMENT);
+        let block = &mut buffer[aligned_offset..][..BENCHMARK_SEQUENTIAL_BLOCK_SIZE];
+        for chunk in block.chunks_exact_mut(size_of::<u64>()) {
+            chunk.copy_from_slice(&next_random().to_le_bytes());
+        }
+        let blocks = BENCHMARK_FILE_SIZE / BENCHMARK_SEQUENTIAL_BLOCK_SIZE as u64;
+
+        let start = Instant::now();
+        for _ in 0..blocks {
+            test_file.write_all(block)?;
+        }
+        test_file.as_file().sync_all()?;
+        let sequential_write = speed(BENCHMARK_FILE_SIZE, start);
+
+        let mut file = open_uncached(test_file.path())?;
+        let start = Instant::now();
+        for _ in 0..blocks {
+            file.read_exact(block)?;
+        }
+        let sequential_read = speed(BENCHMARK_FILE_SIZE, start);
+
+        let random_blocks = BENCHMARK_FILE_SIZE / BENCHMARK_RANDOM_BLOCK_SIZE as u64;
+        let block = &mut block[..BENCHMARK_RANDOM_BLOCK_SIZE];
+        let start = Instant::now();
+        for _ in 0..BENCHMARK_RANDOM_READS {
+            let offset = next_random() % random_blocks * BENCHMARK_RANDOM_BLOCK_SIZE as u64;
+            file.seek(SeekFrom::Start(offset))?;
+            file.read_exact(block)?;
+        }
+        let random_read = speed(
+            (BENCHMARK_RANDOM_READS * BENCHMARK_RANDOM_BLOCK_SIZE) as u64,
+            start,
+        );
+
+        Ok(DirectoryBenchmark {
+            sequential_write,
+            sequential_read,
+            random_read,
+        })
+    })
+    .await
+    .map_err(io::Error::other)?
+}
+
+/// Open file for reading such that reads bypass OS cache
+#[cfg(target_os = "linux")]
+fn open_uncached(path: &Path) -> io::Result<fs::File> {
+    use std::os::fd::AsRawFd;
+    use std::os::unix::fs::OpenOptionsExt;
+
+    match OpenOptions::new()
+        .read(true)
+        .custom_flags(libc::O_DIRECT)
+        .open(path)
+    {
+        Ok(file) => Ok(file),
+        Err(error) if error.raw_os_error() == Some(libc::EINVAL) => {
+            // Some file systems (like tmpfs) don't support direct I/O, drop cached pages instead
+            let file = fs::File::open(path)?;
+            // SAFETY: File descriptor is valid for the lifetime of the file
+            let result =
+                unsafe { libc::posix_fadvise(file.as_raw_fd(), 0, 0, libc::POSIX_FADV_DONTNEED) };
+            if result != 0 {
+                return Err(io::Error::from_raw_os_error(result));
+            }
+
+            Ok(file)
+        }
+        Err(error) => Err(error),
+    }
+}
+
+/// Open file for reading such that reads bypass OS cache
+#[cfg(target_os = "macos")]
+fn open_uncached(path: &Path) -> io::Result<fs::File> {
+    use std::os::fd::AsRawFd;
+
+    let file = fs::File::open(path)?;
+    // SAFETY: File descriptor is valid for the lifetime of the file
+    if unsafe { libc::fcntl(file.as_raw_fd(), libc::F_NOCACHE, 1) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    Ok(file)
+}
+
+/// Open file for reading such that reads bypass OS cache
+#[cfg(windows)]
+fn open_uncached(path: &Path) -> io::Result<fs::File> {
+    use std::os::windows::fs::OpenOptionsExt;
+    use windows_sys::Win32::Storage::FileSystem::FILE_FLAG_NO_BUFFERING;
+
+    OpenOptions::new()
+        .read(true)
+        .custom_flags(FILE_FLAG_NO_BUFFERING)
+        .open(path)
+}
+
+/// Open file for reading, bypassing OS cache is not supported on this platform
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn open_uncached(path: &Path) -> io::Result<fs::File> {
+    fs::File::open(path)
+}
+
+/// Total size of files farmer created in farm directory
+pub(super) async fn farm_data_size(path: PathBuf) -> u64 {
+    task::spawn_blocking(move || {
+        FARM_DATA_FILES
+            .iter()
+            .filter_map(|file_name| fs::metadata(path.join(file_name)).ok())
+            .map(|metadata| metadata.len())
+            .sum()
+    })
+    .await
+    .unwrap_or_default()
+}
+
+/// Delete files farmer created in farm directory, blocking.
+///
+/// `progress` is called with fraction of data deleted so far (0.0..=1.0).
+pub(super) fn delete_farm_data(path: &Path, mut progress: impl FnMut(f64)) -> io::Result<()> {
+    let files = FARM_DATA_FILES
+        .iter()
+        .map(|file_name| path.join(file_name))
+        .filter_map(|file| {
+            let metadata = fs::metadata(&file).ok()?;
+            metadata.is_file().then_some((file, metadata.len()))
+        })
+        .collect::<Vec<_>>();
+    let total_size = files.iter().map(|(_file, size)| *size).sum::<u64>().max(1);
+    let mut deleted_size = 0;
+
+    for (file, size) in files {
+        let mut remaining_size = size;
+        if remaining_size > FARM_DATA_DELETION_STEP {
+            let file = OpenOptions::new().write(true).open(&file)?;
+            while remaining_size > FARM_DATA_DELETION_STEP {
+                remaining_size -= FARM_DATA_DELETION_STEP;
+                file.set_len(remaining_size)?;
+                deleted_size += FARM_DATA_DELETION_STEP;
+                progress(deleted_size as f64 / total_size as f64);
+            }
+        }
+
+        fs::remove_file(&file)?;
+        deleted_size += remaining_size;
+        progress(deleted_size as f64 / total_size as f64);
+    }
+
+    Ok(())
+}
+
+/// Speed in MB/s for given number of bytes processed since `start`
+fn speed(bytes: u64, start: Instant) -> f64 {
+    bytes as f64 / ByteSize::mb(1).as_u64() as f64 / start.elapsed().as_secs_f64().max(f64::EPSILON)
+}
+
+/// Keep only paths that still exist
+pub(super) async fn retain_existing_paths(paths: Vec<PathBuf>) -> Vec<PathBuf> {
+    task::spawn_blocking(move || {
+        paths
+            .into_iter()
+            .filter(|path| path.exists())
+            .collect::<Vec<_>>()
+    })
+    .await
+    .unwrap_or_default()
+}
+
+/// Normalize path such that different ways of referring to the same directory result in the same
+/// path, best-effort
+fn normalize_path(path: &Path) -> PathBuf {
+    let normalized_path = match path.canonicalize() {
+        Ok(path) => path,
+        // Directory may not exist yet, try to canonicalize parent directory instead
+        Err(_error) => match (path.parent(), path.file_name()) {
+            (Some(parent), Some(file_name)) => parent
+                .canonicalize()
+                .map(|parent| parent.join(file_name))
+                .unwrap_or_else(|_error| path.to_path_buf()),
+            _ => path.to_path_buf(),
+        },
+    };
+
+    // Default file systems on Windows and macOS are case-insensitive
+    if cfg!(any(windows, target_os = "macos")) {
+        PathBuf::from(normalized_path.to_string_lossy().to_lowercase())
+    } else {
+        normalized_path
+    }
+}
+
+/// For each path returns whether there is another path in the list that points to the same
+/// directory, empty paths are never considered to be duplicates
+pub(super) async fn find_duplicate_paths(paths: Vec<PathBuf>) -> Vec<bool> {
+    task::spawn_blocking(move || {
+        let normalized_paths = paths
+            .iter()
+            .map(|path| (path != &PathBuf::new()).then(|| normalize_path(path)))
+            .collect::<Vec<_>>();
+
+        let mut occurrences = HashMap::<&Path, usize>::new();
+        for path in normalized_paths.iter().flatten() {
+            *occurrences.entry(path).or_default() += 1;
+        }
+
+        normalized_paths
+            .iter()
+            .map(|maybe_path| {
+                maybe_path
+                    .as_ref()
+                    .and_then(|path| occurrences.get(path.as_path()))
+                    .map(|&count| count > 1)
+                    .unwrap_or_default()
+            })
+            .collect()
+    })
+    .await
+    .unwrap_or_default()
+}
+
+/// For each farm path returns whether it is the same as node path or one of them is nested in the
+/// other, empty paths never conflict
+pub(super) async fn find_node_path_conflicts(
+    node_path: PathBuf,
+    farm_paths: Vec<PathBuf>,
+) -> Vec<bool> {
+    task::spawn_blocking(move || {
+        if node_path == PathBuf::new() {
+            return vec![false; farm_paths.len()];
+        }
+        let node_path = normalize_path(&node_path);
+
+        farm_paths
+            .iter()
+            .map(|farm_path| {
+                if farm_path == &PathBuf::new() {
+                    return false;
+                }
+                let farm_path = normalize_path(farm_path);
+
+                farm_path.starts_with(&node_path) || node_path.starts_with(&farm_path)
+            })
+            .collect()
+    })
+    .await
+    .unwrap_or_default()
+}
+
+/// Detect kind of storage device where `path` is (or will be) located, best-effort
+pub(super) async fn storage_kind(path: PathBuf) -> StorageKind {
+    if path == PathBuf::new() {
+        return StorageKind::Unknown;
+    }
+
+    task::spawn_blocking(move || {
+        // Directory may not exist yet, in which case check the closest existing ancestor
+        let Some(existing_path) = path.ancestors().find(|path| path.exists()) else {
+            return StorageKind::Unknown;
+        };
+
+        detect_storage_kind(existing_path)
+    })
+    .await
+    .unwrap_or(StorageKind::Unknown)
+}
+
+#[cfg(target_os = "linux")]
+fn detect_storage_kind(path: &Path) -> StorageKind {
+    match linux_block_device_attribute(path, "queue/rotational").as_deref() {
+        Some("0") => StorageKind::Ssd,
+        Some("1") => StorageKind::Hdd,
+        _ => StorageKind::Unknown,
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn detect_storage_kind(path: &Path) -> StorageKind {
+    let Some(disk_info) = macos_statfs(path).and_then(|stat| macos_disk_info(&stat)) else {
+        return StorageKind::Unknown;
+    };
+
+    match macos_disk_info_value(&disk_info, "Solid State") {
+        Some("Yes") => StorageKind::Ssd,
+        Some("No") => StorageKind::Hdd,
+        _ => StorageKind::Unknown,
+    }
+}
+
+#[cfg(windows)]
+fn detect_storage_kind(path: &Path) -> StorageKind {
+    use std::os::windows::fs::OpenOptionsExt;
+    use std::os::windows::io::AsRawHandle;
+    use std::{ffi, ptr};
+    use windows_sys::Win32::Storage::FileSystem::{FILE_SHARE_READ, FILE_SHARE_WRITE};
+    use windows_sys::Win32::System::Ioctl::{
+        PropertyStandardQuery, StorageDeviceSeekPenaltyProperty, DEVICE_SEEK_PENALTY_DESCRIPTOR,
+        IOCTL_STORAGE_QUERY_PROPERTY, STORAGE_PROPERTY_QUERY,
+    };
+    use windows_sys::Win32::System::IO::DeviceIoControl;
+
+    let Some(volume_name) = volume_name(path) else {
+        return StorageKind::Unknown;
+    };
+    // Volume is opened without read or write access, which is sufficient for querying its
+    // properties and doesn't require administrator privileges
+    let Ok(volume) = OpenOptions::new()
+        .access_mode(0)
+        .share_mode(FILE_SHARE_READ | FILE_SHARE_WRITE)
+        .open(volume_name)
+    else {
+        return StorageKind::Unknown;
+    };
+
+    let query = STORAGE_PROPERTY_QUERY {
+        PropertyId: StorageDeviceSeekPenaltyProperty,
+        QueryType: PropertyStandardQuery,
+        AdditionalParameters: [0],
+    };
+    // SAFETY: All zeroes is a valid value for this plain data structure
+    let mut descriptor = unsafe { std::mem::zeroed::<DEVICE_SEEK_PENALTY_DESCRIPTOR>() };
+    let mut bytes_returned = 0u32;
+    // SAFETY: Handle is valid while `volume` is alive, input and output buffers are valid for
+    // their respective sizes and operation is synchronous
+    let success = unsafe {
+        DeviceIoControl(
+            volume.as_raw_handle() as _,
+            IOCTL_STORAGE_QUERY_PROPERTY,
+            ptr::from_ref(&query).cast::<ffi::c_void>(),
+            size_of::<STORAGE_PROPERTY_QUERY>() as u32,
+            ptr::from_mut(&mut descriptor).cast::<ffi::c_void>(),
+            size_of::<DEVICE_SEEK_PENALTY_DESCRIPTOR>() as u32,
+            &mut bytes_returned,
+            ptr::null_mut(),
+        )
+    };
+    if success == 0 || (bytes_returned as usize) < size_of::<DEVICE_SEEK_PENALTY_DESCRIPTOR>() {
+        return StorageKind::Unknown;
+    }
+
+    if descriptor.IncursSeekPenalty != 0 {
+        StorageKind::Hdd
+    } else {
+        StorageKind::Ssd
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn detect_storage_kind(_path: &Path) -> StorageKind {
+    StorageKind::Unknown
+}
+
+/// Amount of RAM currently available for new allocations without swapping on this machine,
+/// best-effort
+pub(super) async fn available_memory() -> Option<u64> {
+    task::spawn_blocking(detect_available_memory)
+        .await
+        .ok()
+        .flatten()
+}
+
+#[cfg(target_os = "linux")]
+fn detect_available_memory() -> Option<u64> {
+    let meminfo = fs::read_to_string("/proc/meminfo").ok()?;
+    let kib = meminfo
+        .lines()
+        .find_map(|line| line.strip_prefix("MemAvailable:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+
+    Some(kib * 1024)
+}
+
+#[cfg(target_os = "macos")]
+fn detect_available_memory() -> Option<u64> {
+    let output = std::process::Command::new("vm_stat").output().ok()?;
+    let output = String::from_utf8(output.stdout).ok()?;
+    let mut lines = output.lines();
+
+    // Header looks like `Mach Virtual Memory Statistics: (page size of 16384 bytes)`
+    let page_size = lines
+        .next()?
+        .split_once("page size of ")?
+        .1
+        .split_whitespace()
+        .next()?
+        .parse::<u64>()
+        .ok()?;
+    // Free pages and pages that OS can reclaim right away
+    let pages = lines
+        .filter_map(|line| {
+            let (name, value) = line.split_once(':')?;
+            matches!(
+                name,
+                "Pages free" | "Pages inactive" | "Pages speculative" | "Pages purgeable"
+            )
+            .then(|| value.trim().trim_end_matches('.').parse::<u64>().ok())
+            .flatten()
+        })
+        .sum::<u64>();
+
+    Some(pages * page_size)
+}
+
+#[cfg(windows)]
+fn detect_available_memory() -> Option<u64> {
+    use windows_sys::Win32::System::SystemInformation::{GlobalMemoryStatusEx, MEMORYSTATUSEX};
+
+    // SAFETY: All zeroes is a valid value for this plain data structure
+    let mut memory_status = unsafe { std::mem::zeroed::<MEMORYSTATUSEX>() };
+    memory_status.dwLength = size_of::<MEMORYSTATUSEX>() as u32;
+    // SAFETY: Structure is valid for writes and its length is set as required
+    if unsafe { GlobalMemoryStatusEx(&mut memory_status) } == 0 {
+        return None;
+    }
+
+    Some(memory_status.ullAvailPhys)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn detect_available_memory() -> Option<u64> {
+    None
+}
+
+/// Check whether `path` is (or will be) located on a removable or network drive, best-effort
+pub(super) async fn is_removable_or_network_drive(path: PathBuf) -> bool {
+    if path == PathBuf::new() {
+        return false;
+    }
+
+    task::spawn_blocking(move || {
+        // Directory may not exist yet, in which case check the closest existing ancestor
+        let Some(existing_path) = path.ancestors().find(|path| path.exists()) else {
+            return false;
+        };
+
+        detect_removable_or_network_drive(existing_path)
+    })
+    .await
+    .unwrap_or_default()
+}
+
+#[cfg(target_os = "linux")]
+fn detect_removable_or_network_drive(path: &Path) -> bool {
+    /// File systems that are backed by network storage
+    const NETWORK_FILE_SYSTEMS: &[&str] = &[
+        "9p",
+        "afs",
+        "ceph",
+        "cifs",
+        "davfs",
+        "fuse.sshfs",
+        "glusterfs",
+        "ncpfs",
+        "nfs",
+        "nfs4",
+        "smb3",
+        "smbfs",
+    ];
+
+    if linux_block_device_attribute(path, "removable").as_deref() == Some("1") {
+        return true;
+    }
+
+    let Ok(path) = path.canonicalize() else {
+        return false;
+    };
+    let Ok(mounts) = std::fs::read_to_string("/proc/self/mounts") else {
+        return false;
+    };
+
+    // The most specific mount point that contains the path is the one path belongs to
+    let file_system_type = mounts
+        .lines()
+        .filter_map(|line| {
+            let mut parts = line.split_whitespace();
+            let mount_point = parts.nth(1)?;
+            let file_system_type = parts.next()?;
+            // Spaces in mount points are escaped in mounts file
+            let mount_point = PathBuf::from(mount_point.replace("\\040", " "));
+
+            path.starts_with(&mount_point)
+                .then_some((mount_point, file_system_type))
+        })
+        .max_by_key(|(mount_point, _file_system_type)| mount_point.components().count())
+        .map(|(_mount_point, file_system_type)| file_system_type);
+
+    file_system_type
+        .map(|file_system_type| NETWORK_FILE_SYSTEMS.contains(&file_system_type))
+        .unwrap_or_default()
+}
+
+#[cfg(target_os = "macos")]
+fn detect_removable_or_network_drive(path: &Path) -> bool {
+    let Some(stat) = macos_statfs(path) else {
+        return false;
+    };
+
+    // Volumes that are not local are network volumes
+    if stat.f_flags & libc::MNT_LOCAL as u32 == 0 {
+        return true;
+    }
+
+    // Removable media is only known to disk arbitration
+    macos_disk_info(&stat).is_some_and(|disk_info| {
+        macos_disk_info_value(&disk_info, "Removable Media") == Some("Removable")
+    })
+}
+
+/// File system statistics of the file system `path` is located on
+#[cfg(target_os = "macos")]
+fn macos_statfs(path: &Path) -> Option<libc::statfs> {
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+
+    let c_path = CString::new(path.as_os_str().as_bytes()).ok()?;
+    let mut stat = std::mem::MaybeUninit::<libc::statfs>::uninit();
+    // SAFETY: Path is a valid null-terminated string and `stat` is valid for writes
+    if unsafe { libc::statfs(c_path.as_ptr(), stat.as_mut_ptr()) } != 0 {
+        return None;
+    }
+    // SAFETY: Initialized by successful `statfs()` call above
+    Some(unsafe { stat.assume_init() })
+}
+
+/// Information about the disk file system is mounted from, as printed by `diskutil info`
+#[cfg(target_os = "macos")]
+fn macos_disk_info(stat: &libc::statfs) -> Option<String> {
+    use std::ffi::CStr;
+
+    // SAFETY: `f_mntfromname` is null-terminated by `statfs()`
+    let device = unsafe { CStr::from_ptr(stat.f_mntfromname.as_ptr()) };
+    let output = std::process::Command::new("diskutil")
+        .arg("info")
+        .arg(device.to_string_lossy().as_ref())
+        .output()
+        .ok()?;
+
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Value of `key` in output of `diskutil info`
+#[cfg(target_os = "macos")]
+fn macos_disk_info_value<'a>(disk_info: &'a str, key: &str) -> Option<&'a str> {
+    disk_info.lines().find_map(|line| {
+        let (line_key, value) = line.split_once(':')?;
+        (line_key.trim() == key).then_some(value.trim())
+    })
+}
+
+#[cfg(windows)]
+fn detect_removable_or_network_drive(path: &Path) -> bool {
+    use windows_sys::Win32::Storage::FileSystem::GetDriveTypeW;
+
+    // Values returned by `GetDriveTypeW()`
+    const DRIVE_REMOVABLE: u32 = 2;
+    const DRIVE_REMOTE: u32 = 4;
+
+    let Some(volume_path) = volume_path(path) else {
+        return false;
+    };
+
+    // SAFETY: Volume path is null-terminated
+    let drive_type = unsafe { GetDriveTypeW(volume_path.as_ptr()) };
+
+    matches!(drive_type, DRIVE_REMOVABLE | DRIVE_REMOTE)
+}
+
+/// Serial number of the volume `path` is located on, `None` if it can't be determined
+#[cfg(windows)]
+fn volume_serial_number(path: &Path) -> Option<u32> {
+    use std::ptr;
+    use windows_sys::Win32::Storage::FileSystem::GetVolumeInformationW;
+
+    let volume_path = volume_path(path)?;
+    let mut serial_number = 0u32;
+    // SAFETY: Volume path is null-terminated, serial number is valid for writes and the rest of
+    // the optional outputs are not requested
+    let success = unsafe {
+        GetVolumeInformationW(
+            volume_path.as_ptr(),
+            ptr::null_mut(),
+            0,
+            &mut serial_number,
+            ptr::null_mut(),
+            ptr::null_mut(),
+            ptr::null_mut(),
+            0,
+        )
+    };
+
+    (success != 0).then_some(serial_number)
+}
+
+/// Name of the volume `path` is located on in `\\?\Volume{GUID}` format that can be used to open
+/// the volume itself
+#[cfg(windows)]
+fn volume_name(path: &Path) -> Option<std::ffi::OsString> {
+    use std::ffi::OsString;
+    use std::os::windows::ffi::OsStringExt;
+    use windows_sys::Win32::Storage::FileSystem::GetVolumeNameForVolumeMountPointW;
+
+    /// Volume names are always 49 characters long including trailing backslash and null
+    const VOLUME_NAME_LENGTH: usize = 50;
+
+    let volume_path = volume_path(path)?;
+    let mut volume_name = [0u16; VOLUME_NAME_LENGTH];
+    // SAFETY: Volume path is null-terminated and buffer length matches allocated buffer
+    let success = unsafe {
+        GetVolumeNameForVolumeMountPointW(
+            volume_path.as_ptr(),
+            volume_name.as_mut_ptr(),
+            volume_name.len() as u32,
+        )
+    };
+    if success == 0 {
+        return None;
+    }
+
+    let length = volume_name.iter().position(|&char| char == 0)?;
+    // Trailing backslash must be removed to open volume rather than its root directory
+    let volume_name = volume_name[..length]
+        .strip_suffix(&[u16::from(b'\\')])
+        .unwrap_or(&volume_name[..length]);
+
+    Some(OsString::from_wide(volume_name))
+}
+
+/// Null-terminated root path of the volume `path` is located on (like `C:\`)
+#[cfg(windows)]
+fn volume_path(path: &Path) -> Option<Vec<u16>> {
+    use std::iter;
+    use std::os::windows::ffi::OsStrExt;
+    use windows_sys::Win32::Storage::FileSystem::GetVolumePathNameW;
+
+    let file_name = path
+        .as_os_str()
+        .encode_wide()
+        .chain(iter::once(0))
+        .collect::<Vec<u16>>();
+    // Volume path is at most the path itself with trailing backslash
+    let mut volume_path = vec![0u16; file_name.len() + 1];
+    // SAFETY: File name is null-terminated and buffer length matches allocated buffer
+    let found = unsafe {
+        GetVolumePathNameW(
+            file_name.as_ptr(),
+            volume_path.as_mut_ptr(),
+            volume_path.len() as u32,
+        )
+    };
+    if found == 0 {
+        return None;
+    }
+
+    Some(volume_path)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn detect_removable_or_network_drive(_path: &Path) -> bool {
+    false
+}
+
+/// Directory in sysfs of the block device `path` is located on
+#[cfg(target_os = "linux")]
+pub(in super::super) fn linux_block_device_sysfs_path(path: &Path) -> Option<PathBuf> {
+    use std::os::unix::fs::MetadataExt;
+
+    let device = path.metadata().ok()?.dev();
+    // Same encoding as `major()` and `minor()` macros in glibc
+    let major = ((device >> 8) & 0xfff) | ((device >> 32) & !0xfff);
+    let minor = (device & 0xff) | ((device >> 12) & !0xff);
+
+    Some(PathBuf::from(format!("/sys/dev/block/{major}:{minor}")))
+}
+
+/// Read attribute of the block device `path` is located on from sysfs
+#[cfg(target_os = "linux")]
+fn linux_block_device_attribute(path: &Path, attribute: &str) -> Option<String> {
+    // For partitions some attributes are only present on the parent block device
+    let device_path = linux_block_device_sysfs_path(path)?;
+    std::fs::read_to_string(device_path.join(attribute))
+        .or_else(|_error| std::fs::read_to_string(device_path.join("..").join(attribute)))
+        .ok()
+        .map(|value| value.trim().to_string())
+}
+
+/// Farm suggested by [`suggest_farms()`]
+#[derive(Debug, Clone)]
+pub struct FarmSuggestion {
+    pub(super) path: PathBuf,
+    /// Identifier of the device file system is located on, `None` if unknown
+    pub(super) device_id: Option<u64>,
+}
+
+/// Suggest one farm per disk that has enough free space and is writable, best-effort.
+///
+/// System disk, removable and network drives are excluded.
+pub(super) async fn suggest_farms() -> Vec<FarmSuggestion> {
+    let candidates = task::spawn_blocking(|| {
+        candidate_mount_points()
+            .into_iter()
+            .filter(|mount_point| {
+                !detect_removable_or_network_drive(mount_point)
+                    && fs4::available_space(mount_point)
+                        .is_ok_and(|free_space| free_space >= MIN_SUGGESTED_FARM_FREE_SPACE)
+            })
+            .map(|mount_point| mount_point.join(SUGGESTED_FARM_DIRECTORY_NAME))
+            .collect::<Vec<_>>()
+    })
+    .await
+    .unwrap_or_default();
+
+    let mut suggestions = Vec::with_capacity(candidates.len());
+    for path in candidates {
+        if !is_directory_writable(path.clone()).await {
+            continue;
+        }
+
+        suggestions.push(FarmSuggestion {
+            device_id: device_id(path.clone()).await,
+            path,
+        });
+    }
+
+    suggestions
+}
+
+/// Mount points of disks (one per disk) except system disk, blocking
+#[cfg(target_os = "linux")]
+fn candidate_mount_points() -> Vec<PathBuf> {
+    /// File systems that are either read-only, virtual or can't hold large files
+    const UNSUITABLE_FILE_SYSTEMS: &[&str] = &["iso9660", "msdos", "squashfs", "udf", "vfat"];
+    /// Mount points that belong to the operating system even if located on a separate disk
+    const SYSTEM_MOUNT_POINTS: &[&str] = &["/boot", "/efi", "/snap", "/var/snap"];
+
+    let Ok(mounts) = fs::read_to_string("/proc/self/mounts") else {
+        return Vec::new();
+    };
+    let system_disk = linux_disk_name(Path::new("/"));
+
+    let mut disks = Vec::new();
+    let mut mount_points = Vec::new();
+    for line in mounts.lines() {
+        let mut parts = line.split_whitespace();
+        let (Some(device), Some(mount_point), Some(file_system_type)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            continue;
+        };
+        // Spaces in mount points are escaped in mounts file
+        let mount_point = PathBuf::from(mount_point.replace("\\040", " "));
+
+        if !device.starts_with("/dev/")
+            || device.starts_with("/dev/loop")
+            || UNSUITABLE_FILE_SYSTEMS.contains(&file_system_type)
+            || mount_point == Path::new("/")
+            || SYSTEM_MOUNT_POINTS
+                .iter()
+                .any(|system_mount_point| mount_point.starts_with(system_mount_point))
+        {
+            continue;
+        }
+
+        let Some(disk) = linux_disk_name(&mount_point) else {
+            continue;
+        };
+        if Some(&disk) == system_disk.as_ref() || disks.contains(&disk) {
+            continue;
+        }
+
+        disks.push(disk);
+        mount_points.push(mount_point);
+    }
+
+    mount_points
+}
+
+/// Name of the disk (not partition) `path` is located on, like `sda` or `nvme0n1`
+#[cfg(target_os = "linux")]
+fn linux_disk_name(path: &Path) -> Option<String> {
+    use std::os::unix::fs::MetadataExt;
+
+    let device = path.metadata().ok()?.dev();
+    // Same encoding as `major()` and `minor()` macros in glibc
+    let major = ((device >> 8) & 0xfff) | ((device >> 32) & !0xfff);
+    let minor = (device & 0xff) | ((device >> 12) & !0xff);
+
+    let device_path = PathBuf::from(format!("/sys/dev/block/{major}:{minor}"))
+        .canonicalize()
+        .ok()?;
+    let disk_path = if device_path.join("partition").exists() {
+        device_path.parent()?
+    } else {
+        &device_path
+    };
+
+    disk_path
+        .file_name()
+        .map(|disk_name| disk_name.to_string_lossy().to_string())
+}
+
+/// Mount points of volumes except system volume, blocking
+#[cfg(target_os = "macos")]
+fn candidate_mount_points() -> Vec<PathBuf> {
+    let Ok(volumes) = fs::read_dir("/Volumes") else {
+        return Vec::new();
+    };
+    let system_volume = Path::new("/").canonicalize().ok();
+
+    volumes
+        .filter_map(|entry| {
+            let path = entry.ok()?.path();
+            // System volume is present in the list as a symlink to `/`
+            (path.canonicalize().ok() != system_volume && path.is_dir()).then_some(path)
+        })
+        .collect()
+}
+
+/// Roots of drives except system drive, blocking
+#[cfg(windows)]
+fn candidate_mount_points() -> Vec<PathBuf> {
+    let system_drive = std::env::var("SystemDrive")
+        .unwrap_or_else(|_error| "C:".to_string())
+        .to_uppercase();
+
+    // `A:` and `B:` are reserved for floppy drives, accessing them can be slow
+    ('C'..='Z')
+        .filter(|letter| format!("{letter}:") != system_drive)
+        .map(|letter| PathBuf::from(format!("{letter}:\\")))
+        .filter(|path| path.is_dir())
+        .collect()
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+fn candidate_mount_points() -> Vec<PathBuf> {
+    Vec::new()
+}
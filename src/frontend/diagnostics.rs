@@ -0,0 +1,427 @@
+use crate::backend::config::{available_cpu_threads, RawConfig};
+use crate::frontend::translations::{AsDefaultStr, T};
+use crate::log_buffer::LogBuffer;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use gtk::prelude::*;
+use relm4::prelude::*;
+use relm4_components::save_dialog::{
+    SaveDialog, SaveDialogMsg, SaveDialogResponse, SaveDialogSettings,
+};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use std::{env, fs, io, iter};
+use tokio::task;
+use tracing::{info, warn};
+
+const DIAGNOSTICS_FILE_NAME: &str = "space-acres-diagnostics.tar.gz";
+
+fn diagnostics_file_filter() -> gtk::FileFilter {
+    let filter = gtk::FileFilter::new();
+    filter.set_name(Some(&T.diagnostics_dialog_filter()));
+    filter.add_suffix("tar.gz");
+    filter
+}
+
+#[derive(Debug)]
+pub struct DiagnosticsInit {
+    pub log_buffer: Arc<LogBuffer>,
+    /// Path to the log file on disk, if logs are written to a file
+    pub log_file: Option<PathBuf>,
+    pub parent: gtk::Window,
+}
+
+#[derive(Debug)]
+pub enum DiagnosticsInput {
+    Show { raw_config: Option<RawConfig> },
+    IncludeSensitiveChanged(bool),
+    OpenSaveDialog,
+    Export(PathBuf),
+    Ignore,
+}
+
+#[derive(Debug)]
+pub enum DiagnosticsCommandOutput {
+    ExportResult(Result<PathBuf, String>),
+}
+
+#[tracker::track]
+#[derive(Debug)]
+pub struct Diagnostics {
+    /// Whether reward address and full paths should be included as is
+    include_sensitive: bool,
+    exporting: bool,
+    export_result: Option<Result<PathBuf, String>>,
+    #[do_not_track]
+    raw_config: Option<RawConfig>,
+    #[do_not_track]
+    log_buffer: Arc<LogBuffer>,
+    #[do_not_track]
+    log_file: Option<PathBuf>,
+    #[do_not_track]
+    save_dialog: Controller<SaveDialog>,
+}
+
+#[relm4::component(pub)]
+impl Component for Diagnostics {
+    type Init = DiagnosticsInit;
+    type Input = DiagnosticsInput;
+    type Output = ();
+    type CommandOutput = DiagnosticsCommandOutput;
+
+    view! {
+        #[root]
+        gtk::Window {
+            set_default_width: 500,
+            set_hide_on_close: true,
+            set_resizable: false,
+            set_title: Some(&T.diagnostics_title()),
+
+            gtk::Box {
+                set_margin_all: 10,
+                set_orientation: gtk::Orientation::Vertical,
+                set_spacing: 10,
+
+                gtk::Label {
+                    set_halign: gtk::Align::Start,
+                    set_label: &T.diagnostics_description(),
+                    set_wrap: true,
+                },
+
+                gtk::CheckButton {
+                    connect_toggled[sender] => move |check_button| {
+                        sender.input(DiagnosticsInput::IncludeSensitiveChanged(
+                            check_button.is_active(),
+                        ));
+                    },
+                    set_label: Some(&T.diagnostics_include_sensitive()),
+                    #[track = "model.changed_include_sensitive()"]
+                    set_active: model.include_sensitive,
+                    set_tooltip: &T.diagnostics_include_sensitive_tooltip(),
+                },
+
+                gtk::Label {
+                    #[track = "model.changed_export_result()"]
+                    set_css_classes: match &model.export_result {
+                        Some(Ok(_)) => &["success-label"],
+                        _ => &["error-label"],
+                    },
+                    set_halign: gtk::Align::Start,
+                    #[track = "model.changed_export_result()"]
+                    set_label: &match &model.export_result {
+                        Some(Ok(path)) => T
+                            .diagnostics_export_success(path.display().to_string())
+                            .to_string(),
+                        Some(Err(error)) => T
+                            .diagnostics_export_error(error.clone())
+                            .to_string(),
+                        None => String::new(),
+                    },
+                    #[track = "model.changed_export_result()"]
+                    set_visible: model.export_result.is_some(),
+                    set_wrap: true,
+                },
+
+                gtk::Box {
+                    set_halign: gtk::Align::End,
+
+                    gtk::Button {
+                        add_css_class: "suggested-action",
+                        connect_clicked => DiagnosticsInput::OpenSaveDialog,
+                        #[track = "model.changed_exporting()"]
+                        set_sensitive: !model.exporting,
+
+                        gtk::Label {
+                            set_label: &T.diagnostics_button_export(),
+                            set_margin_all: 10,
+                        },
+                    },
+                },
+            },
+        }
+    }
+
+    fn init(
+        init: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let DiagnosticsInit {
+            log_buffer,
+            log_file,
+            parent,
+        } = init;
+
+        root.set_transient_for(Some(&parent));
+
+        let save_dialog = SaveDialog::builder()
+            .transient_for_native(&root)
+            .launch(SaveDialogSettings {
+                accept_label: T.diagnostics_dialog_button_save().to_string(),
+                cancel_label: T.diagnostics_dialog_button_cancel().to_string(),
+                create_folders: true,
+                is_modal: true,
+                filters: vec![diagnostics_file_filter()],
+            })
+            .forward(sender.input_sender(), |response| match response {
+                SaveDialogResponse::Accept(path) => DiagnosticsInput::Export(path),
+                SaveDialogResponse::Cancel => DiagnosticsInput::Ignore,
+            });
+
+        let model = Self {
+            include_sensitive: false,
+            exporting: false,
+            export_result: None,
+            raw_config: None,
+            log_buffer,
+            log_file,
+            save_dialog,
+            tracker: u8::MAX,
+        };
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, input: Self::Input, sender: ComponentSender<Self>, root: &Self::Root) {
+        // Reset changes
+        self.reset();
+
+        match input {
+            DiagnosticsInput::Show { raw_config } => {
+                self.raw_config = raw_config;
+                self.set_export_result(None);
+                root.present();
+            }
+            DiagnosticsInput::IncludeSensitiveChanged(include_sensitive) => {
+                self.include_sensitive = include_sensitive;
+            }
+            DiagnosticsInput::OpenSaveDialog => {
+                self.save_dialog
+                    .emit(SaveDialogMsg::SaveAs(DIAGNOSTICS_FILE_NAME.to_string()));
+            }
+            DiagnosticsInput::Export(path) => {
+                self.set_exporting(true);
+                self.set_export_result(None);
+
+                let sanitizer = Sanitizer::new(self.raw_config.as_ref(), self.include_sensitive);
+                let raw_config = self.raw_config.clone();
+                let recent_logs = self
+                    .log_buffer
+                    .lines_since(0)
+                    .into_iter()
+                    .map(|line| line.text)
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let log_file = self.log_file.clone();
+
+                sender.oneshot_command(async move {
+                    let result = task::spawn_blocking({
+                        let path = path.clone();
+
+                        move || {
+                            write_diagnostics_bundle(
+                                &path,
+                                &sanitizer,
+                                raw_config.as_ref(),
+                                &recent_logs,
+                                log_file.as_deref(),
+                            )
+                        }
+                    })
+                    .await
+                    .map_err(|error| {
+                        io::Error::new(
+                            io::ErrorKind::Other,
+                            format!("Failed to spawn blocking task: {error}"),
+                        )
+                    })
+                    .flatten();
+
+                    DiagnosticsCommandOutput::ExportResult(match result {
+                        Ok(()) => {
+                            info!(path = %path.display(), "Diagnostics bundle exported");
+                            Ok(path)
+                        }
+                        Err(error) => {
+                            warn!(%error, path = %path.display(), "Failed to export diagnostics");
+                            Err(error.to_string())
+                        }
+                    })
+                });
+            }
+            DiagnosticsInput::Ignore => {
+                // Ignore
+            }
+        }
+    }
+
+    fn update_cmd(
+        &mut self,
+        input: Self::CommandOutput,
+        _sender: ComponentSender<Self>,
+        _root: &Self::Root,
+    ) {
+        // Reset changes
+        self.reset();
+
+        match input {
+            DiagnosticsCommandOutput::ExportResult(result) => {
+                self.set_exporting(false);
+                self.set_export_result(Some(result));
+            }
+        }
+    }
+}
+
+/// Replaces sensitive information in contents of the diagnostics bundle
+#[derive(Debug, Default)]
+struct Sanitizer {
+    replacements: Vec<(String, &'static str)>,
+}
+
+impl Sanitizer {
+    fn new(raw_config: Option<&RawConfig>, include_sensitive: bool) -> Self {
+        if include_sensitive {
+            return Self::default();
+        }
+
+        let mut replacements = Vec::new();
+        if let Some(raw_config) = raw_config {
+            let reward_addresses = iter::once(raw_config.reward_address()).chain(
+                raw_config
+                    .farms()
+                    .iter()
+                    .filter_map(|farm| farm.reward_address.as_deref()),
+            );
+            for reward_address in reward_addresses {
+                if !reward_address.is_empty() {
+                    replacements.push((reward_address.to_string(), "<reward address>"));
+                }
+            }
+        }
+        if let Some(home_dir) = dirs::home_dir() {
+            let home_dir = home_dir.display().to_string();
+            if !home_dir.is_empty() {
+                replacements.push((home_dir, "~"));
+            }
+        }
+
+        Self { replacements }
+    }
+
+    fn sanitize(&self, text: &str) -> String {
+        self.replacements
+            .iter()
+            .fold(text.to_string(), |text, (from, to)| text.replace(from, to))
+    }
+
+    /// Sanitize all strings in JSON value, this is done before serialization since escaping may
+    /// change how paths look like in serialized form
+    fn sanitize_json(&self, value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::String(string) => {
+                *string = self.sanitize(string);
+            }
+            serde_json::Value::Array(values) => {
+                values
+                    .iter_mut()
+                    .for_each(|value| self.sanitize_json(value));
+            }
+            serde_json::Value::Object(map) => {
+                map.values_mut().for_each(|value| self.sanitize_json(value));
+            }
+            serde_json::Value::Null | serde_json::Value::Bool(_) | serde_json::Value::Number(_) => {
+                // Nothing to sanitize
+            }
+        }
+    }
+}
+
+fn system_information(raw_config: Option<&RawConfig>) -> String {
+    let mut system_information = String::new();
+    let _ = writeln!(
+        system_information,
+        "{} {}",
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION")
+    );
+    // Node and farmer are embedded into the application rather than separate binaries
+    let _ = writeln!(
+        system_information,
+        "Node and farmer: embedded, same version as application"
+    );
+    let _ = writeln!(system_information, "OS: {}", env::consts::OS);
+    let _ = writeln!(system_information, "Architecture: {}", env::consts::ARCH);
+    let _ = writeln!(
+        system_information,
+        "CPU threads: {}",
+        available_cpu_threads()
+    );
+    if let Some(raw_config) = raw_config {
+        let _ = writeln!(system_information, "Farms: {}", raw_config.farms().len());
+    }
+
+    system_information
+}
+
+fn write_diagnostics_bundle(
+    path: &Path,
+    sanitizer: &Sanitizer,
+    raw_config: Option<&RawConfig>,
+    recent_logs: &str,
+    log_file: Option<&Path>,
+) -> io::Result<()> {
+    let mtime = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+
+    let mut entries = vec![(
+        "system-information.txt",
+        sanitizer.sanitize(&system_information(raw_config)),
+    )];
+    if let Some(raw_config) = raw_config {
+        let mut config =
+            serde_json::to_value(raw_config).expect("Config serialization is infallible; qed");
+        sanitizer.sanitize_json(&mut config);
+        entries.push((
+            "config.json",
+            serde_json::to_string_pretty(&config).expect("Config serialization is infallible; qed"),
+        ));
+    }
+    entries.push(("recent-logs.txt", sanitizer.sanitize(recent_logs)));
+    if let Some(log_file) = log_file {
+        match fs::read(log_file) {
+            Ok(contents) => {
+                entries.push((
+                    "space-acres.log",
+                    sanitizer.sanitize(&String::from_utf8_lossy(&contents)),
+                ));
+            }
+            Err(error) => {
+                // Log file might not exist, for example when logs are written to terminal only
+                if error.kind() != io::ErrorKind::NotFound {
+                    return Err(error);
+                }
+            }
+        }
+    }
+
+    let mut archive = tar::Builder::new(GzEncoder::new(
+        fs::File::create(path)?,
+        Compression::default(),
+    ));
+    for (name, contents) in entries {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(contents.len() as u64);
+        header.set_mode(0o600);
+        header.set_mtime(mtime);
+        header.set_cksum();
+        archive.append_data(&mut header, name, contents.as_bytes())?;
+    }
+    archive.into_inner()?.finish()?.sync_all()
+}
@@ -0,0 +1,511 @@
+use crate::app_dirs;
+use crate::frontend::translations::{AsDefaultStr, T};
+use futures::channel::mpsc;
+use futures::StreamExt;
+use gtk::glib;
+use gtk::prelude::*;
+use relm4::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use subspace_runtime_primitives::{Balance, SSC};
+use tokio::io::AsyncWriteExt;
+use tracing::{error, warn};
+
+/// Event log file name in app data directory, one JSON-encoded [`Event`] per line
+const EVENT_LOG_FILE_NAME: &str = "events.jsonl";
+/// Event log file is rotated once it reaches this size, only one rotated file is kept
+const EVENT_LOG_FILE_LIMIT_SIZE: u64 = 1024 * 1024;
+/// Maximum number of events kept in memory and shown in the list
+const MAX_EVENTS: usize = 1000;
+/// Filters available in the event log, in the order they are shown
+const FILTERS: [EventFilter; 5] = [
+    EventFilter::All,
+    EventFilter::Node,
+    EventFilter::Farms,
+    EventFilter::Rewards,
+    EventFilter::Errors,
+];
+
+/// Significant event in the lifecycle of node and farmer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum EventKind {
+    /// Node and farmer started successfully
+    ServicesStarted,
+    /// Node and farmer stopped, error is present if they stopped due to an error
+    ServicesStopped {
+        error: Option<String>,
+    },
+    /// Node and farmer are restarting on user request
+    ServicesRestarting,
+    /// Different configuration profile was made active
+    ProfileSwitched {
+        profile: String,
+    },
+    /// Startup failed and will be retried
+    StartupFailed {
+        attempt: u32,
+        error: String,
+    },
+    IrrecoverableError {
+        error: String,
+    },
+    NodeSynced,
+    /// Node fell behind and is syncing again
+    NodeSyncing,
+    /// Node lost connection to all of its peers
+    NodeDisconnected,
+    /// Node connected to peers after it was disconnected
+    NodeReconnected,
+    PlottingStarted {
+        farm: String,
+    },
+    PlottingFinished {
+        farm: String,
+    },
+    FarmError {
+        farm: String,
+        error: String,
+    },
+    Reward {
+        amount: Balance,
+        token_symbol: String,
+    },
+}
+
+impl EventKind {
+    fn message(&self) -> String {
+        match self {
+            Self::ServicesStarted => T.event_log_event_services_started().to_string(),
+            Self::ServicesStopped { error: None } => {
+                T.event_log_event_services_stopped().to_string()
+            }
+            Self::ServicesStopped { error: Some(error) } => T
+                .event_log_event_services_stopped_with_error(error.as_str())
+                .to_string(),
+            Self::ServicesRestarting => T.event_log_event_services_restarting().to_string(),
+            Self::ProfileSwitched { profile } => T
+                .event_log_event_profile_switched(profile.as_str())
+                .to_string(),
+            Self::StartupFailed { attempt, error } => T
+                .event_log_event_startup_failed(*attempt, error.as_str())
+                .to_string(),
+            Self::IrrecoverableError { error } => T
+                .event_log_event_irrecoverable_error(error.as_str())
+                .to_string(),
+            Self::NodeSynced => T.event_log_event_node_synced().to_string(),
+            Self::NodeSyncing => T.event_log_event_node_syncing().to_string(),
+            Self::NodeDisconnected => T.event_log_event_node_disconnected().to_string(),
+            Self::NodeReconnected => T.event_log_event_node_reconnected().to_string(),
+            Self::PlottingStarted { farm } => T
+                .event_log_event_plotting_started(farm.as_str())
+                .to_string(),
+            Self::PlottingFinished { farm } => T
+                .event_log_event_plotting_finished(farm.as_str())
+                .to_string(),
+            Self::FarmError { farm, error } => T
+                .event_log_event_farm_error(farm.as_str(), error.as_str())
+                .to_string(),
+            Self::Reward {
+                amount,
+                token_symbol,
+            } => T
+                .event_log_event_reward(
+                    (amount / (SSC / 100)) as f32 / 100.0,
+                    token_symbol.as_str(),
+                )
+                .to_string(),
+        }
+    }
+
+    fn is_error(&self) -> bool {
+        matches!(
+            self,
+            Self::ServicesStopped { error: Some(_) }
+                | Self::StartupFailed { .. }
+                | Self::IrrecoverableError { .. }
+                | Self::NodeDisconnected
+                | Self::FarmError { .. }
+        )
+    }
+
+    fn matches(&self, filter: EventFilter) -> bool {
+        match filter {
+            EventFilter::All => true,
+            EventFilter::Node => !matches!(
+                self,
+                Self::PlottingStarted { .. }
+                    | Self::PlottingFinished { .. }
+                    | Self::FarmError { .. }
+                    | Self::Reward { .. }
+            ),
+            EventFilter::Farms => matches!(
+                self,
+                Self::PlottingStarted { .. }
+                    | Self::PlottingFinished { .. }
+                    | Self::FarmError { .. }
+            ),
+            EventFilter::Rewards => matches!(self, Self::Reward { .. }),
+            EventFilter::Errors => self.is_error(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Event {
+    /// Unix timestamp in seconds
+    timestamp: u64,
+    kind: EventKind,
+}
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum EventFilter {
+    #[default]
+    All,
+    Node,
+    Farms,
+    Rewards,
+    Errors,
+}
+
+#[derive(Debug)]
+pub struct EventLogInit {
+    pub parent: gtk::Window,
+}
+
+#[derive(Debug)]
+pub enum EventLogInput {
+    Show,
+    Record(EventKind),
+    FilterChanged(EventFilter),
+    OpenEventLogFile,
+}
+
+#[derive(Debug)]
+pub enum EventLogCommandOutput {
+    EventLogLoaded(Vec<Event>),
+}
+
+#[derive(Debug)]
+pub struct EventLog {
+    /// Events from oldest to newest
+    events: VecDeque<Event>,
+    filter: EventFilter,
+    /// Events are sent to a background task that writes them to the event log file in order
+    events_sender: mpsc::UnboundedSender<Event>,
+    events_list: gtk::ListBox,
+}
+
+#[relm4::component(pub)]
+impl Component for EventLog {
+    type Init = EventLogInit;
+    type Input = EventLogInput;
+    type Output = ();
+    type CommandOutput = EventLogCommandOutput;
+
+    view! {
+        #[root]
+        gtk::Window {
+            set_default_size: (700, 500),
+            set_hide_on_close: true,
+            set_title: Some(&T.event_log_title()),
+
+            gtk::Box {
+                set_margin_all: 10,
+                set_orientation: gtk::Orientation::Vertical,
+                set_spacing: 10,
+
+                gtk::Box {
+                    set_spacing: 10,
+
+                    gtk::Label {
+                        set_label: &T.event_log_filter(),
+                    },
+                    gtk::DropDown::from_strings(&[
+                        &*T.event_log_filter_all(),
+                        &*T.event_log_filter_node(),
+                        &*T.event_log_filter_farms(),
+                        &*T.event_log_filter_rewards(),
+                        &*T.event_log_filter_errors(),
+                    ]) {
+                        connect_selected_notify[sender] => move |drop_down| {
+                            if let Some(&filter) = FILTERS.get(drop_down.selected() as usize) {
+                                sender.input(EventLogInput::FilterChanged(filter));
+                            }
+                        },
+                    },
+
+                    gtk::Button {
+                        connect_clicked => EventLogInput::OpenEventLogFile,
+                        set_halign: gtk::Align::End,
+                        set_hexpand: true,
+                        set_label: &T.event_log_button_open_event_log_file(),
+                        set_sensitive: event_log_path().is_some(),
+                    },
+                },
+
+                gtk::ScrolledWindow {
+                    set_vexpand: true,
+
+                    #[local_ref]
+                    events_list -> gtk::ListBox {
+                        set_selection_mode: gtk::SelectionMode::None,
+                        #[wrap(Some)]
+                        set_placeholder = &gtk::Label {
+                            add_css_class: "dim-label",
+                            set_label: &T.event_log_empty(),
+                            set_margin_all: 10,
+                        },
+                    },
+                },
+            },
+        }
+    }
+
+    fn init(
+        init: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        root.set_transient_for(Some(&init.parent));
+
+        let (events_sender, events_receiver) = mpsc::unbounded();
+
+        let model = Self {
+            events: VecDeque::new(),
+            filter: EventFilter::default(),
+            events_sender,
+            events_list: gtk::ListBox::new(),
+        };
+
+        let events_list = &model.events_list;
+
+        let widgets = view_output!();
+
+        sender.oneshot_command(async move {
+            let events = match event_log_path() {
+                Some(path) => match read_event_log(&path).await {
+                    Ok(events) => events,
+                    Err(error) => {
+                        error!(%error, path = %path.display(), "Failed to read event log");
+                        Vec::new()
+                    }
+                },
+                None => Vec::new(),
+            };
+
+            EventLogCommandOutput::EventLogLoaded(events)
+        });
+        sender.command(move |_sender, _shutdown_receiver| write_event_log(events_receiver));
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, input: Self::Input, _sender: ComponentSender<Self>, root: &Self::Root) {
+        match input {
+            EventLogInput::Show => {
+                root.present();
+            }
+            EventLogInput::Record(kind) => {
+                let event = Event {
+                    timestamp: unix_timestamp(),
+                    kind,
+                };
+                if self.events_sender.unbounded_send(event.clone()).is_err() {
+                    warn!("Event log writer exited, event not persisted");
+                }
+                self.push_event(event);
+            }
+            EventLogInput::FilterChanged(filter) => {
+                self.filter = filter;
+                self.render_events();
+            }
+            EventLogInput::OpenEventLogFile => {
+                if let Some(path) = event_log_path()
+                    && let Err(error) = open::that_detached(&path)
+                {
+                    error!(%error, path = %path.display(), "Failed to open event log file");
+                }
+            }
+        }
+    }
+
+    fn update_cmd(
+        &mut self,
+        input: Self::CommandOutput,
+        _sender: ComponentSender<Self>,
+        _root: &Self::Root,
+    ) {
+        match input {
+            EventLogCommandOutput::EventLogLoaded(events) => {
+                // Events recorded while log was loading go after historical events
+                let recorded_events = std::mem::take(&mut self.events);
+                self.events = events.into_iter().chain(recorded_events).collect();
+                while self.events.len() > MAX_EVENTS {
+                    self.events.pop_front();
+                }
+                self.render_events();
+            }
+        }
+    }
+}
+
+impl EventLog {
+    fn push_event(&mut self, event: Event) {
+        if event.kind.matches(self.filter) {
+            // Newest events are at the top
+            self.events_list.prepend(&event_row(&event));
+        }
+        self.events.push_back(event);
+
+        if self.events.len() > MAX_EVENTS
+            && let Some(oldest_event) = self.events.pop_front()
+            && oldest_event.kind.matches(self.filter)
+        {
+            // Row of the oldest event is the last one
+            let displayed_events = self
+                .events
+                .iter()
+                .filter(|event| event.kind.matches(self.filter))
+                .count();
+            if let Some(last_row) = self.events_list.row_at_index(displayed_events as i32) {
+                self.events_list.remove(&last_row);
+            }
+        }
+    }
+
+    fn render_events(&self) {
+        self.events_list.remove_all();
+
+        for event in self.events.iter().rev() {
+            if event.kind.matches(self.filter) {
+                self.events_list.append(&event_row(event));
+            }
+        }
+    }
+}
+
+fn event_row(event: &Event) -> gtk::Box {
+    let time = i64::try_from(event.timestamp)
+        .ok()
+        .and_then(|timestamp| glib::DateTime::from_unix_local(timestamp).ok())
+        .and_then(|date_time| date_time.format("%Y-%m-%d %H:%M:%S").ok())
+        .map(|time| time.to_string())
+        .unwrap_or_default();
+
+    let row = gtk::Box::builder()
+        .margin_bottom(5)
+        .margin_top(5)
+        .spacing(10)
+        .build();
+    let time = gtk::Label::builder()
+        .label(time)
+        .valign(gtk::Align::Start)
+        .build();
+    time.add_css_class("dim-label");
+    row.append(&time);
+
+    let message = gtk::Label::builder()
+        .halign(gtk::Align::Start)
+        .label(event.kind.message())
+        .selectable(true)
+        .wrap(true)
+        .xalign(0.0)
+        .build();
+    if event.kind.is_error() {
+        message.add_css_class("error-label");
+    }
+    row.append(&message);
+
+    row
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+fn event_log_path() -> Option<PathBuf> {
+    app_dirs::data_dir().map(|data_dir| data_dir.join(EVENT_LOG_FILE_NAME))
+}
+
+/// Path of the previous event log file after rotation
+fn rotated_event_log_path(path: &Path) -> PathBuf {
+    path.with_extension("jsonl.1")
+}
+
+/// Read events from rotated and current event log files, from oldest to newest
+async fn read_event_log(path: &Path) -> io::Result<Vec<Event>> {
+    let mut events = Vec::new();
+
+    for path in [rotated_event_log_path(path), path.to_path_buf()] {
+        let contents = match tokio::fs::read_to_string(&path).await {
+            Ok(contents) => contents,
+            Err(error) => {
+                if error.kind() == io::ErrorKind::NotFound {
+                    continue;
+                } else {
+                    return Err(error);
+                }
+            }
+        };
+
+        events.extend(
+            contents
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .filter_map(|line| match serde_json::from_str::<Event>(line) {
+                    Ok(event) => Some(event),
+                    Err(error) => {
+                        warn!(%error, %line, "Failed to parse event log entry, ignored");
+                        None
+                    }
+                }),
+        );
+    }
+
+    // Only the latest events are shown
+    let excess_events = events.len().saturating_sub(MAX_EVENTS);
+    events.drain(..excess_events);
+
+    Ok(events)
+}
+
+/// Write events to the event log file as they arrive, rotating it when it becomes too large
+async fn write_event_log(mut events_receiver: mpsc::UnboundedReceiver<Event>) {
+    let Some(path) = event_log_path() else {
+        warn!("Failed to determine event log path, events will not be persisted");
+        return;
+    };
+
+    while let Some(event) = events_receiver.next().await {
+        if let Err(error) = append_to_event_log(&path, &event).await {
+            error!(%error, "Failed to append event to event log");
+        }
+    }
+}
+
+async fn append_to_event_log(path: &Path, event: &Event) -> io::Result<()> {
+    if let Ok(metadata) = tokio::fs::metadata(path).await
+        && metadata.len() >= EVENT_LOG_FILE_LIMIT_SIZE
+    {
+        tokio::fs::rename(path, rotated_event_log_path(path)).await?;
+    }
+
+    let mut line = serde_json::to_string(event).expect("Event serialization is infallible; qed");
+    line.push('\n');
+
+    tokio::fs::OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(path)
+        .await?
+        .write_all(line.as_bytes())
+        .await
+}
@@ -0,0 +1,161 @@
+use crate::backend::farmer::reconciliation::{
+    FarmMismatch, FarmMismatchKind, FarmMismatchResolution,
+};
+use crate::frontend::translations::{AsDefaultStr, T};
+use bytesize::ByteSize;
+use gtk::prelude::*;
+use std::path::PathBuf;
+
+/// Show summary of farms that don't match configuration and let user choose what to do with each
+/// of them, farms are skipped unless user chooses otherwise (including when dialog is closed)
+pub(super) fn show_farm_mismatches_dialog<F>(mismatches: Vec<FarmMismatch>, on_resolved: F)
+where
+    F: Fn(Vec<(PathBuf, FarmMismatchResolution)>) + 'static,
+{
+    let dialog = gtk::MessageDialog::builder()
+        .buttons(gtk::ButtonsType::None)
+        .message_type(gtk::MessageType::Warning)
+        .modal(true)
+        .secondary_text(T.farm_mismatches_dialog_description().as_str())
+        .text(T.farm_mismatches_dialog_title().as_str())
+        .build();
+    if let Some(window) = relm4::main_application().active_window() {
+        dialog.set_transient_for(Some(&window));
+    }
+
+    let grid = gtk::Grid::builder()
+        .column_spacing(10)
+        .row_spacing(10)
+        .margin_top(10)
+        .build();
+    let choices = mismatches
+        .iter()
+        .enumerate()
+        .map(|(row, mismatch)| {
+            let description = gtk::Label::builder()
+                .halign(gtk::Align::Start)
+                .hexpand(true)
+                .label(format!(
+                    "{}\n{}",
+                    mismatch.directory.display(),
+                    farm_mismatch_description(&mismatch.kind)
+                ))
+                .wrap(true)
+                .xalign(0.0)
+                .build();
+
+            let mut resolutions = vec![
+                (
+                    FarmMismatchResolution::Skip,
+                    T.farm_mismatches_dialog_resolution_skip().to_string(),
+                ),
+                (
+                    FarmMismatchResolution::Replot,
+                    match mismatch.kind {
+                        FarmMismatchKind::MissingPlot => T
+                            .farm_mismatches_dialog_resolution_replot_missing()
+                            .to_string(),
+                        FarmMismatchKind::UnexpectedPlot { .. }
+                        | FarmMismatchKind::SizeMismatch { .. } => {
+                            T.farm_mismatches_dialog_resolution_replot().to_string()
+                        }
+                    },
+                ),
+            ];
+            if mismatch.fixable() {
+                resolutions.push((
+                    FarmMismatchResolution::Fix,
+                    match mismatch.kind {
+                        FarmMismatchKind::MissingPlot => T
+                            .farm_mismatches_dialog_resolution_fix_missing()
+                            .to_string(),
+                        FarmMismatchKind::UnexpectedPlot { .. } => T
+                            .farm_mismatches_dialog_resolution_fix_unexpected()
+                            .to_string(),
+                        FarmMismatchKind::SizeMismatch { .. } => {
+                            T.farm_mismatches_dialog_resolution_fix_size().to_string()
+                        }
+                    },
+                ));
+            }
+
+            let labels = resolutions
+                .iter()
+                .map(|(_resolution, label)| label.as_str())
+                .collect::<Vec<_>>();
+            let resolution_selector = gtk::DropDown::from_strings(&labels);
+            resolution_selector.set_valign(gtk::Align::Center);
+
+            grid.attach(&description, 0, row as i32, 1, 1);
+            grid.attach(&resolution_selector, 1, row as i32, 1, 1);
+
+            (
+                mismatch.directory.clone(),
+                resolutions
+                    .into_iter()
+                    .map(|(resolution, _label)| resolution)
+                    .collect::<Vec<_>>(),
+                resolution_selector,
+            )
+        })
+        .collect::<Vec<_>>();
+    if let Some(message_area) = dialog.message_area().downcast_ref::<gtk::Box>() {
+        message_area.append(&grid);
+    }
+
+    dialog.add_button(
+        &T.farm_mismatches_dialog_button_continue(),
+        gtk::ResponseType::Accept,
+    );
+    dialog.set_default_response(gtk::ResponseType::Accept);
+
+    dialog.connect_response(move |dialog, response| {
+        let resolutions = if response == gtk::ResponseType::Accept {
+            choices
+                .iter()
+                .map(|(directory, resolutions, resolution_selector)| {
+                    let resolution = resolutions
+                        .get(resolution_selector.selected() as usize)
+                        .copied()
+                        .unwrap_or(FarmMismatchResolution::Skip);
+
+                    (directory.clone(), resolution)
+                })
+                .collect()
+        } else {
+            choices
+                .iter()
+                .map(|(directory, _resolutions, _resolution_selector)| {
+                    (directory.clone(), FarmMismatchResolution::Skip)
+                })
+                .collect()
+        };
+
+        on_resolved(resolutions);
+        dialog.close();
+    });
+    dialog.present();
+}
+
+fn farm_mismatch_description(kind: &FarmMismatchKind) -> String {
+    match kind {
+        FarmMismatchKind::MissingPlot => T.farm_mismatches_dialog_missing_plot().to_string(),
+        FarmMismatchKind::UnexpectedPlot {
+            different_chain: true,
+        } => T
+            .farm_mismatches_dialog_unexpected_plot_different_chain()
+            .to_string(),
+        FarmMismatchKind::UnexpectedPlot {
+            different_chain: false,
+        } => T.farm_mismatches_dialog_unexpected_plot().to_string(),
+        FarmMismatchKind::SizeMismatch {
+            configured_size,
+            farm_size,
+        } => T
+            .farm_mismatches_dialog_size_mismatch(
+                ByteSize::b(*configured_size).to_string_as(true),
+                ByteSize::b(*farm_size).to_string_as(true),
+            )
+            .to_string(),
+    }
+}
@@ -101,9 +101,10 @@ impl LoadingView {
                         T.loading_networking_stack_title()
                     }
                     LoadingStep::CreatingConsensusNode
-                    | LoadingStep::ConsensusNodeCreatedSuccessfully => {
-                        T.loading_consensus_node_title()
-                    }
+                    | LoadingStep::ConsensusNodeCreatedSuccessfully
+                    | LoadingStep::ConnectingToExternalNode
+                    | LoadingStep::ConnectedToExternalNode
+                    | LoadingStep::ExternalNodeUnreachable => T.loading_consensus_node_title(),
                     LoadingStep::InitializingFarms { .. }
                     | LoadingStep::FarmInitialized { .. }
                     | LoadingStep::FarmerCreatedSuccessfully => T.loading_farmer_title(),
@@ -179,6 +180,15 @@ impl LoadingView {
                     LoadingStep::ConsensusNodeCreatedSuccessfully => T
                         .loading_consensus_node_step_created_successfully()
                         .to_string(),
+                    LoadingStep::ConnectingToExternalNode => T
+                        .loading_consensus_node_step_connecting_to_external_node()
+                        .to_string(),
+                    LoadingStep::ConnectedToExternalNode => T
+                        .loading_consensus_node_step_connected_to_external_node()
+                        .to_string(),
+                    LoadingStep::ExternalNodeUnreachable => T
+                        .loading_consensus_node_step_external_node_unreachable()
+                        .to_string(),
                     LoadingStep::InitializingFarms { farms_total } => T
                         .loading_farmer_step_initializing(0, farms_total)
                         .to_string(),
@@ -0,0 +1,300 @@
+use crate::frontend::translations::T;
+use crate::log_buffer::LogBuffer;
+use gtk::prelude::*;
+use relm4::prelude::*;
+use relm4::{Sender, ShutdownReceiver};
+use relm4_icons::icon_name;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, Level};
+
+/// How often new log lines are pulled from the buffer
+const LOG_VIEWER_REFRESH_INTERVAL: Duration = Duration::from_millis(500);
+/// Levels available in the filter, from least to most verbose
+const LEVELS: [Level; 5] = [
+    Level::ERROR,
+    Level::WARN,
+    Level::INFO,
+    Level::DEBUG,
+    Level::TRACE,
+];
+
+#[derive(Debug)]
+pub struct LogViewerInit {
+    pub log_buffer: Arc<LogBuffer>,
+    /// Path to the log file on disk, if logs are written to a file
+    pub log_file: Option<PathBuf>,
+    pub parent: gtk::Window,
+}
+
+#[derive(Debug)]
+pub enum LogViewerInput {
+    Show,
+    LevelChanged(Level),
+    SetPaused(bool),
+    CopyAll,
+    OpenLogFile,
+}
+
+#[derive(Debug)]
+pub enum LogViewerCommandOutput {
+    Refresh,
+}
+
+#[tracker::track]
+#[derive(Debug)]
+pub struct LogViewer {
+    paused: bool,
+    #[do_not_track]
+    max_level: Level,
+    #[do_not_track]
+    log_buffer: Arc<LogBuffer>,
+    #[do_not_track]
+    log_file: Option<PathBuf>,
+    /// Sequence number of the next log line to be displayed
+    #[do_not_track]
+    next_sequence: u64,
+    #[do_not_track]
+    text_view: gtk::TextView,
+    /// Mark that stays at the end of the text, used for scrolling to the latest line
+    #[do_not_track]
+    end_mark: gtk::TextMark,
+}
+
+#[relm4::component(pub)]
+impl Component for LogViewer {
+    type Init = LogViewerInit;
+    type Input = LogViewerInput;
+    type Output = ();
+    type CommandOutput = LogViewerCommandOutput;
+
+    view! {
+        #[root]
+        gtk::Window {
+            set_default_size: (900, 600),
+            set_hide_on_close: true,
+            set_title: Some(&T.log_viewer_title()),
+
+            gtk::Box {
+                set_margin_all: 10,
+                set_orientation: gtk::Orientation::Vertical,
+                set_spacing: 10,
+
+                gtk::Box {
+                    set_spacing: 10,
+
+                    gtk::Label {
+                        set_label: &T.log_viewer_level(),
+                    },
+                    gtk::DropDown::from_strings(&[
+                        &*T.log_viewer_level_error(),
+                        &*T.log_viewer_level_warn(),
+                        &*T.log_viewer_level_info(),
+                        &*T.log_viewer_level_debug(),
+                        &*T.log_viewer_level_trace(),
+                    ]) {
+                        connect_selected_notify[sender] => move |drop_down| {
+                            if let Some(&level) = LEVELS.get(drop_down.selected() as usize) {
+                                sender.input(LogViewerInput::LevelChanged(level));
+                            }
+                        },
+                        set_selected: LEVELS
+                            .iter()
+                            .position(|level| *level == model.max_level)
+                            .unwrap_or_default() as u32,
+                    },
+
+                    gtk::ToggleButton {
+                        connect_toggled[sender] => move |toggle_button| {
+                            sender.input(LogViewerInput::SetPaused(toggle_button.is_active()));
+                        },
+                        set_cursor_from_name: Some("pointer"),
+                        set_icon_name: icon_name::PAUSE,
+                        #[track = "model.changed_paused()"]
+                        set_active: model.paused,
+                        #[track = "model.changed_paused()"]
+                        set_tooltip: if model.paused {
+                            &T.log_viewer_resume_tooltip()
+                        } else {
+                            &T.log_viewer_pause_tooltip()
+                        },
+                    },
+
+                    gtk::Box {
+                        set_halign: gtk::Align::End,
+                        set_hexpand: true,
+                        set_spacing: 10,
+
+                        gtk::Button {
+                            connect_clicked => LogViewerInput::CopyAll,
+                            set_label: &T.log_viewer_button_copy_all(),
+                        },
+
+                        gtk::Button {
+                            connect_clicked => LogViewerInput::OpenLogFile,
+                            set_label: &T.log_viewer_button_open_log_file(),
+                            set_sensitive: model.log_file.is_some(),
+                        },
+                    },
+                },
+
+                gtk::ScrolledWindow {
+                    set_vexpand: true,
+
+                    #[local_ref]
+                    text_view -> gtk::TextView {
+                        set_cursor_visible: false,
+                        set_editable: false,
+                        set_monospace: true,
+                        set_wrap_mode: gtk::WrapMode::WordChar,
+                    },
+                },
+            },
+        }
+    }
+
+    fn init(
+        init: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let LogViewerInit {
+            log_buffer,
+            log_file,
+            parent,
+        } = init;
+
+        root.set_transient_for(Some(&parent));
+
+        let text_view = gtk::TextView::new();
+        let text_buffer = text_view.buffer();
+        let end_mark = text_buffer.create_mark(None, &text_buffer.end_iter(), false);
+
+        let model = Self {
+            paused: false,
+            max_level: Level::TRACE,
+            log_buffer,
+            log_file,
+            next_sequence: 0,
+            text_view: text_view.clone(),
+            end_mark,
+            tracker: u8::MAX,
+        };
+
+        let text_view = &model.text_view;
+
+        let widgets = view_output!();
+
+        sender.command(Self::refresh_periodically);
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, input: Self::Input, _sender: ComponentSender<Self>, root: &Self::Root) {
+        // Reset changes
+        self.reset();
+
+        match input {
+            LogViewerInput::Show => {
+                root.present();
+                if !self.paused {
+                    self.append_new_lines();
+                }
+            }
+            LogViewerInput::LevelChanged(level) => {
+                self.max_level = level;
+                // Re-render everything that is still in the buffer with the new filter
+                self.text_view.buffer().set_text("");
+                self.next_sequence = 0;
+                self.append_new_lines();
+            }
+            LogViewerInput::SetPaused(paused) => {
+                self.set_paused(paused);
+                if !paused {
+                    self.append_new_lines();
+                }
+            }
+            LogViewerInput::CopyAll => {
+                let text_buffer = self.text_view.buffer();
+                let text =
+                    text_buffer.text(&text_buffer.start_iter(), &text_buffer.end_iter(), false);
+                root.display().clipboard().set_text(&text);
+            }
+            LogViewerInput::OpenLogFile => {
+                if let Some(log_file) = &self.log_file
+                    && let Err(error) = open::that_detached(log_file)
+                {
+                    error!(%error, path = %log_file.display(), "Failed to open log file");
+                }
+            }
+        }
+    }
+
+    fn update_cmd(
+        &mut self,
+        input: Self::CommandOutput,
+        _sender: ComponentSender<Self>,
+        root: &Self::Root,
+    ) {
+        // Reset changes
+        self.reset();
+
+        match input {
+            LogViewerCommandOutput::Refresh => {
+                // No need to render anything while window is hidden, everything will be caught up
+                // on show
+                if !self.paused && root.is_visible() {
+                    self.append_new_lines();
+                }
+            }
+        }
+    }
+}
+
+impl LogViewer {
+    async fn refresh_periodically(
+        sender: Sender<LogViewerCommandOutput>,
+        shutdown_receiver: ShutdownReceiver,
+    ) {
+        shutdown_receiver
+            .register(async move {
+                loop {
+                    tokio::time::sleep(LOG_VIEWER_REFRESH_INTERVAL).await;
+
+                    if sender.send(LogViewerCommandOutput::Refresh).is_err() {
+                        break;
+                    }
+                }
+            })
+            .drop_on_shutdown()
+            .await
+    }
+
+    fn append_new_lines(&mut self) {
+        let lines = self.log_buffer.lines_since(self.next_sequence);
+        let Some(last_line) = lines.last() else {
+            return;
+        };
+        self.next_sequence = last_line.sequence + 1;
+
+        let text_buffer = self.text_view.buffer();
+        let mut end = text_buffer.end_iter();
+        for line in lines.iter().filter(|line| line.level <= self.max_level) {
+            text_buffer.insert(&mut end, &line.text);
+            text_buffer.insert(&mut end, "\n");
+        }
+
+        // Keep the number of displayed lines bounded the same way as the buffer itself, the last
+        // line is always empty
+        let excess_lines = text_buffer.line_count() - 1 - self.log_buffer.capacity() as i32;
+        if excess_lines > 0
+            && let Some(mut excess_end) = text_buffer.iter_at_line(excess_lines)
+        {
+            text_buffer.delete(&mut text_buffer.start_iter(), &mut excess_end);
+        }
+
+        self.text_view
+            .scroll_to_mark(&self.end_mark, 0.0, true, 0.0, 1.0);
+    }
+}
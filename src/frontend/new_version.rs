@@ -1,91 +1,126 @@
+use crate::backend::config::UpdatesConfiguration;
 use crate::frontend::translations::{AsDefaultStr, T};
+use gtk::glib;
 use gtk::prelude::*;
 use relm4::prelude::*;
 use relm4::{Sender, ShutdownReceiver};
 use reqwest::Client;
 use semver::Version;
 use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
-use tracing::{debug, error, warn};
+use std::{env, io};
+use tempfile::NamedTempFile;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::sync::watch;
+use tracing::{debug, error, info, warn};
 
-/// Check new release every hour
-const NEW_VERSION_CHECK_INTERVAL: Duration = Duration::from_secs(3600);
 /// Retry failed check every 5 minutes
 const NEW_VERSION_CHECK_RETRY_INTERVAL: Duration = Duration::from_secs(5 * 60);
+/// Extension of installer that is downloaded automatically on this platform
+#[cfg(windows)]
+const INSTALLER_EXTENSION: &str = ".msi";
+/// Extension of installer that is downloaded automatically on this platform
+#[cfg(target_os = "macos")]
+const INSTALLER_EXTENSION: &str = ".dmg";
+/// Extension of installer that is downloaded automatically on this platform
+#[cfg(not(any(windows, target_os = "macos")))]
+const INSTALLER_EXTENSION: &str = ".deb";
+
+#[derive(Debug, Deserialize)]
+struct ReleaseAsset {
+    name: String,
+    browser_download_url: String,
+    /// Checksum of the asset in `sha256:<hex>` format
+    #[serde(default)]
+    digest: Option<String>,
+}
 
 #[derive(Debug, Deserialize)]
 struct LatestRelease {
     tag_name: String,
+    /// Link to release page
+    #[serde(default)]
+    html_url: Option<String>,
+    /// Release notes in markdown
+    #[serde(default)]
+    body: Option<String>,
+    #[serde(default)]
+    assets: Vec<ReleaseAsset>,
+}
+
+#[derive(Debug)]
+pub enum NewVersionInput {
+    UpdatesConfiguration(UpdatesConfiguration),
+    Open,
+}
+
+#[derive(Debug)]
+pub enum NewVersionOutput {
+    /// Latest released version, sent after every successful check
+    LatestVersion(Version),
 }
 
 #[derive(Debug)]
 pub enum NewVersionCommandOutput {
-    NewVersion(Version),
+    LatestRelease {
+        version: Version,
+        release_notes: String,
+        release_url: Option<String>,
+    },
+    Downloaded(PathBuf),
 }
 
 #[tracker::track]
 #[derive(Debug)]
 pub struct NewVersion {
     new_version: Option<Version>,
+    /// Installer of the new version that was downloaded automatically
+    downloaded_installer: Option<PathBuf>,
+    /// Release notes of the new version in markdown, cached from the last check
+    #[do_not_track]
+    release_notes: String,
+    /// Link to release page of the new version
+    #[do_not_track]
+    release_url: Option<String>,
+    /// Configuration of checks, `None` until configuration is loaded
+    #[do_not_track]
+    updates_sender: watch::Sender<Option<UpdatesConfiguration>>,
 }
 
 #[relm4::component(pub)]
 impl Component for NewVersion {
     type Init = ();
-    type Input = ();
-    type Output = ();
+    type Input = NewVersionInput;
+    type Output = NewVersionOutput;
     type CommandOutput = NewVersionCommandOutput;
 
     view! {
         #[root]
-        // TODO: Use LinkButton once https://gitlab.gnome.org/GNOME/glib/-/issues/3403 is fixed
-        //  for macOS
         gtk::Button {
             add_css_class: "suggested-action",
-            // TODO: Use LinkButton once https://gitlab.gnome.org/GNOME/glib/-/issues/3403 is fixed
-            //  for macOS
-            connect_clicked => move |_| {
-                let repository = env!("CARGO_PKG_REPOSITORY");
-
-                let link = if repository.starts_with("https://github.com") {
-                    // Turn:
-                    // https://github.com/autonomys/space-acres
-                    // Into:
-                    // https://github.com/autonomys/space-acres/releases
-                    format!("{}/releases", env!("CARGO_PKG_REPOSITORY"))
-                } else {
-                    repository.to_string()
-                };
-
-                if let Err(error) = open::that_detached(link) {
-                    error!(%error, "Failed to open releases page in default browser");
-                }
-            },
+            connect_clicked => NewVersionInput::Open,
             remove_css_class: "flat",
             remove_css_class: "link",
             remove_css_class: "text-button",
-            #[track = "model.changed_new_version()"]
-            set_label: T
-                .new_version_available(
-                    model.new_version.as_ref().map(Version::to_string).unwrap_or_default()
-                )
-                .as_str(),
-            set_tooltip: &T.new_version_available_button_open(),
-            // TODO: Use LinkButton once https://gitlab.gnome.org/GNOME/glib/-/issues/3403 is fixed
-            //  for macOS
-            // set_uri: &{
-            //     let repository = env!("CARGO_PKG_REPOSITORY");
-            //
-            //     if repository.starts_with("https://github.com") {
-            //         // Turn:
-            //         // https://github.com/autonomys/space-acres
-            //         // Into:
-            //         // https://github.com/autonomys/space-acres/releases
-            //         format!("{}/releases", env!("CARGO_PKG_REPOSITORY"))
-            //     } else {
-            //         repository.to_string()
-            //     }
-            // },
+            #[track = "model.changed_new_version() || model.changed_downloaded_installer()"]
+            set_label: &{
+                let version = model.new_version.as_ref().map(Version::to_string).unwrap_or_default();
+
+                if model.downloaded_installer.is_some() {
+                    T.new_version_downloaded(version).to_string()
+                } else {
+                    T.new_version_available(version).to_string()
+                }
+            },
+            #[track = "model.changed_downloaded_installer()"]
+            set_tooltip: &if model.downloaded_installer.is_some() {
+                T.new_version_downloaded_button_open()
+            } else {
+                T.new_version_available_button_open()
+            },
             set_use_underline: false,
             #[track = "model.changed_new_version()"]
             set_visible: model.new_version.is_some(),
@@ -97,33 +132,49 @@ impl Component for NewVersion {
         _root: Self::Root,
         sender: ComponentSender<Self>,
     ) -> ComponentParts<Self> {
+        let (updates_sender, updates_receiver) = watch::channel(None);
+
         let model = Self {
             new_version: None,
+            downloaded_installer: None,
+            release_notes: String::new(),
+            release_url: None,
+            updates_sender,
             tracker: u8::MAX,
         };
 
         let widgets = view_output!();
 
-        sender.command(Self::check_new_version);
+        sender.command(move |sender, shutdown_receiver| {
+            Self::check_new_version(updates_receiver, sender, shutdown_receiver)
+        });
 
         ComponentParts { model, widgets }
     }
 
+    fn update(&mut self, input: Self::Input, _sender: ComponentSender<Self>, _root: &Self::Root) {
+        // Reset changes
+        self.reset();
+
+        self.process_input(input);
+    }
+
     fn update_cmd(
         &mut self,
         input: Self::CommandOutput,
-        _sender: ComponentSender<Self>,
+        sender: ComponentSender<Self>,
         _root: &Self::Root,
     ) {
         // Reset changes
         self.reset();
 
-        self.process_command(input);
+        self.process_command(input, sender);
     }
 }
 
 impl NewVersion {
     async fn check_new_version(
+        mut updates_receiver: watch::Receiver<Option<UpdatesConfiguration>>,
         sender: Sender<NewVersionCommandOutput>,
         shutdown_receiver: ShutdownReceiver,
     ) {
@@ -154,54 +205,489 @@ impl NewVersion {
             .register(async move {
                 let client = Client::new();
                 loop {
-                    let response: reqwest::Result<LatestRelease> = try {
-                        client
-                            .get(&url)
-                            .header("User-Agent", &user_agent)
-                            .send()
-                            .await?
-                            .json()
-                            .await?
+                    // Nothing is sent over the network until configuration is known and checks are
+                    // enabled
+                    let updates = match updates_receiver
+                        .wait_for(|updates| updates.is_some_and(|updates| !updates.disable_checks))
+                        .await
+                    {
+                        Ok(updates) => updates.expect("Only known configuration is awaited; qed"),
+                        Err(_error) => {
+                            break;
+                        }
                     };
 
-                    let tag_name = match response {
-                        Ok(latest_release) => latest_release.tag_name,
-                        Err(error) => {
-                            warn!(%error, %url, "Failed to check new release");
-                            tokio::time::sleep(NEW_VERSION_CHECK_RETRY_INTERVAL).await;
-                            continue;
+                    let delay = 'check: {
+                        let response: reqwest::Result<LatestRelease> = try {
+                            client
+                                .get(&url)
+                                .header("User-Agent", &user_agent)
+                                .send()
+                                .await?
+                                .json()
+                                .await?
+                        };
+
+                        let latest_release = match response {
+                            Ok(latest_release) => latest_release,
+                            Err(error) => {
+                                warn!(%error, %url, "Failed to check new release");
+                                break 'check NEW_VERSION_CHECK_RETRY_INTERVAL;
+                            }
+                        };
+
+                        let latest_version = match Version::parse(&latest_release.tag_name) {
+                            Ok(latest_version) => latest_version,
+                            Err(error) => {
+                                debug!(
+                                    %error,
+                                    tag_name = %latest_release.tag_name,
+                                    "Failed to parse new version"
+                                );
+                                break 'check NEW_VERSION_CHECK_RETRY_INTERVAL;
+                            }
+                        };
+
+                        if sender
+                            .send(NewVersionCommandOutput::LatestRelease {
+                                version: latest_version.clone(),
+                                release_notes: latest_release.body.clone().unwrap_or_default(),
+                                release_url: latest_release.html_url.clone(),
+                            })
+                            .is_err()
+                        {
+                            return;
                         }
-                    };
 
-                    let new_version = match Version::parse(&tag_name) {
-                        Ok(new_version) => new_version,
-                        Err(error) => {
-                            debug!(%error, %tag_name, "Failed to parse new version");
-                            tokio::time::sleep(NEW_VERSION_CHECK_RETRY_INTERVAL).await;
-                            continue;
+                        if !(updates.auto_download && latest_version > current_version) {
+                            break 'check updates.check_interval.duration();
+                        }
+
+                        if !is_installer_supported().await {
+                            debug!(
+                                %latest_version,
+                                "Installer is not supported on this system, not downloading new \
+                                version"
+                            );
+                            break 'check updates.check_interval.duration();
+                        }
+
+                        let Some(installer) = select_installer(&latest_release.assets) else {
+                            debug!(
+                                %latest_version,
+                                "No installer for this platform, not downloading new version"
+                            );
+                            break 'check updates.check_interval.duration();
+                        };
+
+                        match download_installer(&client, &user_agent, installer).await {
+                            Ok(path) => {
+                                info!(path = %path.display(), "New version installer is ready");
+
+                                if sender
+                                    .send(NewVersionCommandOutput::Downloaded(path))
+                                    .is_err()
+                                {
+                                    return;
+                                }
+
+                                updates.check_interval.duration()
+                            }
+                            Err(error) => {
+                                warn!(
+                                    %error,
+                                    url = %installer.browser_download_url,
+                                    "Failed to download new version installer"
+                                );
+                                NEW_VERSION_CHECK_RETRY_INTERVAL
+                            }
                         }
                     };
 
-                    if new_version > current_version
-                        && sender
-                            .send(NewVersionCommandOutput::NewVersion(new_version))
-                            .is_err()
+                    // Changed configuration is applied right away
+                    if let Ok(Err(_error)) =
+                        tokio::time::timeout(delay, updates_receiver.changed()).await
                     {
                         break;
                     }
-
-                    tokio::time::sleep(NEW_VERSION_CHECK_INTERVAL).await;
                 }
             })
             .drop_on_shutdown()
             .await
     }
 
-    fn process_command(&mut self, command_output: NewVersionCommandOutput) {
+    fn process_input(&mut self, input: NewVersionInput) {
+        match input {
+            NewVersionInput::UpdatesConfiguration(updates) => {
+                self.updates_sender.send_if_modified(|current_updates| {
+                    let modified = *current_updates != Some(updates);
+                    current_updates.replace(updates);
+                    modified
+                });
+            }
+            NewVersionInput::Open => {
+                self.show_release_notes_dialog();
+            }
+        }
+    }
+
+    fn show_release_notes_dialog(&self) {
+        let Some(new_version) = &self.new_version else {
+            return;
+        };
+
+        let dialog = gtk::MessageDialog::builder()
+            .buttons(gtk::ButtonsType::None)
+            .message_type(gtk::MessageType::Info)
+            .modal(true)
+            .text(T.new_version_dialog_title(new_version.to_string()).as_str())
+            .build();
+        if let Some(window) = relm4::main_application().active_window() {
+            dialog.set_transient_for(Some(&window));
+        }
+
+        let release_notes = gtk::Label::builder()
+            .halign(gtk::Align::Start)
+            .selectable(true)
+            .valign(gtk::Align::Start)
+            .wrap(true)
+            .xalign(0.0)
+            .build();
+        if self.release_notes.trim().is_empty() {
+            release_notes.set_label(&T.new_version_dialog_no_release_notes());
+        } else {
+            release_notes.set_markup(&markdown_to_pango_markup(&self.release_notes));
+        }
+        let scrolled_window = gtk::ScrolledWindow::builder()
+            .child(&release_notes)
+            .hscrollbar_policy(gtk::PolicyType::Never)
+            .max_content_height(500)
+            .min_content_width(500)
+            .propagate_natural_height(true)
+            .build();
+        if let Some(message_area) = dialog.message_area().downcast_ref::<gtk::Box>() {
+            message_area.append(&scrolled_window);
+        }
+
+        let installer_directory = self
+            .downloaded_installer
+            .as_deref()
+            .and_then(Path::parent)
+            .map(Path::to_path_buf);
+        if installer_directory.is_some() {
+            dialog.add_button(
+                &T.new_version_dialog_button_open_installer_folder(),
+                gtk::ResponseType::Apply,
+            );
+        }
+        dialog.add_button(
+            &T.new_version_dialog_button_open_release_page(),
+            gtk::ResponseType::Accept,
+        );
+        dialog.add_button(
+            &T.new_version_dialog_button_close(),
+            gtk::ResponseType::Close,
+        );
+        dialog.set_default_response(gtk::ResponseType::Close);
+
+        let release_url = self.release_url.clone().unwrap_or_else(releases_page_url);
+        dialog.connect_response(move |dialog, response| {
+            match response {
+                gtk::ResponseType::Accept => {
+                    if let Err(error) = open::that_detached(&release_url) {
+                        error!(%error, "Failed to open release page in default browser");
+                    }
+                }
+                gtk::ResponseType::Apply => {
+                    if let Some(directory) = &installer_directory
+                        && let Err(error) = open::that_detached(directory)
+                    {
+                        error!(
+                            %error,
+                            path = %directory.display(),
+                            "Failed to open folder with downloaded installer"
+                        );
+                    }
+                }
+                _ => {}
+            }
+            dialog.close();
+        });
+        dialog.present();
+    }
+
+    fn process_command(
+        &mut self,
+        command_output: NewVersionCommandOutput,
+        sender: ComponentSender<Self>,
+    ) {
         match command_output {
-            NewVersionCommandOutput::NewVersion(version) => {
-                self.get_mut_new_version().replace(version);
+            NewVersionCommandOutput::LatestRelease {
+                version,
+                release_notes,
+                release_url,
+            } => {
+                let current_version = Version::parse(env!("CARGO_PKG_VERSION")).ok();
+                if current_version.is_some_and(|current_version| version > current_version) {
+                    if self.new_version.as_ref() != Some(&version) {
+                        self.get_mut_new_version().replace(version.clone());
+                        self.set_downloaded_installer(None);
+                    }
+                    // Notes may be edited after release was published
+                    self.release_notes = release_notes;
+                    self.release_url = release_url;
+                }
+
+                if sender
+                    .output(NewVersionOutput::LatestVersion(version))
+                    .is_err()
+                {
+                    debug!("Failed to send latest version");
+                }
+            }
+            NewVersionCommandOutput::Downloaded(path) => {
+                self.get_mut_downloaded_installer().replace(path);
+            }
+        }
+    }
+}
+
+fn releases_page_url() -> String {
+    let repository = env!("CARGO_PKG_REPOSITORY");
+
+    if repository.starts_with("https://github.com") {
+        // Turn:
+        // https://github.com/autonomys/space-acres
+        // Into:
+        // https://github.com/autonomys/space-acres/releases
+        format!("{}/releases", env!("CARGO_PKG_REPOSITORY"))
+    } else {
+        repository.to_string()
+    }
+}
+
+/// Render markdown of release notes as Pango markup.
+///
+/// Only headings, list items, code, bold text and links are supported since that is what release
+/// notes typically contain, everything else is shown as plain text.
+fn markdown_to_pango_markup(markdown: &str) -> String {
+    let mut markup = String::with_capacity(markdown.len());
+    let mut code_block = false;
+
+    for line in markdown.lines() {
+        let trimmed_line = line.trim_start();
+        if trimmed_line.starts_with("```") {
+            code_block = !code_block;
+            continue;
+        }
+        if code_block {
+            let _ = writeln!(markup, "<tt>{}</tt>", glib::markup_escape_text(line));
+            continue;
+        }
+
+        let heading_level = trimmed_line
+            .bytes()
+            .take_while(|&byte| byte == b'#')
+            .count();
+        if (1..=6).contains(&heading_level) && trimmed_line[heading_level..].starts_with(' ') {
+            let heading = inline_markdown_to_pango_markup(trimmed_line[heading_level..].trim());
+            if heading_level <= 2 {
+                let _ = writeln!(markup, "<big><b>{heading}</b></big>");
+            } else {
+                let _ = writeln!(markup, "<b>{heading}</b>");
+            }
+        } else if let Some(item) = trimmed_line
+            .strip_prefix("- ")
+            .or_else(|| trimmed_line.strip_prefix("* "))
+        {
+            let indentation = &line[..line.len() - trimmed_line.len()];
+            let _ = writeln!(
+                markup,
+                "{indentation}• {}",
+                inline_markdown_to_pango_markup(item)
+            );
+        } else {
+            let _ = writeln!(markup, "{}", inline_markdown_to_pango_markup(line));
+        }
+    }
+
+    markup.truncate(markup.trim_end().len());
+    markup
+}
+
+fn inline_markdown_to_pango_markup(text: &str) -> String {
+    let mut markup = String::with_capacity(text.len());
+    let mut bold = false;
+    let mut remaining = text;
+
+    while let Some(character) = remaining.chars().next() {
+        if let Some(after_marker) = remaining.strip_prefix("**") {
+            markup.push_str(if bold { "</b>" } else { "<b>" });
+            bold = !bold;
+            remaining = after_marker;
+        } else if let Some(after_marker) = remaining.strip_prefix('`')
+            && let Some((code, after_code)) = after_marker.split_once('`')
+        {
+            let _ = write!(markup, "<tt>{}</tt>", glib::markup_escape_text(code));
+            remaining = after_code;
+        } else if let Some((link_text, url, after_link)) = parse_markdown_link(remaining) {
+            let _ = write!(
+                markup,
+                "<a href=\"{}\">{}</a>",
+                glib::markup_escape_text(url),
+                glib::markup_escape_text(link_text)
+            );
+            remaining = after_link;
+        } else {
+            markup.push_str(&glib::markup_escape_text(
+                &remaining[..character.len_utf8()],
+            ));
+            remaining = &remaining[character.len_utf8()..];
+        }
+    }
+
+    // Unbalanced markers must not break the whole markup
+    if bold {
+        markup.push_str("</b>");
+    }
+
+    markup
+}
+
+/// Parse `[text](url)` at the beginning of the text, returns text, URL and what remains after link
+fn parse_markdown_link(text: &str) -> Option<(&str, &str, &str)> {
+    let (link_text, after_link_text) = text.strip_prefix('[')?.split_once("](")?;
+    let (url, after_link) = after_link_text.split_once(')')?;
+
+    // Nested brackets are not supported
+    if link_text.contains(['[', ']']) || url.contains(char::is_whitespace) {
+        return None;
+    }
+
+    Some((link_text, url, after_link))
+}
+
+/// Installer for current platform and architecture.
+///
+/// Generic build is preferred over builds optimized for specific CPUs, which have longer names.
+fn select_installer(assets: &[ReleaseAsset]) -> Option<&ReleaseAsset> {
+    assets
+        .iter()
+        .filter(|asset| {
+            asset.name.ends_with(INSTALLER_EXTENSION)
+                && asset.name.contains(env::consts::ARCH)
+                // Name is used as file name, make sure it doesn't point anywhere else
+                && Path::new(&asset.name).file_name() == Some(asset.name.as_ref())
+        })
+        .min_by_key(|asset| asset.name.len())
+}
+
+/// Whether installer downloaded for this platform can be used on this system
+#[cfg(any(windows, target_os = "macos"))]
+async fn is_installer_supported() -> bool {
+    true
+}
+
+/// Whether installer downloaded for this platform can be used on this system, `.deb` packages
+/// only work on Debian and distributions derived from it, users of other distributions are
+/// pointed to the release page instead
+#[cfg(not(any(windows, target_os = "macos")))]
+async fn is_installer_supported() -> bool {
+    let Ok(os_release) = tokio::fs::read_to_string("/etc/os-release").await else {
+        return tokio::fs::try_exists("/etc/debian_version")
+            .await
+            .unwrap_or_default();
+    };
+
+    os_release.lines().any(|line| {
+        let Some((key, value)) = line.split_once('=') else {
+            return false;
+        };
+
+        matches!(key.trim(), "ID" | "ID_LIKE")
+            && value
+                .trim()
+                .trim_matches(['"', '\''])
+                .split_whitespace()
+                .any(|id| id == "debian")
+    })
+}
+
+/// Download installer into downloads folder and verify its checksum, installer that was
+/// downloaded before is replaced
+async fn download_installer(
+    client: &Client,
+    user_agent: &str,
+    installer: &ReleaseAsset,
+) -> anyhow::Result<PathBuf> {
+    let expected_checksum = installer
+        .digest
+        .as_deref()
+        .and_then(|digest| digest.strip_prefix("sha256:"))
+        .ok_or_else(|| anyhow::anyhow!("Release asset doesn't have SHA-256 checksum"))?
+        .to_ascii_lowercase();
+
+    let directory = dirs::download_dir().unwrap_or_else(env::temp_dir);
+    let path = directory.join(&installer.name);
+
+    // Installer of this version might have been downloaded already during previous checks or runs
+    match file_checksum(&path).await {
+        Ok(checksum) => {
+            if checksum == expected_checksum {
+                debug!(path = %path.display(), "Installer was already downloaded");
+                return Ok(path);
+            }
+        }
+        Err(error) => {
+            if error.kind() != io::ErrorKind::NotFound {
+                debug!(%error, path = %path.display(), "Failed to check existing installer");
             }
         }
     }
+
+    // Download into temporary file first, so that interrupted or corrupted download is never
+    // mistaken for complete installer
+    let temp_file = NamedTempFile::with_prefix_in(".space-acres-installer", &directory)?;
+    let mut response = client
+        .get(&installer.browser_download_url)
+        .header("User-Agent", user_agent)
+        .send()
+        .await?
+        .error_for_status()?;
+    let mut file = tokio::fs::File::from_std(temp_file.as_file().try_clone()?);
+    let mut hasher = Sha256::new();
+    while let Some(chunk) = response.chunk().await? {
+        hasher.update(&chunk);
+        file.write_all(&chunk).await?;
+    }
+    file.flush().await?;
+    file.sync_all().await?;
+    drop(file);
+
+    let checksum = hex::encode(hasher.finalize());
+    if checksum != expected_checksum {
+        return Err(anyhow::anyhow!(
+            "Checksum mismatch, expected {expected_checksum}, got {checksum}"
+        ));
+    }
+
+    temp_file.persist(&path)?;
+
+    Ok(path)
+}
+
+/// SHA-256 checksum of file contents as lowercase hex string
+async fn file_checksum(path: &Path) -> io::Result<String> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0; 1024 * 1024];
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+
+    Ok(hex::encode(hasher.finalize()))
 }
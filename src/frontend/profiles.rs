@@ -0,0 +1,434 @@
+use crate::backend::config::profiles::{self, DEFAULT_PROFILE_NAME};
+use crate::frontend::toasts::Toast;
+use crate::frontend::translations::{AsDefaultStr, T};
+use crate::frontend::App;
+use gtk::prelude::*;
+use relm4::factory::FactoryVecDeque;
+use relm4::prelude::*;
+use tracing::warn;
+
+#[derive(Debug)]
+struct ProfileRowInit {
+    name: String,
+    active: bool,
+}
+
+#[derive(Debug)]
+enum ProfileRowInput {
+    Switch,
+    Delete,
+}
+
+#[derive(Debug)]
+enum ProfileRowOutput {
+    Switch(String),
+    Delete(String),
+}
+
+#[derive(Debug)]
+struct ProfileRow {
+    name: String,
+    active: bool,
+}
+
+#[relm4::factory]
+impl FactoryComponent for ProfileRow {
+    type Init = ProfileRowInit;
+    type Input = ProfileRowInput;
+    type Output = ProfileRowOutput;
+    type CommandOutput = ();
+    type ParentWidget = gtk::ListBox;
+
+    view! {
+        #[root]
+        gtk::Box {
+            set_margin_all: 10,
+            set_spacing: 10,
+
+            gtk::Label {
+                set_halign: gtk::Align::Start,
+                set_hexpand: true,
+                set_label: &if self.active {
+                    T.profiles_active_profile(self.name.as_str()).to_string()
+                } else {
+                    self.name.clone()
+                },
+            },
+
+            gtk::Button {
+                connect_clicked => ProfileRowInput::Switch,
+                set_cursor_from_name: Some("pointer"),
+                set_label: &T.profiles_button_switch(),
+                set_sensitive: !self.active,
+            },
+
+            gtk::Button {
+                add_css_class: "destructive-action",
+                connect_clicked => ProfileRowInput::Delete,
+                set_cursor_from_name: Some("pointer"),
+                set_label: &T.profiles_button_delete(),
+                set_sensitive: !self.active && self.name != DEFAULT_PROFILE_NAME,
+            },
+        },
+    }
+
+    fn init_model(init: Self::Init, _index: &DynamicIndex, _sender: FactorySender<Self>) -> Self {
+        Self {
+            name: init.name,
+            active: init.active,
+        }
+    }
+
+    fn update(&mut self, input: Self::Input, sender: FactorySender<Self>) {
+        let output = match input {
+            ProfileRowInput::Switch => ProfileRowOutput::Switch(self.name.clone()),
+            ProfileRowInput::Delete => ProfileRowOutput::Delete(self.name.clone()),
+        };
+        if sender.output(output).is_err() {
+            warn!("Can't send profile row output");
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ProfilesInit {
+    pub parent: gtk::Window,
+}
+
+#[derive(Debug)]
+pub enum ProfilesInput {
+    Show,
+    NewProfileNameChanged(String),
+    Create,
+    Switch(String),
+    /// Switch after user confirmed that node and farmer will be stopped
+    SwitchConfirmed(String),
+    Delete(String),
+    /// Delete after user confirmed it
+    DeleteConfirmed(String),
+}
+
+#[derive(Debug)]
+pub enum ProfilesOutput {
+    /// Active profile changed, node and farmer need to be started with its configuration
+    Switched { name: String },
+}
+
+#[derive(Debug)]
+pub enum ProfilesCommandOutput {
+    Loaded {
+        profiles: Vec<String>,
+        active_profile: String,
+    },
+    Created {
+        name: String,
+    },
+    Deleted {
+        name: String,
+    },
+    Switched {
+        name: String,
+    },
+    Failed(String),
+}
+
+/// Window for managing configuration profiles, each profile has its own configuration file
+#[tracker::track]
+#[derive(Debug)]
+pub struct Profiles {
+    new_profile_name: String,
+    error: Option<String>,
+    #[do_not_track]
+    profiles: Vec<String>,
+    #[do_not_track]
+    profile_rows: FactoryVecDeque<ProfileRow>,
+}
+
+#[relm4::component(pub)]
+impl Component for Profiles {
+    type Init = ProfilesInit;
+    type Input = ProfilesInput;
+    type Output = ProfilesOutput;
+    type CommandOutput = ProfilesCommandOutput;
+
+    view! {
+        #[root]
+        gtk::Window {
+            set_default_width: 500,
+            set_hide_on_close: true,
+            set_resizable: false,
+            set_title: Some(&T.profiles_title()),
+
+            gtk::Box {
+                set_margin_all: 10,
+                set_orientation: gtk::Orientation::Vertical,
+                set_spacing: 10,
+
+                gtk::Label {
+                    set_halign: gtk::Align::Start,
+                    set_label: &T.profiles_description(),
+                    set_wrap: true,
+                },
+
+                gtk::Frame {
+                    #[local_ref]
+                    profile_rows -> gtk::ListBox {
+                        set_selection_mode: gtk::SelectionMode::None,
+                    },
+                },
+
+                gtk::Box {
+                    add_css_class: "linked",
+
+                    gtk::Entry {
+                        connect_activate => ProfilesInput::Create,
+                        connect_changed[sender] => move |entry| {
+                            sender.input(ProfilesInput::NewProfileNameChanged(
+                                entry.text().into(),
+                            ));
+                        },
+                        #[track = "model.changed_new_profile_name()"]
+                        set_css_classes: if model.new_profile_name.is_empty()
+                            || model.is_new_profile_name_valid()
+                        {
+                            &[]
+                        } else {
+                            &["invalid-input"]
+                        },
+                        set_hexpand: true,
+                        set_placeholder_text: Some(&T.profiles_new_profile_name_placeholder()),
+                        set_tooltip: &T.profiles_new_profile_name_tooltip(),
+                        #[track = "model.changed_new_profile_name()"]
+                        set_text: &model.new_profile_name,
+                    },
+
+                    gtk::Button {
+                        add_css_class: "suggested-action",
+                        connect_clicked => ProfilesInput::Create,
+                        set_cursor_from_name: Some("pointer"),
+                        set_label: &T.profiles_button_create(),
+                        #[track = "model.changed_new_profile_name()"]
+                        set_sensitive: model.is_new_profile_name_valid(),
+                    },
+                },
+
+                gtk::Label {
+                    add_css_class: "error-label",
+                    set_halign: gtk::Align::Start,
+                    #[track = "model.changed_error()"]
+                    set_label: &T
+                        .profiles_error(model.error.as_deref().unwrap_or_default())
+                        .to_string(),
+                    #[track = "model.changed_error()"]
+                    set_visible: model.error.is_some(),
+                    set_wrap: true,
+                },
+            },
+        }
+    }
+
+    fn init(
+        init: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        root.set_transient_for(Some(&init.parent));
+
+        let profile_rows = FactoryVecDeque::builder()
+            .launch(gtk::ListBox::new())
+            .forward(sender.input_sender(), |output| match output {
+                ProfileRowOutput::Switch(name) => ProfilesInput::Switch(name),
+                ProfileRowOutput::Delete(name) => ProfilesInput::Delete(name),
+            });
+
+        let model = Self {
+            new_profile_name: String::new(),
+            error: None,
+            profiles: Vec::new(),
+            profile_rows,
+            tracker: u8::MAX,
+        };
+
+        let profile_rows = model.profile_rows.widget();
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, input: Self::Input, sender: ComponentSender<Self>, root: &Self::Root) {
+        // Reset changes
+        self.reset();
+
+        match input {
+            ProfilesInput::Show => {
+                self.set_error(None);
+                sender.oneshot_command(Self::load());
+                root.present();
+            }
+            ProfilesInput::NewProfileNameChanged(new_profile_name) => {
+                self.set_new_profile_name(new_profile_name);
+            }
+            ProfilesInput::Create => {
+                if !self.is_new_profile_name_valid() {
+                    return;
+                }
+
+                let name = self.new_profile_name.clone();
+                sender.oneshot_command(async move {
+                    match profiles::create_profile(&name).await {
+                        Ok(()) => ProfilesCommandOutput::Created { name },
+                        Err(error) => ProfilesCommandOutput::Failed(error.to_string()),
+                    }
+                });
+            }
+            ProfilesInput::Switch(name) => {
+                Self::confirm(
+                    root,
+                    T.profiles_switch_confirmation(name.as_str()).to_string(),
+                    T.profiles_switch_confirmation_details().to_string(),
+                    T.profiles_switch_confirmation_button_switch().to_string(),
+                    sender.input_sender().clone(),
+                    ProfilesInput::SwitchConfirmed(name),
+                );
+            }
+            ProfilesInput::SwitchConfirmed(name) => {
+                sender.oneshot_command(async move {
+                    match profiles::set_active_profile(&name).await {
+                        Ok(()) => ProfilesCommandOutput::Switched { name },
+                        Err(error) => ProfilesCommandOutput::Failed(error.to_string()),
+                    }
+                });
+            }
+            ProfilesInput::Delete(name) => {
+                Self::confirm(
+                    root,
+                    T.profiles_delete_confirmation(name.as_str()).to_string(),
+                    T.profiles_delete_confirmation_details().to_string(),
+                    T.profiles_delete_confirmation_button_delete().to_string(),
+                    sender.input_sender().clone(),
+                    ProfilesInput::DeleteConfirmed(name),
+                );
+            }
+            ProfilesInput::DeleteConfirmed(name) => {
+                sender.oneshot_command(async move {
+                    match profiles::delete_profile(&name).await {
+                        Ok(()) => ProfilesCommandOutput::Deleted { name },
+                        Err(error) => ProfilesCommandOutput::Failed(error.to_string()),
+                    }
+                });
+            }
+        }
+    }
+
+    fn update_cmd(
+        &mut self,
+        input: Self::CommandOutput,
+        sender: ComponentSender<Self>,
+        root: &Self::Root,
+    ) {
+        // Reset changes
+        self.reset();
+
+        match input {
+            ProfilesCommandOutput::Loaded {
+                profiles,
+                active_profile,
+            } => {
+                let mut profile_rows = self.profile_rows.guard();
+                profile_rows.clear();
+                for name in &profiles {
+                    profile_rows.push_back(ProfileRowInit {
+                        name: name.clone(),
+                        active: *name == active_profile,
+                    });
+                }
+                drop(profile_rows);
+
+                self.profiles = profiles;
+                // Re-validate name against updated list of profiles
+                self.get_mut_new_profile_name();
+            }
+            ProfilesCommandOutput::Created { name } => {
+                self.set_error(None);
+                self.set_new_profile_name(String::new());
+                App::show_toast(Toast::info(
+                    T.toast_profile_created(name.as_str()).to_string(),
+                ));
+                sender.oneshot_command(Self::load());
+            }
+            ProfilesCommandOutput::Deleted { name } => {
+                self.set_error(None);
+                App::show_toast(Toast::info(
+                    T.toast_profile_deleted(name.as_str()).to_string(),
+                ));
+                sender.oneshot_command(Self::load());
+            }
+            ProfilesCommandOutput::Switched { name } => {
+                self.set_error(None);
+                root.hide();
+                if sender.output(ProfilesOutput::Switched { name }).is_err() {
+                    warn!("Can't send profile switched output");
+                }
+            }
+            ProfilesCommandOutput::Failed(error) => {
+                warn!(%error, "Configuration profile action failed");
+                self.set_error(Some(error));
+                sender.oneshot_command(Self::load());
+            }
+        }
+    }
+}
+
+impl Profiles {
+    async fn load() -> ProfilesCommandOutput {
+        match profiles::list_profiles().await {
+            Ok(profiles) => ProfilesCommandOutput::Loaded {
+                profiles,
+                active_profile: profiles::active_profile().await,
+            },
+            Err(error) => ProfilesCommandOutput::Failed(error.to_string()),
+        }
+    }
+
+    fn is_new_profile_name_valid(&self) -> bool {
+        profiles::is_valid_profile_name(&self.new_profile_name)
+            && !self.profiles.contains(&self.new_profile_name)
+    }
+
+    fn confirm(
+        root: &gtk::Window,
+        text: String,
+        details: String,
+        confirm_label: String,
+        input_sender: relm4::Sender<ProfilesInput>,
+        confirmed: ProfilesInput,
+    ) {
+        let dialog = gtk::MessageDialog::builder()
+            .buttons(gtk::ButtonsType::None)
+            .message_type(gtk::MessageType::Question)
+            .modal(true)
+            .secondary_text(details)
+            .text(text)
+            .transient_for(root)
+            .build();
+        dialog.add_button(
+            &T.profiles_confirmation_button_cancel(),
+            gtk::ResponseType::Cancel,
+        );
+        dialog
+            .add_button(&confirm_label, gtk::ResponseType::Accept)
+            .add_css_class("destructive-action");
+        dialog.set_default_response(gtk::ResponseType::Cancel);
+
+        let mut confirmed = Some(confirmed);
+        dialog.connect_response(move |dialog, response| {
+            if response == gtk::ResponseType::Accept
+                && let Some(confirmed) = confirmed.take()
+            {
+                input_sender.emit(confirmed);
+            }
+            dialog.close();
+        });
+        dialog.present();
+    }
+}
@@ -1,35 +1,76 @@
 mod farm;
+mod farm_tile;
 mod node;
+mod rewards;
+mod stats;
 
 use crate::backend::config::{Config, RawConfig};
-use crate::backend::farmer::{FarmerNotification, InitialFarmState};
+use crate::backend::farmer::{DiskFarm, FarmerNotification, InitialFarmState};
 use crate::backend::node::ChainInfo;
 use crate::backend::{FarmIndex, NodeNotification};
-use crate::frontend::running::farm::{FarmWidget, FarmWidgetInit, FarmWidgetInput};
+use crate::frontend::event_log::EventKind;
+use crate::frontend::running::farm::{
+    FarmSummary, FarmWidget, FarmWidgetInit, FarmWidgetInput, FarmWidgetOutput,
+};
+use crate::frontend::running::farm_tile::{FarmTile, FarmTileInit, FarmTileInput};
 use crate::frontend::running::node::{NodeInput, NodeView};
+use crate::frontend::running::rewards::{RewardsInput, RewardsView};
+use crate::frontend::running::stats::{StatsInput, StatsView};
 use crate::frontend::translations::{AsDefaultStr, T};
+use crate::frontend::ui_state::UiState;
+use crate::frontend::widgets::copy_button::{CopyButton, CopyButtonInit, CopyButtonInput};
 use crate::frontend::widgets::progress_circle::{
     ProgressCircle, ProgressCircleInit, ProgressCircleInput,
 };
 use crate::frontend::NotificationExt;
+use bytesize::ByteSize;
 use gtk::prelude::*;
 use notify_rust::Notification;
 use relm4::factory::FactoryHashMap;
 use relm4::prelude::*;
 use relm4_icons::icon_name;
+use serde::{Deserialize, Serialize};
 use sp_consensus_subspace::ChainConstants;
+use std::cell::RefCell;
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, HashMap};
 use std::num::NonZeroU8;
+use std::path::PathBuf;
+use std::rc::Rc;
 use std::time::{Duration, Instant};
 use subspace_core_primitives::{solution_range_to_sectors, BlockNumber, Piece, SolutionRange};
 use subspace_farmer::farm::{
     FarmingNotification, ProvingResult, SectorPlottingDetails, SectorUpdate,
 };
+use subspace_networking::libp2p::PeerId;
 use subspace_runtime_primitives::{Balance, SSC};
 use tracing::{debug, error, warn};
 
+/// Reward notifications are not shown more often than this, rewards received in between are
+/// included into the next notification
+const REWARD_NOTIFICATION_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Order in which farms are shown on running screen
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FarmSortOrder {
+    /// Order in which farms are specified in configuration
+    #[default]
+    Default,
+    /// Largest farms first
+    Size,
+    Path,
+    /// Farms that need attention first
+    Status,
+}
+
 #[derive(Debug)]
 pub struct RunningInit {
     pub plotting_paused: bool,
+    pub farming_paused: bool,
+    pub farm_sort_order: FarmSortOrder,
+    /// Whether farms are shown as a grid of small tiles instead of a list
+    pub compact_farms: bool,
 }
 
 #[derive(Debug)]
@@ -39,24 +80,105 @@ pub enum RunningInput {
         best_block_number: BlockNumber,
         reward_address_balance: Balance,
         initial_farm_states: Vec<InitialFarmState>,
+        piece_cache_capacity: u64,
         cache_percentage: NonZeroU8,
         config: Config,
         raw_config: RawConfig,
         chain_info: ChainInfo,
         chain_constants: ChainConstants,
+        peer_id: PeerId,
+    },
+    /// Configuration was updated while running, only settings that don't require restart are
+    /// applied
+    ConfigUpdate {
+        raw_config: RawConfig,
     },
     NodeNotification(NodeNotification),
     FarmerNotification(FarmerNotification<FarmIndex>),
     ToggleFarmDetails,
     TogglePausePlotting,
+    TogglePauseFarming,
+    SetFarmDisabled {
+        farm_index: u8,
+        path: PathBuf,
+        disabled: bool,
+        known_to_farmer: bool,
+    },
+    SlowAuditing {
+        farm_index: u8,
+        slow: bool,
+    },
+    RestartFarm {
+        farm_index: u8,
+    },
+    ResizeFarm {
+        farm_index: u8,
+        path: PathBuf,
+        size: String,
+        previous_allocated_space: u64,
+        allocated_space: u64,
+    },
+    FarmSummaryChanged {
+        farm_index: u8,
+        summary: FarmSummary,
+    },
+    FarmSortOrderChanged(FarmSortOrder),
+    CompactFarmsChanged(bool),
     // TODO: Use LinkButton once https://gitlab.gnome.org/GNOME/glib/-/issues/3403 is fixed
     //  for macOS
     OpenRewardAddressInExplorer,
 }
 
+/// High-level status of farming, used for things like tray icon
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RunningStatus {
+    Syncing,
+    Plotting,
+    Farming,
+    Paused,
+    Error,
+}
+
 #[derive(Debug)]
 pub enum RunningOutput {
+    StatusChanged(RunningStatus),
+    /// Significant event that should be recorded in event log
+    Event(EventKind),
     PausePlotting(bool),
+    PauseFarming(bool),
+    /// Farm was disabled or enabled, indices of farms that are known to the farmer match farm
+    /// indices of the farmer
+    SetFarmDisabled {
+        farm_index: u8,
+        path: PathBuf,
+        disabled: bool,
+        /// Farms that were disabled on startup are not known to the farmer until restart
+        known_to_farmer: bool,
+    },
+    /// Farm that stopped with an error needs to be started again
+    RestartFarm {
+        farm_index: u8,
+    },
+    /// Farm needs to be reopened with a larger size
+    ResizeFarm {
+        farm_index: u8,
+        allocated_space: u64,
+    },
+    /// Farmer reopened farm with a larger size, size needs to be stored in configuration as is
+    FarmResized {
+        path: PathBuf,
+        size: String,
+    },
+}
+
+/// Farm resize that was requested, but not yet confirmed by farmer
+#[derive(Debug)]
+struct PendingFarmResize {
+    path: PathBuf,
+    /// Size as entered by user
+    size: String,
+    previous_allocated_space: u64,
+    allocated_space: u64,
 }
 
 #[tracker::track]
@@ -65,6 +187,8 @@ struct FarmerState {
     initial_reward_address_balance: Balance,
     reward_address_balance: Balance,
     piece_cache_sync_progress: f32,
+    /// Number of pieces piece cache of all farms can hold
+    piece_cache_capacity: u64,
     reward_address_url: String,
     token_symbol: String,
     local_space_pledged: u64,
@@ -79,6 +203,37 @@ struct FarmerState {
     reward_eta_progress_circle: Controller<ProgressCircle>,
 }
 
+impl FarmerState {
+    /// Space pledged that takes into consideration how much space was plotted so far, space
+    /// occupied by piece cache is excluded
+    fn plotted_space_pledged(&self) -> u64 {
+        if self.sectors_total == 0 {
+            0
+        } else {
+            self.local_space_pledged * u64::from(self.sectors_plotted)
+                / u64::from(self.sectors_total)
+                * u64::from(100 - self.cache_percentage.get())
+                / 100
+        }
+    }
+
+    /// Estimated fraction of network space pledged that belongs to this farmer in percent, which
+    /// is also approximately the chance to produce each block
+    fn network_share(&self) -> f64 {
+        self.plotted_space_pledged() as f64 / self.network_space_pledged.max(1) as f64 * 100.0
+    }
+}
+
+#[derive(Debug, Default)]
+struct RewardNotifications {
+    enabled: bool,
+    /// Farm that signed the last reward successfully
+    last_rewarded_farm: Option<u8>,
+    last_shown: Option<Instant>,
+    /// Amount and number of rewards that were not included in a notification yet
+    pending: (Balance, u32),
+}
+
 #[tracker::track]
 #[derive(Debug)]
 pub struct RunningView {
@@ -88,8 +243,54 @@ pub struct RunningView {
     #[do_not_track]
     farmer_state: FarmerState,
     #[do_not_track]
+    reward_address_copy_button: Controller<CopyButton>,
+    #[do_not_track]
+    rewards_view: Controller<RewardsView>,
+    #[do_not_track]
+    stats_view: Controller<StatsView>,
+    #[do_not_track]
     farms: FactoryHashMap<u8, FarmWidget>,
+    #[do_not_track]
+    farm_tiles: FactoryHashMap<u8, FarmTile>,
+    /// Positions of farm tiles according to current sort order, used by sort function of farm
+    /// tiles container
+    #[do_not_track]
+    farm_tile_positions: Rc<RefCell<HashMap<u8, usize>>>,
+    #[do_not_track]
+    enabled_farms: usize,
+    /// Farm names for notifications, farm path is used if farm has no name
+    #[do_not_track]
+    farm_names: HashMap<u8, String>,
+    #[do_not_track]
+    reward_notifications: RewardNotifications,
+    /// When initial plotting started, `None` if it is not happening, tracked regardless of
+    /// whether notification is enabled
+    #[do_not_track]
+    initial_plotting_started: Option<Instant>,
+    /// Whether to notify user once initial plotting is finished
+    #[do_not_track]
+    plotting_notifications: bool,
+    /// Farms that have encountered an error and were not restarted since
+    #[do_not_track]
+    failed_farms: BTreeSet<u8>,
+    /// Farm resizes that farmer didn't confirm yet
+    #[do_not_track]
+    pending_farm_resizes: HashMap<u8, PendingFarmResize>,
+    /// Farms that are consistently auditing slower than slot duration
+    slow_auditing_farms: BTreeSet<u8>,
+    /// Whether node is connected to any peers, `None` until the first peers update
+    #[do_not_track]
+    node_has_peers: Option<bool>,
+    #[do_not_track]
+    farm_health_notifications: bool,
+    /// Last status sent to the app
+    #[do_not_track]
+    status: Option<RunningStatus>,
     plotting_paused: bool,
+    farming_paused: bool,
+    #[do_not_track]
+    farm_sort_order: FarmSortOrder,
+    compact_farms: bool,
 }
 
 #[relm4::component(pub)]
@@ -132,12 +333,28 @@ impl Component for RunningView {
                         },
                         gtk::ToggleButton {
                             connect_clicked => RunningInput::TogglePausePlotting,
+                            #[track = "model.changed_plotting_paused()"]
                             set_active: model.plotting_paused,
                             set_cursor_from_name: Some("pointer"),
                             set_has_frame: false,
                             set_icon_name: icon_name::PAUSE,
                             set_tooltip: &T.running_farmer_button_pause_plotting(),
                         },
+                        gtk::ToggleButton {
+                            connect_clicked => RunningInput::TogglePauseFarming,
+                            #[track = "model.changed_farming_paused()"]
+                            set_active: model.farming_paused,
+                            set_cursor_from_name: Some("pointer"),
+                            set_has_frame: false,
+                            set_icon_name: icon_name::STOP_SIGN,
+                            set_tooltip: &T.running_farmer_button_pause_farming(),
+                        },
+                    },
+                    gtk::Label {
+                        add_css_class: "warning-label",
+                        set_label: &T.running_farmer_farming_paused(),
+                        #[track = "model.changed_farming_paused()"]
+                        set_visible: model.farming_paused,
                     },
                     gtk::Box {
                         set_halign: gtk::Align::End,
@@ -181,10 +398,54 @@ impl Component for RunningView {
                                 },
                                 set_use_markup: true,
                             },
-                        }
+                        },
+
+                        model.reward_address_copy_button.widget().clone(),
                     },
                 },
 
+                gtk::Label {
+                    add_css_class: "dim-label",
+                    set_halign: gtk::Align::Start,
+                    set_margin_start: 10,
+                    set_margin_end: 10,
+                    #[track = "model.farmer_state.changed_network_space_pledged() || model.farmer_state.changed_sectors_plotted() || model.farmer_state.changed_local_space_pledged()"]
+                    set_label: T
+                        .running_farmer_network_space(
+                            ByteSize::b(
+                                u64::try_from(model.farmer_state.network_space_pledged)
+                                    .unwrap_or(u64::MAX)
+                            )
+                            .to_string_as(true),
+                            model.farmer_state.network_share(),
+                        )
+                        .as_str(),
+                    set_tooltip: &T.running_farmer_network_space_tooltip(),
+                    #[track = "model.changed_node_synced()"]
+                    set_visible: model.node_synced,
+                },
+
+                gtk::Label {
+                    add_css_class: "error-label",
+                    set_halign: gtk::Align::Start,
+                    set_margin_start: 10,
+                    set_margin_end: 10,
+                    #[track = "model.changed_slow_auditing_farms()"]
+                    set_label: T
+                        .running_farmer_slow_auditing_warning(
+                            model
+                                .slow_auditing_farms
+                                .iter()
+                                .map(|&farm_index| model.farm_name(farm_index))
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        )
+                        .as_str(),
+                    #[track = "model.changed_slow_auditing_farms()"]
+                    set_visible: !model.slow_auditing_farms.is_empty(),
+                    set_wrap: true,
+                },
+
                 gtk::ScrolledWindow {
                     set_margin_start: 10,
                     set_margin_end: 10,
@@ -222,6 +483,103 @@ impl Component for RunningView {
                             gtk::ProgressBar {
                                 #[track = "model.farmer_state.changed_piece_cache_sync_progress()"]
                                 set_fraction: model.farmer_state.piece_cache_sync_progress as f64 / 100.0,
+                                #[track = "model.farmer_state.changed_piece_cache_sync_progress() || model.farmer_state.changed_piece_cache_capacity()"]
+                                set_text: Some(
+                                    T
+                                        .running_farmer_piece_cache_sync_pieces(
+                                            (model.farmer_state.piece_cache_capacity as f64
+                                                * f64::from(model.farmer_state.piece_cache_sync_progress)
+                                                / 100.0) as u64,
+                                            model.farmer_state.piece_cache_capacity,
+                                        )
+                                        .as_str()
+                                ),
+                                #[track = "model.farmer_state.changed_piece_cache_capacity()"]
+                                set_show_text: model.farmer_state.piece_cache_capacity > 0,
+                            },
+
+                            gtk::Label {
+                                add_css_class: "dim-label",
+                                set_halign: gtk::Align::Start,
+                                set_label: &T.running_farmer_piece_cache_sync_description(),
+                                set_wrap: true,
+                            },
+                        },
+
+                        model.rewards_view.widget().clone(),
+
+                        model.stats_view.widget().clone(),
+
+                        gtk::Box {
+                            set_spacing: 10,
+
+                            gtk::Label {
+                                add_css_class: "dim-label",
+                                set_label: &T.running_farmer_farms_sort_by(),
+                            },
+
+                            gtk::Box {
+                                add_css_class: "linked",
+
+                                #[name = "sort_by_default_button"]
+                                gtk::ToggleButton {
+                                    set_active: model.farm_sort_order == FarmSortOrder::Default,
+                                    set_cursor_from_name: Some("pointer"),
+                                    set_label: &T.running_farmer_farms_sort_by_default(),
+                                    set_tooltip: &T.running_farmer_farms_sort_by_default_tooltip(),
+                                    connect_toggled[sender] => move |button| {
+                                        if button.is_active() {
+                                            sender.input(RunningInput::FarmSortOrderChanged(FarmSortOrder::Default));
+                                        }
+                                    },
+                                },
+                                gtk::ToggleButton {
+                                    set_active: model.farm_sort_order == FarmSortOrder::Size,
+                                    set_cursor_from_name: Some("pointer"),
+                                    set_group: Some(&sort_by_default_button),
+                                    set_label: &T.running_farmer_farms_sort_by_size(),
+                                    set_tooltip: &T.running_farmer_farms_sort_by_size_tooltip(),
+                                    connect_toggled[sender] => move |button| {
+                                        if button.is_active() {
+                                            sender.input(RunningInput::FarmSortOrderChanged(FarmSortOrder::Size));
+                                        }
+                                    },
+                                },
+                                gtk::ToggleButton {
+                                    set_active: model.farm_sort_order == FarmSortOrder::Path,
+                                    set_cursor_from_name: Some("pointer"),
+                                    set_group: Some(&sort_by_default_button),
+                                    set_label: &T.running_farmer_farms_sort_by_path(),
+                                    connect_toggled[sender] => move |button| {
+                                        if button.is_active() {
+                                            sender.input(RunningInput::FarmSortOrderChanged(FarmSortOrder::Path));
+                                        }
+                                    },
+                                },
+                                gtk::ToggleButton {
+                                    set_active: model.farm_sort_order == FarmSortOrder::Status,
+                                    set_cursor_from_name: Some("pointer"),
+                                    set_group: Some(&sort_by_default_button),
+                                    set_label: &T.running_farmer_farms_sort_by_status(),
+                                    set_tooltip: &T.running_farmer_farms_sort_by_status_tooltip(),
+                                    connect_toggled[sender] => move |button| {
+                                        if button.is_active() {
+                                            sender.input(RunningInput::FarmSortOrderChanged(FarmSortOrder::Status));
+                                        }
+                                    },
+                                },
+                            },
+
+                            gtk::ToggleButton {
+                                set_active: model.compact_farms,
+                                set_cursor_from_name: Some("pointer"),
+                                set_halign: gtk::Align::End,
+                                set_hexpand: true,
+                                set_label: &T.running_farmer_farms_compact_view(),
+                                set_tooltip: &T.running_farmer_farms_compact_view_tooltip(),
+                                connect_toggled[sender] => move |button| {
+                                    sender.input(RunningInput::CompactFarmsChanged(button.is_active()));
+                                },
                             },
                         },
 
@@ -229,6 +587,20 @@ impl Component for RunningView {
                         farms_box -> gtk::Box {
                             set_orientation: gtk::Orientation::Vertical,
                             set_spacing: 10,
+                            #[track = "model.changed_compact_farms()"]
+                            set_visible: !model.compact_farms,
+                        },
+
+                        #[local_ref]
+                        farm_tiles_box -> gtk::FlowBox {
+                            set_column_spacing: 10,
+                            set_homogeneous: true,
+                            set_max_children_per_line: 32,
+                            set_row_spacing: 10,
+                            set_selection_mode: gtk::SelectionMode::None,
+                            set_valign: gtk::Align::Start,
+                            #[track = "model.changed_compact_farms()"]
+                            set_visible: model.compact_farms,
                         },
                     },
                 },
@@ -239,12 +611,81 @@ impl Component for RunningView {
     fn init(
         init: Self::Init,
         _root: Self::Root,
-        _sender: ComponentSender<Self>,
+        sender: ComponentSender<Self>,
     ) -> ComponentParts<Self> {
         let node_view = NodeView::builder().launch(()).detach();
+        let reward_address_copy_button = CopyButton::builder()
+            .launch(CopyButtonInit {
+                tooltip: T.running_farmer_button_copy_reward_address().to_string(),
+                value: String::new(),
+            })
+            .detach();
+        let rewards_view = RewardsView::builder().launch(()).detach();
+        let stats_view = StatsView::builder().launch(()).detach();
         let farms = FactoryHashMap::builder()
             .launch(gtk::Box::default())
+            .forward(sender.input_sender(), |output| match output {
+                FarmWidgetOutput::SetDisabled {
+                    farm_index,
+                    path,
+                    disabled,
+                    known_to_farmer,
+                } => RunningInput::SetFarmDisabled {
+                    farm_index,
+                    path,
+                    disabled,
+                    known_to_farmer,
+                },
+                FarmWidgetOutput::SlowAuditing { farm_index, slow } => {
+                    RunningInput::SlowAuditing { farm_index, slow }
+                }
+                FarmWidgetOutput::Restart { farm_index } => {
+                    RunningInput::RestartFarm { farm_index }
+                }
+                FarmWidgetOutput::Resize {
+                    farm_index,
+                    path,
+                    size,
+                    previous_allocated_space,
+                    allocated_space,
+                } => RunningInput::ResizeFarm {
+                    farm_index,
+                    path,
+                    size,
+                    previous_allocated_space,
+                    allocated_space,
+                },
+                FarmWidgetOutput::SummaryChanged {
+                    farm_index,
+                    summary,
+                } => RunningInput::FarmSummaryChanged {
+                    farm_index,
+                    summary,
+                },
+            });
+
+        let farm_tile_positions = Rc::<RefCell<HashMap<u8, usize>>>::default();
+        let farm_tiles = FactoryHashMap::builder()
+            .launch(gtk::FlowBox::default())
             .detach();
+        farm_tiles.widget().set_sort_func({
+            let farm_tile_positions = Rc::clone(&farm_tile_positions);
+
+            move |a, b| {
+                // Farm tiles are named after farm index
+                let position = |child: &gtk::FlowBoxChild| {
+                    child
+                        .child()
+                        .and_then(|farm_tile| farm_tile.widget_name().parse::<u8>().ok())
+                        .and_then(|farm_index| {
+                            farm_tile_positions.borrow().get(&farm_index).copied()
+                        })
+                        .unwrap_or(usize::MAX)
+                };
+
+                position(a).cmp(&position(b)).into()
+            }
+        });
 
         let reward_eta_progress_circle = ProgressCircle::builder()
             .launch(ProgressCircleInit {
@@ -260,6 +701,7 @@ impl Component for RunningView {
                 initial_reward_address_balance: 0,
                 reward_address_balance: 0,
                 piece_cache_sync_progress: 0.0,
+                piece_cache_capacity: 0,
                 reward_address_url: String::new(),
                 token_symbol: String::new(),
                 local_space_pledged: 0,
@@ -273,12 +715,32 @@ impl Component for RunningView {
                 reward_eta_progress_circle,
                 tracker: u16::MAX,
             },
+            reward_address_copy_button,
+            rewards_view,
+            stats_view,
             farms,
+            farm_tiles,
+            farm_tile_positions,
+            enabled_farms: 0,
+            farm_names: HashMap::new(),
+            reward_notifications: RewardNotifications::default(),
+            initial_plotting_started: None,
+            plotting_notifications: false,
+            failed_farms: BTreeSet::new(),
+            pending_farm_resizes: HashMap::new(),
+            slow_auditing_farms: BTreeSet::new(),
+            node_has_peers: None,
+            farm_health_notifications: false,
+            status: None,
             plotting_paused: init.plotting_paused,
+            farming_paused: init.farming_paused,
+            farm_sort_order: init.farm_sort_order,
+            compact_farms: init.compact_farms,
             tracker: u8::MAX,
         };
 
         let farms_box = model.farms.widget();
+        let farm_tiles_box = model.farm_tiles.widget();
         let widgets = view_output!();
 
         ComponentParts { model, widgets }
@@ -289,23 +751,35 @@ impl Component for RunningView {
         self.reset();
         self.farmer_state.reset();
 
-        self.process_input(input, sender);
+        self.process_input(input, &sender);
+        self.update_status(&sender);
     }
 }
 
 impl RunningView {
-    fn process_input(&mut self, input: RunningInput, sender: ComponentSender<Self>) {
+    fn process_input(&mut self, input: RunningInput, sender: &ComponentSender<Self>) {
         match input {
             RunningInput::Initialize {
                 best_block_number,
                 reward_address_balance,
                 initial_farm_states,
+                piece_cache_capacity,
                 cache_percentage,
                 config,
                 raw_config,
                 chain_info,
                 chain_constants,
+                peer_id,
             } => {
+                // Might be initialized again after node and farmer were restarted, in which case
+                // farms from previous configuration need to be removed first
+                self.farms.clear();
+                self.farm_tiles.clear();
+                self.failed_farms.clear();
+                self.pending_farm_resizes.clear();
+                self.get_mut_slow_auditing_farms().clear();
+                self.node_has_peers = None;
+
                 for (farm_index, (initial_farm_state, farm)) in initial_farm_states
                     .iter()
                     .copied()
@@ -322,6 +796,8 @@ impl RunningView {
                             total_sectors: initial_farm_state.total_sectors_count,
                             plotted_total_sectors: initial_farm_state.plotted_sectors_count,
                             plotting_paused: self.plotting_paused,
+                            farming_paused: self.farming_paused,
+                            disabled: false,
                             slot_duration: chain_constants.slot_duration().as_duration(),
                             block_authoring_delay: chain_constants.slot_duration().as_duration()
                                 * u64::from(chain_constants.block_authoring_delay()) as u32,
@@ -329,6 +805,72 @@ impl RunningView {
                     );
                 }
 
+                // Disabled farms are not known to the farmer, so they are added after all enabled
+                // farms in order to not clash with farm indices of the farmer
+                let disabled_farms = raw_config.farms().iter().filter(|farm| farm.disabled);
+                for (farm_index, farm) in (initial_farm_states.len()..).zip(disabled_farms) {
+                    let Ok(farm_index) = u8::try_from(farm_index) else {
+                        warn!(path = %farm.path.display(), "Too many farms, disabled farm ignored");
+                        continue;
+                    };
+
+                    self.farms.insert(
+                        farm_index,
+                        FarmWidgetInit {
+                            farm: DiskFarm {
+                                directory: farm.path.clone(),
+                                allocated_space: 0,
+                                reward_address: None,
+                            },
+                            total_sectors: 0,
+                            plotted_total_sectors: 0,
+                            plotting_paused: self.plotting_paused,
+                            farming_paused: self.farming_paused,
+                            disabled: true,
+                            slot_duration: chain_constants.slot_duration().as_duration(),
+                            block_authoring_delay: chain_constants.slot_duration().as_duration()
+                                * u64::from(chain_constants.block_authoring_delay()) as u32,
+                        },
+                    );
+                }
+                self.enabled_farms = initial_farm_states.len();
+                // Farm widgets use the same indices, enabled farms come first and disabled follow
+                let farms = raw_config
+                    .farms()
+                    .iter()
+                    .filter(|farm| !farm.disabled)
+                    .chain(raw_config.farms().iter().filter(|farm| farm.disabled));
+                for (farm_index, farm) in (0..=u8::MAX).zip(farms) {
+                    let Some(farm_widget) = self.farms.get(&farm_index) else {
+                        continue;
+                    };
+
+                    let summary = farm_widget.summary();
+                    self.farm_tiles.insert(
+                        farm_index,
+                        FarmTileInit {
+                            name: farm.name.clone(),
+                            path: farm.path.clone(),
+                            summary,
+                        },
+                    );
+                }
+                // Enabled farms in config have the same order as farms in the farmer
+                self.farm_names = (0..)
+                    .zip(raw_config.farms().iter().filter(|farm| !farm.disabled))
+                    .map(|(farm_index, farm)| {
+                        (
+                            farm_index,
+                            farm.name
+                                .clone()
+                                .unwrap_or_else(|| farm.path.display().to_string()),
+                        )
+                    })
+                    .collect();
+                self.apply_notification_settings(&raw_config);
+                self.farms
+                    .broadcast(FarmWidgetInput::CanDisable(self.enabled_farms > 1));
+
                 self.farmer_state
                     .set_initial_reward_address_balance(reward_address_balance);
                 self.farmer_state
@@ -343,9 +885,19 @@ impl RunningView {
                         .unwrap_or(&chain_info.protocol_id),
                     raw_config.reward_address()
                 ));
+                self.reward_address_copy_button
+                    .emit(CopyButtonInput::SetValue(
+                        raw_config.reward_address().to_string(),
+                    ));
                 self.farmer_state
                     .get_mut_token_symbol()
                     .clone_from(&chain_info.token_symbol);
+                self.rewards_view.emit(RewardsInput::Initialize {
+                    token_symbol: chain_info.token_symbol.clone(),
+                });
+                self.stats_view.emit(StatsInput::Initialize {
+                    token_symbol: chain_info.token_symbol.clone(),
+                });
                 self.farmer_state.local_space_pledged =
                     config.farms.iter().map(|farm| farm.allocated_space).sum();
                 let (total_sectors_count, plotted_sectors_count) = initial_farm_states.iter().fold(
@@ -358,8 +910,13 @@ impl RunningView {
                         )
                     },
                 );
+                self.farmer_state
+                    .set_piece_cache_capacity(piece_cache_capacity);
                 self.farmer_state.sectors_total = total_sectors_count;
                 self.farmer_state.sectors_plotted = plotted_sectors_count;
+                // Tracked even with notification disabled, since it can be enabled while running
+                self.initial_plotting_started =
+                    (plotted_sectors_count < total_sectors_count).then(Instant::now);
                 self.farmer_state.cache_percentage = cache_percentage;
                 self.farmer_state.slot_probability = chain_constants.slot_probability();
                 self.farmer_state.slot_duration = chain_constants.slot_duration().as_duration();
@@ -367,7 +924,12 @@ impl RunningView {
                     best_block_number,
                     chain_info,
                     node_path: raw_config.node_path().clone(),
+                    peer_id,
                 });
+                self.sort_farms();
+            }
+            RunningInput::ConfigUpdate { raw_config } => {
+                self.apply_notification_settings(&raw_config);
             }
             RunningInput::NodeNotification(node_notification) => {
                 self.node_view
@@ -379,6 +941,14 @@ impl RunningView {
                         if self.node_synced != new_synced {
                             self.farms
                                 .broadcast(FarmWidgetInput::NodeSynced(new_synced));
+                            self.record_event(
+                                if new_synced {
+                                    EventKind::NodeSynced
+                                } else {
+                                    EventKind::NodeSyncing
+                                },
+                                sender,
+                            );
                         }
                         self.set_node_synced(new_synced);
                     }
@@ -405,6 +975,23 @@ impl RunningView {
                         if self.farmer_state.reward_address_balance
                             != imported_block.reward_address_balance
                         {
+                            // Only increases while synced are considered to be farming rewards
+                            if self.node_synced
+                                && let Some(amount) = imported_block
+                                    .reward_address_balance
+                                    .checked_sub(self.farmer_state.reward_address_balance)
+                            {
+                                self.rewards_view.emit(RewardsInput::Reward { amount });
+                                self.stats_view.emit(StatsInput::Reward { amount });
+                                self.record_event(
+                                    EventKind::Reward {
+                                        amount,
+                                        token_symbol: self.farmer_state.token_symbol.clone(),
+                                    },
+                                    sender,
+                                );
+                                self.notify_reward(amount, sender);
+                            }
                             self.farmer_state
                                 .set_reward_address_balance(imported_block.reward_address_balance);
                             self.farmer_state.last_reward_received_time = Instant::now();
@@ -419,10 +1006,30 @@ impl RunningView {
                         );
                         let network_space_pledged =
                             u128::from(network_space_pledged_sectors) * 2 * Piece::SIZE as u128;
-                        self.farmer_state.network_space_pledged = network_space_pledged;
+                        self.farmer_state
+                            .set_network_space_pledged(network_space_pledged);
 
                         self.update_reward_eta_progress(imported_block.voting_solution_range);
                     }
+                    NodeNotification::PeersUpdate(peers) => {
+                        // Displayed by node view, only connectivity changes are recorded here
+                        let has_peers = !peers.is_empty();
+                        if let Some(had_peers) = self.node_has_peers.replace(has_peers)
+                            && had_peers != has_peers
+                        {
+                            self.record_event(
+                                if has_peers {
+                                    EventKind::NodeReconnected
+                                } else {
+                                    EventKind::NodeDisconnected
+                                },
+                                sender,
+                            );
+                        }
+                    }
+                    NodeNotification::ClockOffset(_clock_offset) => {
+                        // Handled by node view
+                    }
                 }
             }
             RunningInput::FarmerNotification(farmer_notification) => match farmer_notification {
@@ -438,7 +1045,18 @@ impl RunningView {
                             ..
                         })
                     ) {
-                        self.farmer_state.sectors_plotted += 1;
+                        *self.farmer_state.get_mut_sectors_plotted() += 1;
+
+                        if self.farmer_state.sectors_plotted == self.farmer_state.sectors_total
+                            && let Some(initial_plotting_started) =
+                                self.initial_plotting_started.take()
+                            && self.plotting_notifications
+                        {
+                            self.notify_initial_plotting_finished(
+                                initial_plotting_started.elapsed(),
+                                sender,
+                            );
+                        }
                     }
                     self.farms.send(
                         &farm_index,
@@ -453,27 +1071,32 @@ impl RunningView {
                     notification,
                 } => {
                     if let FarmingNotification::Proving(proving_details) = &notification {
-                        let mut notification = Notification::new();
                         match proving_details.result {
                             ProvingResult::Success => {
-                                notification
-                                    .summary(&T.notification_signed_reward_successfully())
-                                    .body(&T.notification_signed_reward_successfully_body());
+                                // Notification is shown once reward is actually received
+                                self.reward_notifications
+                                    .last_rewarded_farm
+                                    .replace(farm_index);
                             }
                             ProvingResult::Timeout
                             | ProvingResult::Rejected
                             | ProvingResult::Failed => {
-                                notification
-                                    .summary(&T.notification_missed_reward())
-                                    .body(&T.notification_missed_reward_body());
-                            }
-                        }
+                                if self.reward_notifications.enabled {
+                                    let mut notification = Notification::new();
+                                    notification
+                                        .summary(&T.notification_missed_reward())
+                                        .body(&T.notification_missed_reward_body());
 
-                        sender.spawn_command(move |_sender| {
-                            if let Err(error) = notification.with_typical_options().show() {
-                                warn!(%error, "Failed to show desktop notification");
+                                    sender.spawn_command(move |_sender| {
+                                        if let Err(error) =
+                                            notification.with_typical_options().show()
+                                        {
+                                            warn!(%error, "Failed to show desktop notification");
+                                        }
+                                    });
+                                }
                             }
-                        });
+                        }
                     }
                     self.farms.send(
                         &farm_index,
@@ -484,15 +1107,84 @@ impl RunningView {
                     self.farmer_state.set_piece_cache_sync_progress(progress);
                 }
                 FarmerNotification::FarmError { farm_index, error } => {
+                    self.record_event(
+                        EventKind::FarmError {
+                            farm: self.farm_name(farm_index),
+                            error: error.to_string(),
+                        },
+                        sender,
+                    );
+                    self.failed_farms.insert(farm_index);
+                    // Farm failed to open with a larger size, previous size is kept
+                    self.pending_farm_resizes.remove(&farm_index);
                     self.farms
                         .send(&farm_index, FarmWidgetInput::Error { error });
                 }
+                FarmerNotification::FarmResized {
+                    farm_index,
+                    previous_total_sectors_count,
+                    total_sectors_count,
+                } => {
+                    let sectors_total = self.farmer_state.get_mut_sectors_total();
+                    *sectors_total = *sectors_total - u32::from(previous_total_sectors_count)
+                        + u32::from(total_sectors_count);
+
+                    let pending_farm_resize = self.pending_farm_resizes.remove(&farm_index);
+                    self.farms.send(
+                        &farm_index,
+                        FarmWidgetInput::Resized {
+                            total_sectors: total_sectors_count,
+                            allocated_space: pending_farm_resize
+                                .as_ref()
+                                .map(|pending_farm_resize| pending_farm_resize.allocated_space),
+                        },
+                    );
+
+                    if let Some(PendingFarmResize {
+                        path,
+                        size,
+                        previous_allocated_space,
+                        allocated_space,
+                    }) = pending_farm_resize
+                    {
+                        let local_space_pledged = self.farmer_state.get_mut_local_space_pledged();
+                        *local_space_pledged =
+                            *local_space_pledged - previous_allocated_space + allocated_space;
+                        if self.farm_sort_order == FarmSortOrder::Size {
+                            self.sort_farms();
+                        }
+
+                        if sender
+                            .output(RunningOutput::FarmResized { path, size })
+                            .is_err()
+                        {
+                            debug!("Failed to send RunningOutput::FarmResized");
+                        }
+                    }
+                }
+                FarmerNotification::PauseChanged {
+                    plotting_paused,
+                    farming_paused,
+                } => {
+                    // Pausing might have been requested through control API rather than UI, in
+                    // which case UI needs to catch up
+                    if self.plotting_paused != plotting_paused {
+                        self.set_plotting_paused(plotting_paused);
+                        self.farms
+                            .broadcast(FarmWidgetInput::PausePlotting(plotting_paused));
+                    }
+                    if self.farming_paused != farming_paused {
+                        self.set_farming_paused(farming_paused);
+                        self.farms
+                            .broadcast(FarmWidgetInput::PauseFarming(farming_paused));
+                    }
+                }
             },
             RunningInput::ToggleFarmDetails => {
                 self.farms.broadcast(FarmWidgetInput::ToggleFarmDetails);
             }
             RunningInput::TogglePausePlotting => {
-                self.plotting_paused = !self.plotting_paused;
+                self.set_plotting_paused(!self.plotting_paused);
                 self.farms
                     .broadcast(FarmWidgetInput::PausePlotting(self.plotting_paused));
                 if sender
@@ -502,6 +1194,152 @@ impl RunningView {
                     debug!("Failed to send RunningOutput::TogglePausePlotting");
                 }
             }
+            RunningInput::TogglePauseFarming => {
+                self.set_farming_paused(!self.farming_paused);
+                self.farms
+                    .broadcast(FarmWidgetInput::PauseFarming(self.farming_paused));
+                if sender
+                    .output(RunningOutput::PauseFarming(self.farming_paused))
+                    .is_err()
+                {
+                    debug!("Failed to send RunningOutput::PauseFarming");
+                }
+            }
+            RunningInput::SetFarmDisabled {
+                farm_index,
+                path,
+                disabled,
+                known_to_farmer,
+            } => {
+                if disabled {
+                    self.enabled_farms -= 1;
+                    // Disabled farm is not auditing anymore
+                    self.get_mut_slow_auditing_farms().remove(&farm_index);
+                } else {
+                    self.enabled_farms += 1;
+                }
+                self.farms
+                    .broadcast(FarmWidgetInput::CanDisable(self.enabled_farms > 1));
+                if sender
+                    .output(RunningOutput::SetFarmDisabled {
+                        farm_index,
+                        path,
+                        disabled,
+                        known_to_farmer,
+                    })
+                    .is_err()
+                {
+                    debug!("Failed to send RunningOutput::SetFarmDisabled");
+                }
+            }
+            RunningInput::FarmSummaryChanged {
+                farm_index,
+                summary,
+            } => {
+                let Some(previous_summary) = self
+                    .farm_tiles
+                    .get(&farm_index)
+                    .map(|farm_tile| farm_tile.summary().clone())
+                else {
+                    return;
+                };
+                let status_changed = previous_summary.status != summary.status;
+                if previous_summary.plotting != summary.plotting {
+                    let farm = self.farm_name(farm_index);
+                    self.record_event(
+                        if summary.plotting {
+                            EventKind::PlottingStarted { farm }
+                        } else {
+                            EventKind::PlottingFinished { farm }
+                        },
+                        sender,
+                    );
+                }
+                self.farm_tiles
+                    .send(&farm_index, FarmTileInput::SummaryChanged(summary));
+                if status_changed && self.farm_sort_order == FarmSortOrder::Status {
+                    self.sort_farms();
+                }
+            }
+            RunningInput::FarmSortOrderChanged(farm_sort_order) => {
+                if self.farm_sort_order == farm_sort_order {
+                    return;
+                }
+
+                self.farm_sort_order = farm_sort_order;
+                self.sort_farms();
+
+                sender.oneshot_command(async move {
+                    if let Err(error) = UiState::update(|ui_state| {
+                        ui_state.farm_sort_order = farm_sort_order;
+                    })
+                    .await
+                    {
+                        warn!(%error, "Failed to save UI state");
+                    }
+                });
+            }
+            RunningInput::CompactFarmsChanged(compact_farms) => {
+                if self.compact_farms == compact_farms {
+                    return;
+                }
+
+                self.set_compact_farms(compact_farms);
+
+                sender.oneshot_command(async move {
+                    if let Err(error) = UiState::update(|ui_state| {
+                        ui_state.compact_farms = compact_farms;
+                    })
+                    .await
+                    {
+                        warn!(%error, "Failed to save UI state");
+                    }
+                });
+            }
+            RunningInput::SlowAuditing { farm_index, slow } => {
+                if slow {
+                    self.get_mut_slow_auditing_farms().insert(farm_index);
+                    self.notify_slow_auditing(farm_index, sender);
+                } else {
+                    self.get_mut_slow_auditing_farms().remove(&farm_index);
+                }
+            }
+            RunningInput::RestartFarm { farm_index } => {
+                self.failed_farms.remove(&farm_index);
+                if sender
+                    .output(RunningOutput::RestartFarm { farm_index })
+                    .is_err()
+                {
+                    debug!("Failed to send RunningOutput::RestartFarm");
+                }
+            }
+            RunningInput::ResizeFarm {
+                farm_index,
+                path,
+                size,
+                previous_allocated_space,
+                allocated_space,
+            } => {
+                // Applied once farmer confirms resize
+                self.pending_farm_resizes.insert(
+                    farm_index,
+                    PendingFarmResize {
+                        path,
+                        size,
+                        previous_allocated_space,
+                        allocated_space,
+                    },
+                );
+                if sender
+                    .output(RunningOutput::ResizeFarm {
+                        farm_index,
+                        allocated_space,
+                    })
+                    .is_err()
+                {
+                    debug!("Failed to send RunningOutput::ResizeFarm");
+                }
+            }
             RunningInput::OpenRewardAddressInExplorer => {
                 if let Err(error) = open::that_detached(&self.farmer_state.reward_address_url) {
                     error!(%error, "Failed to open explorer in default browser");
@@ -510,6 +1348,189 @@ impl RunningView {
         }
     }
 
+    fn update_status(&mut self, sender: &ComponentSender<Self>) {
+        let status = if !self.failed_farms.is_empty() {
+            RunningStatus::Error
+        } else if self.farming_paused {
+            RunningStatus::Paused
+        } else if !self.node_synced {
+            RunningStatus::Syncing
+        } else if self.farmer_state.sectors_plotted < self.farmer_state.sectors_total {
+            RunningStatus::Plotting
+        } else {
+            RunningStatus::Farming
+        };
+
+        if self.status.replace(status) != Some(status)
+            && sender.output(RunningOutput::StatusChanged(status)).is_err()
+        {
+            debug!("Failed to send RunningOutput::StatusChanged");
+        }
+    }
+
+    /// Reorder farm widgets according to current sort order
+    fn sort_farms(&self) {
+        let farms_box = self.farms.widget();
+
+        let mut farm_widgets = Vec::new();
+        let mut maybe_child = farms_box.first_child();
+        while let Some(child) = maybe_child {
+            maybe_child = child.next_sibling();
+
+            // Farm widgets are named after farm index
+            if let Ok(farm_index) = child.widget_name().parse::<u8>()
+                && let Some(farm) = self.farms.get(&farm_index)
+            {
+                farm_widgets.push((farm_index, farm, child));
+            }
+        }
+
+        // Farm index is used as a tie-breaker, such that farms don't jump around unnecessarily
+        match self.farm_sort_order {
+            FarmSortOrder::Default => {
+                farm_widgets.sort_by_key(|(farm_index, _farm, _widget)| *farm_index);
+            }
+            FarmSortOrder::Size => {
+                farm_widgets.sort_by_key(|(farm_index, farm, _widget)| {
+                    (Reverse(farm.allocated_space()), *farm_index)
+                });
+            }
+            FarmSortOrder::Path => {
+                farm_widgets.sort_by(|(a_farm_index, a_farm, _), (b_farm_index, b_farm, _)| {
+                    (a_farm.path(), a_farm_index).cmp(&(b_farm.path(), b_farm_index))
+                });
+            }
+            FarmSortOrder::Status => {
+                farm_widgets
+                    .sort_by_key(|(farm_index, farm, _widget)| (farm.status(), *farm_index));
+            }
+        }
+
+        {
+            let mut farm_tile_positions = self.farm_tile_positions.borrow_mut();
+            farm_tile_positions.clear();
+            farm_tile_positions.extend(
+                farm_widgets
+                    .iter()
+                    .enumerate()
+                    .map(|(position, (farm_index, _farm, _widget))| (*farm_index, position)),
+            );
+        }
+        self.farm_tiles.widget().invalidate_sort();
+
+        let mut previous_widget = None::<gtk::Widget>;
+        for (_farm_index, _farm, widget) in farm_widgets {
+            farms_box.reorder_child_after(&widget, previous_widget.as_ref());
+            previous_widget.replace(widget);
+        }
+    }
+
+    fn record_event(&self, event_kind: EventKind, sender: &ComponentSender<Self>) {
+        if sender.output(RunningOutput::Event(event_kind)).is_err() {
+            debug!("Failed to send RunningOutput::Event");
+        }
+    }
+
+    fn apply_notification_settings(&mut self, raw_config: &RawConfig) {
+        self.reward_notifications.enabled = !raw_config.disable_reward_notifications();
+        self.plotting_notifications = !raw_config.disable_plotting_notifications();
+        self.farm_health_notifications = !raw_config.disable_farm_health_notifications();
+    }
+
+    fn notify_initial_plotting_finished(
+        &self,
+        time_taken: Duration,
+        sender: &ComponentSender<Self>,
+    ) {
+        let mut notification = Notification::new();
+        notification
+            .summary(&T.notification_initial_plotting_finished())
+            .body(
+                T.notification_initial_plotting_finished_body(
+                    ByteSize::b(self.farmer_state.local_space_pledged).to_string_as(true),
+                    time_taken.as_secs_f32() / 3600.0,
+                )
+                .as_str(),
+            );
+
+        sender.spawn_command(move |_sender| {
+            if let Err(error) = notification.with_typical_options().show() {
+                warn!(%error, "Failed to show desktop notification");
+            }
+        });
+    }
+
+    fn farm_name(&self, farm_index: u8) -> String {
+        self.farm_names
+            .get(&farm_index)
+            .cloned()
+            .unwrap_or_else(|| T.notification_reward_received_unknown_farm().to_string())
+    }
+
+    fn notify_slow_auditing(&self, farm_index: u8, sender: &ComponentSender<Self>) {
+        if !self.farm_health_notifications {
+            return;
+        }
+
+        let mut notification = Notification::new();
+        notification.summary(&T.notification_slow_auditing()).body(
+            T.notification_slow_auditing_body(self.farm_name(farm_index))
+                .as_str(),
+        );
+        #[cfg(all(unix, not(target_os = "macos")))]
+        notification.urgency(notify_rust::Urgency::Critical);
+
+        sender.spawn_command(move |_sender| {
+            if let Err(error) = notification.with_typical_options().show() {
+                warn!(%error, "Failed to show desktop notification");
+            }
+        });
+    }
+
+    fn notify_reward(&mut self, amount: Balance, sender: &ComponentSender<Self>) {
+        let reward_notifications = &mut self.reward_notifications;
+        if !reward_notifications.enabled {
+            return;
+        }
+
+        reward_notifications.pending.0 += amount;
+        reward_notifications.pending.1 += 1;
+
+        if let Some(last_shown) = reward_notifications.last_shown
+            && last_shown.elapsed() < REWARD_NOTIFICATION_INTERVAL
+        {
+            return;
+        }
+        reward_notifications.last_shown.replace(Instant::now());
+
+        let (amount, rewards_count) = std::mem::take(&mut reward_notifications.pending);
+        let farm = reward_notifications
+            .last_rewarded_farm
+            .take()
+            .and_then(|farm_index| self.farm_names.get(&farm_index).cloned())
+            .unwrap_or_else(|| T.notification_reward_received_unknown_farm().to_string());
+        let amount = (amount / (SSC / 100)) as f32 / 100.0;
+
+        let mut notification = Notification::new();
+        notification
+            .summary(
+                T.notification_reward_received(amount, &self.farmer_state.token_symbol)
+                    .as_str(),
+            )
+            .body(
+                T.notification_reward_received_body(rewards_count, farm)
+                    .as_str(),
+            );
+
+        // Notification server is responsible for "do not disturb" mode, notifications are
+        // suppressed by it when enabled
+        sender.spawn_command(move |_sender| {
+            if let Err(error) = notification.with_typical_options().show() {
+                warn!(%error, "Failed to show desktop notification");
+            }
+        });
+    }
+
     fn update_reward_eta_progress(&self, voting_solution_range: SolutionRange) {
         // Space pledged derived from voting solution range is not real, but it is useful to
         // identify reward ETA because it is wider than regular solution range and will result
@@ -526,15 +1547,7 @@ impl RunningView {
         let network_voting_space_pledged =
             u128::from(network_voting_space_pledged_sectors) * 2 * Piece::SIZE as u128;
 
-        // Take into consideration how much space was plotted so far
-        let local_space_pledged = if self.farmer_state.sectors_total == 0 {
-            0
-        } else {
-            self.farmer_state.local_space_pledged * u64::from(self.farmer_state.sectors_plotted)
-                / u64::from(self.farmer_state.sectors_total)
-                * u64::from(self.farmer_state.cache_percentage.get())
-                / 100
-        };
+        let local_space_pledged = self.farmer_state.plotted_space_pledged();
 
         // network_voting_space_pledged/local_space_pledged is a time multiplier based on how much
         // smaller space pledged is comparing to network space pledged, then we also account for
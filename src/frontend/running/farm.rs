@@ -1,22 +1,28 @@
-use crate::backend::farmer::DiskFarm;
+use crate::backend::config::{FARM_SIZE_ALLOCATION_MARGIN, MIN_FARM_SIZE};
+use crate::backend::farmer::{
+    is_disk_full_error, is_farm_directory_accessible, is_farm_directory_inaccessible_error,
+    DiskFarm, FARM_DIRECTORY_CHECK_INTERVAL,
+};
 use crate::frontend::translations::{AsDefaultStr, T};
 use crate::frontend::NotificationExt;
 use bytesize::ByteSize;
 use gtk::prelude::*;
 use notify_rust::Notification;
 use relm4::prelude::*;
+use relm4::{Sender, ShutdownReceiver};
 use relm4_icons::icon_name;
 use simple_moving_average::{SingleSumSMA, SMA};
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{HashMap, VecDeque};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use subspace_core_primitives::SectorIndex;
 use subspace_farmer::farm::{
     FarmingError, FarmingNotification, ProvingResult, SectorExpirationDetails,
     SectorPlottingDetails, SectorUpdate,
 };
-use tracing::{error, warn};
+use tracing::{debug, error, warn};
 
 const INVALID_SCORE_VALUE: f64 = -1.0;
 /// Experimentally found number that is good for default window size to not have horizontal scroll
@@ -31,6 +37,28 @@ const PROVING_TIME_TRACKING_WINDOW: usize = 10;
 const EXCELLENT_PROVING_TIME: Duration = Duration::from_millis(1800);
 /// Number of samples over which to track sector plotting time
 const SECTOR_PLOTTING_TIME_TRACKING_WINDOW: usize = 10;
+/// Number of intervals between plotted sectors over which to track plotting throughput, large
+/// enough to smooth out fluctuations
+const PLOTTING_THROUGHPUT_TRACKING_WINDOW: usize = 20;
+/// Minimum number of throughput samples before plotting ETA is shown
+const MIN_PLOTTING_ETA_SAMPLES: usize = 3;
+/// Number of samples over which auditing time percentiles are calculated, 10 minutes in slots
+const AUDITING_LATENCY_WINDOW: usize = 600;
+/// Number of samples over which proving time percentiles are calculated, proving happens much less
+/// frequently than auditing
+const PROVING_LATENCY_WINDOW: usize = 100;
+/// Number of consecutive audits exceeding slot duration after which farm is reported as auditing
+/// too slowly
+const SLOW_AUDITING_ALERT_THRESHOLD: usize = 10;
+/// Number of consecutive audits within slot duration after which farm is no longer reported as
+/// auditing too slowly
+const SLOW_AUDITING_RECOVERY_THRESHOLD: usize = 60;
+/// Latency percentiles are rounded to this precision to avoid updating UI on insignificant changes
+const LATENCY_PRECISION: Duration = Duration::from_millis(10);
+/// Interval at which I/O counters of the disk farm is located on are sampled
+const DISK_IO_SAMPLE_INTERVAL: Duration = Duration::from_secs(2);
+/// Number of samples over which disk I/O is smoothed, 10 seconds
+const DISK_IO_TRACKING_WINDOW: usize = 5;
 
 fn format_size(bytes: u64) -> String {
     ByteSize::b(bytes).to_string_as(bytes % ByteSize::mb(1).as_u64() != 0)
@@ -52,6 +80,111 @@ enum PlottingState {
     Idle,
 }
 
+/// Smoothed I/O of the disk (partition) farm is located on, includes I/O of anything else located
+/// on the same partition
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub(super) struct DiskIo {
+    read_bytes_per_second: u64,
+    write_bytes_per_second: u64,
+    operations_per_second: u64,
+}
+
+/// Cumulative I/O counters of the disk (partition) since boot
+#[derive(Debug, Copy, Clone)]
+struct DiskIoCounters {
+    read_bytes: u64,
+    write_bytes: u64,
+    operations: u64,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct LatencyPercentiles {
+    p50: Duration,
+    p95: Duration,
+    max: Duration,
+}
+
+/// Rolling window of latency samples, old samples are evicted so that transient spikes don't skew
+/// percentiles permanently
+#[derive(Debug)]
+struct LatencyWindow {
+    samples: VecDeque<Duration>,
+    capacity: usize,
+}
+
+impl LatencyWindow {
+    fn new(capacity: usize) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    fn add_sample(&mut self, sample: Duration) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    fn percentiles(&self) -> Option<LatencyPercentiles> {
+        if self.samples.is_empty() {
+            return None;
+        }
+
+        let mut samples = Vec::from_iter(self.samples.iter().copied());
+        samples.sort_unstable();
+        let percentile = |percentile: f64| {
+            let index = ((samples.len() - 1) as f64 * percentile).round() as usize;
+            let sample = samples[index];
+            // Round to configured precision
+            LATENCY_PRECISION * (sample.as_nanos() / LATENCY_PRECISION.as_nanos()) as u32
+        };
+
+        Some(LatencyPercentiles {
+            p50: percentile(0.5),
+            p95: percentile(0.95),
+            max: percentile(1.0),
+        })
+    }
+}
+
+/// Current status of the farm, ordered such that farms that need attention come first
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub(super) enum FarmStatus {
+    Error,
+    Plotting,
+    Farming,
+    /// Plotting or farming was paused
+    Paused,
+    /// Disabled or not started yet
+    Stopped,
+}
+
+impl FarmStatus {
+    /// Used both as CSS class and as a selector in translations
+    pub(super) fn as_str(&self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Plotting => "plotting",
+            Self::Farming => "farming",
+            Self::Paused => "paused",
+            Self::Stopped => "stopped",
+        }
+    }
+}
+
+/// Summary of the farm state that is shown on the farm tile
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub(super) struct FarmSummary {
+    pub(super) status: FarmStatus,
+    pub(super) size: String,
+    pub(super) plotted_sectors: SectorIndex,
+    pub(super) total_sectors: SectorIndex,
+    /// Whether sectors are being plotted or replotted, even if paused
+    pub(super) plotting: bool,
+}
+
 #[derive(Debug)]
 enum SectorState {
     Plotted,
@@ -81,6 +214,8 @@ pub(super) struct FarmWidgetInit {
     pub(super) total_sectors: SectorIndex,
     pub(super) plotted_total_sectors: SectorIndex,
     pub(super) plotting_paused: bool,
+    pub(super) farming_paused: bool,
+    pub(super) disabled: bool,
     pub(super) slot_duration: Duration,
     pub(super) block_authoring_delay: Duration,
 }
@@ -93,20 +228,78 @@ pub(super) enum FarmWidgetInput {
     },
     FarmingNotification(FarmingNotification),
     PausePlotting(bool),
+    PauseFarming(bool),
     OpenFarmFolder,
     NodeSynced(bool),
     ToggleFarmDetails,
+    SetDisabled(bool),
+    /// Whether farm can be disabled, the last enabled farm can't be
+    CanDisable(bool),
     Error {
         error: Arc<anyhow::Error>,
     },
+    /// Start farm again after it stopped with an error
+    Restart,
+    ResizeSizeChanged(String),
+    Resize,
+    /// Farm was reopened by farmer with a larger size
+    Resized {
+        total_sectors: SectorIndex,
+        /// Allocated space if resize was requested from UI
+        allocated_space: Option<u64>,
+    },
+}
+
+#[derive(Debug)]
+pub(super) enum FarmWidgetOutput {
+    SetDisabled {
+        farm_index: u8,
+        path: PathBuf,
+        disabled: bool,
+        known_to_farmer: bool,
+    },
+    /// Farm started or stopped consistently auditing slower than slot duration
+    SlowAuditing { farm_index: u8, slow: bool },
+    /// Farm that stopped with an error needs to be started again
+    Restart { farm_index: u8 },
+    /// Farm needs to be reopened with a larger size
+    Resize {
+        farm_index: u8,
+        path: PathBuf,
+        /// Size as entered by user, stored in configuration as is
+        size: String,
+        previous_allocated_space: u64,
+        allocated_space: u64,
+    },
+    /// Farm status, size or plotting progress changed
+    SummaryChanged {
+        farm_index: u8,
+        summary: FarmSummary,
+    },
+}
+
+#[derive(Debug)]
+pub(super) enum FarmWidgetCommandOutput {
+    /// Directory of the farm that became inaccessible is accessible again
+    DirectoryAccessible,
+    DiskIo(DiskIo),
 }
 
 #[tracker::track]
 #[derive(Debug)]
 pub(super) struct FarmWidget {
+    #[do_not_track]
+    index: u8,
     path: PathBuf,
     size: String,
     #[do_not_track]
+    allocated_space: u64,
+    /// Size entered in resize popover
+    #[do_not_track]
+    resize_size: String,
+    /// Validated size entered in resize popover, error message is empty when nothing was entered yet
+    resize_allocated_space: Result<u64, String>,
+    #[do_not_track]
     auditing_time: SingleSumSMA<Duration, u32, AUDITING_TIME_TRACKING_WINDOW>,
     auditing_time_average: Duration,
     /// 0.0..=1.0
@@ -118,9 +311,31 @@ pub(super) struct FarmWidget {
     proving_time_score: f64,
     /// (success, total)
     proving_result: (u64, u64),
+    #[do_not_track]
+    auditing_latency_window: LatencyWindow,
+    auditing_latency: Option<LatencyPercentiles>,
+    #[do_not_track]
+    proving_latency_window: LatencyWindow,
+    proving_latency: Option<LatencyPercentiles>,
+    /// Whether farm was reported as auditing too slowly
+    #[do_not_track]
+    slow_auditing: bool,
+    /// Number of consecutive audits that contradict `slow_auditing`
+    #[do_not_track]
+    slow_auditing_streak: usize,
     #[no_eq]
     sector_plotting_time: SingleSumSMA<Duration, u32, SECTOR_PLOTTING_TIME_TRACKING_WINDOW>,
     last_sector_plotted: Option<SectorIndex>,
+    /// Time between consecutive sectors finishing plotting, unlike sector plotting time accounts
+    /// for sectors being plotted concurrently
+    #[no_eq]
+    sector_finish_interval: SingleSumSMA<Duration, u32, PLOTTING_THROUGHPUT_TRACKING_WINDOW>,
+    #[do_not_track]
+    last_sector_finished: Option<Instant>,
+    /// Number of sectors that were plotted at least once
+    plotted_sectors: SectorIndex,
+    #[do_not_track]
+    total_sectors: SectorIndex,
     plotting_state: PlottingState,
     is_node_synced: bool,
     sector_rows: gtk::Box,
@@ -130,18 +345,34 @@ pub(super) struct FarmWidget {
     farm_details: bool,
     encoding_sectors: usize,
     plotting_paused: bool,
+    farming_paused: bool,
+    disabled: bool,
+    /// Whether farm is running in the farmer
+    running: bool,
+    /// Farms that were disabled on startup are not known to the farmer and can't be started until
+    /// restart
+    #[do_not_track]
+    known_to_farmer: bool,
+    can_disable: bool,
     slot_duration: Duration,
     block_authoring_delay: Duration,
     #[no_eq]
     error: Option<Arc<anyhow::Error>>,
+    /// Whether farm directory is accessible again after farm stopped due to it being inaccessible
+    directory_accessible: bool,
+    /// `None` until sampled for the first time or if not supported on this platform
+    disk_io: Option<DiskIo>,
+    /// Last summary reported to the running view
+    #[do_not_track]
+    reported_summary: Option<FarmSummary>,
 }
 
 #[relm4::factory(pub(super))]
 impl FactoryComponent for FarmWidget {
     type Init = FarmWidgetInit;
     type Input = FarmWidgetInput;
-    type Output = ();
-    type CommandOutput = ();
+    type Output = FarmWidgetOutput;
+    type CommandOutput = FarmWidgetCommandOutput;
     type ParentWidget = gtk::Box;
     type Index = u8;
 
@@ -149,6 +380,8 @@ impl FactoryComponent for FarmWidget {
         #[root]
         gtk::Box {
             set_orientation: gtk::Orientation::Vertical,
+            // Used by running view to find farm widget when sorting farms
+            set_widget_name: &self.index.to_string(),
 
             gtk::Box {
                 gtk::Button {
@@ -160,16 +393,124 @@ impl FactoryComponent for FarmWidget {
                     set_tooltip: &T.running_farmer_farm_tooltip(),
 
                     gtk::Label {
-                        #[track = "self.changed_error()"]
+                        #[track = "self.changed_error() || self.changed_disabled()"]
                         set_css_classes: if self.error.is_some() {
                             &["farm-error"]
+                        } else if self.disabled {
+                            &["farm-disabled"]
                         } else {
                             &[]
                         },
-                        set_label: &format!("{} [{}]:", self.path.display(), self.size),
+                        #[track = "self.changed_disabled() || self.changed_size()"]
+                        set_label: &if self.disabled {
+                            format!("{}:", self.path.display())
+                        } else {
+                            format!("{} [{}]:", self.path.display(), self.size)
+                        },
                     },
                 },
 
+                gtk::Switch {
+                    #[track = "self.changed_disabled()"]
+                    set_active: !self.disabled,
+                    set_cursor_from_name: Some("pointer"),
+                    set_margin_start: 10,
+                    #[track = "self.changed_disabled() || self.changed_can_disable()"]
+                    set_sensitive: self.disabled || self.can_disable,
+                    set_tooltip: &T.running_farmer_farm_enabled_tooltip(),
+                    set_valign: gtk::Align::Center,
+
+                    connect_active_notify[sender] => move |switch| {
+                        sender.input(FarmWidgetInput::SetDisabled(!switch.is_active()));
+                    },
+                },
+
+                gtk::MenuButton {
+                    set_cursor_from_name: Some("pointer"),
+                    set_has_frame: false,
+                    set_icon_name: icon_name::SIZE_HORIZONTALLY,
+                    set_margin_start: 5,
+                    #[wrap(Some)]
+                    set_popover = &gtk::Popover {
+                        gtk::Box {
+                            set_orientation: gtk::Orientation::Vertical,
+                            set_spacing: 10,
+
+                            gtk::Label {
+                                add_css_class: "heading",
+                                set_halign: gtk::Align::Start,
+                                set_label: &T.running_farmer_farm_resize_title(),
+                            },
+
+                            gtk::Box {
+                                add_css_class: "linked",
+
+                                gtk::Entry {
+                                    connect_activate[sender] => move |_entry| {
+                                        sender.input(FarmWidgetInput::Resize);
+                                    },
+                                    connect_changed[sender] => move |entry| {
+                                        sender.input(FarmWidgetInput::ResizeSizeChanged(entry.text().into()));
+                                    },
+                                    #[track = "self.changed_resize_allocated_space()"]
+                                    set_css_classes: match &self.resize_allocated_space {
+                                        Ok(_) => &["valid-input"],
+                                        Err(error) if error.is_empty() => &[],
+                                        Err(_) => &["invalid-input"],
+                                    },
+                                    set_placeholder_text: Some(
+                                        &T.running_farmer_farm_resize_placeholder(),
+                                    ),
+                                    set_primary_icon_name: Some(icon_name::SIZE_HORIZONTALLY),
+                                    set_primary_icon_activatable: false,
+                                    set_primary_icon_sensitive: false,
+                                },
+
+                                gtk::Button {
+                                    connect_clicked[sender] => move |button| {
+                                        sender.input(FarmWidgetInput::Resize);
+
+                                        if let Some(popover) = button
+                                            .ancestor(gtk::Popover::static_type())
+                                            .and_then(|widget| widget.downcast::<gtk::Popover>().ok())
+                                        {
+                                            popover.popdown();
+                                        }
+                                    },
+                                    set_cursor_from_name: Some("pointer"),
+                                    set_label: &T.running_farmer_farm_resize_apply(),
+                                    #[track = "self.changed_resize_allocated_space()"]
+                                    set_sensitive: self.resize_allocated_space.is_ok(),
+                                },
+                            },
+
+                            gtk::Label {
+                                add_css_class: "error-label",
+                                set_halign: gtk::Align::Start,
+                                #[track = "self.changed_resize_allocated_space()"]
+                                set_label: self
+                                    .resize_allocated_space
+                                    .as_ref()
+                                    .err()
+                                    .map(String::as_str)
+                                    .unwrap_or_default(),
+                                set_max_width_chars: 40,
+                                #[track = "self.changed_resize_allocated_space()"]
+                                set_visible: self
+                                    .resize_allocated_space
+                                    .as_ref()
+                                    .is_err_and(|error| !error.is_empty()),
+                                set_wrap: true,
+                                set_xalign: 0.0,
+                            },
+                        },
+                    },
+                    set_tooltip: &T.running_farmer_farm_resize_button_tooltip(),
+                    set_valign: gtk::Align::Center,
+                    #[track = "self.changed_disabled() || self.changed_running() || self.changed_error()"]
+                    set_visible: !self.disabled && self.running && self.error.is_none(),
+                },
+
                 match &self.error {
                     Some(_error) => gtk::Box {
                         add_css_class: "farm-error",
@@ -188,8 +529,8 @@ impl FactoryComponent for FarmWidget {
 
                             gtk::Box {
                                 set_spacing: 10,
-                                #[track = "self.changed_is_node_synced()"]
-                                set_visible: self.is_node_synced,
+                                #[track = "self.changed_is_node_synced() || self.changed_disabled()"]
+                                set_visible: self.is_node_synced && !self.disabled,
 
                                 gtk::Box {
                                     #[track = "self.changed_proving_result() || self.changed_auditing_time_score() || self.changed_proving_time_score()"]
@@ -270,6 +611,14 @@ impl FactoryComponent for FarmWidget {
                                     },
                                 },
 
+                                gtk::Image {
+                                    add_css_class: "error-label",
+                                    set_icon_name: Some(icon_name::WARNING),
+                                    set_tooltip: &T.running_farmer_farm_auditing_latency_slow_tooltip(),
+                                    #[track = "self.changed_auditing_latency()"]
+                                    set_visible: self.is_auditing_slow(),
+                                },
+
                                 gtk::Image {
                                     set_icon_name: Some(icon_name::WARNING),
                                     #[track = "self.changed_non_fatal_farming_error()"]
@@ -293,16 +642,63 @@ impl FactoryComponent for FarmWidget {
             },
 
             #[transition = "SlideUpDown"]
-            match (&self.error, self.plotting_state) {
-                (Some(error), _) => gtk::Box {
+            match (self.disabled, self.running, &self.error, self.plotting_state) {
+                (true, _, _, _) => gtk::Box {
+                    gtk::Label {
+                        add_css_class: "farm-disabled",
+                        set_halign: gtk::Align::Start,
+                        set_label: &T.running_farmer_farm_disabled(),
+                    }
+                },
+                (false, false, _, _) => gtk::Box {
+                    gtk::Label {
+                        add_css_class: "warning-label",
+                        set_halign: gtk::Align::Start,
+                        set_label: &T.running_farmer_farm_enabled_restart_needed(),
+                    }
+                },
+                (_, _, Some(error), _) => gtk::Box {
+                    set_spacing: 10,
+
                     gtk::Label {
                         add_css_class: "farm-error",
                         set_halign: gtk::Align::Start,
+                        set_hexpand: true,
+                        #[track = "self.changed_error() || self.changed_directory_accessible()"]
+                        set_label: &if is_farm_directory_inaccessible_error(error) {
+                            if self.directory_accessible {
+                                T.running_farmer_farm_directory_accessible().to_string()
+                            } else {
+                                T.running_farmer_farm_directory_inaccessible().to_string()
+                            }
+                        } else if is_disk_full_error(error) {
+                            T.running_farmer_farm_disk_full().to_string()
+                        } else {
+                            T.running_farmer_farm_crashed(error.to_string()).to_string()
+                        },
+                        set_wrap: true,
+                        set_xalign: 0.0,
+                    },
+
+                    gtk::Button {
+                        connect_clicked => FarmWidgetInput::Restart,
+                        set_cursor_from_name: Some("pointer"),
                         #[track = "self.changed_error()"]
-                        set_label: T.running_farmer_farm_crashed(error.to_string()).as_str(),
-                    }
+                        set_label: &if is_farm_directory_inaccessible_error(error) {
+                            T.running_farmer_farm_button_reconnect().to_string()
+                        } else {
+                            T.running_farmer_farm_button_resume().to_string()
+                        },
+                        set_tooltip: &T.running_farmer_farm_button_resume_tooltip(),
+                        set_valign: gtk::Align::Center,
+                        // Other errors are unlikely to go away without user changing something
+                        #[track = "self.changed_error() || self.changed_directory_accessible()"]
+                        set_visible: is_disk_full_error(error)
+                            || (is_farm_directory_inaccessible_error(error)
+                                && self.directory_accessible),
+                    },
                 },
-                (_, PlottingState::Plotting { kind, progress }) => gtk::Box {
+                (_, _, _, PlottingState::Plotting { kind, progress }) => gtk::Box {
                     set_orientation: gtk::Orientation::Vertical,
                     set_spacing: 10,
 
@@ -312,9 +708,9 @@ impl FactoryComponent for FarmWidget {
                         gtk::Label {
                             set_halign: gtk::Align::Start,
 
-                            #[track = "self.changed_plotting_state() || self.changed_encoding_sectors() || self.changed_plotting_paused() || self.changed_is_node_synced()"]
+                            #[track = "self.changed_plotting_state() || self.changed_encoding_sectors() || self.changed_plotting_paused() || self.changed_farming_paused() || self.changed_is_node_synced()"]
                             set_label: {
-                                let pausing_state = if self.plotting_paused {
+                                let pausing_state = if self.plotting_paused || self.farming_paused {
                                     if self.encoding_sectors > 0 {
                                         "pausing"
                                     } else {
@@ -334,7 +730,9 @@ impl FactoryComponent for FarmWidget {
                                 } else {
                                     String::new()
                                 };
-                                let farming = if self.is_node_synced {
+                                let farming = if self.farming_paused {
+                                    "paused"
+                                } else if self.is_node_synced {
                                     "yes"
                                 } else {
                                     "no"
@@ -363,15 +761,69 @@ impl FactoryComponent for FarmWidget {
                         },
                     },
 
-                    gtk::ProgressBar {
-                        #[track = "self.changed_plotting_state()"]
-                        set_fraction: progress as f64 / 100.0,
+                    gtk::Box {
+                        set_spacing: 10,
+
+                        gtk::ProgressBar {
+                            #[track = "self.changed_plotting_state()"]
+                            set_fraction: progress as f64 / 100.0,
+                            set_hexpand: true,
+                            set_show_text: true,
+                            #[track = "self.changed_plotted_sectors()"]
+                            set_text: Some(
+                                T
+                                    .running_farmer_farm_plotted_sectors(
+                                        self.plotted_sectors,
+                                        self.total_sectors,
+                                    )
+                                    .as_str()
+                            ),
+                        },
+
+                        gtk::Label {
+                            add_css_class: "dim-label",
+                            #[track = "self.changed_plotting_state() || self.changed_plotted_sectors() || self.changed_plotting_paused() || self.changed_farming_paused()"]
+                            set_label: &self.plotting_eta(kind),
+                            #[track = "self.changed_plotting_state() || self.changed_plotting_paused() || self.changed_farming_paused()"]
+                            set_visible: kind == PlottingKind::Initial
+                                && !self.plotting_paused
+                                && !self.farming_paused,
+                        },
+
+                        gtk::Label {
+                            add_css_class: "dim-label",
+                            #[track = "self.changed_disk_io()"]
+                            set_label: &self
+                                .disk_io
+                                .map(|disk_io| {
+                                    T.running_farmer_farm_disk_io(
+                                        ByteSize::b(disk_io.read_bytes_per_second).to_string_as(true),
+                                        ByteSize::b(disk_io.write_bytes_per_second).to_string_as(true),
+                                        disk_io.operations_per_second,
+                                    )
+                                    .to_string()
+                                })
+                                .unwrap_or_default(),
+                            set_tooltip: &T.running_farmer_farm_disk_io_tooltip(),
+                            #[track = "self.changed_disk_io()"]
+                            set_visible: self.disk_io.is_some(),
+                        },
                     },
                 },
-                (_, PlottingState::Idle) => gtk::Box {
+                (_, _, _, PlottingState::Idle) => gtk::Box {
                     gtk::Label {
-                        #[track = "self.changed_is_node_synced()"]
-                        set_label: &if self.is_node_synced {
+                        #[track = "self.changed_is_node_synced() || self.changed_farming_paused()"]
+                        set_css_classes: if self.farming_paused {
+                            &["warning-label"]
+                        } else if self.is_node_synced {
+                            &["success-label"]
+                        } else {
+                            &[]
+                        },
+                        #[track = "self.changed_is_node_synced() || self.changed_farming_paused()"]
+                        set_label: &if self.farming_paused {
+                            T.running_farmer_farm_farming_paused()
+                        } else if self.is_node_synced {
                             T.running_farmer_farm_farming()
                         } else {
                             T.running_farmer_farm_waiting_for_node_to_sync()
@@ -381,15 +833,73 @@ impl FactoryComponent for FarmWidget {
             },
 
             gtk::Box {
-                #[track = "self.changed_farm_details() || self.changed_error()"]
-                set_visible: self.farm_details && self.error.is_none(),
+                set_margin_bottom: 5,
+                set_spacing: 20,
+                #[track = "self.changed_farm_details() || self.changed_error() || self.changed_disabled() || self.changed_auditing_latency()"]
+                set_visible: self.farm_details
+                    && self.error.is_none()
+                    && !self.disabled
+                    && self.auditing_latency.is_some(),
+
+                gtk::Label {
+                    #[track = "self.changed_auditing_latency()"]
+                    set_css_classes: if self.is_auditing_slow() {
+                        &["error-label"]
+                    } else {
+                        &[]
+                    },
+                    #[track = "self.changed_auditing_latency()"]
+                    set_label: &self
+                        .auditing_latency
+                        .map(|latency| {
+                            T.running_farmer_farm_auditing_latency(
+                                latency.p50.as_secs_f32(),
+                                latency.p95.as_secs_f32(),
+                                latency.max.as_secs_f32(),
+                            )
+                            .as_str()
+                            .to_string()
+                        })
+                        .unwrap_or_default(),
+                    set_tooltip: T
+                        .running_farmer_farm_auditing_latency_tooltip(self.slot_duration.as_secs_f32())
+                        .as_str(),
+                },
+
+                gtk::Label {
+                    #[track = "self.changed_proving_latency()"]
+                    set_label: &self
+                        .proving_latency
+                        .map(|latency| {
+                            T.running_farmer_farm_proving_latency(
+                                latency.p50.as_secs_f32(),
+                                latency.p95.as_secs_f32(),
+                                latency.max.as_secs_f32(),
+                            )
+                            .as_str()
+                            .to_string()
+                        })
+                        .unwrap_or_default(),
+                    set_tooltip: T
+                        .running_farmer_farm_proving_latency_tooltip(
+                            self.block_authoring_delay.as_secs_f32()
+                        )
+                        .as_str(),
+                    #[track = "self.changed_proving_latency()"]
+                    set_visible: self.proving_latency.is_some(),
+                },
+            },
+
+            gtk::Box {
+                #[track = "self.changed_farm_details() || self.changed_error() || self.changed_disabled()"]
+                set_visible: self.farm_details && self.error.is_none() && !self.disabled,
 
                 self.sector_rows.clone(),
             },
         },
     }
 
-    fn init_model(init: Self::Init, _index: &Self::Index, _sender: FactorySender<Self>) -> Self {
+    fn init_model(init: Self::Init, index: &Self::Index, sender: FactorySender<Self>) -> Self {
         let mut sectors = Vec::with_capacity(usize::from(init.total_sectors));
         for sector_index in 0..init.total_sectors {
             let sector = gtk::Box::builder()
@@ -412,9 +922,19 @@ impl FactoryComponent for FarmWidget {
             }
         });
 
+        sender.command({
+            let path = init.farm.directory.clone();
+
+            move |sender, shutdown_receiver| Self::sample_disk_io(path, sender, shutdown_receiver)
+        });
+
         Self {
+            index: *index,
             path: init.farm.directory,
             size: format_size(init.farm.allocated_space),
+            allocated_space: init.farm.allocated_space,
+            resize_size: String::new(),
+            resize_allocated_space: Err(String::new()),
             auditing_time: SingleSumSMA::from_zero(Duration::ZERO),
             auditing_time_average: Duration::ZERO,
             auditing_time_score: INVALID_SCORE_VALUE,
@@ -422,8 +942,18 @@ impl FactoryComponent for FarmWidget {
             proving_time_average: Duration::ZERO,
             proving_time_score: INVALID_SCORE_VALUE,
             proving_result: (0, 0),
+            auditing_latency_window: LatencyWindow::new(AUDITING_LATENCY_WINDOW),
+            auditing_latency: None,
+            proving_latency_window: LatencyWindow::new(PROVING_LATENCY_WINDOW),
+            proving_latency: None,
+            slow_auditing: false,
+            slow_auditing_streak: 0,
             sector_plotting_time: SingleSumSMA::from_zero(Duration::ZERO),
             last_sector_plotted: None,
+            sector_finish_interval: SingleSumSMA::from_zero(Duration::ZERO),
+            last_sector_finished: None,
+            plotted_sectors: init.plotted_total_sectors,
+            total_sectors: init.total_sectors,
             plotting_state: PlottingState::Idle,
             is_node_synced: false,
             sector_rows,
@@ -432,9 +962,17 @@ impl FactoryComponent for FarmWidget {
             farm_details: false,
             encoding_sectors: 0,
             plotting_paused: init.plotting_paused,
+            farming_paused: init.farming_paused,
+            disabled: init.disabled,
+            running: !init.disabled,
+            known_to_farmer: !init.disabled,
+            can_disable: true,
             slot_duration: init.slot_duration,
             block_authoring_delay: init.block_authoring_delay,
             error: None,
+            directory_accessible: false,
+            disk_io: None,
+            reported_summary: None,
             tracker: u32::MAX,
         }
     }
@@ -443,11 +981,76 @@ impl FactoryComponent for FarmWidget {
         // Reset changes
         self.reset();
 
-        self.process_input(input, sender);
+        self.process_input(input, sender.clone());
+
+        let summary = self.summary();
+        if self.reported_summary.as_ref() != Some(&summary) {
+            self.reported_summary.replace(summary.clone());
+
+            if sender
+                .output(FarmWidgetOutput::SummaryChanged {
+                    farm_index: self.index,
+                    summary,
+                })
+                .is_err()
+            {
+                debug!("Failed to send FarmWidgetOutput::SummaryChanged");
+            }
+        }
+    }
+
+    fn update_cmd(&mut self, input: Self::CommandOutput, _sender: FactorySender<Self>) {
+        // Reset changes
+        self.reset();
+
+        match input {
+            FarmWidgetCommandOutput::DirectoryAccessible => {
+                self.set_directory_accessible(true);
+            }
+            FarmWidgetCommandOutput::DiskIo(disk_io) => {
+                self.set_disk_io(Some(disk_io));
+            }
+        }
     }
 }
 
 impl FarmWidget {
+    pub(super) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub(super) fn allocated_space(&self) -> u64 {
+        self.allocated_space
+    }
+
+    pub(super) fn status(&self) -> FarmStatus {
+        if self.disabled || !self.running {
+            FarmStatus::Stopped
+        } else if self.error.is_some() {
+            FarmStatus::Error
+        } else if matches!(self.plotting_state, PlottingState::Plotting { .. }) {
+            if self.plotting_paused || self.farming_paused {
+                FarmStatus::Paused
+            } else {
+                FarmStatus::Plotting
+            }
+        } else if self.farming_paused {
+            FarmStatus::Paused
+        } else {
+            FarmStatus::Farming
+        }
+    }
+
+    pub(super) fn summary(&self) -> FarmSummary {
+        FarmSummary {
+            status: self.status(),
+            size: self.size.clone(),
+            plotted_sectors: self.plotted_sectors,
+            total_sectors: self.total_sectors,
+            plotting: matches!(self.plotting_state, PlottingState::Plotting { .. }),
+        }
+    }
+
     fn process_input(&mut self, input: FarmWidgetInput, sender: FactorySender<Self>) {
         match input {
             FarmWidgetInput::SectorUpdate {
@@ -500,8 +1103,17 @@ impl FarmWidget {
                             self.set_plotting_state(PlottingState::Idle);
                         }
 
+                        if !self.is_sector_plotted(sector_index) {
+                            *self.get_mut_plotted_sectors() += 1;
+                        }
                         self.update_sector_state(sector_index, SectorState::Plotted);
                         self.sector_plotting_time.add_sample(time);
+                        if let Some(last_sector_finished) =
+                            self.last_sector_finished.replace(Instant::now())
+                        {
+                            self.sector_finish_interval
+                                .add_sample(last_sector_finished.elapsed());
+                        }
                     }
                     SectorPlottingDetails::Error(_error) => {
                         // TODO: treat sector as expired for now, in future with plotting retries
@@ -525,6 +1137,13 @@ impl FarmWidget {
             FarmWidgetInput::FarmingNotification(notification) => match notification {
                 FarmingNotification::Auditing(auditing_details) => {
                     self.auditing_time.add_sample(auditing_details.time);
+                    self.auditing_latency_window
+                        .add_sample(auditing_details.time);
+                    let auditing_latency = self.auditing_latency_window.percentiles();
+                    if self.auditing_latency != auditing_latency {
+                        self.set_auditing_latency(auditing_latency);
+                    }
+                    self.update_slow_auditing(auditing_details.time, &sender);
 
                     let average_time = self.auditing_time.get_average();
                     let slot_time_fraction_remaining =
@@ -544,6 +1163,11 @@ impl FarmWidget {
                 }
                 FarmingNotification::Proving(proving_details) => {
                     self.proving_time.add_sample(proving_details.time);
+                    self.proving_latency_window.add_sample(proving_details.time);
+                    let proving_latency = self.proving_latency_window.percentiles();
+                    if self.proving_latency != proving_latency {
+                        self.set_proving_latency(proving_latency);
+                    }
 
                     let average_time = self.proving_time.get_average();
                     let slot_time_fraction_remaining =
@@ -574,6 +1198,14 @@ impl FarmWidget {
             },
             FarmWidgetInput::PausePlotting(plotting_paused) => {
                 self.set_plotting_paused(plotting_paused);
+                // Time spent on pause should not affect plotting throughput
+                self.last_sector_finished.take();
+            }
+            FarmWidgetInput::PauseFarming(farming_paused) => {
+                self.set_farming_paused(farming_paused);
+                // Plotting is paused together with farming, so time spent on pause should not
+                // affect plotting throughput either
+                self.last_sector_finished.take();
             }
             FarmWidgetInput::OpenFarmFolder => {
                 if let Err(error) = open::that_detached(&self.path) {
@@ -586,13 +1218,52 @@ impl FarmWidget {
             FarmWidgetInput::ToggleFarmDetails => {
                 self.set_farm_details(!self.farm_details);
             }
+            FarmWidgetInput::SetDisabled(disabled) => {
+                if self.disabled == disabled {
+                    return;
+                }
+
+                self.set_disabled(disabled);
+                if disabled {
+                    self.set_running(false);
+                } else if self.known_to_farmer {
+                    // Farmer starts the farm again right away
+                    self.set_running(true);
+                }
+                if sender
+                    .output(FarmWidgetOutput::SetDisabled {
+                        farm_index: self.index,
+                        path: self.path.clone(),
+                        disabled,
+                        known_to_farmer: self.known_to_farmer,
+                    })
+                    .is_err()
+                {
+                    debug!("Failed to send FarmWidgetOutput::SetDisabled");
+                }
+            }
+            FarmWidgetInput::CanDisable(can_disable) => {
+                self.set_can_disable(can_disable);
+            }
             FarmWidgetInput::Error { error } => {
-                sender.spawn_command(|_sender| {
+                let directory_inaccessible = is_farm_directory_inaccessible_error(&error);
+                let disk_full = is_disk_full_error(&error);
+                sender.spawn_command(move |_sender| {
                     let mut notification = Notification::new();
-                    notification
-                        .summary(&T.notification_farm_error())
-                        .body(&T.notification_farm_error_body())
-                        .with_typical_options();
+                    if directory_inaccessible {
+                        notification
+                            .summary(&T.notification_farm_directory_inaccessible())
+                            .body(&T.notification_farm_directory_inaccessible_body());
+                    } else if disk_full {
+                        notification
+                            .summary(&T.notification_farm_disk_full())
+                            .body(&T.notification_farm_disk_full_body());
+                    } else {
+                        notification
+                            .summary(&T.notification_farm_error())
+                            .body(&T.notification_farm_error_body());
+                    }
+                    notification.with_typical_options();
                     #[cfg(all(unix, not(target_os = "macos")))]
                     notification.urgency(notify_rust::Urgency::Critical);
                     if let Err(error) = notification.show() {
@@ -600,9 +1271,127 @@ impl FarmWidget {
                     }
                 });
 
+                if directory_inaccessible {
+                    self.set_directory_accessible(false);
+                    let path = self.path.clone();
+                    sender.command(move |sender, shutdown_receiver| {
+                        Self::wait_for_directory(path, sender, shutdown_receiver)
+                    });
+                }
                 self.get_mut_error().replace(error);
             }
+            FarmWidgetInput::Restart => {
+                // Error will be reported again if farm fails to start
+                self.set_error(None);
+                self.set_directory_accessible(false);
+                if sender
+                    .output(FarmWidgetOutput::Restart {
+                        farm_index: self.index,
+                    })
+                    .is_err()
+                {
+                    debug!("Failed to send FarmWidgetOutput::Restart");
+                }
+            }
+            FarmWidgetInput::ResizeSizeChanged(size) => {
+                let resize_allocated_space = self.validate_resize_size(&size);
+                self.resize_size = size;
+                self.set_resize_allocated_space(resize_allocated_space);
+            }
+            FarmWidgetInput::Resize => {
+                let Ok(allocated_space) = self.resize_allocated_space else {
+                    return;
+                };
+
+                self.set_resize_allocated_space(Err(String::new()));
+                // Size is updated once farmer confirms resize
+                if sender
+                    .output(FarmWidgetOutput::Resize {
+                        farm_index: self.index,
+                        path: self.path.clone(),
+                        size: self.resize_size.trim().to_string(),
+                        previous_allocated_space: self.allocated_space,
+                        allocated_space,
+                    })
+                    .is_err()
+                {
+                    debug!("Failed to send FarmWidgetOutput::Resize");
+                }
+            }
+            FarmWidgetInput::Resized {
+                total_sectors,
+                allocated_space,
+            } => {
+                if let Some(allocated_space) = allocated_space {
+                    self.allocated_space = allocated_space;
+                    self.set_size(format_size(allocated_space));
+                }
+                self.add_sectors(total_sectors);
+            }
+        }
+    }
+
+    /// Validate new size of the farm, only growing farm is supported
+    fn validate_resize_size(&self, size: &str) -> Result<u64, String> {
+        let size = size.trim();
+        if size.is_empty() {
+            return Err(String::new());
+        }
+
+        let Ok(allocated_space) = ByteSize::from_str(size).map(|size| size.as_u64()) else {
+            return Err(T.running_farmer_farm_resize_error_invalid().to_string());
+        };
+
+        if allocated_space <= self.allocated_space {
+            return Err(T.running_farmer_farm_resize_error_shrink().to_string());
+        }
+
+        if allocated_space < MIN_FARM_SIZE {
+            return Err(T
+                .running_farmer_farm_resize_error_too_small(format_size(MIN_FARM_SIZE))
+                .to_string());
+        }
+
+        match fs4::available_space(&self.path) {
+            Ok(available_space) => {
+                let free_space = available_space.saturating_sub(FARM_SIZE_ALLOCATION_MARGIN);
+                if allocated_space - self.allocated_space > free_space {
+                    return Err(T
+                        .running_farmer_farm_resize_error_doesnt_fit(format_size(free_space))
+                        .to_string());
+                }
+            }
+            Err(error) => {
+                warn!(%error, path = %self.path.display(), "Failed to check available space");
+            }
+        }
+
+        Ok(allocated_space)
+    }
+
+    /// Add sectors that appeared after farm was resized
+    fn add_sectors(&mut self, total_sectors: SectorIndex) {
+        for sector_index in self.total_sectors..total_sectors {
+            let sector = gtk::Box::builder().css_name("farm-sector").build();
+            Self::update_sector_tooltip(&sector, sector_index);
+
+            let sector_row = match self.sector_rows.last_child() {
+                Some(sector_row) if usize::from(sector_index) % SECTORS_PER_ROW != 0 => sector_row,
+                _ => {
+                    let sector_row = gtk::Box::new(gtk::Orientation::Horizontal, 0);
+                    self.sector_rows.append(&sector_row);
+                    sector_row.upcast()
+                }
+            };
+            if let Some(sector_row) = sector_row.downcast_ref::<gtk::Box>() {
+                sector_row.append(&sector);
+            }
+
+            self.get_mut_sectors().insert(sector_index, sector);
         }
+        self.total_sectors = total_sectors;
+        // Plotted sectors label also shows total number of sectors
+        self.get_mut_plotted_sectors();
     }
 
     fn update_sector_state(&self, sector_index: SectorIndex, sector_state: SectorState) {
@@ -620,6 +1409,47 @@ impl FarmWidget {
         }
     }
 
+    /// Estimated time remaining until initial plotting is finished
+    fn plotting_eta(&self, kind: PlottingKind) -> String {
+        if kind != PlottingKind::Initial {
+            return String::new();
+        }
+
+        if self.sector_finish_interval.get_num_samples() < MIN_PLOTTING_ETA_SAMPLES {
+            return T.running_farmer_farm_plotting_eta_calculating().to_string();
+        }
+
+        let remaining_sectors = self.total_sectors.saturating_sub(self.plotted_sectors);
+        let time_remaining =
+            self.sector_finish_interval.get_average() * u32::from(remaining_sectors);
+
+        if time_remaining > Duration::from_secs(3600) {
+            T.running_farmer_farm_plotting_eta_hours(time_remaining.as_secs_f32() / 3600.0)
+                .as_str()
+                .to_string()
+        } else {
+            T.running_farmer_farm_plotting_eta_minutes(time_remaining.as_secs_f32() / 60.0)
+                .as_str()
+                .to_string()
+        }
+    }
+
+    /// Whether sector was plotted before, even if it is expired now
+    fn is_sector_plotted(&self, sector_index: SectorIndex) -> bool {
+        self.sectors
+            .get(&sector_index)
+            .map(|sector| {
+                [
+                    SectorState::Plotted,
+                    SectorState::AboutToExpire,
+                    SectorState::Expired,
+                ]
+                .iter()
+                .any(|sector_state| sector.has_css_class(sector_state.css_class()))
+            })
+            .unwrap_or_default()
+    }
+
     fn remove_sector_state(&self, sector_index: SectorIndex, sector_state: SectorState) {
         if let Some(sector) = self.sectors.get(&sector_index) {
             sector.remove_css_class(sector_state.css_class());
@@ -647,6 +1477,130 @@ impl FarmWidget {
         sector.set_tooltip_text(Some(tooltip.as_str()));
     }
 
+    async fn wait_for_directory(
+        path: PathBuf,
+        sender: Sender<FarmWidgetCommandOutput>,
+        shutdown_receiver: ShutdownReceiver,
+    ) {
+        shutdown_receiver
+            .register(async move {
+                loop {
+                    tokio::time::sleep(FARM_DIRECTORY_CHECK_INTERVAL).await;
+
+                    if is_farm_directory_accessible(path.clone()).await {
+                        if sender
+                            .send(FarmWidgetCommandOutput::DirectoryAccessible)
+                            .is_err()
+                        {
+                            debug!("Failed to send FarmWidgetCommandOutput::DirectoryAccessible");
+                        }
+                        break;
+                    }
+                }
+            })
+            .drop_on_shutdown()
+            .await
+    }
+
+    /// Periodically sample I/O counters of the disk farm is located on and send smoothed
+    /// throughput, exits immediately if counters are not available
+    async fn sample_disk_io(
+        path: PathBuf,
+        sender: Sender<FarmWidgetCommandOutput>,
+        shutdown_receiver: ShutdownReceiver,
+    ) {
+        shutdown_receiver
+            .register(async move {
+                let Some(mut last_counters) = disk_io_counters(path.clone()).await else {
+                    return;
+                };
+                let mut last_sampled = Instant::now();
+                let mut read = SingleSumSMA::<f64, f64, DISK_IO_TRACKING_WINDOW>::from_zero(0.0);
+                let mut write = SingleSumSMA::<f64, f64, DISK_IO_TRACKING_WINDOW>::from_zero(0.0);
+                let mut operations =
+                    SingleSumSMA::<f64, f64, DISK_IO_TRACKING_WINDOW>::from_zero(0.0);
+
+                loop {
+                    tokio::time::sleep(DISK_IO_SAMPLE_INTERVAL).await;
+
+                    // Farm directory might be temporarily inaccessible
+                    let Some(counters) = disk_io_counters(path.clone()).await else {
+                        continue;
+                    };
+                    let elapsed = last_sampled.elapsed().as_secs_f64().max(f64::EPSILON);
+                    last_sampled = Instant::now();
+
+                    read.add_sample(
+                        counters.read_bytes.saturating_sub(last_counters.read_bytes) as f64
+                            / elapsed,
+                    );
+                    write.add_sample(
+                        counters
+                            .write_bytes
+                            .saturating_sub(last_counters.write_bytes)
+                            as f64
+                            / elapsed,
+                    );
+                    operations.add_sample(
+                        counters.operations.saturating_sub(last_counters.operations) as f64
+                            / elapsed,
+                    );
+                    last_counters = counters;
+
+                    let disk_io = DiskIo {
+                        read_bytes_per_second: read.get_average() as u64,
+                        write_bytes_per_second: write.get_average() as u64,
+                        operations_per_second: operations.get_average().round() as u64,
+                    };
+                    if sender
+                        .send(FarmWidgetCommandOutput::DiskIo(disk_io))
+                        .is_err()
+                    {
+                        break;
+                    }
+                }
+            })
+            .drop_on_shutdown()
+            .await
+    }
+
+    fn update_slow_auditing(&mut self, auditing_time: Duration, sender: &FactorySender<Self>) {
+        let slow = auditing_time > self.slot_duration;
+        if slow == self.slow_auditing {
+            self.slow_auditing_streak = 0;
+            return;
+        }
+
+        self.slow_auditing_streak += 1;
+        let threshold = if slow {
+            SLOW_AUDITING_ALERT_THRESHOLD
+        } else {
+            SLOW_AUDITING_RECOVERY_THRESHOLD
+        };
+        if self.slow_auditing_streak < threshold {
+            return;
+        }
+
+        self.slow_auditing = slow;
+        self.slow_auditing_streak = 0;
+        if sender
+            .output(FarmWidgetOutput::SlowAuditing {
+                farm_index: self.index,
+                slow,
+            })
+            .is_err()
+        {
+            debug!("Failed to send FarmWidgetOutput::SlowAuditing");
+        }
+    }
+
+    /// Whether auditing is taking longer than slot duration, meaning farm is missing rewards
+    fn is_auditing_slow(&self) -> bool {
+        self.auditing_latency
+            .map(|latency| latency.p95 > self.slot_duration)
+            .unwrap_or_default()
+    }
+
     /// 0.0..=1.0
     fn farm_score(&self) -> f64 {
         // 95% success rate with signing is good
@@ -673,3 +1627,46 @@ impl FarmWidget {
         (proving_result_score + auditing_time_score + proving_time_score) / 3.0
     }
 }
+
+/// Read cumulative I/O counters of the partition `path` is located on, `None` if not supported on
+/// this platform or path is not accessible
+async fn disk_io_counters(path: PathBuf) -> Option<DiskIoCounters> {
+    tokio::task::spawn_blocking(move || read_disk_io_counters(&path))
+        .await
+        .ok()
+        .flatten()
+}
+
+#[cfg(target_os = "linux")]
+fn read_disk_io_counters(path: &std::path::Path) -> Option<DiskIoCounters> {
+    use crate::frontend::configuration::utils::linux_block_device_sysfs_path;
+
+    /// Sizes in block device statistics are in 512-byte sectors regardless of actual sector size
+    const STAT_SECTOR_SIZE: u64 = 512;
+
+    // See https://www.kernel.org/doc/Documentation/block/stat.txt for format
+    let stat = std::fs::read_to_string(linux_block_device_sysfs_path(path)?.join("stat")).ok()?;
+    let fields = stat
+        .split_whitespace()
+        .map(u64::from_str)
+        .collect::<Result<Vec<_>, _>>()
+        .ok()?;
+    let (read_operations, read_sectors, write_operations, write_sectors) = (
+        *fields.first()?,
+        *fields.get(2)?,
+        *fields.get(4)?,
+        *fields.get(6)?,
+    );
+
+    Some(DiskIoCounters {
+        read_bytes: read_sectors * STAT_SECTOR_SIZE,
+        write_bytes: write_sectors * STAT_SECTOR_SIZE,
+        operations: read_operations + write_operations,
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_disk_io_counters(_path: &std::path::Path) -> Option<DiskIoCounters> {
+    // TODO: Counters on macOS (IOKit) and Windows (`IOCTL_DISK_PERFORMANCE`)
+    None
+}
@@ -0,0 +1,146 @@
+use crate::frontend::running::farm::FarmSummary;
+use crate::frontend::translations::{AsDefaultStr, T};
+use gtk::prelude::*;
+use relm4::prelude::*;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub(super) struct FarmTileInit {
+    /// Farm name, farm path is shown if farm has no name
+    pub(super) name: Option<String>,
+    pub(super) path: PathBuf,
+    pub(super) summary: FarmSummary,
+}
+
+#[derive(Debug)]
+pub(super) enum FarmTileInput {
+    SummaryChanged(FarmSummary),
+}
+
+/// Small tile colored according to farm status, used in compact view of the running screen
+#[tracker::track]
+#[derive(Debug)]
+pub(super) struct FarmTile {
+    #[do_not_track]
+    index: u8,
+    #[do_not_track]
+    label: String,
+    #[do_not_track]
+    path: PathBuf,
+    summary: FarmSummary,
+}
+
+#[relm4::factory(pub(super))]
+impl FactoryComponent for FarmTile {
+    type Init = FarmTileInit;
+    type Input = FarmTileInput;
+    type Output = ();
+    type CommandOutput = ();
+    type ParentWidget = gtk::FlowBox;
+    type Index = u8;
+
+    view! {
+        #[root]
+        gtk::MenuButton {
+            #[track = "self.changed_summary()"]
+            set_css_classes: &["farm-tile", self.summary.status.as_str()],
+            set_cursor_from_name: Some("pointer"),
+            set_tooltip: &self.path.display().to_string(),
+            // Used by running view to find farm tile when sorting farms
+            set_widget_name: &self.index.to_string(),
+
+            #[wrap(Some)]
+            set_child = &gtk::Box {
+                set_orientation: gtk::Orientation::Vertical,
+                set_spacing: 5,
+
+                gtk::Label {
+                    set_ellipsize: gtk::pango::EllipsizeMode::Middle,
+                    set_label: &self.label,
+                    set_max_width_chars: 12,
+                },
+
+                gtk::Label {
+                    #[track = "self.changed_summary()"]
+                    set_label: T
+                        .running_farmer_farm_tile_progress(self.plotted_percentage())
+                        .as_str(),
+                    #[track = "self.changed_summary()"]
+                    set_visible: self.summary.total_sectors > 0,
+                },
+            },
+
+            #[wrap(Some)]
+            set_popover = &gtk::Popover {
+                gtk::Box {
+                    set_orientation: gtk::Orientation::Vertical,
+                    set_spacing: 10,
+
+                    gtk::Label {
+                        add_css_class: "heading",
+                        set_halign: gtk::Align::Start,
+                        #[track = "self.changed_summary()"]
+                        set_label: &format!("{} [{}]", self.path.display(), self.summary.size),
+                    },
+
+                    gtk::Label {
+                        set_halign: gtk::Align::Start,
+                        #[track = "self.changed_summary()"]
+                        set_label: T
+                            .running_farmer_farm_tile_status(self.summary.status.as_str())
+                            .as_str(),
+                    },
+
+                    gtk::Label {
+                        set_halign: gtk::Align::Start,
+                        #[track = "self.changed_summary()"]
+                        set_label: T
+                            .running_farmer_farm_plotted_sectors(
+                                self.summary.plotted_sectors,
+                                self.summary.total_sectors,
+                            )
+                            .as_str(),
+                        #[track = "self.changed_summary()"]
+                        set_visible: self.summary.total_sectors > 0,
+                    },
+                },
+            },
+        },
+    }
+
+    fn init_model(init: Self::Init, index: &Self::Index, _sender: FactorySender<Self>) -> Self {
+        Self {
+            index: *index,
+            label: init.name.unwrap_or_else(|| init.path.display().to_string()),
+            path: init.path,
+            summary: init.summary,
+            tracker: u8::MAX,
+        }
+    }
+
+    fn update(&mut self, input: Self::Input, _sender: FactorySender<Self>) {
+        // Reset changes
+        self.reset();
+
+        match input {
+            FarmTileInput::SummaryChanged(summary) => {
+                self.set_summary(summary);
+            }
+        }
+    }
+}
+
+impl FarmTile {
+    pub(super) fn summary(&self) -> &FarmSummary {
+        &self.summary
+    }
+
+    /// Plotted sectors in %
+    fn plotted_percentage(&self) -> f32 {
+        if self.summary.total_sectors == 0 {
+            0.0
+        } else {
+            f32::from(self.summary.plotted_sectors) * 100.0 / f32::from(self.summary.total_sectors)
+        }
+    }
+}
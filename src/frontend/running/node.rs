@@ -1,6 +1,8 @@
-use crate::backend::node::{ChainInfo, SyncState};
+use crate::backend::clock::ClockOffset;
+use crate::backend::node::{ChainInfo, PeerInfo, SyncState};
 use crate::backend::NodeNotification;
 use crate::frontend::translations::{AsDefaultStr, T};
+use crate::frontend::widgets::copy_button::{CopyButton, CopyButtonInit, CopyButtonInput};
 use bytesize::ByteSize;
 use gtk::prelude::*;
 use parking_lot::Mutex;
@@ -12,6 +14,7 @@ use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use subspace_core_primitives::BlockNumber;
+use subspace_networking::libp2p::PeerId;
 use tracing::error;
 
 /// Maximum blocks to store in the import queue.
@@ -23,6 +26,8 @@ const FREE_DISK_SPACE_CHECK_INTERVAL: Duration = Duration::from_secs(5);
 const FREE_DISK_SPACE_CHECK_WARNING_THRESHOLD: u64 = ByteSize::gib(10).as_u64();
 /// Number of samples over which to track block import time, 1 minute in slots
 const BLOCK_IMPORT_TIME_TRACKING_WINDOW: usize = 1000;
+/// Clock offset starting with which it is shown in minutes rather than seconds
+const CLOCK_OFFSET_MINUTES_THRESHOLD: Duration = Duration::from_secs(120);
 
 #[derive(Debug)]
 pub enum NodeInput {
@@ -30,6 +35,7 @@ pub enum NodeInput {
         best_block_number: BlockNumber,
         chain_info: ChainInfo,
         node_path: PathBuf,
+        peer_id: PeerId,
     },
     NodeNotification(NodeNotification),
     OpenNodeFolder,
@@ -47,11 +53,20 @@ pub struct NodeView {
     sync_state: SyncState,
     free_disk_space: Option<ByteSize>,
     chain_name: String,
+    /// Hex-encoded genesis hash of the chain node is running, empty until initialized
+    genesis_hash: String,
     #[no_eq]
     node_path: Arc<Mutex<PathBuf>>,
+    #[do_not_track]
+    peer_id_copy_button: Controller<CopyButton>,
+    #[do_not_track]
+    genesis_hash_copy_button: Controller<CopyButton>,
+    peers: Vec<PeerInfo>,
     #[no_eq]
     block_import_time: SingleSumSMA<Duration, u32, BLOCK_IMPORT_TIME_TRACKING_WINDOW>,
     last_block_import_time: Option<Instant>,
+    /// Offset of the system clock, `None` until checked
+    clock_offset: Option<ClockOffset>,
 }
 
 #[relm4::component(pub)]
@@ -81,6 +96,8 @@ impl Component for NodeView {
                     set_tooltip: &T.running_node_title_tooltip(),
                 },
 
+                model.peer_id_copy_button.widget().clone(),
+
 
                 gtk::Box {
                     set_halign: gtk::Align::End,
@@ -119,9 +136,82 @@ impl Component for NodeView {
                             set_width_request: 100,
                         },
                     },
+
+                    gtk::MenuButton {
+                        set_cursor_from_name: Some("pointer"),
+                        set_has_frame: false,
+                        #[track = "model.changed_peers()"]
+                        set_label: T.running_node_peers(model.peers.len()).as_str(),
+                        set_tooltip: &T.running_node_peers_tooltip(),
+                        #[wrap(Some)]
+                        set_popover = &gtk::Popover {
+                            gtk::ScrolledWindow {
+                                set_max_content_height: 300,
+                                set_propagate_natural_height: true,
+                                set_propagate_natural_width: true,
+
+                                gtk::Label {
+                                    set_halign: gtk::Align::Start,
+                                    #[track = "model.changed_peers()"]
+                                    set_label: &if model.peers.is_empty() {
+                                        T.running_node_peers_empty().to_string()
+                                    } else {
+                                        model
+                                            .peers
+                                            .iter()
+                                            .map(|peer| {
+                                                T
+                                                    .running_node_peer(
+                                                        peer.peer_id.as_str(),
+                                                        peer.role.as_str(),
+                                                        peer.best_block_number,
+                                                        peer.address
+                                                            .as_deref()
+                                                            .unwrap_or(&T.running_node_peer_address_unknown()),
+                                                    )
+                                                    .as_str()
+                                                    .to_string()
+                                            })
+                                            .collect::<Vec<_>>()
+                                            .join("\n\n")
+                                    },
+                                    set_selectable: true,
+                                },
+                            },
+                        },
+                    },
                 },
             },
 
+            gtk::Box {
+                set_spacing: 5,
+                #[track = "model.changed_genesis_hash()"]
+                set_visible: !model.genesis_hash.is_empty(),
+
+                gtk::Label {
+                    add_css_class: "dim-label",
+                    set_ellipsize: gtk::pango::EllipsizeMode::Middle,
+                    set_halign: gtk::Align::Start,
+                    #[track = "model.changed_genesis_hash()"]
+                    set_label: T
+                        .running_node_genesis_hash(format!("0x{}", model.genesis_hash))
+                        .as_str(),
+                    set_selectable: true,
+                },
+
+                model.genesis_hash_copy_button.widget().clone(),
+            },
+
+            gtk::Label {
+                add_css_class: "warning-label",
+                set_halign: gtk::Align::Start,
+                #[track = "model.changed_clock_offset()"]
+                set_label: &model.clock_offset.map(clock_offset_warning).unwrap_or_default(),
+                #[track = "model.changed_clock_offset()"]
+                set_visible: model.clock_offset.is_some_and(|clock_offset| clock_offset.is_significant()),
+                set_wrap: true,
+            },
+
             #[transition = "SlideUpDown"]
             match model.sync_state {
                 SyncState::Unknown => gtk::Box {
@@ -224,15 +314,32 @@ impl Component for NodeView {
         sender: ComponentSender<Self>,
     ) -> ComponentParts<Self> {
         let node_path = Arc::<Mutex<PathBuf>>::default();
+        let peer_id_copy_button = CopyButton::builder()
+            .launch(CopyButtonInit {
+                tooltip: T.running_node_button_copy_peer_id().to_string(),
+                value: String::new(),
+            })
+            .detach();
+        let genesis_hash_copy_button = CopyButton::builder()
+            .launch(CopyButtonInit {
+                tooltip: T.running_node_button_copy_genesis_hash().to_string(),
+                value: String::new(),
+            })
+            .detach();
         let model = Self {
             best_block_number: 0,
             sync_state: SyncState::default(),
             free_disk_space: None,
             chain_name: String::new(),
+            genesis_hash: String::new(),
+            peers: Vec::new(),
             node_path: node_path.clone(),
+            peer_id_copy_button,
+            genesis_hash_copy_button,
             block_import_time: SingleSumSMA::from_zero(Duration::ZERO),
             last_block_import_time: None,
-            tracker: u8::MAX,
+            clock_offset: None,
+            tracker: u16::MAX,
         };
 
         let widgets = view_output!();
@@ -271,6 +378,7 @@ impl NodeView {
                 best_block_number,
                 chain_info,
                 node_path,
+                peer_id,
             } => {
                 self.set_best_block_number(best_block_number);
                 self.set_chain_name(
@@ -281,7 +389,17 @@ impl NodeView {
                             chain_name.to_string()
                         }),
                 );
+                self.genesis_hash_copy_button
+                    .emit(CopyButtonInput::SetValue(format!(
+                        "0x{}",
+                        chain_info.genesis_hash
+                    )));
+                self.set_genesis_hash(chain_info.genesis_hash);
                 *self.get_mut_node_path().lock() = node_path;
+                self.peer_id_copy_button
+                    .emit(CopyButtonInput::SetValue(peer_id.to_string()));
+                // Node was (re)started, previous measurements are not relevant anymore
+                self.reset_block_import_time();
             }
             NodeInput::NodeNotification(node_notification) => match node_notification {
                 NodeNotification::SyncStateUpdate(mut new_sync_state) => {
@@ -306,13 +424,30 @@ impl NodeView {
                             }
                         }
                     }
-                    // Reset block import time on transition to sync
-                    if self.sync_state.is_synced() && !new_sync_state.is_synced() {
-                        self.block_import_time = SingleSumSMA::from_zero(Duration::ZERO);
-                        self.last_block_import_time.take();
+                    // Reset block import time on transition to sync, when node reconnects to the
+                    // network or changes kind of sync since speed will be different from what it
+                    // was before
+                    let reset_block_import_time = match (&self.sync_state, &new_sync_state) {
+                        (_, SyncState::Unknown) => true,
+                        (
+                            SyncState::Syncing { kind: old_kind, .. },
+                            SyncState::Syncing { kind: new_kind, .. },
+                        ) => old_kind != new_kind,
+                        (old_sync_state, new_sync_state) => {
+                            old_sync_state.is_synced() != new_sync_state.is_synced()
+                        }
+                    };
+                    if reset_block_import_time {
+                        self.reset_block_import_time();
                     }
                     self.set_sync_state(new_sync_state);
                 }
+                NodeNotification::PeersUpdate(peers) => {
+                    self.set_peers(peers);
+                }
+                NodeNotification::ClockOffset(clock_offset) => {
+                    self.set_clock_offset(Some(clock_offset));
+                }
                 NodeNotification::BlockImported(imported_block) => {
                     self.set_best_block_number(imported_block.number);
                     // Ensure target is never below current block
@@ -337,6 +472,11 @@ impl NodeView {
         }
     }
 
+    fn reset_block_import_time(&mut self) {
+        self.block_import_time = SingleSumSMA::from_zero(Duration::ZERO);
+        self.last_block_import_time.take();
+    }
+
     fn process_command(&mut self, command_output: NodeCommandOutput) {
         match command_output {
             NodeCommandOutput::FreeDiskSpace(bytes) => {
@@ -389,3 +529,20 @@ impl NodeView {
             .await
     }
 }
+
+fn clock_offset_warning(clock_offset: ClockOffset) -> String {
+    let offset = clock_offset.magnitude();
+
+    if offset >= CLOCK_OFFSET_MINUTES_THRESHOLD {
+        T.running_node_clock_offset_warning_minutes(
+            clock_offset.direction(),
+            offset.as_secs_f32() / 60.0,
+        )
+        .as_str()
+        .to_string()
+    } else {
+        T.running_node_clock_offset_warning_seconds(clock_offset.direction(), offset.as_secs_f32())
+            .as_str()
+            .to_string()
+    }
+}
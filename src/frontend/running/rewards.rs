@@ -0,0 +1,447 @@
+use crate::backend::config::profiles;
+use crate::frontend::translations::{AsDefaultStr, T};
+use gtk::prelude::*;
+use relm4::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::io;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use subspace_runtime_primitives::{Balance, SSC};
+use tokio::io::AsyncWriteExt;
+use tracing::{error, warn};
+
+/// Reward log file name in app data directory, one JSON-encoded [`RewardEvent`] per line
+const REWARD_LOG_FILE_NAME: &str = "rewards.jsonl";
+const CHART_HEIGHT: i32 = 120;
+const CHART_MARGIN: f64 = 5.0;
+/// Space reserved at the top of the chart for the label
+const CHART_LABEL_HEIGHT: f64 = 15.0;
+const HOUR: Duration = Duration::from_secs(3600);
+const DAY: Duration = Duration::from_secs(24 * 3600);
+const WEEK: Duration = Duration::from_secs(7 * 24 * 3600);
+
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct RewardEvent {
+    /// Unix timestamp in seconds
+    timestamp: u64,
+    amount: Balance,
+}
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub(super) enum ChartMode {
+    #[default]
+    Cumulative,
+    PerInterval,
+}
+
+#[derive(Debug)]
+pub(super) enum RewardsInput {
+    Initialize { token_symbol: String },
+    Reward { amount: Balance },
+    SetMode(ChartMode),
+}
+
+#[derive(Debug)]
+pub(super) enum RewardsCommandOutput {
+    RewardLogLoaded(Vec<RewardEvent>),
+}
+
+#[derive(Debug, Default)]
+struct ChartData {
+    events: Vec<RewardEvent>,
+    mode: ChartMode,
+    token_symbol: String,
+}
+
+#[tracker::track]
+#[derive(Debug)]
+pub(super) struct RewardsView {
+    #[do_not_track]
+    chart_data: Rc<RefCell<ChartData>>,
+    has_rewards: bool,
+    mode: ChartMode,
+}
+
+#[relm4::component(pub(super))]
+impl Component for RewardsView {
+    type Init = ();
+    type Input = RewardsInput;
+    type Output = ();
+    type CommandOutput = RewardsCommandOutput;
+
+    view! {
+        #[root]
+        gtk::Box {
+            set_orientation: gtk::Orientation::Vertical,
+            set_spacing: 5,
+            #[track = "model.changed_has_rewards()"]
+            set_visible: model.has_rewards,
+
+            gtk::Box {
+                set_spacing: 10,
+
+                gtk::Label {
+                    add_css_class: "heading",
+                    set_halign: gtk::Align::Start,
+                    set_label: &T.running_farmer_rewards_title(),
+                },
+
+                gtk::Box {
+                    add_css_class: "linked",
+                    set_halign: gtk::Align::End,
+                    set_hexpand: true,
+
+                    #[name = "cumulative_button"]
+                    gtk::ToggleButton {
+                        set_active: true,
+                        set_cursor_from_name: Some("pointer"),
+                        set_label: &T.running_farmer_rewards_mode_cumulative(),
+                        connect_toggled[sender] => move |button| {
+                            if button.is_active() {
+                                sender.input(RewardsInput::SetMode(ChartMode::Cumulative));
+                            }
+                        },
+                    },
+                    gtk::ToggleButton {
+                        set_cursor_from_name: Some("pointer"),
+                        set_group: Some(&cumulative_button),
+                        set_label: &T.running_farmer_rewards_mode_per_interval(),
+                        connect_toggled[sender] => move |button| {
+                            if button.is_active() {
+                                sender.input(RewardsInput::SetMode(ChartMode::PerInterval));
+                            }
+                        },
+                    },
+                },
+            },
+
+            #[name = "drawing_area"]
+            gtk::DrawingArea {
+                set_content_height: CHART_HEIGHT,
+                set_hexpand: true,
+                set_draw_func: {
+                    let chart_data = Rc::clone(&model.chart_data);
+
+                    move |drawing_area, cr, width, height| {
+                        let chart_data = chart_data.borrow();
+                        let color = drawing_area.style_context().color();
+
+                        // Clear everything
+                        cr.set_operator(gtk::cairo::Operator::Clear);
+                        let _ = cr.paint();
+
+                        cr.set_operator(gtk::cairo::Operator::Over);
+                        cr.set_source_rgba(
+                            f64::from(color.red()),
+                            f64::from(color.green()),
+                            f64::from(color.blue()),
+                            f64::from(color.alpha()),
+                        );
+
+                        draw_chart(cr, f64::from(width), f64::from(height), &chart_data);
+                    }
+                },
+                #[track = "model.changed_mode() || model.changed_has_rewards()"]
+                set_tooltip_text: Some(&model.tooltip()),
+            },
+        }
+    }
+
+    fn init(
+        _init: Self::Init,
+        _root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self {
+            chart_data: Rc::default(),
+            has_rewards: false,
+            mode: ChartMode::default(),
+            tracker: u8::MAX,
+        };
+
+        let widgets = view_output!();
+
+        sender.oneshot_command(async {
+            let events = match reward_log_path().await {
+                Some(path) => match read_reward_log(path).await {
+                    Ok(events) => events,
+                    Err(error) => {
+                        error!(%error, "Failed to read reward log");
+                        Vec::new()
+                    }
+                },
+                None => Vec::new(),
+            };
+
+            RewardsCommandOutput::RewardLogLoaded(events)
+        });
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        input: Self::Input,
+        sender: ComponentSender<Self>,
+        _root: &Self::Root,
+    ) {
+        // Reset changes
+        self.reset();
+
+        self.process_input(input, sender.clone());
+
+        self.update_view(widgets, sender.clone());
+        widgets.drawing_area.queue_draw();
+    }
+
+    fn update_cmd_with_view(
+        &mut self,
+        widgets: &mut Self::Widgets,
+        input: Self::CommandOutput,
+        sender: ComponentSender<Self>,
+        _root: &Self::Root,
+    ) {
+        // Reset changes
+        self.reset();
+
+        self.process_command(input);
+
+        self.update_view(widgets, sender);
+        widgets.drawing_area.queue_draw();
+    }
+}
+
+impl RewardsView {
+    fn process_input(&mut self, input: RewardsInput, sender: ComponentSender<Self>) {
+        match input {
+            RewardsInput::Initialize { token_symbol } => {
+                self.chart_data.borrow_mut().token_symbol = token_symbol;
+            }
+            RewardsInput::Reward { amount } => {
+                let event = RewardEvent {
+                    timestamp: unix_timestamp(),
+                    amount,
+                };
+                self.chart_data.borrow_mut().events.push(event);
+                self.set_has_rewards(true);
+
+                sender.command(move |_sender, _shutdown_receiver| async move {
+                    let Some(path) = reward_log_path().await else {
+                        warn!("Failed to determine reward log path, reward not persisted");
+                        return;
+                    };
+
+                    if let Err(error) = append_to_reward_log(path, event).await {
+                        error!(%error, "Failed to append reward to reward log");
+                    }
+                });
+            }
+            RewardsInput::SetMode(mode) => {
+                self.chart_data.borrow_mut().mode = mode;
+                self.set_mode(mode);
+            }
+        }
+    }
+
+    fn process_command(&mut self, command_output: RewardsCommandOutput) {
+        match command_output {
+            RewardsCommandOutput::RewardLogLoaded(mut events) => {
+                let mut chart_data = self.chart_data.borrow_mut();
+                // Rewards received while log was loading go after historical rewards
+                events.append(&mut chart_data.events);
+                chart_data.events = events;
+                let has_rewards = !chart_data.events.is_empty();
+                drop(chart_data);
+
+                self.set_has_rewards(has_rewards);
+            }
+        }
+    }
+
+    fn tooltip(&self) -> String {
+        match self.mode {
+            ChartMode::Cumulative => T.running_farmer_rewards_cumulative_tooltip().to_string(),
+            ChartMode::PerInterval => {
+                let interval = chart_interval(&self.chart_data.borrow().events);
+                // Fluent selector, the unit itself is translated
+                let unit = if interval == HOUR {
+                    "hour"
+                } else if interval == DAY {
+                    "day"
+                } else {
+                    "week"
+                };
+
+                T.running_farmer_rewards_per_interval_tooltip(unit)
+                    .as_str()
+                    .to_string()
+            }
+        }
+    }
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Each profile has its own reward addresses, so rewards are logged separately for each profile
+async fn reward_log_path() -> Option<PathBuf> {
+    let profile_directory = profiles::active_profile_directory().await?;
+
+    Some(profile_directory.join(REWARD_LOG_FILE_NAME))
+}
+
+async fn read_reward_log(path: PathBuf) -> io::Result<Vec<RewardEvent>> {
+    let contents = match tokio::fs::read_to_string(&path).await {
+        Ok(contents) => contents,
+        Err(error) => {
+            return if error.kind() == io::ErrorKind::NotFound {
+                Ok(Vec::new())
+            } else {
+                Err(error)
+            };
+        }
+    };
+
+    Ok(contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| match serde_json::from_str::<RewardEvent>(line) {
+            Ok(event) => Some(event),
+            Err(error) => {
+                warn!(%error, %line, "Failed to parse reward log entry, ignored");
+                None
+            }
+        })
+        .collect())
+}
+
+async fn append_to_reward_log(path: PathBuf, event: RewardEvent) -> io::Result<()> {
+    let mut line = serde_json::to_string(&event).expect("Event serialization is infallible; qed");
+    line.push('\n');
+
+    tokio::fs::OpenOptions::new()
+        .append(true)
+        .create(true)
+        .open(path)
+        .await?
+        .write_all(line.as_bytes())
+        .await
+}
+
+/// Interval used for per-interval chart, depends on how long reward history is
+fn chart_interval(events: &[RewardEvent]) -> Duration {
+    let span = events
+        .first()
+        .map(|event| Duration::from_secs(unix_timestamp().saturating_sub(event.timestamp)))
+        .unwrap_or_default();
+
+    if span <= 2 * DAY {
+        HOUR
+    } else if span <= 60 * DAY {
+        DAY
+    } else {
+        WEEK
+    }
+}
+
+fn balance_to_f64(balance: Balance) -> f64 {
+    (balance / (SSC / 100)) as f64 / 100.0
+}
+
+fn draw_chart(cr: &gtk::cairo::Context, width: f64, height: f64, chart_data: &ChartData) {
+    let Some(first_event) = chart_data.events.first() else {
+        return;
+    };
+
+    let start = first_event.timestamp;
+    let end = unix_timestamp().max(start + 1);
+    let chart_left = CHART_MARGIN;
+    let chart_width = (width - CHART_MARGIN * 2.0).max(1.0);
+    let chart_top = CHART_MARGIN + CHART_LABEL_HEIGHT;
+    let chart_height = (height - chart_top - CHART_MARGIN).max(1.0);
+    let chart_bottom = chart_top + chart_height;
+    let x_for = |timestamp: u64| {
+        chart_left + (timestamp.saturating_sub(start)) as f64 / (end - start) as f64 * chart_width
+    };
+
+    // Baseline
+    cr.set_line_width(1.0);
+    cr.move_to(chart_left, chart_bottom);
+    cr.line_to(chart_left + chart_width, chart_bottom);
+    let _ = cr.stroke();
+
+    let max_value = match chart_data.mode {
+        ChartMode::Cumulative => {
+            let total = chart_data
+                .events
+                .iter()
+                .map(|event| event.amount)
+                .sum::<Balance>();
+            let max_value = balance_to_f64(total).max(f64::EPSILON);
+
+            // Step line of cumulative rewards
+            let mut cumulative = 0;
+            cr.move_to(x_for(start), chart_bottom);
+            for event in &chart_data.events {
+                let x = x_for(event.timestamp);
+                let y_before = chart_bottom - balance_to_f64(cumulative) / max_value * chart_height;
+                cumulative += event.amount;
+                let y_after = chart_bottom - balance_to_f64(cumulative) / max_value * chart_height;
+                cr.line_to(x, y_before);
+                cr.line_to(x, y_after);
+            }
+            cr.line_to(
+                chart_left + chart_width,
+                chart_bottom - balance_to_f64(cumulative) / max_value * chart_height,
+            );
+            cr.set_line_width(2.0);
+            let _ = cr.stroke();
+
+            max_value
+        }
+        ChartMode::PerInterval => {
+            let interval = chart_interval(&chart_data.events).as_secs();
+            let buckets_count = ((end - start) / interval + 1) as usize;
+            let mut buckets = vec![0; buckets_count];
+            for event in &chart_data.events {
+                let bucket = ((event.timestamp.saturating_sub(start)) / interval) as usize;
+                buckets[bucket.min(buckets_count - 1)] += event.amount;
+            }
+            let max_value = buckets
+                .iter()
+                .copied()
+                .map(balance_to_f64)
+                .fold(f64::EPSILON, f64::max);
+
+            let bar_width = chart_width / buckets_count as f64;
+            for (index, bucket) in buckets.into_iter().enumerate() {
+                if bucket == 0 {
+                    continue;
+                }
+
+                let bar_height = balance_to_f64(bucket) / max_value * chart_height;
+                cr.rectangle(
+                    chart_left + index as f64 * bar_width,
+                    chart_bottom - bar_height,
+                    (bar_width - 1.0).max(1.0),
+                    bar_height,
+                );
+            }
+            let _ = cr.fill();
+
+            max_value
+        }
+    };
+
+    // Maximum value label
+    cr.move_to(chart_left, CHART_MARGIN + CHART_LABEL_HEIGHT - 4.0);
+    let _ = cr.show_text(&format!("{max_value:.2} {}", chart_data.token_symbol));
+}
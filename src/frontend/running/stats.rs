@@ -0,0 +1,345 @@
+use crate::backend::config::profiles;
+use crate::frontend::translations::{AsDefaultStr, T};
+use gtk::prelude::*;
+use relm4::prelude::*;
+use relm4::{Sender, ShutdownReceiver};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use subspace_runtime_primitives::{Balance, SSC};
+use tempfile::NamedTempFile;
+use tracing::{error, warn};
+
+/// Lifetime stats file name in app data directory
+const STATS_FILE_NAME: &str = "stats.json";
+/// How often uptime is accounted for and stats are persisted
+const UPTIME_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Stats accumulated over all runs of the application
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct LifetimeStats {
+    /// Total time farmer was running, in seconds
+    #[serde(default)]
+    uptime: u64,
+    /// Number of rewards (blocks and votes) received
+    #[serde(default)]
+    rewards_count: u64,
+    /// Total amount of rewards received
+    #[serde(default)]
+    rewards_total: Balance,
+}
+
+impl LifetimeStats {
+    fn add(&mut self, other: &Self) {
+        self.uptime += other.uptime;
+        self.rewards_count += other.rewards_count;
+        self.rewards_total += other.rewards_total;
+    }
+}
+
+#[derive(Debug)]
+pub(super) enum StatsInput {
+    Initialize {
+        token_symbol: String,
+    },
+    Reward {
+        amount: Balance,
+    },
+    /// Ask user to confirm reset
+    RequestReset,
+    Reset,
+}
+
+#[derive(Debug)]
+pub(super) enum StatsCommandOutput {
+    StatsLoaded(LifetimeStats),
+    /// Persisted stats exist, but can't be read, they are left untouched
+    StatsLoadingFailed,
+    UptimeTick,
+}
+
+#[tracker::track]
+#[derive(Debug)]
+pub(super) struct StatsView {
+    stats: LifetimeStats,
+    token_symbol: String,
+    /// Stats are not written until previously persisted stats are loaded, so they are not
+    /// overwritten
+    #[do_not_track]
+    loaded: bool,
+    #[do_not_track]
+    uptime_tracking_started: bool,
+}
+
+#[relm4::component(pub(super))]
+impl Component for StatsView {
+    type Init = ();
+    type Input = StatsInput;
+    type Output = ();
+    type CommandOutput = StatsCommandOutput;
+
+    view! {
+        #[root]
+        gtk::Box {
+            set_orientation: gtk::Orientation::Vertical,
+            set_spacing: 5,
+
+            gtk::Box {
+                set_spacing: 10,
+
+                gtk::Label {
+                    add_css_class: "heading",
+                    set_halign: gtk::Align::Start,
+                    set_label: &T.running_farmer_stats_title(),
+                },
+
+                gtk::Button {
+                    connect_clicked => StatsInput::RequestReset,
+                    set_cursor_from_name: Some("pointer"),
+                    set_halign: gtk::Align::End,
+                    set_hexpand: true,
+                    set_label: &T.running_farmer_stats_button_reset(),
+                    set_tooltip: &T.running_farmer_stats_button_reset_tooltip(),
+                },
+            },
+
+            gtk::Label {
+                set_halign: gtk::Align::Start,
+                #[track = "model.changed_stats()"]
+                set_label: &{
+                    let minutes = model.stats.uptime / 60;
+
+                    T.running_farmer_stats_uptime(minutes / (24 * 60), minutes / 60 % 24, minutes % 60)
+                        .as_str()
+                        .to_string()
+                },
+            },
+
+            gtk::Label {
+                set_halign: gtk::Align::Start,
+                #[track = "model.changed_stats()"]
+                set_label: T
+                    .running_farmer_stats_rewards_count(model.stats.rewards_count)
+                    .as_str(),
+            },
+
+            gtk::Label {
+                set_halign: gtk::Align::Start,
+                #[track = "model.changed_stats() || model.changed_token_symbol()"]
+                set_label: T
+                    .running_farmer_stats_rewards_total(
+                        (model.stats.rewards_total / (SSC / 100)) as f32 / 100.0,
+                        model.token_symbol.as_str(),
+                    )
+                    .as_str(),
+            },
+        }
+    }
+
+    fn init(
+        _init: Self::Init,
+        _root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self {
+            stats: LifetimeStats::default(),
+            token_symbol: String::new(),
+            loaded: false,
+            uptime_tracking_started: false,
+            tracker: u8::MAX,
+        };
+
+        let widgets = view_output!();
+
+        sender.oneshot_command(async {
+            let Some(path) = stats_path().await else {
+                return StatsCommandOutput::StatsLoaded(LifetimeStats::default());
+            };
+
+            match read_stats(&path).await {
+                Ok(stats) => StatsCommandOutput::StatsLoaded(stats),
+                Err(error) => {
+                    error!(
+                        %error,
+                        path = %path.display(),
+                        "Failed to read lifetime stats, they will not be updated until fixed"
+                    );
+                    StatsCommandOutput::StatsLoadingFailed
+                }
+            }
+        });
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, input: Self::Input, sender: ComponentSender<Self>, _root: &Self::Root) {
+        // Reset changes
+        self.reset();
+
+        match input {
+            StatsInput::Initialize { token_symbol } => {
+                self.set_token_symbol(token_symbol);
+
+                // Uptime is only accounted for while farmer is actually running
+                if !self.uptime_tracking_started {
+                    self.uptime_tracking_started = true;
+                    sender.command(Self::track_uptime);
+                }
+            }
+            StatsInput::Reward { amount } => {
+                let stats = self.get_mut_stats();
+                stats.rewards_count += 1;
+                stats.rewards_total += amount;
+                self.persist(&sender);
+            }
+            StatsInput::RequestReset => {
+                self.confirm_reset(sender);
+            }
+            StatsInput::Reset => {
+                self.set_stats(LifetimeStats::default());
+                self.persist(&sender);
+            }
+        }
+    }
+
+    fn update_cmd(
+        &mut self,
+        input: Self::CommandOutput,
+        sender: ComponentSender<Self>,
+        _root: &Self::Root,
+    ) {
+        // Reset changes
+        self.reset();
+
+        match input {
+            StatsCommandOutput::StatsLoaded(mut stats) => {
+                // Stats accumulated while file was loading are added on top of persisted stats
+                stats.add(&self.stats);
+                self.set_stats(stats);
+                self.loaded = true;
+                self.persist(&sender);
+            }
+            StatsCommandOutput::StatsLoadingFailed => {
+                // Stats are not marked as loaded, so file on disk is not overwritten
+            }
+            StatsCommandOutput::UptimeTick => {
+                self.get_mut_stats().uptime += UPTIME_INTERVAL.as_secs();
+                self.persist(&sender);
+            }
+        }
+    }
+}
+
+impl StatsView {
+    async fn track_uptime(sender: Sender<StatsCommandOutput>, shutdown_receiver: ShutdownReceiver) {
+        shutdown_receiver
+            .register(async move {
+                loop {
+                    tokio::time::sleep(UPTIME_INTERVAL).await;
+
+                    if sender.send(StatsCommandOutput::UptimeTick).is_err() {
+                        break;
+                    }
+                }
+            })
+            .drop_on_shutdown()
+            .await
+    }
+
+    fn persist(&self, sender: &ComponentSender<Self>) {
+        if !self.loaded {
+            return;
+        }
+
+        let stats = self.stats;
+        sender.command(move |_sender, _shutdown_receiver| async move {
+            let Some(path) = stats_path().await else {
+                warn!("Failed to determine lifetime stats path, stats not persisted");
+                return;
+            };
+
+            if let Err(error) = write_stats(path, stats).await {
+                error!(%error, "Failed to write lifetime stats");
+            }
+        });
+    }
+
+    fn confirm_reset(&self, sender: ComponentSender<Self>) {
+        let dialog = gtk::MessageDialog::builder()
+            .buttons(gtk::ButtonsType::None)
+            .message_type(gtk::MessageType::Question)
+            .modal(true)
+            .text(T.running_farmer_stats_reset_confirmation().as_str())
+            .build();
+        if let Some(window) = relm4::main_application().active_window() {
+            dialog.set_transient_for(Some(&window));
+        }
+        dialog.add_button(
+            &T.running_farmer_stats_reset_confirmation_button_cancel(),
+            gtk::ResponseType::Cancel,
+        );
+        dialog
+            .add_button(
+                &T.running_farmer_stats_reset_confirmation_button_reset(),
+                gtk::ResponseType::Accept,
+            )
+            .add_css_class("destructive-action");
+        dialog.set_default_response(gtk::ResponseType::Cancel);
+
+        dialog.connect_response(move |dialog, response| {
+            if response == gtk::ResponseType::Accept {
+                sender.input(StatsInput::Reset);
+            }
+            dialog.close();
+        });
+        dialog.present();
+    }
+}
+
+/// Each profile farms independently, so lifetime stats are stored separately for each profile
+async fn stats_path() -> Option<PathBuf> {
+    let profile_directory = profiles::active_profile_directory().await?;
+
+    Some(profile_directory.join(STATS_FILE_NAME))
+}
+
+async fn read_stats(path: &Path) -> io::Result<LifetimeStats> {
+    let contents = match tokio::fs::read_to_string(path).await {
+        Ok(contents) => contents,
+        Err(error) => {
+            return if error.kind() == io::ErrorKind::NotFound {
+                Ok(LifetimeStats::default())
+            } else {
+                Err(error)
+            };
+        }
+    };
+
+    serde_json::from_str(&contents)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+}
+
+async fn write_stats(path: PathBuf, stats: LifetimeStats) -> io::Result<()> {
+    let contents =
+        serde_json::to_string_pretty(&stats).expect("Stats serialization is infallible; qed");
+
+    tokio::task::spawn_blocking(move || {
+        let parent = path
+            .parent()
+            .expect("Stats file is always inside of profile directory; qed");
+        fs::create_dir_all(parent)?;
+
+        // Written to temporary file that replaces stats file once complete, such that stats are
+        // not lost if application exits in the middle of writing
+        let mut file = NamedTempFile::new_in(parent)?;
+        file.write_all(contents.as_bytes())?;
+        file.as_file().sync_all()?;
+        file.persist(&path)?;
+
+        Ok(())
+    })
+    .await?
+}
@@ -0,0 +1,220 @@
+use gtk::prelude::*;
+use relm4::prelude::*;
+use relm4_icons::icon_name;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// How long confirmation is shown
+const INFO_TOAST_DURATION: Duration = Duration::from_secs(3);
+/// Warnings are shown for longer, so they are less likely to be missed
+const WARNING_TOAST_DURATION: Duration = Duration::from_secs(6);
+/// Older toasts are dropped when more than this many are waiting to be shown
+const MAX_QUEUED_TOASTS: usize = 5;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum ToastKind {
+    Info,
+    Warning,
+}
+
+/// Transient notification shown on top of the main window
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Toast {
+    kind: ToastKind,
+    message: String,
+}
+
+impl Toast {
+    /// Confirmation of successful action
+    pub fn info<M>(message: M) -> Self
+    where
+        M: Into<String>,
+    {
+        Self {
+            kind: ToastKind::Info,
+            message: message.into(),
+        }
+    }
+
+    /// Non-fatal problem that user should know about
+    pub fn warning<M>(message: M) -> Self
+    where
+        M: Into<String>,
+    {
+        Self {
+            kind: ToastKind::Warning,
+            message: message.into(),
+        }
+    }
+
+    fn duration(&self) -> Duration {
+        match self.kind {
+            ToastKind::Info => INFO_TOAST_DURATION,
+            ToastKind::Warning => WARNING_TOAST_DURATION,
+        }
+    }
+
+    fn css_class(&self) -> &'static str {
+        match self.kind {
+            ToastKind::Info => "toast-info",
+            ToastKind::Warning => "toast-warning",
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ToastsInput {
+    Show(Toast),
+    Dismiss,
+}
+
+#[derive(Debug)]
+pub enum ToastsCommandOutput {
+    Expired { toast_id: u64 },
+}
+
+/// Shows toasts one at a time, in the order they were enqueued
+#[tracker::track]
+#[derive(Debug)]
+pub struct Toasts {
+    /// Toast that is shown or was shown last, kept while it is being hidden
+    toast: Option<Toast>,
+    revealed: bool,
+    #[do_not_track]
+    queue: VecDeque<Toast>,
+    /// Incremented every time toast is shown, such that expiration of previous toast is ignored
+    #[do_not_track]
+    toast_id: u64,
+}
+
+#[relm4::component(pub)]
+impl Component for Toasts {
+    type Init = ();
+    type Input = ToastsInput;
+    type Output = ();
+    type CommandOutput = ToastsCommandOutput;
+
+    view! {
+        #[root]
+        gtk::Revealer {
+            set_halign: gtk::Align::Center,
+            set_margin_bottom: 50,
+            #[track = "model.changed_revealed()"]
+            set_reveal_child: model.revealed,
+            set_transition_type: gtk::RevealerTransitionType::SlideUp,
+            set_valign: gtk::Align::End,
+
+            gtk::Box {
+                #[track = "model.changed_toast()"]
+                set_css_classes: &[
+                    "toast",
+                    model.toast.as_ref().map(Toast::css_class).unwrap_or_default(),
+                ],
+                set_spacing: 10,
+
+                gtk::Label {
+                    #[track = "model.changed_toast()"]
+                    set_label: model
+                        .toast
+                        .as_ref()
+                        .map(|toast| toast.message.as_str())
+                        .unwrap_or_default(),
+                    set_max_width_chars: 60,
+                    set_wrap: true,
+                },
+
+                gtk::Button {
+                    add_css_class: "flat",
+                    connect_clicked => ToastsInput::Dismiss,
+                    set_cursor_from_name: Some("pointer"),
+                    set_icon_name: icon_name::CROSS,
+                    set_valign: gtk::Align::Center,
+                },
+            },
+        }
+    }
+
+    fn init(
+        _init: Self::Init,
+        root: Self::Root,
+        _sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self {
+            toast: None,
+            revealed: false,
+            queue: VecDeque::new(),
+            toast_id: 0,
+            tracker: u8::MAX,
+        };
+
+        let widgets = view_output!();
+
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, input: Self::Input, sender: ComponentSender<Self>, _root: &Self::Root) {
+        // Reset changes
+        self.reset();
+
+        match input {
+            ToastsInput::Show(toast) => {
+                if self.revealed {
+                    if self.queue.len() == MAX_QUEUED_TOASTS {
+                        self.queue.pop_front();
+                    }
+                    self.queue.push_back(toast);
+                } else {
+                    self.show(toast, &sender);
+                }
+            }
+            ToastsInput::Dismiss => {
+                self.show_next(&sender);
+            }
+        }
+    }
+
+    fn update_cmd(
+        &mut self,
+        input: Self::CommandOutput,
+        sender: ComponentSender<Self>,
+        _root: &Self::Root,
+    ) {
+        // Reset changes
+        self.reset();
+
+        match input {
+            ToastsCommandOutput::Expired { toast_id } => {
+                if toast_id == self.toast_id && self.revealed {
+                    self.show_next(&sender);
+                }
+            }
+        }
+    }
+}
+
+impl Toasts {
+    fn show(&mut self, toast: Toast, sender: &ComponentSender<Self>) {
+        self.toast_id += 1;
+        let toast_id = self.toast_id;
+        let duration = toast.duration();
+
+        self.set_toast(Some(toast));
+        self.set_revealed(true);
+
+        sender.oneshot_command(async move {
+            tokio::time::sleep(duration).await;
+            ToastsCommandOutput::Expired { toast_id }
+        });
+    }
+
+    fn show_next(&mut self, sender: &ComponentSender<Self>) {
+        match self.queue.pop_front() {
+            Some(toast) => {
+                self.show(toast, sender);
+            }
+            None => {
+                self.set_revealed(false);
+            }
+        }
+    }
+}
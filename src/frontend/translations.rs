@@ -23,6 +23,7 @@ mod messages {
     include!(concat!(env!("OUT_DIR"), "/l10n.rs"));
 }
 
+use crate::backend::config::RawConfig;
 use fluent_langneg::{
     convert_vec_str_to_langids, convert_vec_str_to_langids_lossy, negotiate_languages,
     NegotiationStrategy,
@@ -34,13 +35,20 @@ use std::iter;
 use std::sync::LazyLock;
 use tracing::error;
 
-/// Translations for local language on this machine
+/// Translations for language selected in configuration or local language on this machine
 pub static T: LazyLock<MessagesBundle> = LazyLock::new(|| {
     let all_languages = MessagesBundle::all_languages();
     let available = convert_vec_str_to_langids(all_languages)
         .expect("Translations are all statically valid due to code generation; qed");
+    // Configured language goes first, if it isn't available system languages will be used
+    // instead
+    let configured_language = RawConfig::read_from_default_path_blocking()
+        .and_then(|raw_config| raw_config.language().map(str::to_string));
     let requested = convert_vec_str_to_langids_lossy(
-        sys_locale::get_locales().chain(iter::once("en".to_string())),
+        configured_language
+            .into_iter()
+            .chain(sys_locale::get_locales())
+            .chain(iter::once("en".to_string())),
     );
 
     let selected_languages =
@@ -62,6 +70,21 @@ pub static T: LazyLock<MessagesBundle> = LazyLock::new(|| {
     MessagesBundle::from(primary_language)
 });
 
+/// Languages translations are available for
+pub fn available_languages() -> &'static [&'static str] {
+    MessagesBundle::all_languages()
+}
+
+/// Name of the language in that language, falls back to language tag for unknown languages
+pub fn language_name(language: &str) -> &str {
+    match language {
+        "en" => "English",
+        "rs" => "Srpski",
+        "zh-CN" => "简体中文",
+        language => language,
+    }
+}
+
 pub trait AsDefaultStr {
     /// Get a `&str` and use placeholder message value in case of an error
     fn as_str(&self) -> &str;
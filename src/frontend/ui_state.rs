@@ -0,0 +1,109 @@
+use crate::app_dirs;
+use crate::frontend::running::FarmSortOrder;
+use gtk::gdk;
+use gtk::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::PathBuf;
+
+const UI_STATE_FILE_NAME: &str = "ui-state.json";
+
+/// Size and state of the main window
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct WindowState {
+    pub(super) width: i32,
+    pub(super) height: i32,
+    pub(super) maximized: bool,
+}
+
+impl WindowState {
+    /// Clamp window size so that it fits into the largest of currently connected monitors
+    pub(super) fn clamp_to_monitors(self, min_width: i32, min_height: i32) -> Self {
+        let Some(display) = gdk::Display::default() else {
+            return self;
+        };
+
+        let monitors = display.monitors();
+        let (max_width, max_height) = (0..monitors.n_items())
+            .filter_map(|position| monitors.item(position)?.downcast::<gdk::Monitor>().ok())
+            .map(|monitor| {
+                let geometry = monitor.geometry();
+                (geometry.width(), geometry.height())
+            })
+            .fold((0, 0), |(max_width, max_height), (width, height)| {
+                (max_width.max(width), max_height.max(height))
+            });
+
+        if max_width == 0 || max_height == 0 {
+            return self;
+        }
+
+        Self {
+            width: self.width.clamp(min_width, max_width.max(min_width)),
+            height: self.height.clamp(min_height, max_height.max(min_height)),
+            maximized: self.maximized,
+        }
+    }
+}
+
+/// UI state that is remembered between restarts, stored separately from configuration
+#[derive(Debug, Default, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct UiState {
+    #[serde(default)]
+    pub(super) window: Option<WindowState>,
+    /// Paths of farms that are collapsed on configuration screen
+    #[serde(default)]
+    pub(super) collapsed_farms: Vec<PathBuf>,
+    #[serde(default)]
+    pub(super) farm_sort_order: FarmSortOrder,
+    /// Whether farms are shown as compact tiles on running screen
+    #[serde(default)]
+    pub(super) compact_farms: bool,
+}
+
+impl UiState {
+    fn path() -> Option<PathBuf> {
+        app_dirs::config_dir().map(|config_dir| config_dir.join(UI_STATE_FILE_NAME))
+    }
+
+    /// Read UI state, default state is returned if it doesn't exist or can't be read
+    pub(super) async fn read() -> Self {
+        let Some(path) = Self::path() else {
+            return Self::default();
+        };
+
+        match tokio::fs::read_to_string(&path).await {
+            Ok(ui_state) => serde_json::from_str(&ui_state).unwrap_or_default(),
+            Err(_error) => Self::default(),
+        }
+    }
+
+    /// Read UI state, modify it and write back, such that unrelated parts of the state are
+    /// preserved
+    pub(super) async fn update(modify: impl FnOnce(&mut Self)) -> io::Result<()> {
+        let mut ui_state = Self::read().await;
+        modify(&mut ui_state);
+        ui_state.write().await
+    }
+
+    async fn write(&self) -> io::Result<()> {
+        let Some(path) = Self::path() else {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "Failed to determine config directory",
+            ));
+        };
+
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+
+        tokio::fs::write(
+            path,
+            serde_json::to_string_pretty(self).expect("UI state serialization is infallible; qed"),
+        )
+        .await
+    }
+}
@@ -1 +1,2 @@
+pub(super) mod copy_button;
 pub(super) mod progress_circle;
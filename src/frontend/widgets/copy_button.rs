@@ -0,0 +1,78 @@
+use crate::frontend::toasts::Toast;
+use crate::frontend::translations::{AsDefaultStr, T};
+use crate::frontend::App;
+use gtk::prelude::*;
+use relm4::prelude::*;
+use relm4_icons::icon_name;
+
+#[derive(Debug, Clone)]
+pub(in super::super) struct CopyButtonInit {
+    pub(in super::super) tooltip: String,
+    pub(in super::super) value: String,
+}
+
+#[derive(Debug)]
+pub(in super::super) enum CopyButtonInput {
+    SetValue(String),
+    Copy,
+}
+
+#[tracker::track]
+#[derive(Debug)]
+pub(in super::super) struct CopyButton {
+    value: String,
+    #[do_not_track]
+    tooltip: String,
+}
+
+#[relm4::component(pub)]
+impl Component for CopyButton {
+    type Init = CopyButtonInit;
+    type Input = CopyButtonInput;
+    type Output = ();
+    type CommandOutput = ();
+
+    view! {
+        #[root]
+        gtk::Button {
+            connect_clicked => CopyButtonInput::Copy,
+            set_cursor_from_name: Some("pointer"),
+            set_has_frame: false,
+            set_icon_name: icon_name::COPY,
+            set_tooltip: &model.tooltip,
+            #[track = "model.changed_value()"]
+            set_sensitive: !model.value.is_empty(),
+        }
+    }
+
+    fn init(
+        init: Self::Init,
+        root: Self::Root,
+        _sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = Self {
+            value: init.value,
+            tooltip: init.tooltip,
+            tracker: u8::MAX,
+        };
+
+        let widgets = view_output!();
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, input: Self::Input, _sender: ComponentSender<Self>, root: &Self::Root) {
+        // Reset changes
+        self.reset();
+
+        match input {
+            CopyButtonInput::SetValue(value) => {
+                self.set_value(value);
+            }
+            CopyButtonInput::Copy => {
+                root.display().clipboard().set_text(&self.value);
+
+                App::show_toast(Toast::info(T.copy_button_copied().to_string()));
+            }
+        }
+    }
+}
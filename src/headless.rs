@@ -0,0 +1,230 @@
+use crate::backend::{self, BackendAction, BackendNotification};
+use crate::instance_lock::InstanceLock;
+use crate::log_filter::LogFilterHandle;
+use futures::channel::mpsc;
+use futures::{select, FutureExt, SinkExt, StreamExt};
+use std::future::pending;
+use std::path::Path;
+use std::process::ExitCode;
+use subspace_farmer::utils::run_future_in_dedicated_thread;
+use tracing::{debug, error, info, warn};
+
+/// Run backend without GUI, driven entirely by the same configuration file GUI uses, until it is
+/// stopped by a signal or exits on its own
+pub fn run(app_data_dir: Option<&Path>, log_filter_handle: LogFilterHandle) -> ExitCode {
+    // Running two instances against the same farms would corrupt them, lock is held until this
+    // function returns
+    let _instance_lock = match app_data_dir.map(InstanceLock::try_acquire) {
+        Some(Ok(Some(instance_lock))) => Some(instance_lock),
+        Some(Ok(None)) => {
+            error!("Another instance is already running, exiting");
+            return ExitCode::FAILURE;
+        }
+        Some(Err(error)) => {
+            warn!(%error, "Failed to acquire instance lock");
+            None
+        }
+        None => None,
+    };
+
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(error) => {
+            error!(%error, "Failed to create Tokio runtime");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let exit_code = runtime.block_on(run_backend(log_filter_handle));
+    // Backend might still be running in case of forced exit, don't wait for it
+    runtime.shutdown_background();
+
+    exit_code
+}
+
+async fn run_backend(log_filter_handle: LogFilterHandle) -> ExitCode {
+    let (mut backend_action_sender, backend_action_receiver) = mpsc::channel(1);
+    let (backend_notification_sender, mut backend_notification_receiver) = mpsc::channel(100);
+
+    // Create and run backend in dedicated thread
+    let backend_fut = match run_future_in_dedicated_thread(
+        move || backend::create(backend_action_receiver, backend_notification_sender),
+        "backend".to_string(),
+    ) {
+        Ok(backend_fut) => backend_fut,
+        Err(error) => {
+            error!(%error, "Failed to spawn backend thread");
+            return ExitCode::FAILURE;
+        }
+    };
+    let mut backend_fut = backend_fut.fuse();
+
+    let mut shutdown_signal_fut = Box::pin(shutdown_signal().fuse());
+    let mut shutdown_requested = false;
+    // The first reason to stop determines exit code
+    let mut exit_code = None;
+
+    loop {
+        select! {
+            maybe_notification = backend_notification_receiver.next() => {
+                let Some(notification) = maybe_notification else {
+                    // Backend is exiting
+                    continue;
+                };
+
+                if let Some(notification_exit_code) =
+                    process_notification(notification, &log_filter_handle)
+                {
+                    exit_code.get_or_insert(notification_exit_code);
+
+                    if !shutdown_requested {
+                        shutdown_requested = true;
+                        request_shutdown(&mut backend_action_sender).await;
+                    }
+                }
+            }
+            _ = shutdown_signal_fut => {
+                if shutdown_requested {
+                    warn!("Received shutdown signal again, exiting without waiting for backend");
+                    return ExitCode::FAILURE;
+                }
+
+                info!("Received shutdown signal, stopping gracefully (repeat to exit immediately)");
+                shutdown_requested = true;
+                request_shutdown(&mut backend_action_sender).await;
+                shutdown_signal_fut = Box::pin(shutdown_signal().fuse());
+            }
+            result = backend_fut => {
+                match result {
+                    Ok(()) => {
+                        info!("Backend exited");
+                    }
+                    Err(_) => {
+                        error!("Backend spawning failed");
+                        exit_code.get_or_insert(ExitCode::FAILURE);
+                    }
+                }
+
+                return exit_code.unwrap_or(ExitCode::SUCCESS);
+            }
+        }
+    }
+}
+
+/// Process backend notification, returns exit code if backend should be stopped
+fn process_notification(
+    notification: BackendNotification,
+    log_filter_handle: &LogFilterHandle,
+) -> Option<ExitCode> {
+    match notification {
+        BackendNotification::Loading(step) => {
+            debug!(?step, "Loading");
+        }
+        BackendNotification::ConfigurationFound { raw_config } => {
+            log_filter_handle.set_log_level(raw_config.log_level());
+        }
+        BackendNotification::IncompatibleChain { compatible_chain } => {
+            error!(
+                %compatible_chain,
+                "Configuration is for a chain this version doesn't support, upgrade Space Acres \
+                or configure it again using GUI"
+            );
+            return Some(ExitCode::FAILURE);
+        }
+        BackendNotification::NotConfigured => {
+            error!("Space Acres is not configured yet, configure it using GUI first");
+            return Some(ExitCode::FAILURE);
+        }
+        BackendNotification::ConfigurationIsInvalid { error } => {
+            error!(%error, "Configuration is invalid, fix it using GUI");
+            return Some(ExitCode::FAILURE);
+        }
+        BackendNotification::ConfigSaveResult(result) => {
+            if let Err(error) = result {
+                error!(%error, "Failed to save configuration");
+            }
+        }
+        BackendNotification::FarmMismatches { mismatches } => {
+            // There is nobody to ask and every resolution either changes configuration or data on
+            // disk, so user has to decide
+            for mismatch in &mismatches {
+                error!(
+                    directory = %mismatch.directory.display(),
+                    kind = ?mismatch.kind,
+                    "Farm doesn't match configuration"
+                );
+            }
+            error!(
+                "Resolve farm mismatches using GUI, or disable or remove mismatched farms in \
+                configuration"
+            );
+            return Some(ExitCode::FAILURE);
+        }
+        BackendNotification::StartupRetry { .. } => {
+            // Already logged by backend, retried automatically
+        }
+        BackendNotification::Running { config, .. } => {
+            info!(farms = %config.farms.len(), "Farming started");
+        }
+        BackendNotification::Node(_) | BackendNotification::Farmer(_) => {
+            // Backend logs everything important already
+        }
+        BackendNotification::Stopped { error } => {
+            return Some(match error {
+                Some(error) => {
+                    error!(%error, "Backend stopped with error");
+                    ExitCode::FAILURE
+                }
+                None => ExitCode::SUCCESS,
+            });
+        }
+        BackendNotification::ShuttingDown(step) => {
+            info!(?step, "Shutting down");
+        }
+        BackendNotification::IrrecoverableError { error } => {
+            error!(%error, "Irrecoverable error");
+            return Some(ExitCode::FAILURE);
+        }
+    }
+
+    None
+}
+
+async fn request_shutdown(backend_action_sender: &mut mpsc::Sender<BackendAction>) {
+    if let Err(error) = backend_action_sender.send(BackendAction::Shutdown).await {
+        // Backend has already exited
+        debug!(%error, "Failed to request backend shutdown");
+    }
+}
+
+/// Resolves on SIGINT or SIGTERM
+#[cfg(unix)]
+async fn shutdown_signal() {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut sigterm = match signal(SignalKind::terminate()) {
+        Ok(sigterm) => sigterm,
+        Err(error) => {
+            warn!(%error, "Failed to subscribe to SIGTERM");
+            return ctrl_c().await;
+        }
+    };
+
+    select! {
+        _ = Box::pin(sigterm.recv()).fuse() => {}
+        _ = Box::pin(ctrl_c()).fuse() => {}
+    }
+}
+
+/// Resolves on Ctrl+C
+#[cfg(not(unix))]
+async fn shutdown_signal() {
+    ctrl_c().await
+}
+
+async fn ctrl_c() {
+    if let Err(error) = tokio::signal::ctrl_c().await {
+        warn!(%error, "Failed to subscribe to Ctrl+C");
+        pending::<()>().await;
+    }
+}
@@ -0,0 +1,44 @@
+use fs4::FileExt;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::{io, process};
+
+/// Name of the lock file in app data directory
+const INSTANCE_LOCK_FILE_NAME: &str = "instance.lock";
+
+/// Lock that ensures only one instance of the application is running at a time.
+///
+/// Lock is held by the operating system for as long as the file is open, so it is released even if
+/// application crashes, lock file left behind by such instance is simply locked again.
+#[derive(Debug)]
+pub struct InstanceLock {
+    _file: File,
+}
+
+impl InstanceLock {
+    /// Try to acquire instance lock in app data directory, returns `Ok(None)` if another instance
+    /// is already holding it
+    pub fn try_acquire(app_data_dir: &Path) -> io::Result<Option<Self>> {
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(app_data_dir.join(INSTANCE_LOCK_FILE_NAME))?;
+
+        if let Err(error) = file.try_lock_exclusive() {
+            return if error.raw_os_error() == fs4::lock_contended_error().raw_os_error() {
+                Ok(None)
+            } else {
+                Err(error)
+            };
+        }
+
+        // Process ID is only written for troubleshooting purposes, lock itself is what matters
+        file.set_len(0)?;
+        write!(file, "{}", process::id())?;
+
+        Ok(Some(Self { _file: file }))
+    }
+}
@@ -0,0 +1,133 @@
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+use std::io;
+use std::sync::Arc;
+use tracing::{Level, Metadata};
+use tracing_subscriber::fmt::MakeWriter;
+
+/// Single formatted log line
+#[derive(Debug, Clone)]
+pub struct LogLine {
+    /// Sequence number, increases monotonically with every new line
+    pub sequence: u64,
+    pub level: Level,
+    pub text: String,
+}
+
+#[derive(Debug, Default)]
+struct Lines {
+    lines: VecDeque<LogLine>,
+    next_sequence: u64,
+}
+
+/// Ring buffer that keeps up to `capacity` most recent log lines in memory
+#[derive(Debug)]
+pub struct LogBuffer {
+    lines: Mutex<Lines>,
+    capacity: usize,
+}
+
+impl LogBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            lines: Mutex::new(Lines {
+                lines: VecDeque::with_capacity(capacity),
+                next_sequence: 0,
+            }),
+            capacity,
+        }
+    }
+
+    /// Max number of lines stored in the buffer
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Lines that are still in the buffer with sequence number equal or larger than `sequence`
+    pub fn lines_since(&self, sequence: u64) -> Vec<LogLine> {
+        let lines = self.lines.lock();
+        let skip = lines
+            .lines
+            .front()
+            .map(|first_line| sequence.saturating_sub(first_line.sequence) as usize)
+            .unwrap_or_default();
+
+        lines.lines.iter().skip(skip).cloned().collect()
+    }
+
+    fn push(&self, level: Level, text: String) {
+        let mut lines = self.lines.lock();
+        if lines.lines.len() == self.capacity {
+            lines.lines.pop_front();
+        }
+        let sequence = lines.next_sequence;
+        lines.next_sequence += 1;
+        lines.lines.push_back(LogLine {
+            sequence,
+            level,
+            text,
+        });
+    }
+}
+
+/// Writer factory for `tracing_subscriber::fmt` layer that stores formatted events in
+/// [`LogBuffer`]
+#[derive(Debug, Clone)]
+pub struct LogBufferMakeWriter {
+    log_buffer: Arc<LogBuffer>,
+}
+
+impl LogBufferMakeWriter {
+    pub fn new(log_buffer: Arc<LogBuffer>) -> Self {
+        Self { log_buffer }
+    }
+}
+
+impl<'a> MakeWriter<'a> for LogBufferMakeWriter {
+    type Writer = LogBufferWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        LogBufferWriter {
+            log_buffer: Arc::clone(&self.log_buffer),
+            level: Level::INFO,
+            bytes: Vec::new(),
+        }
+    }
+
+    fn make_writer_for(&'a self, meta: &Metadata<'_>) -> Self::Writer {
+        LogBufferWriter {
+            log_buffer: Arc::clone(&self.log_buffer),
+            level: *meta.level(),
+            bytes: Vec::new(),
+        }
+    }
+}
+
+/// Collects one formatted event and pushes it into [`LogBuffer`] on drop
+pub struct LogBufferWriter {
+    log_buffer: Arc<LogBuffer>,
+    level: Level,
+    bytes: Vec<u8>,
+}
+
+impl io::Write for LogBufferWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.bytes.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for LogBufferWriter {
+    fn drop(&mut self) {
+        if self.bytes.is_empty() {
+            return;
+        }
+
+        let text = String::from_utf8_lossy(&self.bytes).trim_end().to_string();
+        self.log_buffer.push(self.level, text);
+    }
+}
@@ -0,0 +1,50 @@
+use crate::backend::config::LogLevel;
+use tracing::warn;
+use tracing_subscriber::filter::LevelFilter;
+use tracing_subscriber::{reload, EnvFilter, Registry};
+
+/// Reloadable log filter layer
+pub type LogFilterLayer = reload::Layer<EnvFilter, Registry>;
+
+/// Handle to change log filter at runtime
+#[derive(Debug, Clone)]
+pub struct LogFilterHandle {
+    handle: reload::Handle<EnvFilter, Registry>,
+}
+
+impl LogFilterHandle {
+    /// Create filter layer initialized from environment and corresponding handle
+    pub fn new() -> (LogFilterLayer, Self) {
+        let (layer, handle) = reload::Layer::new(new_env_filter(None));
+
+        (layer, Self { handle })
+    }
+
+    /// Change log level, `None` means `RUST_LOG` environment variable with fallback to `info`
+    pub fn set_log_level(&self, log_level: Option<LogLevel>) {
+        if let Err(error) = self.handle.reload(new_env_filter(log_level)) {
+            warn!(%error, ?log_level, "Failed to change log level");
+        }
+    }
+}
+
+fn new_env_filter(log_level: Option<LogLevel>) -> EnvFilter {
+    match log_level {
+        Some(log_level) => {
+            let level_filter = match log_level {
+                LogLevel::Error => LevelFilter::ERROR,
+                LogLevel::Warn => LevelFilter::WARN,
+                LogLevel::Info => LevelFilter::INFO,
+                LogLevel::Debug => LevelFilter::DEBUG,
+                LogLevel::Trace => LevelFilter::TRACE,
+            };
+
+            EnvFilter::builder()
+                .with_default_directive(level_filter.into())
+                .parse_lossy("")
+        }
+        None => EnvFilter::builder()
+            .with_default_directive(LevelFilter::INFO.into())
+            .from_env_lossy(),
+    }
+}
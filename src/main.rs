@@ -9,18 +9,32 @@
     variant_count
 )]
 
+mod app_dirs;
 mod backend;
 mod frontend;
-
-use crate::frontend::{App, AppInit, RunBackendResult, GLOBAL_CSS};
+mod headless;
+mod instance_lock;
+mod log_buffer;
+mod log_filter;
+mod validate;
+
+use crate::backend::config::Theme;
+use crate::frontend::{
+    apply_theme, show_already_running_notification, App, AppInit, RunBackendResult, APP_BROKER,
+    GLOBAL_CSS,
+};
+use crate::instance_lock::InstanceLock;
+use crate::log_buffer::{LogBuffer, LogBufferMakeWriter};
+use crate::log_filter::LogFilterHandle;
 use bytesize::ByteSize;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use duct::{cmd, Expression};
 use file_rotate::compression::Compression;
 use file_rotate::suffix::AppendCount;
 use file_rotate::{ContentLimit, FileRotate};
 use futures::channel::mpsc;
-use gtk::glib;
+use gtk::prelude::*;
+use gtk::{gio, glib};
 use relm4::prelude::*;
 use relm4::RELM_THREADS;
 use std::borrow::Cow;
@@ -29,6 +43,7 @@ use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
 use std::process::{ExitCode, Termination};
 use std::rc::Rc;
+use std::sync::Arc;
 use std::thread::available_parallelism;
 use std::time::{Duration, Instant};
 use std::{env, fs, io, process};
@@ -36,17 +51,20 @@ use subspace_farmer::utils::run_future_in_dedicated_thread;
 use subspace_proof_of_space::chia::ChiaTable;
 use subspace_proof_of_space::chia_legacy::ChiaTableLegacy;
 use tracing::{debug, error, info, warn};
-use tracing_subscriber::filter::LevelFilter;
 use tracing_subscriber::prelude::*;
-use tracing_subscriber::EnvFilter;
 
 #[global_allocator]
 static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
+const APPLICATION_ID: &str = "xyz.autonomys.space_acres";
 /// Number of log files to keep
 const LOG_FILE_LIMIT_COUNT: usize = 5;
 /// Size of one log file
 const LOG_FILE_LIMIT_SIZE: usize = ByteSize::mib(10).as_u64() as usize;
+/// Name of the log file in app data directory
+const LOG_FILE_NAME: &str = "space-acres.log";
+/// Number of most recent log lines kept in memory for log viewer
+const LOG_BUFFER_CAPACITY: usize = 10_000;
 const LOG_READ_BUFFER: usize = ByteSize::mib(1).as_u64() as usize;
 /// If `true`, this means supervisor will not be able to capture logs from child application and logger needs to be in
 /// the child process itself, while supervisor will not attempt to read stdout/stderr at all
@@ -102,9 +120,25 @@ impl AppStatusCode {
     }
 }
 
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Validate configuration file and print report, exits with non-zero code if configuration is
+    /// invalid
+    Validate {
+        /// Path to configuration file, the one used by the application is validated if not
+        /// specified
+        config: Option<PathBuf>,
+        /// Print report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
 #[derive(Debug, Parser)]
-#[clap(about, version)]
+#[clap(about, version, args_conflicts_with_subcommands = true)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
     /// Used for startup to minimize the window
     #[arg(long)]
     startup: bool,
@@ -114,6 +148,10 @@ struct Cli {
     /// Used by child process such that supervisor parent process can control it
     #[arg(long)]
     child_process: bool,
+    /// Run farming without GUI using existing configuration, logs are printed to stdout, stops
+    /// gracefully on SIGINT/SIGTERM
+    #[arg(long, alias = "no-gui")]
+    headless: bool,
     /// Show uninstall dialog to delete configuration and logs, typically called from installer
     /// during package uninstallation
     #[arg(long)]
@@ -126,6 +164,15 @@ struct Cli {
 
 impl Cli {
     fn run(self) -> ExitCode {
+        // Must happen before anything reads configuration or writes data
+        app_dirs::migrate_legacy_dirs();
+
+        if let Some(command) = self.command {
+            return match command {
+                Command::Validate { config, json } => validate::run(config, json),
+            };
+        }
+
         if self.uninstall {
             #[cfg(windows)]
             {
@@ -166,6 +213,8 @@ impl Cli {
             }
 
             ExitCode::SUCCESS
+        } else if self.headless {
+            self.headless()
         } else if self.child_process {
             ExitCode::from(self.app().into_status_code() as u8)
         } else {
@@ -175,6 +224,9 @@ impl Cli {
 
     fn app(self) -> AppStatusCode {
         let maybe_app_data_dir = Self::app_data_dir();
+        let log_buffer = Arc::new(LogBuffer::new(LOG_BUFFER_CAPACITY));
+        // Filter is shared by all layers and can be changed from the UI at runtime
+        let (log_filter_layer, log_filter_handle) = LogFilterHandle::new();
 
         {
             let layer = tracing_subscriber::fmt::layer()
@@ -185,27 +237,33 @@ impl Cli {
                 } else {
                     supports_color::on(supports_color::Stream::Stderr).is_some()
                 });
-            let filter = EnvFilter::builder()
-                .with_default_directive(LevelFilter::INFO.into())
-                .from_env_lossy();
+            let log_buffer_layer = tracing_subscriber::fmt::layer()
+                .with_ansi(false)
+                .with_writer(LogBufferMakeWriter::new(Arc::clone(&log_buffer)));
             if WINDOWS_SUBSYSTEM_WINDOWS {
                 if let Some(app_data_dir) = &maybe_app_data_dir {
                     let logger = std::sync::Mutex::new(Self::new_logger(app_data_dir));
                     let layer = layer.with_writer(logger);
 
                     tracing_subscriber::registry()
-                        .with(layer.with_filter(filter))
+                        .with(log_filter_layer)
+                        .with(layer)
+                        .with(log_buffer_layer)
                         .init();
                 } else {
                     tracing_subscriber::registry()
-                        .with(layer.with_filter(filter))
+                        .with(log_filter_layer)
+                        .with(layer)
+                        .with(log_buffer_layer)
                         .init();
                 }
                 #[cfg(windows)]
                 std::panic::set_hook(Box::new(tracing_panic::panic_hook));
             } else {
                 tracing_subscriber::registry()
-                    .with(layer.with_filter(filter))
+                    .with(log_filter_layer)
+                    .with(layer)
+                    .with(log_buffer_layer)
                     .init();
             }
         }
@@ -257,6 +315,22 @@ impl Cli {
             glib::LogWriterOutput::Handled
         });
 
+        // Running two instances against the same farms would corrupt them, lock is held until
+        // this process exits
+        let _instance_lock = match maybe_app_data_dir.as_deref().map(InstanceLock::try_acquire) {
+            Some(Ok(Some(instance_lock))) => Some(instance_lock),
+            Some(Ok(None)) => {
+                info!("Another instance is already running, exiting");
+                Self::activate_running_instance();
+                return AppStatusCode::Exit;
+            }
+            Some(Err(error)) => {
+                warn!(%error, "Failed to acquire instance lock");
+                None
+            }
+            None => None,
+        };
+
         // The default in `relm4` is `1`, set this back to Tokio's default
         RELM_THREADS
             .set(
@@ -266,28 +340,27 @@ impl Cli {
             )
             .expect("The first thing in the app, is not set; qed");
 
-        let app = RelmApp::new("xyz.autonomys.space_acres");
+        let app = RelmApp::new(APPLICATION_ID);
         let app = app.with_args({
             let mut args = self.gtk_arguments;
             // Application itself is expected as the first argument
             args.insert(0, env::args().next().expect("Guaranteed to exist; qed"));
             args
         });
+        let app = app.with_broker(&APP_BROKER);
 
         app.set_global_css(GLOBAL_CSS);
         relm4_icons::initialize_icons();
 
-        // Prefer dark theme in cross-platform way if environment is configured that way
-        if let Some(settings) = gtk::Settings::default() {
-            if matches!(dark_light::detect(), dark_light::Mode::Dark) {
-                settings.set_gtk_application_prefer_dark_theme(true);
-            }
-        }
+        // Follow system theme until configuration is loaded
+        apply_theme(Theme::System);
 
         let exit_status_code = Rc::new(Cell::new(AppStatusCode::Exit));
 
         app.run_async::<App>(AppInit {
             app_data_dir: maybe_app_data_dir,
+            log_buffer,
+            log_filter_handle,
             exit_status_code: Rc::clone(&exit_status_code),
             minimize_on_start: self.startup,
             crash_notification: self.after_crash,
@@ -330,6 +403,54 @@ impl Cli {
         exit_status_code
     }
 
+    fn headless(self) -> ExitCode {
+        let (log_filter_layer, log_filter_handle) = LogFilterHandle::new();
+        tracing_subscriber::registry()
+            .with(log_filter_layer)
+            .with(
+                tracing_subscriber::fmt::layer()
+                    .with_writer(io::stdout)
+                    // TODO: Workaround for https://github.com/tokio-rs/tracing/issues/2214, also
+                    //  on Windows terminal doesn't support the same colors as bash does
+                    .with_ansi(if cfg!(windows) {
+                        false
+                    } else {
+                        supports_color::on(supports_color::Stream::Stdout).is_some()
+                    }),
+            )
+            .init();
+
+        info!(
+            "Starting {} {} in headless mode",
+            env!("CARGO_PKG_NAME"),
+            env!("CARGO_PKG_VERSION")
+        );
+
+        let exit_code = headless::run(Self::app_data_dir().as_deref(), log_filter_handle);
+
+        info!(
+            "Exiting {} {}",
+            env!("CARGO_PKG_NAME"),
+            env!("CARGO_PKG_VERSION")
+        );
+        exit_code
+    }
+
+    /// Bring window of already running instance to the front if possible, otherwise let user know
+    /// that application is already running
+    fn activate_running_instance() {
+        let application = gtk::Application::builder()
+            .application_id(APPLICATION_ID)
+            .build();
+
+        if application.register(gio::Cancellable::NONE).is_ok() && application.is_remote() {
+            // Activation is forwarded to the primary instance, which presents its window
+            let _exit_code = application.run_with_args::<&str>(&[]);
+        } else {
+            show_already_running_notification();
+        }
+    }
+
     fn supervisor(mut self) -> io::Result<()> {
         let maybe_app_data_dir = Self::app_data_dir();
 
@@ -469,27 +590,25 @@ impl Cli {
     }
 
     fn app_data_dir() -> Option<PathBuf> {
-        dirs::data_local_dir()
-            .map(|data_local_dir| data_local_dir.join(env!("CARGO_PKG_NAME")))
-            .and_then(|app_data_dir| {
-                if !app_data_dir.exists() {
-                    if let Err(error) = fs::create_dir_all(&app_data_dir) {
-                        eprintln!(
-                            "App data directory \"{}\" doesn't exist and can't be created: {}",
-                            app_data_dir.display(),
-                            error
-                        );
-                        return None;
-                    }
+        app_dirs::data_dir().and_then(|app_data_dir| {
+            if !app_data_dir.exists() {
+                if let Err(error) = fs::create_dir_all(&app_data_dir) {
+                    eprintln!(
+                        "App data directory \"{}\" doesn't exist and can't be created: {}",
+                        app_data_dir.display(),
+                        error
+                    );
+                    return None;
                 }
+            }
 
-                Some(app_data_dir)
-            })
+            Some(app_data_dir)
+        })
     }
 
     fn new_logger(app_data_dir: &Path) -> FileRotate<AppendCount> {
         FileRotate::new(
-            app_data_dir.join("space-acres.log"),
+            app_data_dir.join(LOG_FILE_NAME),
             AppendCount::new(LOG_FILE_LIMIT_COUNT),
             ContentLimit::Bytes(LOG_FILE_LIMIT_SIZE),
             Compression::OnRotate(0),
@@ -0,0 +1,148 @@
+use crate::backend::config::{validate_raw_config, ConfigError, RawConfig};
+use serde::Serialize;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// Result of configuration file validation
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ValidationReport {
+    config_path: PathBuf,
+    valid: bool,
+    errors: Vec<ValidationError>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ValidationError {
+    /// Stable identifier of the kind of error, for scripts
+    kind: &'static str,
+    /// Human-readable description of the error
+    message: String,
+}
+
+impl From<&ConfigError> for ValidationError {
+    fn from(error: &ConfigError) -> Self {
+        let kind = match error {
+            ConfigError::InvalidSs58RewardAddress { .. } => "invalidRewardAddress",
+            ConfigError::InvalidPath { .. } => "invalidPath",
+            ConfigError::PathError { .. } => "pathError",
+            ConfigError::InvalidSizeFormat { .. } => "invalidSizeFormat",
+            ConfigError::InvalidNodeRpcUrl { .. } => "invalidNodeRpcUrl",
+            ConfigError::InvalidPort { .. } => "invalidPort",
+            ConfigError::InvalidExternalAddress { .. } => "invalidExternalAddress",
+            ConfigError::InvalidMetricsAddress { .. } => "invalidMetricsAddress",
+            ConfigError::InvalidNodeRpcAddress { .. } => "invalidNodeRpcAddress",
+            ConfigError::InvalidPlottingThreads { .. } => "invalidPlottingThreads",
+            ConfigError::InvalidSectorEncodingConcurrency { .. } => {
+                "invalidSectorEncodingConcurrency"
+            }
+            ConfigError::InvalidAuditingThreads { .. } => "invalidAuditingThreads",
+            ConfigError::TooFewAuditingThreads { .. } => "tooFewAuditingThreads",
+            ConfigError::PieceCacheTooLarge { .. } => "pieceCacheTooLarge",
+            ConfigError::NoFarms => "noFarms",
+            ConfigError::FarmTooSmall { .. } => "farmTooSmall",
+            ConfigError::DuplicateFarmPath { .. } => "duplicateFarmPath",
+            ConfigError::FarmPathConflict { .. } => "farmPathConflict",
+        };
+
+        Self {
+            kind,
+            message: error.to_string(),
+        }
+    }
+}
+
+/// Validate configuration file (the one application uses if not specified) and print report,
+/// exit code is non-zero if configuration is invalid or can't be read
+pub fn run(config_path: Option<PathBuf>, json: bool) -> ExitCode {
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(error) => {
+            eprintln!("Failed to create Tokio runtime: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let report = runtime.block_on(async {
+        let config_path = match config_path {
+            Some(config_path) => config_path,
+            None => RawConfig::default_path()
+                .await
+                .map_err(|error| error.to_string())?,
+        };
+
+        let raw_config = match RawConfig::read_from_path(&config_path).await {
+            Ok(Some(raw_config)) => raw_config,
+            Ok(None) => {
+                return Err(format!(
+                    "Configuration file \"{}\" doesn't exist",
+                    config_path.display()
+                ));
+            }
+            Err(error) => {
+                return Err(format!(
+                    "Failed to read configuration file \"{}\": {error}",
+                    config_path.display()
+                ));
+            }
+        };
+
+        let errors = validate_raw_config(&raw_config)
+            .await
+            .iter()
+            .map(ValidationError::from)
+            .collect::<Vec<_>>();
+
+        Ok(ValidationReport {
+            config_path,
+            valid: errors.is_empty(),
+            errors,
+        })
+    });
+
+    let report = match report {
+        Ok(report) => report,
+        Err(error) => {
+            if json {
+                println!(
+                    "{}",
+                    serde_json::json!({
+                        "valid": false,
+                        "error": error,
+                    })
+                );
+            } else {
+                eprintln!("{error}");
+            }
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&report)
+                .expect("Validation report serialization is infallible; qed")
+        );
+    } else if report.valid {
+        println!(
+            "Configuration file \"{}\" is valid",
+            report.config_path.display()
+        );
+    } else {
+        println!(
+            "Configuration file \"{}\" is invalid:",
+            report.config_path.display()
+        );
+        for error in &report.errors {
+            println!("  - {}", error.message);
+        }
+    }
+
+    if report.valid {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}